@@ -1,3 +1,4 @@
+pub mod keywords;
 mod shared;
 pub mod system_verilog;
 pub mod vhdl;
@@ -5,13 +6,14 @@ pub mod vhdl;
 pub use system_verilog::VerilogCodegenBackend;
 pub use vhdl::VHDLCodegenBackend;
 
+use crate::linker::GlobalUUID;
+use crate::prelude::FileUUID;
 use crate::{InstantiatedModule, Linker, Module};
 
 use std::{
-    fs::{self, File},
-    io::Write,
-    path::PathBuf,
-    rc::Rc,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
 };
 
 /// Implemented for SystemVerilog [self::system_verilog] or VHDL [self::vhdl]
@@ -26,7 +28,12 @@ pub trait CodeGenBackend {
         use_latency: bool,
     ) -> String;
 
-    fn make_output_file(&self, name: &str) -> File {
+    /// Writes the generated code to `[output_dir_name]/[name].[file_extension]`.
+    ///
+    /// The file is only rewritten if its contents changed, such that build systems tracking timestamps don't rebuild needlessly.
+    ///
+    /// Returns the path of the file
+    fn write_output_file(&self, name: &str, code: &str) -> PathBuf {
         let mut path = PathBuf::with_capacity(
             name.len() + self.output_dir_name().len() + self.file_extension().len() + 2,
         );
@@ -34,15 +41,16 @@ pub trait CodeGenBackend {
         fs::create_dir_all(&path).unwrap();
         path.push(name);
         path.set_extension(self.file_extension());
-        let mut file = File::create(path).unwrap();
 
-        file.write_fmt(format_args!(
-            "// DO NOT EDIT THIS FILE\n// This file was generated with SUS Compiler {}\n",
+        let file_text = format!(
+            "// DO NOT EDIT THIS FILE\n// This file was generated with SUS Compiler {}\n{code}",
             std::env!("CARGO_PKG_VERSION")
-        ))
-        .unwrap();
+        );
 
-        file
+        if !fs::read(&path).is_ok_and(|existing| existing == file_text.as_bytes()) {
+            fs::write(&path, file_text).unwrap();
+        }
+        path
     }
 
     fn codegen_instance(
@@ -50,31 +58,59 @@ pub trait CodeGenBackend {
         inst: &InstantiatedModule,
         md: &Module,
         linker: &Linker,
-        out_file: &mut File,
+        out_file: &mut String,
     ) {
         let inst_name = &inst.name;
         if inst.errors.did_error {
-            println!("Instantiating error: {inst_name}");
+            log::info!("Instantiating error: {inst_name}");
             return; // Continue
         }
-        println!("Instantiating success: {inst_name}");
+        log::info!("Instantiating success: {inst_name}");
         let code = self.codegen(md, inst, linker, true); // hardcode use_latency = true for now. Maybe forever, we'll see
-        write!(out_file, "{code}").unwrap();
+        out_file.push_str(&code);
+    }
+
+    /// The code of all instances of `md`, in a deterministic order
+    fn codegen_module(&self, md: &Module, linker: &Linker) -> String {
+        let mut code = String::new();
+        for inst in sorted_instances(md) {
+            self.codegen_instance(inst.as_ref(), md, linker, &mut code)
+        }
+        code
     }
 
-    fn codegen_to_file(&self, md: &Module, linker: &Linker) {
-        let mut out_file = self.make_output_file(&md.link_info.name);
-        md.instantiations.for_each_instance(|_template_args, inst| {
-            self.codegen_instance(inst.as_ref(), md, linker, &mut out_file)
-        });
+    /// Returns the path of the generated file.
+    ///
+    /// An `override` module shares its name with the module it shadows, so its file also gets the name of the source file it's in
+    fn codegen_to_file(&self, md: &Module, linker: &Linker) -> PathBuf {
+        let code = self.codegen_module(md, linker);
+        let name = &md.link_info.name;
+        if md.link_info.is_override {
+            let source_file = source_file_stem(&linker.files[md.link_info.file].file_identifier);
+            self.write_output_file(&format!("{name}_{source_file}"), &code)
+        } else {
+            self.write_output_file(name, &code)
+        }
     }
 
-    fn codegen_with_dependencies(&self, linker: &Linker, md: &Module, file_name: &str) {
-        let mut out_file = self.make_output_file(file_name);
-        let mut top_level_instances: Vec<Rc<InstantiatedModule>> = Vec::new();
-        md.instantiations.for_each_instance(|_template_args, inst| {
-            top_level_instances.push(inst.clone());
-        });
+    /// All modules defined in `file`, in source order, written to one file named after the source file.
+    ///
+    /// Returns the path of the generated file
+    fn codegen_source_file_to_file(&self, file: FileUUID, linker: &Linker) -> PathBuf {
+        let file_data = &linker.files[file];
+        let mut code = String::new();
+        for global in &file_data.associated_values {
+            if let &GlobalUUID::Module(md_id) = global {
+                code.push_str(&self.codegen_module(&linker.modules[md_id], linker));
+            }
+        }
+        self.write_output_file(&source_file_stem(&file_data.file_identifier), &code)
+    }
+
+    /// Returns the path of the generated file
+    fn codegen_with_dependencies(&self, linker: &Linker, md: &Module, file_name: &str) -> PathBuf {
+        let mut out_file = String::new();
+        let top_level_instances = sorted_instances(md);
         let mut to_process_queue: Vec<(&InstantiatedModule, &Module)> = top_level_instances
             .iter()
             .map(|v| (v.as_ref(), md))
@@ -104,5 +140,24 @@ pub trait CodeGenBackend {
 
             cur_idx += 1;
         }
+
+        self.write_output_file(file_name, &out_file)
     }
 }
+
+/// The instances of a module, sorted by name because the instantiation cache is unordered
+pub fn sorted_instances(md: &Module) -> Vec<Arc<InstantiatedModule>> {
+    let mut instances = Vec::new();
+    md.instantiations
+        .for_each_instance(|_template_args, inst| instances.push(inst.clone()));
+    instances.sort_by(|a, b| a.name.cmp(&b.name));
+    instances
+}
+
+/// `dir/file.sus` becomes `file`
+fn source_file_stem(file_identifier: &str) -> String {
+    Path::new(file_identifier)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
@@ -1,18 +1,27 @@
 use std::borrow::Cow;
 use std::ops::Deref;
 
+use num::bigint::Sign;
+use num::{BigInt, ToPrimitive};
+
 use crate::linker::{IsExtern, LinkInfo};
 use crate::prelude::*;
 
-use crate::flattening::{DeclarationKind, Instruction, Module, Port};
+use crate::flattening::{
+    AssertionKind, DeclarationKind, ExpressionSource, Instruction, Module, Port, WireReferenceRoot,
+};
 use crate::instantiation::{
-    InstantiatedModule, RealWire, RealWireDataSource, RealWirePathElem, CALCULATE_LATENCY_LATER,
+    InstantiatedModule, InstantiatedPort, RealWire, RealWireDataSource, RealWirePathElem,
+    SubModuleOrWire, CALCULATE_LATENCY_LATER,
 };
-use crate::typing::template::TVec;
-use crate::{typing::concrete_type::ConcreteType, value::Value};
+use crate::typing::abstract_type::DomainType;
+use crate::typing::concrete_type::ConcreteType;
+use crate::typing::template::{GenerativeParameterKind, ParameterKind, TVec};
+use crate::value::{IntegerLiteral, Value};
 
 use super::shared::*;
 use std::fmt::Write;
+use sus_proc_macro::get_builtin_const;
 
 #[derive(Debug)]
 pub struct VerilogCodegenBackend;
@@ -35,6 +44,20 @@ impl super::CodeGenBackend for VerilogCodegenBackend {
     }
 }
 
+/// The `--annotate-ports` comment of a port, like `input [7:0] width=8 latency=0`. The bit range is left out if the width isn't known
+pub fn port_annotation(port: &InstantiatedPort) -> String {
+    let input_or_output = if port.is_input { "input" } else { "output" };
+    let latency = port.absolute_latency;
+    match port.typ.sizeof().and_then(|sz| sz.to_u64()) {
+        Some(width) if width > 0 => {
+            let high = width - 1;
+            format!("{input_or_output} [{high}:0] width={width} latency={latency}")
+        }
+        Some(width) => format!("{input_or_output} width={width} latency={latency}"),
+        None => format!("{input_or_output} width=? latency={latency}"),
+    }
+}
+
 /// Creates the Verilog variable declaration for tbis variable.
 ///
 /// IE for `int[15] myVar` it creates `[31:0] myVar[14:0]`
@@ -80,6 +103,7 @@ impl<'g> CodeGenerationContext<'g> {
         match &wire.source {
             RealWireDataSource::Constant {
                 value: Value::Bool(_) | Value::Integer(_),
+                width: _,
             } => true,
             _other => false,
         }
@@ -88,28 +112,39 @@ impl<'g> CodeGenerationContext<'g> {
     fn operation_to_string(&self, wire: &'g RealWire) -> Cow<'g, str> {
         assert!(self.can_inline(wire));
         match &wire.source {
-            RealWireDataSource::Constant { value } => value.inline_constant_to_string(),
+            RealWireDataSource::Constant {
+                value: Value::Integer(v),
+                width: Some(width),
+            } => Cow::Owned(format!("{width}'d{v}")),
+            RealWireDataSource::Constant { value, width: None } => {
+                value.inline_constant_to_string()
+            }
             _other => unreachable!(),
         }
     }
 
-    fn wire_name(&self, wire_id: WireID, requested_latency: i64) -> Cow<'g, str> {
+    /// The name of `wire_id` at the latency at which `reader` reads it
+    fn wire_name(&self, wire_id: WireID, reader: &RealWire) -> Cow<'g, str> {
         let wire = &self.instance.wires[wire_id];
         if self.can_inline(wire) {
             self.operation_to_string(wire)
+        } else if wire.domain != reader.domain {
+            // An unsafe clock domain crossing. Latencies of different domains are unrelated, so no latency registers are added
+            wire_name_self_latency(wire, self.use_latency)
         } else {
-            wire_name_with_latency(wire, requested_latency, self.use_latency)
+            wire_name_with_latency(wire, reader.absolute_latency, self.use_latency)
         }
     }
 
-    fn wire_ref_path_to_string(&self, path: &[RealWirePathElem], absolute_latency: i64) -> String {
+    fn wire_ref_path_to_string(&self, path: &[RealWirePathElem], reader: &RealWire) -> String {
         let mut result = String::new();
         for path_elem in path {
             result.push_str(&match path_elem {
                 RealWirePathElem::ArrayAccess { span: _, idx_wire } => {
-                    let idx_wire_name = self.wire_name(*idx_wire, absolute_latency);
+                    let idx_wire_name = self.wire_name(*idx_wire, reader);
                     format!("[{idx_wire_name}]")
                 }
+                RealWirePathElem::ArraySlice { span: _, high, low } => format!("[{high}:{low}]"),
             });
         }
         result
@@ -130,7 +165,7 @@ impl<'g> CodeGenerationContext<'g> {
 
                 let var_decl = typ_to_declaration(&w.typ, &to);
 
-                let clk_name = self.md.get_clock_name();
+                let clk_name = self.md.get_clock_name(w.domain);
                 writeln!(
                     self.program_text,
                     "/*latency*/ logic {var_decl}; always_ff @(posedge {clk_name}) begin {to} <= {from}; end"
@@ -160,11 +195,21 @@ impl<'g> CodeGenerationContext<'g> {
         });
         match self.md.link_info.is_extern {
             IsExtern::Normal => {
+                self.write_default_nettype("none");
                 self.write_module_signature();
                 self.write_wire_declarations();
                 self.write_submodules();
                 self.write_multiplexers();
+                if self.linker.options.emit_assertions {
+                    self.program_text.push_str(&generative_assertions(
+                        self.md,
+                        self.instance,
+                        self.linker,
+                    ));
+                    self.write_runtime_assertions();
+                }
                 self.write_endmodule();
+                self.write_default_nettype("wire");
             }
             IsExtern::Extern => {
                 // Do nothing, it's provided externally
@@ -174,33 +219,78 @@ impl<'g> CodeGenerationContext<'g> {
                 });
             }
             IsExtern::Builtin => {
+                self.write_default_nettype("none");
                 self.write_module_signature();
                 self.write_builtins();
                 self.write_endmodule();
+                self.write_default_nettype("wire");
             }
         }
     }
 
+    /// For `--emit-assertions`: The runtime `assert(cond)`s and `assume(cond)`s as concurrent SystemVerilog assertions, checked at every clock edge
+    fn write_runtime_assertions(&mut self) {
+        if self.instance.assertions.is_empty() {
+            return;
+        }
+        let file_text = &self.linker.files[self.md.link_info.file].file_text;
+        self.program_text.push_str("\n// Runtime assertions\n");
+        for assertion in &self.instance.assertions {
+            let w = &self.instance.wires[assertion.wire];
+            let clk_name = self.md.get_clock_name(w.domain);
+            let wire_name = self.wire_name(assertion.wire, w);
+            let Instruction::Assertion(source) =
+                &self.md.link_info.instructions[assertion.original_instruction]
+            else {
+                unreachable!()
+            };
+            let keyword = source.kind.keyword();
+            let statement_text = escape_string_literal(&file_text[source.span]);
+            writeln!(
+                self.program_text,
+                "{keyword} property (@(posedge {clk_name}) {wire_name}) else $error(\"{statement_text} failed\");"
+            )
+            .unwrap();
+        }
+    }
+
+    /// For `--strict-sv`. The module is closed with `wire` again, the SystemVerilog default, so it doesn't leak into other files that are compiled after it
+    fn write_default_nettype(&mut self, net_type: &str) {
+        if self.linker.options.strict_sv {
+            writeln!(self.program_text, "`default_nettype {net_type}").unwrap();
+        }
+    }
+
     fn write_module_signature(&mut self) {
         // First output the interface of the module
-        let clk_name = self.md.get_clock_name();
-        write!(
-            self.program_text,
-            "module {}(\n\tinput {clk_name}",
-            &self.instance.mangled_name
-        )
-        .unwrap();
+        write!(self.program_text, "module {}(", &self.instance.mangled_name).unwrap();
+        // Each port with its `--annotate-ports` comment, which goes on its own line above the port
+        let mut ports: Vec<(String, Option<String>)> = Vec::new();
+        for (_id, domain) in &self.md.domains {
+            ports.push((format!("input wire {}", domain.name), None));
+        }
         for (_id, port) in self.instance.interface_ports.iter_valids() {
             let port_wire = &self.instance.wires[port.wire];
             let input_or_output = if port.is_input { "input" } else { "output" };
             let wire_doc = port_wire.source.wire_or_reg();
             let wire_name = wire_name_self_latency(port_wire, self.use_latency);
             let wire_decl = typ_to_declaration(&port_wire.typ, &wire_name);
-            write!(
-                self.program_text,
-                ",\n\t{input_or_output} {wire_doc} {wire_decl}"
-            )
-            .unwrap();
+            let annotation = self
+                .linker
+                .options
+                .annotate_ports
+                .then(|| port_annotation(port));
+            ports.push((
+                format!("{input_or_output} {wire_doc} {wire_decl}"),
+                annotation,
+            ));
+        }
+        for (idx, (port_decl, annotation)) in ports.iter().enumerate() {
+            if let Some(annotation) = annotation {
+                write!(self.program_text, "\n\t// {annotation}").unwrap();
+            }
+            let separator = if idx + 1 < ports.len() { "," } else { "" };
+            write!(self.program_text, "\n\t{port_decl}{separator}").unwrap();
         }
         write!(self.program_text, "\n);\n\n").unwrap();
 
@@ -252,8 +342,8 @@ impl<'g> CodeGenerationContext<'g> {
 
             match &w.source {
                 RealWireDataSource::Select { root, path } => {
-                    let wire_name = self.wire_name(*root, w.absolute_latency);
-                    let path = self.wire_ref_path_to_string(path, w.absolute_latency);
+                    let wire_name = self.wire_name(*root, w);
+                    let path = self.wire_ref_path_to_string(path, w);
                     writeln!(self.program_text, " = {wire_name}{path};").unwrap();
                 }
                 RealWireDataSource::UnaryOp { op, right } => {
@@ -261,7 +351,7 @@ impl<'g> CodeGenerationContext<'g> {
                         self.program_text,
                         " = {}{};",
                         op.op_text(),
-                        self.wire_name(*right, w.absolute_latency)
+                        self.wire_name(*right, w)
                     )
                     .unwrap();
                 }
@@ -269,13 +359,43 @@ impl<'g> CodeGenerationContext<'g> {
                     writeln!(
                         self.program_text,
                         " = {} {} {};",
-                        self.wire_name(*left, w.absolute_latency),
+                        self.wire_name(*left, w),
                         op.op_text(),
-                        self.wire_name(*right, w.absolute_latency)
+                        self.wire_name(*right, w)
                     )
                     .unwrap();
                 }
-                RealWireDataSource::Constant { value } => {
+                RealWireDataSource::Concatenation { elements } => {
+                    // Zero-width elements are not allowed in SystemVerilog concatenations
+                    let element_names: Vec<_> = elements
+                        .iter()
+                        .filter(|elem| {
+                            self.instance.wires[**elem].typ.sizeof() != Some(BigInt::ZERO)
+                        })
+                        .map(|elem| self.wire_name(*elem, w))
+                        .collect();
+                    if element_names.is_empty() {
+                        writeln!(self.program_text, ";").unwrap();
+                    } else {
+                        writeln!(self.program_text, " = {{{}}};", element_names.join(", "))
+                            .unwrap();
+                    }
+                }
+                RealWireDataSource::Conditional {
+                    condition,
+                    if_true,
+                    if_false,
+                } => {
+                    writeln!(
+                        self.program_text,
+                        " = {} ? {} : {};",
+                        self.wire_name(*condition, w),
+                        self.wire_name(*if_true, w),
+                        self.wire_name(*if_false, w)
+                    )
+                    .unwrap();
+                }
+                RealWireDataSource::Constant { value, width: _ } => {
                     // Trivial constants (bools & ints) should have been inlined already
                     // So appearences of this are always arrays or other compound types
                     writeln!(self.program_text, ";").unwrap();
@@ -300,7 +420,6 @@ impl<'g> CodeGenerationContext<'g> {
     }
 
     fn write_submodules(&mut self) {
-        let parent_clk_name = self.md.get_clock_name();
         for (_id, sm) in &self.instance.submodules {
             let sm_md = &self.linker.modules[sm.module_uuid];
             let sm_inst: &InstantiatedModule = sm
@@ -313,13 +432,29 @@ impl<'g> CodeGenerationContext<'g> {
                 self.program_text.write_str(&sm_inst.mangled_name).unwrap();
             };
             let sm_name = &sm.name;
-            let submodule_clk_name = sm_md.get_clock_name();
             writeln!(self.program_text, " {sm_name}(").unwrap();
-            write!(
-                self.program_text,
-                "\t.{submodule_clk_name}({parent_clk_name})"
-            )
-            .unwrap();
+            // Each clock of the submodule is connected to the clock of the domain it is used in here
+            let local_interface_domains = &self.md.link_info.instructions[sm.original_instruction]
+                .unwrap_submodule()
+                .local_interface_domains;
+            let mut is_first_port = true;
+            for (sm_domain_id, sm_domain) in &sm_md.domains {
+                let parent_clk_name = match local_interface_domains[sm_domain_id] {
+                    DomainType::Physical(parent_domain) => self.md.get_clock_name(parent_domain),
+                    // Typechecking gives domains of the submodule that aren't used here a fresh clock of this module, so these are all connected
+                    DomainType::Generative | DomainType::Unknown(_) => {
+                        unreachable!("Submodule domains are always physical after typechecking")
+                    }
+                };
+                let separator = if is_first_port { "" } else { ",\n" };
+                is_first_port = false;
+                write!(
+                    self.program_text,
+                    "{separator}\t.{}({parent_clk_name})",
+                    sm_domain.name
+                )
+                .unwrap();
+            }
             for (port_id, iport) in sm_inst.interface_ports.iter_valids() {
                 let port_name =
                     wire_name_self_latency(&sm_inst.wires[iport.wire], self.use_latency);
@@ -375,7 +510,7 @@ impl<'g> CodeGenerationContext<'g> {
                 RealWireDataSource::Multiplexer { is_state, sources } => {
                     let output_name = wire_name_self_latency(w, self.use_latency);
                     let arrow_str = if is_state.is_some() {
-                        let clk_name = self.md.get_clock_name();
+                        let clk_name = self.md.get_clock_name(w.domain);
                         writeln!(self.program_text, "always_ff @(posedge {clk_name}) begin")
                             .unwrap();
                         "<="
@@ -388,11 +523,11 @@ impl<'g> CodeGenerationContext<'g> {
                     };
 
                     for s in sources {
-                        let path = self.wire_ref_path_to_string(&s.to_path, w.absolute_latency);
-                        let from_name = self.wire_name(s.from, w.absolute_latency);
+                        let path = self.wire_ref_path_to_string(&s.to_path, w);
+                        let from_name = self.wire_name(s.from, w);
                         self.program_text.write_char('\t').unwrap();
                         for cond in s.condition.iter() {
-                            let cond_name = self.wire_name(cond.condition_wire, w.absolute_latency);
+                            let cond_name = self.wire_name(cond.condition_wire, w);
                             let invert = if cond.inverse { "!" } else { "" };
                             write!(self.program_text, "if({invert}{cond_name}) ").unwrap();
                         }
@@ -412,7 +547,13 @@ impl<'g> CodeGenerationContext<'g> {
                     left: _,
                     right: _,
                 } => {}
-                RealWireDataSource::Constant { value: _ } => {}
+                RealWireDataSource::Concatenation { elements: _ } => {}
+                RealWireDataSource::Conditional {
+                    condition: _,
+                    if_true: _,
+                    if_false: _,
+                } => {}
+                RealWireDataSource::Constant { .. } => {}
             }
         }
     }
@@ -472,7 +613,7 @@ impl<'g> CodeGenerationContext<'g> {
 }
 
 impl Value {
-    fn inline_constant_to_string(&self) -> Cow<str> {
+    fn inline_constant_to_string(&self) -> Cow<'_, str> {
         match self {
             Value::Bool(b) => Cow::Borrowed(if *b { "1'b1" } else { "1'b0" }),
             Value::Integer(v) => Cow::Owned(v.to_string()),
@@ -510,6 +651,163 @@ impl RealWireDataSource {
     }
 }
 
+/// For `--emit-assertions`: The generative `assert #(C: ...)`s and `assert(cond)`s of the module as SystemVerilog `initial assert`s.
+///
+/// The template parameters are declared as `localparam`s, so the conditions can refer to them by name. All other generative values are replaced by their value.
+/// Asserts inside generative `if` or `for` blocks are left out, as they don't hold for the whole module.
+pub fn generative_assertions(
+    md: &Module,
+    instance: &InstantiatedModule,
+    linker: &Linker,
+) -> String {
+    let file_text = &linker.files[md.link_info.file].file_text;
+    let instructions = &md.link_info.instructions;
+
+    let mut in_control_flow: FlatAlloc<bool, FlatIDMarker> = instructions.map(|_| false);
+    for (_, instr) in instructions {
+        let body = match instr {
+            Instruction::IfStatement(stm) => FlatIDRange::new(stm.then_start, stm.else_end),
+            Instruction::ForStatement(stm) => stm.loop_body,
+            _ => continue,
+        };
+        for id in body {
+            in_control_flow[id] = true;
+        }
+    }
+
+    let mut assertions = String::new();
+    for (id, instr) in instructions {
+        if in_control_flow[id] {
+            continue;
+        }
+        let (condition_id, condition_span) = match instr {
+            Instruction::Expression(expr) => {
+                let ExpressionSource::WireRef(wr) = &expr.source else {
+                    continue;
+                };
+                let WireReferenceRoot::NamedConstant(cst) = &wr.root else {
+                    continue;
+                };
+                if cst.id != get_builtin_const!("assert") {
+                    continue;
+                }
+                let Some(condition) = cst.get_first_template_argument() else {
+                    continue;
+                };
+                (condition.kind.unwrap_value(), condition.value_span)
+            }
+            // Runtime ones are written by [CodeGenerationContext::write_runtime_assertions]
+            Instruction::Assertion(assertion)
+                if assertion.kind == AssertionKind::Assert
+                    && matches!(
+                        instance.generation_state[assertion.condition],
+                        SubModuleOrWire::CompileTimeValue(_)
+                    ) =>
+            {
+                let condition_expr = instructions[assertion.condition].unwrap_expression();
+                (assertion.condition, condition_expr.span)
+            }
+            _ => continue,
+        };
+        let condition_text = escape_string_literal(&file_text[condition_span]);
+        writeln!(
+            assertions,
+            "initial assert({}) else $error(\"Assertion failed: {condition_text}\");",
+            assertion_expression(md, instance, condition_id)
+        )
+        .unwrap();
+    }
+    if assertions.is_empty() {
+        return assertions;
+    }
+
+    let mut result = String::from("\n// Generative assertions\n");
+    for (_, param) in &md.link_info.template_parameters {
+        let ParameterKind::Generative(GenerativeParameterKind {
+            declaration_instruction,
+            ..
+        }) = &param.kind
+        else {
+            continue;
+        };
+        let (param_typ, value) = match &instance.generation_state[*declaration_instruction] {
+            SubModuleOrWire::CompileTimeValue(Value::Integer(v)) => integer_localparam(v),
+            SubModuleOrWire::CompileTimeValue(v @ Value::Bool(_)) => {
+                ("bit".to_owned(), v.inline_constant_to_string().into_owned())
+            }
+            _ => continue,
+        };
+        writeln!(result, "localparam {param_typ} {} = {value};", param.name).unwrap();
+    }
+    result.push_str(&assertions);
+    result
+}
+
+/// The type and value of a `localparam` for an integer template parameter. `int` is only 32 bits, so larger values get a signed vector that fits them, with a literal of that size
+fn integer_localparam(value: &BigInt) -> (String, String) {
+    if let Some(small) = value.to_i32() {
+        return ("int".to_owned(), small.to_string());
+    }
+    // One more bit for the sign
+    let width = value.bits() + 1;
+    let sign = if value.sign() == Sign::Minus { "-" } else { "" };
+    (
+        format!("logic signed [{}:0]", width - 1),
+        format!("{sign}{width}'sd{}", value.magnitude()),
+    )
+}
+
+fn escape_string_literal(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// See [generative_assertions]. Template parameters are referred to by name, all other subexpressions are written as their value.
+fn assertion_expression(md: &Module, instance: &InstantiatedModule, expr_id: FlatID) -> String {
+    let is_scalar = |id: FlatID| {
+        matches!(
+            &instance.generation_state[id],
+            SubModuleOrWire::CompileTimeValue(Value::Integer(_) | Value::Bool(_))
+        )
+    };
+    let expr = md.link_info.instructions[expr_id].unwrap_expression();
+    match &expr.source {
+        ExpressionSource::WireRef(wr) if wr.path.is_empty() && is_scalar(expr_id) => {
+            if let WireReferenceRoot::LocalDecl(decl_id, _) = &wr.root {
+                let decl = md.link_info.instructions[*decl_id].unwrap_declaration();
+                if let DeclarationKind::GenerativeInput(_) = decl.decl_kind {
+                    return decl.name.clone();
+                }
+            }
+        }
+        ExpressionSource::UnaryOp { op, right } if is_scalar(*right) => {
+            return format!(
+                "({}{})",
+                op.op_text(),
+                assertion_expression(md, instance, *right)
+            );
+        }
+        ExpressionSource::BinaryOp { op, left, right } if is_scalar(*left) && is_scalar(*right) => {
+            return format!(
+                "({} {} {})",
+                assertion_expression(md, instance, *left),
+                op.op_text(),
+                assertion_expression(md, instance, *right)
+            );
+        }
+        ExpressionSource::Literal(IntegerLiteral {
+            value,
+            width: Some(width),
+        }) => {
+            return format!("{width}'d{value}");
+        }
+        _ => {}
+    }
+    instance.generation_state[expr_id]
+        .unwrap_generation_value()
+        .inline_constant_to_string()
+        .into_owned()
+}
+
 fn gen_verilog_code(
     md: &Module,
     instance: &InstantiatedModule,
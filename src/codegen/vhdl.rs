@@ -86,17 +86,25 @@ impl<Stream: std::fmt::Write> CodeGenerationContext<'_, '_, Stream> {
 
     fn write_entity(&mut self, commented_out: bool) {
         let comment_text = if commented_out { "-- " } else { "" };
-        let instance_name = &self.instance.name;
+        let instance_name = &self.instance.mangled_name;
 
         let mut it = self.instance.interface_ports.iter_valids().peekable();
-        let end = if it.peek().is_some() { ";" } else { "" };
-        let clk_name = self.md.get_clock_name();
         write!(
             self.program_text,
-            "{comment_text}entity {} is (\n{comment_text}    port (\n        {clk_name} : in std_logic{end}\n",
+            "{comment_text}entity {} is (\n{comment_text}    port (\n",
             instance_name
         )
         .unwrap();
+        let mut clocks = self.md.domains.iter().peekable();
+        while let Some((_, domain)) = clocks.next() {
+            let clk_name = &domain.name;
+            let end = if clocks.peek().is_some() || it.peek().is_some() {
+                ";"
+            } else {
+                ""
+            };
+            writeln!(self.program_text, "        {clk_name} : in std_logic{end}").unwrap();
+        }
 
         while let Some((_, port)) = it.next() {
             let port_wire = &self.instance.wires[port.wire];
@@ -119,7 +127,7 @@ impl<Stream: std::fmt::Write> CodeGenerationContext<'_, '_, Stream> {
     }
 
     fn write_architecture(&mut self) {
-        let instance_name = &self.instance.name;
+        let instance_name = &self.instance.mangled_name;
         writeln!(
             &mut self.program_text,
             "architecture Behavioral of {instance_name} is"
@@ -1,25 +1,48 @@
+use std::collections::{BTreeMap, HashSet};
 use std::ffi::OsStr;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use crate::config::EarlyExitUpTo;
-use crate::linker::AFTER_INITIAL_PARSE_CP;
+use crate::alloc::ArenaVector;
+use crate::codegen::{sorted_instances, CodeGenBackend, VHDLCodegenBackend, VerilogCodegenBackend};
+use crate::config::{CompilerOptions, DumpStage, EarlyExitUpTo, TargetLanguage, TimingsFormat};
+use crate::dev_aid::ariadne_interface::{print_all_errors, print_diagnostics, FileSourcesManager};
+use crate::dev_aid::dot_graphs::{hierarchy_dot, latency_dot};
+use crate::dev_aid::flat_ir_json::flattened_module_json;
+use crate::dev_aid::resource_estimate::estimate_table;
+use crate::dev_aid::timings::{start_module_timer, Timings};
+use crate::instantiation::InstantiatedModule;
+use crate::linker::features::{blank_out_pragmas, find_pragmas, Feature, Pragma};
+use crate::linker::{
+    AFTER_FLATTEN_CP, AFTER_INITIAL_PARSE_CP, AFTER_TYPECHECK_CP, AFTER_UNUSED_MODULES_CP,
+};
 use crate::prelude::*;
 
 use sus_proc_macro::{get_builtin_const, get_builtin_type};
-use tree_sitter::Parser;
+use tree_sitter::{InputEdit, Parser, Point, Tree};
 
 use crate::{
-    config::config, debug::SpanDebugger, errors::ErrorStore, file_position::FileText,
-    linker::FileData,
+    debug::{stable_hash_64, SpanDebugger},
+    errors::{CompileError, ErrorCollector, ErrorLevel, ErrorStore},
+    file_position::FileText,
+    linker::{FileData, GlobalUUID},
 };
 
 use crate::flattening::{
-    flatten_all_globals, gather_initial_file_data, perform_lints, typecheck_all_modules, Module,
+    flatten_all_globals, gather_initial_file_data, perform_lints, typecheck_all_modules,
+    Instruction, Module,
 };
+use crate::value::Value;
 
 const STD_LIB_PATH: &str = env!("SUS_COMPILER_STD_LIB_PATH");
 
+/// [Compiler::eval_expression] wraps the expression in a module of this name, in a file of this name
+const EVAL_MODULE_NAME: &str = "__eval__";
+const EVAL_FILE_NAME: &str = "<eval>";
+
 /// Any extra operations that should happen when files are added or removed from the linker. Such as caching line offsets.
 pub trait LinkerExtraFileInfoManager {
     /// This is there to give an acceptable identifier that can be printed
@@ -33,20 +56,228 @@ pub trait LinkerExtraFileInfoManager {
 
 impl LinkerExtraFileInfoManager for () {}
 
+/// Where source files are read from. Normally that's the filesystem ([FsSourceProvider]),
+/// but without one, like in tests or in WASM, the files can come from memory ([MemorySourceProvider]).
+///
+/// Also used for the standard library, which is read from [STD_LIB_PATH]
+pub trait SourceProvider {
+    fn read_file(&self, path: &Path) -> std::io::Result<String>;
+    /// The files directly within `directory`, not including subdirectories
+    fn files_in_directory(&self, directory: &Path) -> std::io::Result<Vec<PathBuf>>;
+}
+
+/// Reads source files from disk. Files in directories are given as absolute paths
+pub struct FsSourceProvider;
+
+impl SourceProvider for FsSourceProvider {
+    fn read_file(&self, path: &Path) -> std::io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+    fn files_in_directory(&self, directory: &Path) -> std::io::Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(directory)? {
+            let path = entry?.path().canonicalize()?;
+            if path.is_file() {
+                files.push(path);
+            }
+        }
+        Ok(files)
+    }
+}
+
+/// Source files kept in memory, by path
+#[derive(Debug, Clone, Default)]
+pub struct MemorySourceProvider {
+    pub files: BTreeMap<PathBuf, String>,
+}
+
+impl MemorySourceProvider {
+    /// The standard library that was compiled into this binary, at the same paths it has on disk under [STD_LIB_PATH]
+    pub fn with_standard_library() -> Self {
+        let std_path = Path::new(STD_LIB_PATH);
+        let files = [
+            ("core.sus", include_str!("../std/core.sus")),
+            ("util.sus", include_str!("../std/util.sus")),
+        ];
+        Self {
+            files: files
+                .into_iter()
+                .map(|(name, text)| (std_path.join(name), text.to_owned()))
+                .collect(),
+        }
+    }
+}
+
+impl SourceProvider for MemorySourceProvider {
+    fn read_file(&self, path: &Path) -> std::io::Result<String> {
+        self.files.get(path).cloned().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "it doesn't exist in memory")
+        })
+    }
+    fn files_in_directory(&self, directory: &Path) -> std::io::Result<Vec<PathBuf>> {
+        Ok(self
+            .files
+            .keys()
+            .filter(|path| path.parent() == Some(directory))
+            .cloned()
+            .collect())
+    }
+}
+
+/// Read-only access to the results of the compilation stages, for analyses outside of the compiler such as custom lints. See [Linker::recompile_up_to_observed]
+///
+/// Each method is called for every module once the whole stage is done, in the order of [Linker::modules]. Stages past the `early_exit` aren't reported.
+pub trait CompilationObserver {
+    fn after_flatten(&mut self, _md: &Module, _linker: &Linker) {}
+    fn after_typecheck(&mut self, _md: &Module, _linker: &Linker) {}
+    /// Called for all instances of all modules, see [sorted_instances]
+    fn after_instantiate(&mut self, _instance: &InstantiatedModule, _linker: &Linker) {}
+}
+
+impl CompilationObserver for () {}
+
+/// A single edit to the text of a file, for [Linker::update_file_incremental]
+///
+/// `replace_range` is in bytes, and refers to the text as it is after all previous edits in the same batch have been applied.
+#[derive(Debug, Clone)]
+pub struct TextEdit {
+    pub replace_range: Range<usize>,
+    pub new_text: String,
+}
+
+/// Why [Linker::splice_edits] refused the edits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpliceError {
+    /// These two edits overlap
+    Overlapping(Span, Span),
+    /// This span doesn't lie within the text, or splits a character
+    OutOfBounds(Span),
+}
+
+/// tree-sitter wants the column in bytes, not chars
+fn byte_to_point(text: &str, byte_pos: usize) -> Point {
+    let before = &text[..byte_pos];
+    let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+    Point {
+        row: before.matches('\n').count(),
+        column: byte_pos - line_start,
+    }
+}
+
+/// Clamps the range to lie within the text, on char boundaries. Returns false if it had to be changed.
+fn clamp_edit_range(text: &str, range: &mut Range<usize>) -> bool {
+    let original = range.clone();
+    range.end = range.end.min(text.len());
+    range.start = range.start.min(range.end);
+    while !text.is_char_boundary(range.start) {
+        range.start -= 1;
+    }
+    while !text.is_char_boundary(range.end) {
+        range.end += 1;
+    }
+    *range == original
+}
+
+/// Applies the edit to the text, and to the old tree if given.
+///
+/// If the edit doesn't fit the text it is clamped, and the old tree is discarded, as it can no longer be reused.
+fn apply_text_edit(text: &mut String, old_tree: &mut Option<Tree>, edit: &TextEdit) {
+    let mut replace_range = edit.replace_range.clone();
+    if !clamp_edit_range(text, &mut replace_range) {
+        *old_tree = None;
+    }
+    let start_position = byte_to_point(text, replace_range.start);
+    let old_end_position = byte_to_point(text, replace_range.end);
+    text.replace_range(replace_range.clone(), &edit.new_text);
+    let new_end_byte = replace_range.start + edit.new_text.len();
+
+    if let Some(tree) = old_tree {
+        tree.edit(&InputEdit {
+            start_byte: replace_range.start,
+            old_end_byte: replace_range.end,
+            new_end_byte,
+            start_position,
+            old_end_position,
+            new_end_position: byte_to_point(text, new_end_byte),
+        });
+    }
+}
+
+/// Parses `text`, reusing `old_tree` if given.
+///
+/// Tree-sitter returns no tree at all when it gives up on the input, such as when the `--parse-timeout-ms` expires.
+/// Instead of crashing, the tree of an empty file is returned then, along with the error to report for the file.
+/// This way the file simply contains no globals, and the rest of the files still compile.
+fn parse_source(
+    parser: &mut Parser,
+    text: &str,
+    old_tree: Option<&Tree>,
+) -> (Tree, Option<String>) {
+    if let Some(tree) = parser.parse(text, old_tree) {
+        return (tree, None);
+    }
+    let timeout_micros = parser.timeout_micros();
+    let reason = if timeout_micros != 0 {
+        format!(
+            "This file is too complex to parse within {} ms (--parse-timeout-ms), it is treated as if it were empty",
+            timeout_micros.div_ceil(1000)
+        )
+    } else {
+        "Could not parse this file, it is treated as if it were empty".to_owned()
+    };
+    parser.reset();
+    parser.set_timeout_micros(0);
+    let empty_tree = parser.parse("", None).unwrap();
+    (empty_tree, Some(reason))
+}
+
+/// [parse_source], after reading the feature pragmas at the top of `text`. Tree-sitter gets the text without them, see [crate::linker::features]
+fn parse_file(
+    parser: &mut Parser,
+    text: &str,
+    old_tree: Option<&Tree>,
+) -> (Tree, Option<String>, Vec<Pragma>) {
+    let pragmas = find_pragmas(text);
+    let (tree, parse_failure) = parse_source(parser, &blank_out_pragmas(text, &pragmas), old_tree);
+    (tree, parse_failure, pragmas)
+}
+
+/// The text of each pragma of `text`, see [find_pragmas]
+fn pragma_texts(text: &str) -> Vec<&str> {
+    (find_pragmas(text).into_iter())
+        .map(|pragma| &text[pragma.range])
+        .collect()
+}
+
+fn new_sus_parser(options: &CompilerOptions) -> Parser {
+    let mut parser = Parser::new();
+    parser.set_language(&tree_sitter_sus::language()).unwrap();
+    if let Some(timeout_ms) = options.parse_timeout_ms {
+        parser.set_timeout_micros(timeout_ms.saturating_mul(1000));
+    }
+    parser
+}
+
 impl Linker {
     pub fn add_standard_library<ExtraInfoManager: LinkerExtraFileInfoManager>(
         &mut self,
+        sources: &dyn SourceProvider,
         info_mngr: &mut ExtraInfoManager,
     ) {
+        assert!(self.files.is_empty());
         assert!(self.modules.is_empty());
         assert!(self.types.is_empty());
         assert!(self.constants.is_empty());
-        if !config().ci {
-            println!("Standard Library Directory: {STD_LIB_PATH}");
+        if !self.options.ci {
+            log::info!("Standard Library Directory: {STD_LIB_PATH}");
         }
         let std_path = PathBuf::from_str(STD_LIB_PATH)
             .expect("Standard library directory is not a valid path?");
-        self.add_all_files_in_directory(&std_path, info_mngr);
+        self.add_all_files_in_directory(&std_path, sources, info_mngr);
+        // The linker was empty, so these are all standard library files
+        for (_, file) in &mut self.files {
+            file.is_standard_library = true;
+        }
 
         // Sanity check for the names the compiler knows internally.
         // They are defined in std/core.sus
@@ -84,21 +315,31 @@ impl Linker {
 
     pub fn add_all_files_in_directory<ExtraInfoManager: LinkerExtraFileInfoManager>(
         &mut self,
-        directory: &PathBuf,
+        directory: &Path,
+        sources: &dyn SourceProvider,
         info_mngr: &mut ExtraInfoManager,
     ) {
-        let mut files = std::fs::read_dir(directory)
-            .unwrap()
-            .map(|res| res.map(|e| e.path()))
-            .collect::<Result<Vec<_>, std::io::Error>>()
-            .unwrap();
+        let mut files = match sources.files_in_directory(directory) {
+            Ok(files) => files,
+            Err(reason) => {
+                // Reported on an empty file standing in for the directory, like an unreadable file in add_file_from_path
+                let file_identifier: String = info_mngr.convert_filename(directory);
+                let file_id = self.add_file(file_identifier, String::new(), info_mngr);
+                let errors = ErrorCollector::new_empty(file_id, &self.files);
+                let directory_disp = directory.display();
+                errors.error(
+                    Span::from(0..0),
+                    format!("Could not read directory '{directory_disp}' because {reason}"),
+                );
+                self.files[file_id].parsing_errors = errors.into_storage();
+                return;
+            }
+        };
         files.sort();
-        for file in files {
-            let file_path = file.canonicalize().unwrap();
-            if file_path.is_file() && file_path.extension() == Some(OsStr::new("sus")) {
-                let file_text = std::fs::read_to_string(&file_path).unwrap();
+        for file_path in files {
+            if file_path.extension() == Some(OsStr::new("sus")) {
                 let file_identifier: String = info_mngr.convert_filename(&file_path);
-                self.add_file(file_identifier, file_text, info_mngr);
+                self.add_file_from_path(file_identifier, &file_path, sources, info_mngr);
             }
         }
     }
@@ -115,64 +356,244 @@ impl Linker {
             .iter()
             .any(|fd| fd.1.file_identifier == file_identifier));
 
-        let mut parser = Parser::new();
-        parser.set_language(&tree_sitter_sus::language()).unwrap();
-        let tree = parser.parse(&text, None).unwrap();
+        let (tree, parse_failure, pragmas) =
+            parse_file(&mut new_sus_parser(&self.options), &text, None);
 
         let file_id = self.files.reserve();
         self.files.alloc_reservation(
             file_id,
             FileData {
                 file_identifier,
+                content_hash: stable_hash_64(&text),
                 file_text: FileText::new(text),
                 tree,
                 associated_values: Vec::new(),
                 parsing_errors: ErrorStore::new(),
+                enabled_features: Vec::new(),
+                is_standard_library: false,
             },
         );
+        self.enable_features(file_id, &pragmas);
 
-        self.with_file_builder(file_id, |builder| {
-            let mut span_debugger =
-                SpanDebugger::new("gather_initial_file_data in add_file", builder.file_data);
-            gather_initial_file_data(builder);
-            span_debugger.defuse();
-        });
+        if let Some(reason) = parse_failure {
+            self.report_file_error(file_id, reason);
+        } else {
+            self.with_file_builder(file_id, |builder| {
+                let mut span_debugger =
+                    SpanDebugger::new("gather_initial_file_data in add_file", builder.file_data);
+                gather_initial_file_data(builder);
+                span_debugger.defuse();
+            });
+        }
 
         info_mngr.on_file_added(file_id, self);
 
         file_id
     }
 
+    /// Reads and adds the file at `file_path`.
+    ///
+    /// If it can't be read, an empty file is added instead, with an error explaining why. This way the rest of the files still compile.
+    pub fn add_file_from_path<ExtraInfoManager: LinkerExtraFileInfoManager>(
+        &mut self,
+        file_identifier: String,
+        file_path: &Path,
+        sources: &dyn SourceProvider,
+        info_mngr: &mut ExtraInfoManager,
+    ) -> FileUUID {
+        match sources.read_file(file_path) {
+            Ok(file_text) => self.add_file(file_identifier, file_text, info_mngr),
+            Err(reason) => {
+                let file_id = self.add_file(file_identifier, String::new(), info_mngr);
+                let errors = ErrorCollector::new_empty(file_id, &self.files);
+                let file_path_disp = file_path.display();
+                errors.error(
+                    Span::from(0..0),
+                    format!("Could not read file '{file_path_disp}' because {reason}"),
+                );
+                self.files[file_id].parsing_errors = errors.into_storage();
+                file_id
+            }
+        }
+    }
+
     // When --feature lsp is not used, this gives a warning
     #[allow(dead_code)]
+    /// Returns false if the file already existed with exactly this text, in which case nothing needs to be recompiled
     pub fn add_or_update_file<ExtraInfoManager: LinkerExtraFileInfoManager>(
         &mut self,
         file_identifier: &str,
         text: String,
         info_mngr: &mut ExtraInfoManager,
-    ) {
+    ) -> bool {
         if let Some(file_id) = self.find_file(file_identifier) {
-            let file_data = self.remove_everything_in_file(file_id);
+            self.update_file(file_id, text, None, info_mngr)
+        } else {
+            self.add_file(file_identifier.to_owned(), text, info_mngr);
+            true
+        }
+    }
+
+    /// Applies the edits in order to the text of an existing file, and reparses it incrementally by reusing the old tree-sitter tree.
+    ///
+    /// If any of the edits doesn't fit the text, it is clamped to the text, and the file is fully reparsed instead.
+    ///
+    /// Returns false if the edits didn't change the text, in which case nothing needs to be recompiled
+    // When --feature lsp is not used, this gives a warning
+    #[allow(dead_code)]
+    pub fn update_file_incremental<ExtraInfoManager: LinkerExtraFileInfoManager>(
+        &mut self,
+        edits: &[TextEdit],
+        file_id: FileUUID,
+        info_mngr: &mut ExtraInfoManager,
+    ) -> bool {
+        let file_data = &self.files[file_id];
+        let mut text = file_data.file_text.file_text.clone();
+        let mut old_tree = Some(file_data.tree.clone());
+
+        for edit in edits {
+            apply_text_edit(&mut text, &mut old_tree, edit);
+        }
+
+        self.update_file(file_id, text, old_tree.as_ref(), info_mngr)
+    }
+
+    /// Returns the text of `file` with every span in `edits` replaced by its new text. The file itself isn't changed.
+    ///
+    /// Unlike with [Self::update_file_incremental], all spans refer to the original text, so the edits can be given in any order.
+    /// Edits may touch, but not overlap. Two insertions at the same position also count as overlapping, as their order would be ambiguous.
+    pub fn splice_edits(
+        &self,
+        file: FileUUID,
+        edits: &[(Span, String)],
+    ) -> Result<String, SpliceError> {
+        let mut text = self.files[file].file_text.file_text.clone();
 
-            let mut parser = Parser::new();
-            parser.set_language(&tree_sitter_sus::language()).unwrap();
-            let tree = parser.parse(&text, None).unwrap();
+        let mut sorted_edits: Vec<&(Span, String)> = edits.iter().collect();
+        sorted_edits.sort_by_key(|(span, _)| {
+            let range = span.as_range();
+            (range.start, range.end)
+        });
+
+        for (span, _) in &sorted_edits {
+            let range = span.as_range();
+            if range.end > text.len()
+                || !text.is_char_boundary(range.start)
+                || !text.is_char_boundary(range.end)
+            {
+                return Err(SpliceError::OutOfBounds(*span));
+            }
+        }
+        for pair in sorted_edits.windows(2) {
+            let (first, second) = (pair[0].0.as_range(), pair[1].0.as_range());
+            if second.start < first.end || first == second {
+                return Err(SpliceError::Overlapping(pair[0].0, pair[1].0));
+            }
+        }
 
-            file_data.parsing_errors = ErrorStore::new();
-            file_data.file_text = FileText::new(text);
-            file_data.tree = tree;
+        // Right-to-left, such that the spans of the remaining edits stay valid
+        for (span, new_text) in sorted_edits.into_iter().rev() {
+            text.replace_range(span.as_range(), new_text);
+        }
+        Ok(text)
+    }
 
+    /// Replaces the text of an existing file. `old_tree` must already have been edited to match the new text.
+    ///
+    /// Returns false without reparsing if the text is the same as before, as is common with autosave.
+    fn update_file<ExtraInfoManager: LinkerExtraFileInfoManager>(
+        &mut self,
+        file_id: FileUUID,
+        text: String,
+        old_tree: Option<&Tree>,
+        info_mngr: &mut ExtraInfoManager,
+    ) -> bool {
+        let content_hash = stable_hash_64(&text);
+        let old_file_data = &self.files[file_id];
+        // The text comparison only runs on the rare hash match, to guard against collisions
+        if old_file_data.content_hash == content_hash && old_file_data.file_text.file_text == text {
+            return false;
+        }
+
+        // The old tree was parsed with the pragmas blanked out. If other lines are blanked now, the tree no longer matches text that no edit touched
+        let old_tree = old_tree
+            .filter(|_| pragma_texts(&old_file_data.file_text.file_text) == pragma_texts(&text));
+
+        let mut parser = new_sus_parser(&self.options);
+        let file_data = self.remove_everything_in_file(file_id);
+
+        let (tree, parse_failure, pragmas) = parse_file(&mut parser, &text, old_tree);
+
+        file_data.parsing_errors = ErrorStore::new();
+        file_data.content_hash = content_hash;
+        file_data.file_text = FileText::new(text);
+        file_data.tree = tree;
+        self.enable_features(file_id, &pragmas);
+
+        if let Some(reason) = parse_failure {
+            self.report_file_error(file_id, reason);
+        } else {
             self.with_file_builder(file_id, |builder| {
                 let mut span_debugger =
                     SpanDebugger::new("gather_initial_file_data in update_file", builder.file_data);
                 gather_initial_file_data(builder);
                 span_debugger.defuse();
             });
+        }
 
-            info_mngr.on_file_updated(file_id, self);
-        } else {
-            self.add_file(file_identifier.to_owned(), text, info_mngr);
+        info_mngr.on_file_updated(file_id, self);
+        true
+    }
+
+    /// Sets [FileData::enabled_features] from the pragmas of the file and [CompilerOptions::features], and reports pragmas that aren't `#![feature(...)]` and unknown features
+    fn enable_features(&mut self, file_id: FileUUID, pragmas: &[Pragma]) {
+        let parsing_errors = std::mem::take(&mut self.files[file_id].parsing_errors);
+        let errors = ErrorCollector::from_storage(parsing_errors, file_id, &self.files);
+        let file_text = &self.files[file_id].file_text.file_text;
+        let mut enabled_features = self.options.features.clone();
+        for pragma in pragmas {
+            let Some(feature_names) = &pragma.feature_names else {
+                errors.error(
+                    Span::from(pragma.range.clone()),
+                    "Unknown pragma, only '#![feature(name, ...)]' is supported",
+                );
+                continue;
+            };
+            for name_range in feature_names {
+                let name = &file_text[name_range.clone()];
+                match Feature::from_name(name) {
+                    Some(feature) => {
+                        if !enabled_features.contains(&feature) {
+                            enabled_features.push(feature);
+                        }
+                    }
+                    None => {
+                        let available: Vec<&str> = (Feature::ALL.iter())
+                            .map(|feature| feature.name())
+                            .collect();
+                        errors.error(
+                            Span::from(name_range.clone()),
+                            format!(
+                                "Unknown feature '{name}'. The available features are: {}",
+                                available.join(", ")
+                            ),
+                        );
+                    }
+                }
+            }
         }
+        let parsing_errors = errors.into_storage();
+        let file_data = &mut self.files[file_id];
+        file_data.parsing_errors = parsing_errors;
+        file_data.enabled_features = enabled_features;
+    }
+
+    /// Adds an error for the file as a whole, placed at its start
+    fn report_file_error(&mut self, file_id: FileUUID, reason: String) {
+        let parsing_errors = std::mem::take(&mut self.files[file_id].parsing_errors);
+        let errors = ErrorCollector::from_storage(parsing_errors, file_id, &self.files);
+        errors.error(Span::from(0..0), reason);
+        self.files[file_id].parsing_errors = errors.into_storage();
     }
 
     pub fn find_file(&self, file_identifier: &str) -> Option<FileUUID> {
@@ -180,54 +601,117 @@ impl Linker {
             .find(|_id, f| f.file_identifier == file_identifier)
     }
 
+    /// Runs all compilation stages up to [CompilerOptions::early_exit], see [Linker::recompile_up_to]
     pub fn recompile_all(&mut self) {
-        // First reset all modules back to post-gather_initial_file_data
-        for (_, md) in &mut self.modules {
+        self.recompile_up_to(self.options.early_exit);
+    }
+
+    /// [Self::recompile_all], reporting the result of each stage to `observer`
+    pub fn recompile_all_observed(&mut self, observer: &mut impl CompilationObserver) {
+        self.recompile_up_to_observed(self.options.early_exit, observer);
+    }
+
+    /// Resets all globals, and runs all compilation stages up to and including `early_exit`
+    ///
+    /// Globals that didn't change since the last run, and whose dependencies didn't either, keep their flattening and typechecking results.
+    /// See [Linker::find_reusable_globals]
+    pub fn recompile_up_to(&mut self, early_exit: EarlyExitUpTo) {
+        self.recompile_up_to_observed(early_exit, &mut ());
+    }
+
+    /// [Self::recompile_up_to], reporting the result of each stage to `observer`
+    pub fn recompile_up_to_observed(
+        &mut self,
+        early_exit: EarlyExitUpTo,
+        observer: &mut impl CompilationObserver,
+    ) {
+        // A partial run must not see globals that are further along than the stage it stops at
+        let reusable = if early_exit >= EarlyExitUpTo::AbstractTypecheck {
+            self.find_reusable_globals()
+        } else {
+            HashSet::new()
+        };
+        self.reached_stage = early_exit;
+        self.timings = self.options.timings.map(|_| Timings::default());
+        // First reset all modules back to post-gather_initial_file_data, or to their reusable checkpoint
+        for (md_id, md) in &mut self.modules {
             let Module {
                 link_info,
                 instantiations,
                 ..
             } = md;
-            link_info.reset_to(AFTER_INITIAL_PARSE_CP);
-            link_info.instructions.clear();
+            if reusable.contains(&GlobalUUID::Module(md_id)) {
+                link_info.reset_to(AFTER_TYPECHECK_CP);
+            } else {
+                link_info.reset_to(AFTER_INITIAL_PARSE_CP);
+                link_info.instructions.clear();
+            }
             instantiations.clear_instances()
         }
-        for (_, typ) in &mut self.types {
-            typ.link_info.reset_to(AFTER_INITIAL_PARSE_CP);
+        for (typ_id, typ) in &mut self.types {
+            if reusable.contains(&GlobalUUID::Type(typ_id)) {
+                typ.link_info.reset_to(AFTER_FLATTEN_CP);
+            } else {
+                typ.link_info.reset_to(AFTER_INITIAL_PARSE_CP);
+            }
         }
-        for (_, cst) in &mut self.constants {
-            cst.link_info.reset_to(AFTER_INITIAL_PARSE_CP);
+        for (cst_id, cst) in &mut self.constants {
+            if reusable.contains(&GlobalUUID::Constant(cst_id)) {
+                cst.link_info.reset_to(AFTER_TYPECHECK_CP);
+            } else {
+                cst.link_info.reset_to(AFTER_INITIAL_PARSE_CP);
+            }
+            cst.values.clear();
         }
-        if config().early_exit == EarlyExitUpTo::Initialize {
+        if early_exit == EarlyExitUpTo::Initialize {
             return;
         }
 
+        let stage_start = self.start_timing_stage("flatten");
         flatten_all_globals(self);
-        config().for_each_debug_module(config().debug_print_module_contents, &self.modules, |md| {
-            md.print_flattened_module(&self.files[md.link_info.file]);
+        self.finish_timing_stage(stage_start);
+        let options = &self.options;
+        options.for_each_debug_module(options.debug_print_module_contents, &self.modules, |md| {
+            md.print_flattened_module(self);
         });
-        if config().early_exit == EarlyExitUpTo::Flatten {
+        self.dump_stage(DumpStage::Flatten);
+        for (_id, md) in &self.modules {
+            observer.after_flatten(md, self);
+        }
+        if early_exit == EarlyExitUpTo::Flatten {
             return;
         }
 
+        let stage_start = self.start_timing_stage("typecheck");
         typecheck_all_modules(self);
+        self.finish_timing_stage(stage_start);
 
-        config().for_each_debug_module(config().debug_print_module_contents, &self.modules, |md| {
-            md.print_flattened_module(&self.files[md.link_info.file]);
+        let options = &self.options;
+        options.for_each_debug_module(options.debug_print_module_contents, &self.modules, |md| {
+            md.print_flattened_module(self);
         });
-        if config().early_exit == EarlyExitUpTo::AbstractTypecheck {
+        self.dump_stage(DumpStage::AbstractTypecheck);
+        for (_id, md) in &self.modules {
+            observer.after_typecheck(md, self);
+        }
+        if early_exit == EarlyExitUpTo::AbstractTypecheck {
             return;
         }
 
+        let stage_start = self.start_timing_stage("lint");
         perform_lints(self);
+        self.finish_timing_stage(stage_start);
 
-        if config().early_exit == EarlyExitUpTo::Lint {
+        if early_exit == EarlyExitUpTo::Lint {
             return;
         }
 
-        // Make an initial instantiation of all modules
+        // Make an initial instantiation of all modules, or only of the `--top` modules
         // Won't be possible once we have template modules
-        for (_id, md) in &self.modules {
+        let stage_start = self.start_timing_stage("instantiate");
+        let top_modules = &self.options.top_modules;
+        let mut module_times = Vec::new();
+        for (id, md) in &self.modules {
             //md.print_flattened_module();
             // Already instantiate any modules without parameters
             // Currently this is all modules
@@ -235,11 +719,801 @@ impl Linker {
             let mut span_debugger =
                 SpanDebugger::new(&span_debug_message, &self.files[md.link_info.file]);
             // Can immediately instantiate modules that have no template args
-            if md.link_info.template_parameters.is_empty() {
-                let _inst = md.instantiations.instantiate(md, self, FlatAlloc::new());
+            if md.link_info.template_parameters.is_empty()
+                && (top_modules.is_empty() || top_modules.contains(&md.link_info.name))
+            {
+                let module_timer = start_module_timer(&self.options);
+                let _inst = md
+                    .instantiations
+                    .instantiate(md, self, FlatAlloc::new(), &[]);
+                module_times.push((id, module_timer.map(|start| start.elapsed())));
             }
             span_debugger.defuse();
         }
-        if config().early_exit == EarlyExitUpTo::Instantiate {}
+        for (id, duration) in module_times {
+            self.add_module_time(GlobalUUID::Module(id), duration);
+        }
+        self.finish_timing_stage(stage_start);
+        if self.options.warn_unused_modules {
+            warn_unused_modules(self);
+        }
+        self.dump_stage(DumpStage::Instantiate);
+        for (_id, md) in &self.modules {
+            for inst in sorted_instances(md) {
+                observer.after_instantiate(&inst, self);
+            }
+        }
+    }
+
+    /// Starts a stage of `--timings`. Measures nothing if it wasn't given
+    fn start_timing_stage(&mut self, stage: &'static str) -> Option<Instant> {
+        (self.timings.as_mut()).map(|timings| timings.start_stage(stage))
+    }
+
+    fn finish_timing_stage(&mut self, stage_start: Option<Instant>) {
+        if let (Some(timings), Some(stage_start)) = (&mut self.timings, stage_start) {
+            timings.finish_stage(stage_start);
+        }
+    }
+
+    /// Adds the time `global` took in the current stage of `--timings`, as measured with [start_module_timer]. Only modules are listed
+    pub(crate) fn add_module_time(&mut self, global: GlobalUUID, duration: Option<Duration>) {
+        if let (Some(timings), Some(duration), GlobalUUID::Module(md_id)) =
+            (&mut self.timings, duration, global)
+        {
+            timings.add_module_time(&self.modules[md_id].link_info.name, duration);
+        }
+    }
+
+    /// Prints the intermediate representation of all (whitelisted) modules, if `stage` was requested with `--dump-stage`
+    fn dump_stage(&self, stage: DumpStage) {
+        let should_dump = self.options.dump_stage == Some(stage);
+        self.options
+            .for_each_debug_module(should_dump, &self.modules, |md| match stage {
+                DumpStage::Flatten | DumpStage::AbstractTypecheck => {
+                    let text = md.dump_flattened_module(&self.files[md.link_info.file].file_text);
+                    print!("{}", self.stabilize_debug_text(&text));
+                }
+                DumpStage::Instantiate => {
+                    for inst in sorted_instances(md) {
+                        print!("{}", self.stabilize_debug_text(&inst.dump_instance()));
+                    }
+                }
+            });
+    }
+}
+
+/// Warns on all modules that aren't used as a submodule in any instance, see `--warn-unused-modules`
+///
+/// The standard library, the `--top` modules and the `--standalone` module are meant to be used from outside, so they are exempt.
+fn warn_unused_modules(linker: &mut Linker) {
+    let mut used_modules: HashSet<ModuleUUID> = HashSet::new();
+    for (_, md) in &linker.modules {
+        md.instantiations.for_each_instance(|_, inst| {
+            for (_, sm) in &inst.submodules {
+                used_modules.insert(sm.module_uuid);
+            }
+        });
+    }
+
+    for (md_id, md) in &mut linker.modules {
+        let errors = ErrorCollector::from_storage(
+            md.link_info.errors.take(),
+            md.link_info.file,
+            &linker.files,
+        );
+        let resolved_globals = md.link_info.resolved_globals.take();
+        let is_std = linker.files[md.link_info.file].is_standard_library;
+        let is_root = linker.options.top_modules.contains(&md.link_info.name)
+            || linker
+                .options
+                .codegen_module_and_dependencies_one_file
+                .as_ref()
+                == Some(&md.link_info.name);
+        if !used_modules.contains(&md_id) && !is_std && !is_root {
+            errors.warn(
+                md.link_info.name_span,
+                format!(
+                    "Unused Module: '{}' is never used as a submodule",
+                    md.link_info.name
+                ),
+            );
+        }
+        md.link_info
+            .reabsorb_errors_globals((errors, resolved_globals), AFTER_UNUSED_MODULES_CP);
+    }
+}
+
+/// The result of [compile_sources]
+pub struct CompileResult {
+    pub linker: Linker,
+    /// The [FileUUID] of each of the given sources, in the same order
+    pub files: Vec<FileUUID>,
+    /// All errors and warnings, including those of the standard library
+    pub diagnostics: Vec<(FileUUID, CompileError)>,
+}
+
+impl CompileResult {
+    /// All diagnostics of [ErrorLevel::Error]
+    pub fn errors(&self) -> impl Iterator<Item = &CompileError> {
+        self.diagnostics
+            .iter()
+            .map(|(_, err)| err)
+            .filter(|err| err.level == ErrorLevel::Error)
+    }
+
+    pub fn find_module(&self, name: &str) -> Option<&Module> {
+        self.linker
+            .modules
+            .iter()
+            .find(|(_, md)| md.link_info.name == name)
+            .map(|(_, md)| md)
+    }
+
+    /// The instances of the module with the given name, in a deterministic order. Empty if there is no such module
+    pub fn instances(&self, module_name: &str) -> Vec<Arc<InstantiatedModule>> {
+        self.find_module(module_name)
+            .map(sorted_instances)
+            .unwrap_or_default()
+    }
+
+    /// The SystemVerilog code of all instances of the module, as `--codegen` would write it
+    pub fn verilog(&self, module_name: &str) -> Option<String> {
+        let md = self.find_module(module_name)?;
+        Some(VerilogCodegenBackend.codegen_module(md, &self.linker))
+    }
+}
+
+/// Compiles the given `(file identifier, text)` pairs together with the standard library, without touching the filesystem.
+///
+/// The standard library is the one embedded in the compiler, see [MemorySourceProvider::with_standard_library].
+/// Compilation stops after `options.early_exit`. Meant for tests, where the options are given directly instead of parsed from the CLI arguments.
+pub fn compile_sources(options: CompilerOptions, sources: Vec<(String, String)>) -> CompileResult {
+    let mut linker = Linker::with_options(options);
+    linker.add_standard_library(&MemorySourceProvider::with_standard_library(), &mut ());
+    let files = sources
+        .into_iter()
+        .map(|(file_identifier, text)| linker.add_file(file_identifier, text, &mut ()))
+        .collect();
+    linker.recompile_up_to(linker.options.early_exit);
+    let diagnostics = linker.collect_all_diagnostics();
+
+    CompileResult {
+        linker,
+        files,
+        diagnostics,
+    }
+}
+
+/// With `--top`, only the top modules and their submodules are instantiated, so only modules with instances get code
+fn is_selected_by_top(md: &Module, options: &CompilerOptions) -> bool {
+    options.top_modules.is_empty() || !sorted_instances(md).is_empty()
+}
+
+/// Writes a JSON list of `{"module": ..., "path": ...}` objects, with absolute paths
+fn write_manifest(
+    manifest_path: &Path,
+    generated_files: &[(PathBuf, String)],
+) -> std::io::Result<()> {
+    let entries = generated_files
+        .iter()
+        .map(|(path, module)| {
+            let path = std::fs::canonicalize(path)?;
+            Ok(serde_json::json!({
+                "module": module,
+                "path": path.to_string_lossy(),
+            }))
+        })
+        .collect::<std::io::Result<Vec<_>>>()?;
+    let mut manifest = serde_json::to_string_pretty(&entries)?;
+    manifest.push('\n');
+    std::fs::write(manifest_path, manifest)
+}
+
+/// Make treats spaces, `#` and `$` in paths specially, so they are escaped the same way GCC's `-MD` does
+fn escape_make_path(path: &str) -> String {
+    let mut result = String::with_capacity(path.len());
+    for c in path.chars() {
+        match c {
+            ' ' => result.push_str("\\ "),
+            '#' => result.push_str("\\#"),
+            '$' => result.push_str("$$"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Writes one Makefile rule per generated file, listing the source files it depends on. See [CompilerOptions::emit_deps]
+fn write_deps(deps_path: &Path, generated_files: &[(PathBuf, Vec<String>)]) -> std::io::Result<()> {
+    let mut deps = String::new();
+    for (path, sources) in generated_files {
+        deps.push_str(&escape_make_path(&path.to_string_lossy()));
+        deps.push(':');
+        for source in sources {
+            deps.push(' ');
+            deps.push_str(&escape_make_path(source));
+        }
+        deps.push('\n');
+    }
+    std::fs::write(deps_path, deps)
+}
+
+/// The library entry point of the compiler. Owns the [Linker] and the sources used for error reporting.
+///
+/// ```ignore
+/// let mut compiler = Compiler::with_options(CompilerOptions::default());
+/// compiler.add_file("my_file.sus".to_owned(), source_text);
+/// compiler.compile();
+/// compiler.print_errors();
+/// ```
+pub struct Compiler {
+    pub linker: Linker,
+    pub file_sources: FileSourcesManager,
+    /// Where [Self::add_file_from_path] and the standard library read from
+    pub source_provider: Box<dyn SourceProvider>,
+}
+
+impl Compiler {
+    /// Creates a new compiler with the standard library already added. The options are kept in its [Linker::options]
+    pub fn with_options(options: CompilerOptions) -> Self {
+        Self::with_source_provider(options, Box::new(FsSourceProvider))
+    }
+
+    /// [Self::with_options], reading files from `source_provider` instead of from disk.
+    /// It must also provide the standard library, in [STD_LIB_PATH]
+    pub fn with_source_provider(
+        options: CompilerOptions,
+        source_provider: Box<dyn SourceProvider>,
+    ) -> Self {
+        let mut linker = Linker::with_options(options);
+        let mut file_sources = FileSourcesManager {
+            file_sources: ArenaVector::new(),
+        };
+        linker.add_standard_library(source_provider.as_ref(), &mut file_sources);
+
+        Self {
+            linker,
+            file_sources,
+            source_provider,
+        }
+    }
+
+    pub fn add_file(&mut self, file_identifier: String, text: String) -> FileUUID {
+        self.linker
+            .add_file(file_identifier, text, &mut self.file_sources)
+    }
+
+    /// See [Linker::add_file_from_path]. The path is used as the file identifier.
+    pub fn add_file_from_path(&mut self, file_path: &Path) -> FileUUID {
+        self.linker.add_file_from_path(
+            file_path.to_string_lossy().into_owned(),
+            file_path,
+            self.source_provider.as_ref(),
+            &mut self.file_sources,
+        )
+    }
+
+    /// Runs all compilation stages up to [CompilerOptions::early_exit]
+    pub fn compile(&mut self) {
+        self.linker.recompile_all();
+    }
+
+    /// Prints all errors and warnings in the requested [CompilerOptions::message_format]
+    pub fn print_errors(&mut self) {
+        print_all_errors(&self.linker, &mut self.file_sources.file_sources);
+    }
+
+    /// Prints only the given diagnostics, like those returned by [Self::eval_expression]
+    pub fn print_diagnostics(&mut self, diagnostics: Vec<(FileUUID, CompileError)>) {
+        print_diagnostics(
+            &self.linker,
+            &mut self.file_sources.file_sources,
+            diagnostics,
+        );
+    }
+
+    /// Evaluates a single generative expression like `clog2 #(V: 100) + 3`, for `--eval`.
+    ///
+    /// The expression can use all globals of the files added so far. It is compiled as a module of its own,
+    /// so if it doesn't parse, uses unknown names, or doesn't typecheck, the errors of that module are returned.
+    /// Otherwise it's evaluated with [Linker::eval_generative], which reports errors like division by zero.
+    /// The returned errors can be printed with [Self::print_diagnostics] until the next call
+    pub fn eval_expression(
+        &mut self,
+        expression: &str,
+    ) -> Result<Value, Vec<(FileUUID, CompileError)>> {
+        // The file of the previous evaluation is only removed now, such that its errors could still be printed
+        let previous_file = (self.linker.files.iter())
+            .find(|(_, file_data)| file_data.file_identifier == EVAL_FILE_NAME)
+            .map(|(file, _)| file);
+        if let Some(previous_file) = previous_file {
+            self.file_sources
+                .before_file_remove(previous_file, &self.linker);
+            self.linker.remove_file(previous_file);
+        }
+
+        let file = self.add_file(
+            EVAL_FILE_NAME.to_owned(),
+            format!(
+                "module {EVAL_MODULE_NAME} {{\n    {}\n}}\n",
+                expression.trim()
+            ),
+        );
+        self.linker.recompile_up_to(EarlyExitUpTo::Lint);
+
+        let file_errors: Vec<_> = self
+            .linker
+            .collect_all_diagnostics()
+            .into_iter()
+            .filter(|(err_file, err)| *err_file == file && err.level == ErrorLevel::Error)
+            .collect();
+        if !file_errors.is_empty() {
+            return Err(file_errors);
+        }
+
+        let (md_id, md) = self
+            .linker
+            .modules
+            .iter()
+            .find(|(_, md)| md.link_info.file == file)
+            .unwrap();
+        // Expressions are flattened after their parts, so the whole expression is the last one
+        let Some((expr, _)) = (md.link_info.instructions.iter())
+            .filter(|(_, instr)| matches!(instr, Instruction::Expression(_)))
+            .last()
+        else {
+            return Err(vec![(
+                file,
+                CompileError {
+                    position: md.link_info.name_span,
+                    reason: "Expected an expression".to_owned(),
+                    infos: Vec::new(),
+                    level: ErrorLevel::Error,
+                    instantiation_stack: Vec::new(),
+                },
+            )]);
+        };
+        self.linker
+            .eval_generative(md_id, expr, &FlatAlloc::new())
+            .map_err(|errors| errors.into_iter().map(|err| (file, err)).collect())
+    }
+
+    /// The flattened and typechecked form of all (whitelisted) modules as a JSON list, if `--dump-flat-json` was given
+    pub fn flat_json(&self) -> Option<String> {
+        let options = &self.linker.options;
+        if !options.dump_flat_json {
+            return None;
+        }
+        let mut modules = Vec::new();
+        options.for_each_debug_module(true, &self.linker.modules, |md| {
+            modules.push(flattened_module_json(md, &self.linker));
+        });
+        Some(serde_json::to_string_pretty(&modules).unwrap())
+    }
+
+    /// The resource estimate table of the instances of all (whitelisted) modules, if `--estimate` was given
+    pub fn estimate_table(&self) -> Option<String> {
+        let options = &self.linker.options;
+        if !options.estimate {
+            return None;
+        }
+        let mut instances = Vec::new();
+        options.for_each_debug_module(true, &self.linker.modules, |md| {
+            instances.extend(sorted_instances(md));
+        });
+        Some(estimate_table(instances.iter().map(|inst| inst.as_ref())))
+    }
+
+    /// Prints how long each stage of the last [Self::compile] took to stderr, if `--timings` was given
+    pub fn print_timings(&self) {
+        let Some(timings) = &self.linker.timings else {
+            return;
+        };
+        match self.linker.options.timings {
+            Some(TimingsFormat::Json) => eprintln!("{}", timings.to_json()),
+            Some(TimingsFormat::Human) | None => eprint!("{}", timings.to_text()),
+        }
+    }
+
+    /// Whether any error (not warning) was reported in any file
+    pub fn has_errors(&self) -> bool {
+        self.linker
+            .collect_all_diagnostics()
+            .iter()
+            .any(|(_, err)| err.level == ErrorLevel::Error)
+    }
+
+    /// Every `--top` module must exist and have no template parameters
+    fn check_top_modules(&self) -> Result<(), String> {
+        for top in &self.linker.options.top_modules {
+            match self
+                .linker
+                .modules
+                .iter()
+                .find(|(_, md)| &md.link_info.name == top)
+            {
+                Some((_, md)) if !md.link_info.template_parameters.is_empty() => {
+                    return Err(format!("Top module {top} can't have template parameters"));
+                }
+                Some(_) => {}
+                None => {
+                    let mut available: Vec<&str> = self
+                        .linker
+                        .modules
+                        .iter()
+                        .filter(|(_, md)| md.link_info.template_parameters.is_empty())
+                        .map(|(_, md)| md.link_info.name.as_str())
+                        .collect();
+                    available.sort_unstable();
+                    available.dedup();
+                    return Err(format!(
+                        "Unknown top module {top}. Available modules are: {}",
+                        available.join(", ")
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The source files of each generated file, see [Linker::transitive_source_files]. Sorted, and merged for files that hold multiple modules
+    fn generated_file_sources(
+        &self,
+        generated_files: &[(PathBuf, ModuleUUID)],
+    ) -> Vec<(PathBuf, Vec<String>)> {
+        let mut result: Vec<(PathBuf, Vec<String>)> = Vec::new();
+        for (path, md_id) in generated_files {
+            let sources = self
+                .linker
+                .transitive_source_files(GlobalUUID::Module(*md_id))
+                .into_iter()
+                .map(|file| self.linker.files[file].file_identifier.clone());
+            match result.iter_mut().find(|(existing, _)| existing == path) {
+                Some((_, existing_sources)) => existing_sources.extend(sources),
+                None => result.push((path.clone(), sources.collect())),
+            }
+        }
+        for (_, sources) in &mut result {
+            sources.sort();
+            sources.dedup();
+        }
+        result
+    }
+
+    /// Writes out the code requested by [CompilerOptions::codegen], [CompilerOptions::codegen_group_by_file] and [CompilerOptions::codegen_module_and_dependencies_one_file],
+    /// and the graphs requested by [CompilerOptions::emit_hierarchy_dot] and [CompilerOptions::emit_latency_dot].
+    /// Finally, a manifest of the generated code files is written for [CompilerOptions::emit_manifest], and their dependencies for [CompilerOptions::emit_deps]
+    pub fn codegen(&self) -> Result<(), String> {
+        let options = &self.linker.options;
+        if options.early_exit != EarlyExitUpTo::CodeGen {
+            return Ok(());
+        }
+
+        let codegen_backend = match options.target_language {
+            TargetLanguage::SystemVerilog => {
+                Box::new(VerilogCodegenBackend) as Box<dyn CodeGenBackend>
+            }
+            TargetLanguage::Vhdl => Box::new(VHDLCodegenBackend) as Box<dyn CodeGenBackend>,
+        };
+
+        self.check_top_modules()?;
+
+        let mut generated_files: Vec<(PathBuf, ModuleUUID)> = Vec::new();
+
+        if options.codegen {
+            // Arena order depends on the order files were added and edited, so sort for a stable output
+            let mut modules: Vec<(ModuleUUID, &Module)> = self.linker.modules.iter().collect();
+            modules.sort_by(|(_, a), (_, b)| {
+                let file_a = &self.linker.files[a.link_info.file].file_identifier;
+                let file_b = &self.linker.files[b.link_info.file].file_identifier;
+                (&a.link_info.name, file_a).cmp(&(&b.link_info.name, file_b))
+            });
+            for (md_id, md) in modules {
+                if !is_selected_by_top(md, options) {
+                    continue;
+                }
+                let path = codegen_backend.codegen_to_file(md, &self.linker);
+                generated_files.push((path, md_id));
+            }
+        }
+
+        if options.codegen_group_by_file {
+            let mut files: Vec<_> = self.linker.files.iter().collect();
+            files.sort_by(|(_, a), (_, b)| a.file_identifier.cmp(&b.file_identifier));
+            for (file_id, file_data) in files {
+                let module_ids: Vec<ModuleUUID> = file_data
+                    .associated_values
+                    .iter()
+                    .filter_map(|global| match global {
+                        GlobalUUID::Module(md_id) => {
+                            is_selected_by_top(&self.linker.modules[*md_id], options)
+                                .then_some(*md_id)
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                if module_ids.is_empty() {
+                    continue;
+                }
+                let path = codegen_backend.codegen_source_file_to_file(file_id, &self.linker);
+                generated_files.extend(module_ids.into_iter().map(|md_id| (path.clone(), md_id)));
+            }
+        }
+
+        if let Some(md_name) = &options.codegen_module_and_dependencies_one_file {
+            let Some((md_id, md)) = self
+                .linker
+                .modules
+                .iter()
+                .find(|(_, md)| &md.link_info.name == md_name)
+            else {
+                return Err(format!("Unknown module {md_name}"));
+            };
+
+            let path = codegen_backend.codegen_with_dependencies(
+                &self.linker,
+                md,
+                &format!("{md_name}_standalone"),
+            );
+            generated_files.push((path, md_id));
+        }
+
+        if let Some(manifest_path) = &options.emit_manifest {
+            let manifest_entries: Vec<(PathBuf, String)> = generated_files
+                .iter()
+                .map(|(path, md_id)| {
+                    let name = self.linker.modules[*md_id].link_info.name.clone();
+                    (path.clone(), name)
+                })
+                .collect();
+            write_manifest(manifest_path, &manifest_entries).map_err(|reason| {
+                format!(
+                    "Could not write '{}' because {reason}",
+                    manifest_path.display()
+                )
+            })?;
+        }
+
+        if let Some(deps_path) = &options.emit_deps {
+            write_deps(deps_path, &self.generated_file_sources(&generated_files)).map_err(
+                |reason| format!("Could not write '{}' because {reason}", deps_path.display()),
+            )?;
+        }
+
+        if let Some(dot_path) = &options.emit_hierarchy_dot {
+            std::fs::write(dot_path, hierarchy_dot(&self.linker)).map_err(|reason| {
+                format!("Could not write '{}' because {reason}", dot_path.display())
+            })?;
+        }
+
+        for md_name in &options.emit_latency_dot {
+            let Some((_, md)) = self
+                .linker
+                .modules
+                .iter()
+                .find(|(_, md)| &md.link_info.name == md_name)
+            else {
+                return Err(format!("Unknown module {md_name}"));
+            };
+
+            let dot_path = format!("{md_name}_latency.dot");
+            std::fs::write(&dot_path, latency_dot(md))
+                .map_err(|reason| format!("Could not write '{dot_path}' because {reason}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_text_edit, compile_sources, escape_make_path, warn_unused_modules, write_deps,
+        write_manifest, Compiler, MemorySourceProvider, TextEdit, STD_LIB_PATH,
+    };
+    use crate::config::CompilerOptions;
+    use std::path::{Path, PathBuf};
+    use tree_sitter::Parser;
+
+    #[test]
+    fn test_incremental_reparse_matches_full_reparse() {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_sus::language()).unwrap();
+
+        let mut text = "module a {\n    int x = 3\n}\n".to_owned();
+        let mut old_tree = Some(parser.parse(&text, None).unwrap());
+        let edit = TextEdit {
+            replace_range: 19..24,
+            new_text: "y = 4 + 5".to_owned(),
+        };
+        apply_text_edit(&mut text, &mut old_tree, &edit);
+        assert_eq!(text, "module a {\n    int y = 4 + 5\n}\n");
+
+        let incremental = parser.parse(&text, old_tree.as_ref()).unwrap();
+        let full = parser.parse(&text, None).unwrap();
+        assert_eq!(
+            incremental.root_node().to_sexp(),
+            full.root_node().to_sexp()
+        );
+    }
+
+    #[test]
+    fn test_inconsistent_edit_discards_old_tree() {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_sus::language()).unwrap();
+
+        let mut text = "module a {}\n".to_owned();
+        let mut old_tree = Some(parser.parse(&text, None).unwrap());
+        let edit = TextEdit {
+            replace_range: 11..100,
+            new_text: "\nmodule b {}\n".to_owned(),
+        };
+        apply_text_edit(&mut text, &mut old_tree, &edit);
+        assert!(old_tree.is_none());
+        assert_eq!(text, "module a {}\nmodule b {}\n");
+    }
+
+    #[test]
+    fn test_write_manifest() {
+        let dir = std::env::temp_dir().join(format!("sus_manifest_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let generated = dir.join("myMod.sv");
+        std::fs::write(&generated, "").unwrap();
+        let manifest_path = dir.join("manifest.json");
+        write_manifest(&manifest_path, &[(generated.clone(), "myMod".to_owned())]).unwrap();
+
+        let manifest: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&manifest_path).unwrap()).unwrap();
+        let entries = manifest.as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["module"], "myMod");
+        let path = PathBuf::from(entries[0]["path"].as_str().unwrap());
+        assert!(path.is_absolute());
+        assert_eq!(path, std::fs::canonicalize(&generated).unwrap());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_deps() {
+        let mut compiler = Compiler::with_source_provider(
+            CompilerOptions::default(),
+            Box::new(MemorySourceProvider::with_standard_library()),
+        );
+        compiler.add_file(
+            "a.sus".to_owned(),
+            "module passA {\n    interface passA : int x -> int y\n    y = x\n}\n".to_owned(),
+        );
+        compiler.add_file(
+            "my dir/b.sus".to_owned(),
+            "module passB {\n    interface passB : int x -> int y\n    y = passA(x)\n}\n"
+                .to_owned(),
+        );
+        compiler.add_file(
+            "c.sus".to_owned(),
+            "module passC {\n    interface passC : int x -> int y\n    y = passB(x)\n}\nmodule alone {\n    interface alone : int x -> int y\n    y = x\n}\n"
+                .to_owned(),
+        );
+        compiler.compile();
+        let md_id = |name: &str| {
+            compiler
+                .linker
+                .modules
+                .iter()
+                .find(|(_, md)| md.link_info.name == name)
+                .unwrap()
+                .0
+        };
+
+        // passC depends on a.sus through passB. Both modules of c.sus share one generated file
+        let sources = compiler.generated_file_sources(&[
+            (PathBuf::from("out/c.sv"), md_id("passC")),
+            (PathBuf::from("out/c.sv"), md_id("alone")),
+            (PathBuf::from("out/passA.sv"), md_id("passA")),
+        ]);
+        // 'int' is declared in the standard library, so that's a dependency as well
+        let std_core = Path::new(STD_LIB_PATH).join("core.sus");
+        let std_core = std_core.to_string_lossy();
+        assert_eq!(
+            sources,
+            [
+                (
+                    PathBuf::from("out/c.sv"),
+                    vec![
+                        std_core.to_string(),
+                        "a.sus".to_owned(),
+                        "c.sus".to_owned(),
+                        "my dir/b.sus".to_owned()
+                    ]
+                ),
+                (
+                    PathBuf::from("out/passA.sv"),
+                    vec![std_core.to_string(), "a.sus".to_owned()]
+                )
+            ]
+        );
+
+        let dir = std::env::temp_dir().join(format!("sus_deps_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let deps_path = dir.join("out.d");
+        let sources = [
+            (
+                PathBuf::from("out/c.sv"),
+                vec!["a.sus".to_owned(), "my dir/b.sus".to_owned()],
+            ),
+            (PathBuf::from("out/passA.sv"), vec!["a.sus".to_owned()]),
+        ];
+        write_deps(&deps_path, &sources).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&deps_path).unwrap(),
+            "out/c.sv: a.sus my\\ dir/b.sus\nout/passA.sv: a.sus\n"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(escape_make_path("a #1$.sus"), "a\\ \\#1$$.sus");
+    }
+
+    #[test]
+    fn test_parse_failure_gives_empty_file() {
+        let mut parser = super::new_sus_parser(&CompilerOptions::default());
+        let text = "module a {\n    int x = 3\n}\n".repeat(100000);
+        // Tree-sitter gives up once the timeout expires
+        parser.set_timeout_micros(1000);
+        let (tree, failure) = super::parse_source(&mut parser, &text, None);
+        assert!(failure
+            .unwrap()
+            .contains("too complex to parse within 1 ms"));
+        assert_eq!(tree.root_node().kind(), "source_file");
+        assert_eq!(tree.root_node().child_count(), 0);
+    }
+
+    #[test]
+    fn test_warn_unused_modules() {
+        let mut result = compile_sources(
+            CompilerOptions::default(),
+            vec![
+                (
+                    "unused.sus".to_owned(),
+                    "
+module leaf {
+    interface leaf : int a -> int b
+    b = a
+}
+module top {
+    interface top : int a -> int b
+    b = leaf(a)
+}
+module dead {
+    output int o = 3
+}
+"
+                    .to_owned(),
+                ),
+                // Its path looks like that of the standard library, but it wasn't added as part of it
+                (
+                    format!("{STD_LIB_PATH}_copy/lonely.sus"),
+                    "module lonely {\n    output int o = 3\n}\n".to_owned(),
+                ),
+            ],
+        );
+        warn_unused_modules(&mut result.linker);
+        let mut warnings: Vec<String> = result
+            .linker
+            .collect_all_diagnostics()
+            .into_iter()
+            .filter(|(_, err)| err.reason.starts_with("Unused Module"))
+            .map(|(_, err)| err.reason)
+            .collect();
+        warnings.sort();
+        // leaf is used by top, the standard library is exempt
+        assert_eq!(
+            warnings,
+            [
+                "Unused Module: 'dead' is never used as a submodule",
+                "Unused Module: 'lonely' is never used as a submodule",
+                "Unused Module: 'top' is never used as a submodule"
+            ]
+        );
     }
 }
@@ -3,15 +3,19 @@ use std::{
     collections::HashSet,
     env,
     ffi::{OsStr, OsString},
-    path::PathBuf,
+    io::IsTerminal,
+    net::IpAddr,
+    path::{Path, PathBuf},
     sync::LazyLock,
 };
 
+use crate::linker::features::Feature;
+
 /// Describes at what point in the compilation process we should exit early.
 ///
 /// This is mainly to aid in debugging, where incorrect results from flattening/typechecking may lead to errors,
 /// which we still wish to see in say the LSP
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 pub enum EarlyExitUpTo {
     Initialize,
     Flatten,
@@ -21,28 +25,318 @@ pub enum EarlyExitUpTo {
     CodeGen,
 }
 
+/// The compilation stages after which the intermediate representation can be printed with `--dump-stage`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DumpStage {
+    Flatten,
+    AbstractTypecheck,
+    Instantiate,
+}
+
+impl DumpStage {
+    /// The [EarlyExitUpTo] that must at least be reached for this stage to be dumped
+    pub fn required_stage(self) -> EarlyExitUpTo {
+        match self {
+            DumpStage::Flatten => EarlyExitUpTo::Flatten,
+            DumpStage::AbstractTypecheck => EarlyExitUpTo::AbstractTypecheck,
+            DumpStage::Instantiate => EarlyExitUpTo::Instantiate,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
 pub enum TargetLanguage {
     SystemVerilog,
     Vhdl,
 }
 
+/// How the names of module instances in the generated code are derived from their template arguments
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ManglingScheme {
+    /// The module name and all template arguments, like `FIFO__Ttypeint_DEPTH3__`.
+    /// Should two instances of a module end up with the same name, a hash of the arguments is appended
+    Readable,
+    /// The module name and a hash of the template arguments, like `FIFO_5f1c0a6e2b9d8e73`
+    Hash,
+}
+
+/// How errors and warnings are printed on the CLI
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum MessageFormat {
+    /// Pretty-printed reports with source snippets
+    Human,
+    /// One `file:line:col: level: message` line per diagnostic
+    Short,
+    /// One JSON object per diagnostic, per line
+    Json,
+}
+
+/// How `--timings` prints how long each compilation stage took
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TimingsFormat {
+    /// A table on stderr
+    Human,
+    /// A single JSON object on stderr, with all durations in microseconds
+    Json,
+}
+
+/// Whether errors and warnings are printed in color
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Use color if the stream that is printed to is a terminal
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Decides per output stream, such that piping one of stdout and stderr to a file doesn't affect the other
+    pub fn use_color_for(self, stream: &impl IsTerminal) -> bool {
+        match self {
+            ColorChoice::Auto => stream.is_terminal(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
 /// All command-line flags are converted to this struct, of which the singleton instance can be acquired using [crate::config::config]
-#[derive(Debug, PartialEq, Eq)]
+///
+/// Only the CLI itself and the LSP read it. Everything that influences compilation is in [Self::options], which is passed on to the [crate::linker::Linker]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ConfigStruct {
     pub use_lsp: bool,
     pub lsp_debug_mode: bool,
+    /// Use stdin/stdout as the LSP transport instead of a TCP socket
+    pub lsp_stdio: bool,
+    pub lsp_host: IpAddr,
     pub lsp_port: u16,
+    /// Show progress messages, see [crate::dev_aid::logger]
+    pub verbose: bool,
+    /// `--check`: Only report diagnostics, and exit with an error code if there were errors. Implies [EarlyExitUpTo::Lint]
+    pub check: bool,
+    /// `--eval`: Print the value of this generative expression instead of generating code, see [crate::Compiler::eval_expression]
+    pub eval: Option<String>,
+    pub files: Vec<PathBuf>,
+    /// No files were given, so [Self::files] are the `.sus` files in the current directory
+    pub files_from_current_directory: bool,
+    pub options: CompilerOptions,
+}
+
+/// The options that influence compilation, see [crate::Compiler::with_options]. Each [crate::linker::Linker] has its own.
+///
+/// The CLI builds them from its flags, see [ConfigStruct::options]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompilerOptions {
+    /// `--quiet`: Only print errors, no warnings or progress messages. Exits with an error code if there were errors
+    pub quiet: bool,
+    /// `--timings`: Print how long each compilation stage took, see [crate::dev_aid::timings]
+    pub timings: Option<TimingsFormat>,
+    /// `--timings-per-module`: Also list the time of each module within the stages of `--timings`
+    pub timings_per_module: bool,
     pub codegen: bool,
     pub debug_print_module_contents: bool,
     pub debug_print_latency_graph: bool,
+    /// Print global UUIDs as their raw arena index in debug output, instead of [crate::linker::Linker::stable_global_names]
+    pub debug_raw_uuids: bool,
     pub debug_whitelist: Option<HashSet<String>>,
     pub codegen_module_and_dependencies_one_file: Option<String>,
+    /// `--group-by-file`: Generate one file per source file, containing all modules defined in it
+    pub codegen_group_by_file: bool,
+    /// `--top`: Only instantiate and generate code for these modules and their submodules. Empty means all modules without template parameters
+    pub top_modules: Vec<String>,
+    pub emit_hierarchy_dot: Option<PathBuf>,
+    pub emit_latency_dot: Vec<String>,
+    pub emit_manifest: Option<PathBuf>,
+    /// `--emit-deps`: After code generation, write a Makefile dependency list of each generated file on its `.sus` sources to this file
+    pub emit_deps: Option<PathBuf>,
     pub early_exit: EarlyExitUpTo,
-    pub use_color: bool,
+    /// `--parse-timeout-ms`: Files that take longer than this to parse are reported as too complex, instead of hanging the compiler
+    pub parse_timeout_ms: Option<u64>,
+    pub dump_stage: Option<DumpStage>,
+    /// `--dump-flat-json`: Print the flattened IR of all modules as JSON after typechecking
+    pub dump_flat_json: bool,
+    /// `--estimate`: Print a rough resource estimate of every instance after instantiation
+    pub estimate: bool,
+    pub color: ColorChoice,
+    pub message_format: MessageFormat,
+    /// `--error-limit`: At most this many errors are printed. Warnings don't count towards it. 0 means no limit
+    pub error_limit: usize,
     pub ci: bool,
     pub target_language: TargetLanguage,
-    pub files: Vec<PathBuf>,
+    pub mangling: ManglingScheme,
+    /// `--strict-sv`: Wrap generated SystemVerilog modules in `` `default_nettype none ``, such that downstream tools reject implicit nets
+    pub strict_sv: bool,
+    /// `--annotate-ports`: Add a comment with the direction, bit range, width and latency to each port of the generated SystemVerilog modules
+    pub annotate_ports: bool,
+    /// `--emit-assertions`: Repeat the generative `assert`s of each module as SystemVerilog assertions, such that third-party elaboration also checks them.
+    /// Runtime `assert(cond)`s and `assume(cond)`s become `assert property` and `assume property`, and are dropped without this
+    pub emit_assertions: bool,
+    /// `--warn-unused-modules`: Warn on modules that aren't used as a submodule anywhere, and aren't a `--top` or the `--standalone` module
+    pub warn_unused_modules: bool,
+    /// Enabled in every file, as if it had a `#![feature(...)]` pragma. The CLI takes them from the `features` of the [ProjectConfig]
+    pub features: Vec<Feature>,
+}
+
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        Self {
+            quiet: false,
+            timings: None,
+            timings_per_module: false,
+            codegen: false,
+            debug_print_module_contents: false,
+            debug_print_latency_graph: false,
+            debug_raw_uuids: false,
+            debug_whitelist: None,
+            codegen_module_and_dependencies_one_file: None,
+            codegen_group_by_file: false,
+            top_modules: Vec::new(),
+            emit_hierarchy_dot: None,
+            emit_latency_dot: Vec::new(),
+            emit_manifest: None,
+            emit_deps: None,
+            early_exit: EarlyExitUpTo::CodeGen,
+            parse_timeout_ms: None,
+            dump_stage: None,
+            dump_flat_json: false,
+            estimate: false,
+            color: ColorChoice::Never,
+            message_format: MessageFormat::Human,
+            error_limit: 100,
+            ci: false,
+            target_language: TargetLanguage::SystemVerilog,
+            mangling: ManglingScheme::Readable,
+            strict_sv: false,
+            annotate_ports: false,
+            emit_assertions: false,
+            warn_unused_modules: false,
+            features: Vec::new(),
+        }
+    }
+}
+
+/// The project configuration file that is read from the current directory, unless `--config` names another one
+pub const PROJECT_CONFIG_FILE: &str = "sus.toml";
+
+/// Defaults for command-line flags, read from [PROJECT_CONFIG_FILE]. Flags given on the command line override them.
+///
+/// ```toml
+/// target = "vhdl"
+/// top = ["cpu", "uart"]
+/// mangle = "hash"
+/// strict-sv = true
+/// emit-assertions = true
+/// warn-unused-modules = true
+/// features = ["assert_statements"]
+/// ```
+///
+/// The `features` are enabled in every file, as if they had a `#![feature(...)]` pragma, see [crate::linker::features]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectConfig {
+    pub target_language: Option<TargetLanguage>,
+    pub top_modules: Option<Vec<String>>,
+    pub mangling: Option<ManglingScheme>,
+    pub strict_sv: Option<bool>,
+    pub emit_assertions: Option<bool>,
+    pub warn_unused_modules: Option<bool>,
+    pub features: Vec<Feature>,
+}
+
+impl ProjectConfig {
+    const KEYS: &'static [&'static str] = &[
+        "target",
+        "top",
+        "mangle",
+        "strict-sv",
+        "emit-assertions",
+        "warn-unused-modules",
+        "features",
+    ];
+
+    /// Reads the configuration at `path`. Returns `Ok(None)` if there is no file there and `required` is false
+    pub fn load(path: &Path, required: bool) -> Result<Option<Self>, String> {
+        let path_disp = path.display();
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound && !required => {
+                return Ok(None);
+            }
+            Err(err) => {
+                return Err(format!(
+                    "Could not read the project configuration '{path_disp}' because {err}"
+                ))
+            }
+        };
+        Self::parse(&text)
+            .map(Some)
+            .map_err(|reason| format!("Invalid project configuration '{path_disp}': {reason}"))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table: toml::Table =
+            (text.parse()).map_err(|err: toml::de::Error| err.to_string().trim_end().to_owned())?;
+        let mut result = ProjectConfig::default();
+        for (key, value) in &table {
+            match key.as_str() {
+                "target" => result.target_language = Some(parse_enum_value(key, value)?),
+                "top" => result.top_modules = Some(parse_string_list(key, value)?),
+                "mangle" => result.mangling = Some(parse_enum_value(key, value)?),
+                "strict-sv" => result.strict_sv = Some(parse_bool_value(key, value)?),
+                "emit-assertions" => result.emit_assertions = Some(parse_bool_value(key, value)?),
+                "warn-unused-modules" => {
+                    result.warn_unused_modules = Some(parse_bool_value(key, value)?)
+                }
+                "features" => {
+                    for name in parse_string_list(key, value)? {
+                        let feature = Feature::from_name(&name).ok_or_else(|| {
+                            let available: Vec<&str> =
+                                Feature::ALL.iter().map(|feature| feature.name()).collect();
+                            format!(
+                                "Unknown feature '{name}'. The available features are: {}",
+                                available.join(", ")
+                            )
+                        })?;
+                        if !result.features.contains(&feature) {
+                            result.features.push(feature);
+                        }
+                    }
+                }
+                _ => {
+                    return Err(format!(
+                        "Unknown key '{key}'. The supported keys are: {}",
+                        Self::KEYS.join(", ")
+                    ))
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn parse_bool_value(key: &str, value: &toml::Value) -> Result<bool, String> {
+    value
+        .as_bool()
+        .ok_or_else(|| format!("'{key}' must be true or false"))
+}
+
+fn parse_string_list(key: &str, value: &toml::Value) -> Result<Vec<String>, String> {
+    let error = || format!("'{key}' must be a list of strings");
+    let list = value.as_array().ok_or_else(error)?;
+    list.iter()
+        .map(|v| v.as_str().map(str::to_owned).ok_or_else(error))
+        .collect()
+}
+
+/// Accepts the same names as the command-line flag of `key`
+fn parse_enum_value<E: ValueEnum>(key: &str, value: &toml::Value) -> Result<E, String> {
+    let possible_values: Vec<String> = (E::value_variants().iter())
+        .filter_map(|v| v.to_possible_value())
+        .map(|v| v.get_name().to_owned())
+        .collect();
+    let error = || format!("'{key}' must be one of: {}", possible_values.join(", "));
+    let name = value.as_str().ok_or_else(error)?;
+    E::from_str(name, false).map_err(|_| error())
 }
 
 fn command_builder() -> Command {
@@ -61,16 +355,53 @@ fn command_builder() -> Command {
                 }
             })
             .requires("lsp"))
+        .arg(Arg::new("lsp-host")
+            .long("lsp-host")
+            .default_value("127.0.0.1")
+            .help("Set the IP address of the LSP TCP socket")
+            .value_parser(|host: &str| {
+                match host.parse::<IpAddr>() {
+                    Ok(addr) => Ok(addr),
+                    Err(_) => Err("Must be a valid IPv4 or IPv6 address")
+                }
+            })
+            .requires("lsp"))
         .arg(Arg::new("lsp")
             .long("lsp")
             .help("Enable LSP mode")
             .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("lsp-stdio")
+            .long("lsp-stdio")
+            .help("Enable LSP mode, communicating over stdin/stdout instead of a TCP socket")
+            .conflicts_with_all(["socket", "lsp-host"])
+            .action(clap::ArgAction::SetTrue))
         .arg(Arg::new("lsp-debug")
             .long("lsp-debug")
             .hide(true)
             .help("Enable LSP debug mode")
             .requires("lsp")
             .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("verbose")
+            .long("verbose")
+            .short('v')
+            .help("Print progress messages of the compiler to stderr")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("quiet")
+            .long("quiet")
+            .short('q')
+            .help("Only print errors. Warnings and informational messages are suppressed, and the compiler exits with an error code if there were errors")
+            .conflicts_with("verbose")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("check")
+            .long("check")
+            .help("Only check the code for errors, without generating any code or files. Runs parsing, flattening, typechecking and lints, but no instantiation (same as --upto lint). Exits with code 1 if there were errors")
+            .conflicts_with_all(["upto", "codegen", "standalone", "group-by-file", "emit-hierarchy-dot", "emit-latency-dot", "emit-manifest", "emit-deps"])
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("eval")
+            .long("eval")
+            .value_name("EXPR")
+            .help("Evaluate a single generative expression like \"clog2 #(V: 100) + 3\" and print its value. It can use the globals of the given files. Exits with code 1 if it couldn't be evaluated")
+            .conflicts_with_all(["check", "upto", "codegen", "standalone", "group-by-file", "emit-hierarchy-dot", "emit-latency-dot", "emit-manifest", "emit-deps"]))
         .arg(Arg::new("codegen")
             .long("codegen")
             .help("Enable code generation for all modules. This creates a file named [ModuleName].sv per module.")
@@ -85,23 +416,107 @@ fn command_builder() -> Command {
             .hide(true)
             .help("Print latency graph for debugging")
             .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("debug-raw-uuids")
+            .long("debug-raw-uuids")
+            .hide(true)
+            .help("In debug output, print modules, types, constants and files by their internal index (like type_1), instead of by their name and a hash of their source (like type(::int@8c3a51f0)). The indices depend on the order files were added in")
+            .action(clap::ArgAction::SetTrue))
         .arg(Arg::new("debug-whitelist")
             .long("debug-whitelist")
             .hide(true)
-            .help("Sets the modules that should be shown by --debug. When not provided all modules are whitelisted")
+            .help("Sets the modules that should be shown by --debug and --debug-latency. When not provided all modules are whitelisted")
             .action(clap::ArgAction::Append))
         .arg(Arg::new("standalone")
             .long("standalone")
             .help("Generate standalone code with all dependencies in one file of the module specified."))
+        .arg(Arg::new("group-by-file")
+            .long("group-by-file")
+            .help("Generate one file per source file instead of one per module. For example, all modules defined in util.sus go into util.sv")
+            .conflicts_with("codegen")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("top")
+            .long("top")
+            .value_name("MODULES")
+            .help("Only instantiate and generate code for the given comma-separated modules and their submodules, instead of all modules without template parameters")
+            .value_delimiter(',')
+            .action(clap::ArgAction::Append))
+        .arg(Arg::new("emit-hierarchy-dot")
+            .long("emit-hierarchy-dot")
+            .value_name("FILE")
+            .help("Write the module hierarchy as a Graphviz DOT graph to the given file")
+            .value_parser(clap::value_parser!(PathBuf)))
+        .arg(Arg::new("emit-latency-dot")
+            .long("emit-latency-dot")
+            .value_name("MODULE")
+            .help("Write the latency graph of the given module as a Graphviz DOT graph to [MODULE]_latency.dot. Can be given multiple times")
+            .action(clap::ArgAction::Append))
+        .arg(Arg::new("emit-manifest")
+            .long("emit-manifest")
+            .value_name("FILE")
+            .help("After code generation, write a JSON list of all generated files and the module they came from to the given file")
+            .value_parser(clap::value_parser!(PathBuf)))
+        .arg(Arg::new("emit-deps")
+            .long("emit-deps")
+            .value_name("FILE")
+            .help("After code generation, write a Makefile-style list of the .sus files each generated file depends on to the given file, for incremental builds with Make or Ninja")
+            .value_parser(clap::value_parser!(PathBuf)))
         .arg(Arg::new("upto")
             .long("upto")
             .help("Describes at what point in the compilation process we should exit early. This is mainly to aid in debugging, where incorrect results from flattening/typechecking may lead to errors, which we still wish to see in say the LSP")
             .value_parser(clap::builder::EnumValueParser::<EarlyExitUpTo>::new())
             .default_value("code-gen"))
+        .arg(Arg::new("parse-timeout-ms")
+            .long("parse-timeout-ms")
+            .value_name("MILLISECONDS")
+            .help("Give up parsing a file after this many milliseconds, and report it as too complex to parse. Useful to keep the LSP responsive on very large or adversarial files")
+            .value_parser(clap::value_parser!(u64).range(1..)))
+        .arg(Arg::new("dump-stage")
+            .long("dump-stage")
+            .help("Print the intermediate representation of all modules after the given compilation stage to stdout. Respects --debug-whitelist")
+            .value_parser(clap::builder::EnumValueParser::<DumpStage>::new()))
+        .arg(Arg::new("dump-flat-json")
+            .long("dump-flat-json")
+            .help("Print the flattened IR of all modules after typechecking to stdout, as a JSON list with one object per module. Respects --debug-whitelist")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("estimate")
+            .long("estimate")
+            .help("Print a rough resource estimate of every instance after instantiation: registers, adders, multipliers and wire bits. This counts the netlist, it is no substitute for synthesis. Respects --debug-whitelist")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("timings")
+            .long("timings")
+            .value_name("FORMAT")
+            .help("Print how long flattening, typechecking, linting and instantiation took to stderr, as a table or as JSON")
+            .value_parser(clap::builder::EnumValueParser::<TimingsFormat>::new())
+            .num_args(0..=1)
+            .require_equals(true)
+            .default_missing_value("human"))
+        .arg(Arg::new("timings-per-module")
+            .long("timings-per-module")
+            .help("With --timings, also list the time of each module within every stage. Modules are flattened and typechecked in parallel, so their times can add up to more than the stage")
+            .requires("timings")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("color")
+            .long("color")
+            .help("Sets when errors and warnings are printed in color. 'auto' checks if stdout and stderr are terminals separately")
+            .value_parser(clap::builder::EnumValueParser::<ColorChoice>::new())
+            .default_value("auto"))
         .arg(Arg::new("nocolor")
             .long("nocolor")
-            .help("Disables color printing in the errors of the sus_compiler output")
+            .hide(true)
+            .help("Same as --color never")
+            .conflicts_with("color")
             .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("message-format")
+            .long("message-format")
+            .help("Sets how errors and warnings are printed")
+            .value_parser(clap::builder::EnumValueParser::<MessageFormat>::new())
+            .default_value("human"))
+        .arg(Arg::new("error-limit")
+            .long("error-limit")
+            .value_name("N")
+            .help("Print at most N errors, and how many more were suppressed. Warnings don't count towards the limit, and --message-format=json always prints everything. 0 means no limit")
+            .value_parser(clap::value_parser!(usize))
+            .default_value("100"))
         .arg(Arg::new("ci")
                 .long("ci")
                 .help("Makes the compiler output as environment agnostic as possible")
@@ -111,6 +526,47 @@ fn command_builder() -> Command {
             .help("Sets the target HDL")
             .value_parser(clap::builder::EnumValueParser::<TargetLanguage>::new())
             .default_value("system-verilog"))
+        .arg(Arg::new("mangle")
+            .long("mangle")
+            .help("How instances of modules with template arguments are named in the generated code. 'readable' spells out all arguments, like FIFO__Ttypeint_DEPTH3__. 'hash' uses a hash of the arguments instead, like FIFO_5f1c0a6e2b9d8e73, which keeps names short")
+            .value_parser(clap::builder::EnumValueParser::<ManglingScheme>::new())
+            .default_value("readable"))
+        .arg(Arg::new("strict-sv")
+            .long("strict-sv")
+            .help("Wrap each generated SystemVerilog module in `default_nettype none and `default_nettype wire, such that any net that isn't explicitly declared is an error in downstream tools")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("no-strict-sv")
+            .long("no-strict-sv")
+            .help("Turn off --strict-sv, also when the project configuration turns it on")
+            .conflicts_with("strict-sv")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("annotate-ports")
+            .long("annotate-ports")
+            .help("Add a comment like '// input [7:0] width=8 latency=0' above each port of the generated SystemVerilog modules")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("emit-assertions")
+            .long("emit-assertions")
+            .help("Repeat the generative assert #(C: ...) checks of each module as SystemVerilog 'initial assert' statements, in terms of its template parameters. Runtime assert(cond) and assume(cond) statements become 'assert property' and 'assume property' statements")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("no-emit-assertions")
+            .long("no-emit-assertions")
+            .help("Turn off --emit-assertions, also when the project configuration turns it on")
+            .conflicts_with("emit-assertions")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("warn-unused-modules")
+            .long("warn-unused-modules")
+            .help("Warn on every module that is never used as a submodule. The standard library, the --top modules and the --standalone module are exempt")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("no-warn-unused-modules")
+            .long("no-warn-unused-modules")
+            .help("Turn off --warn-unused-modules, also when the project configuration turns it on")
+            .conflicts_with("warn-unused-modules")
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("config")
+            .long("config")
+            .value_name("FILE")
+            .help("Read defaults for the flags from this project configuration file instead of sus.toml in the current directory. Unlike sus.toml, it must exist")
+            .value_parser(clap::value_parser!(PathBuf)))
         .arg(Arg::new("files")
             .action(clap::ArgAction::Append)
             .help(".sus Files")
@@ -128,58 +584,207 @@ fn command_builder() -> Command {
             }))
 }
 
-fn parse_args<I, T>(itr: I) -> Result<ConfigStruct, clap::Error>
+/// The `.sus` files directly in `dir`, sorted by path. Used when no files are given on the command line
+fn sus_files_in_directory(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut file_paths = Vec::new();
+    for file in std::fs::read_dir(dir)? {
+        let file_path = file?.path();
+        if file_path.is_file() && file_path.extension() == Some("sus".as_ref()) {
+            file_paths.push(file_path);
+        }
+    }
+    // read_dir doesn't guarantee any order
+    file_paths.sort();
+    Ok(file_paths)
+}
+
+/// The command line arguments, with defaults from the project configuration given with --config, or from sus.toml in the current directory.
+///
+/// The language server doesn't read a project configuration, its working directory need not be a project.
+fn parse_args_and_project<I, T>(itr: I) -> Result<ConfigStruct, clap::Error>
 where
     I: IntoIterator<Item = T>,
     T: Into<OsString> + Clone,
 {
     let matches = command_builder().try_get_matches_from(itr)?;
+    if matches.get_flag("lsp") || matches.get_flag("lsp-stdio") {
+        return config_from_matches(&matches, ProjectConfig::default());
+    }
+    let project = match matches.get_one::<PathBuf>("config") {
+        Some(path) => ProjectConfig::load(path, true),
+        None => ProjectConfig::load(Path::new(PROJECT_CONFIG_FILE), false),
+    }
+    .map_err(|reason| command_builder().error(clap::error::ErrorKind::Io, reason))?
+    .unwrap_or_default();
+    config_from_matches(&matches, project)
+}
+
+/// Whether the flag `id` was given on the command line, instead of being left at its default
+fn given_on_command_line(matches: &clap::ArgMatches, id: &str) -> bool {
+    matches.value_source(id) == Some(clap::parser::ValueSource::CommandLine)
+}
+
+/// A flag given on the command line as `--flag` or `--no-flag` wins, otherwise the [ProjectConfig] decides
+fn flag_or_project(matches: &clap::ArgMatches, id: &str, project_value: Option<bool>) -> bool {
+    if matches.get_flag(id) {
+        true
+    } else if matches.get_flag(&format!("no-{id}")) {
+        false
+    } else {
+        project_value.unwrap_or(false)
+    }
+}
+
+/// The command-line flags, with the [ProjectConfig] filling in what wasn't given
+fn config_from_matches(
+    matches: &clap::ArgMatches,
+    project: ProjectConfig,
+) -> Result<ConfigStruct, clap::Error> {
+    let lsp_host = *matches.get_one("lsp-host").unwrap();
     let lsp_port = *matches.get_one("socket").unwrap();
-    let use_lsp = matches.get_flag("lsp");
+    let lsp_stdio = matches.get_flag("lsp-stdio");
+    let use_lsp = matches.get_flag("lsp") || lsp_stdio;
     let lsp_debug_mode = matches.get_flag("lsp-debug");
+    let verbose = matches.get_flag("verbose");
+    let quiet = matches.get_flag("quiet");
+    let check = matches.get_flag("check");
 
-    let codegen = matches.get_flag("codegen") || matches.get_many::<PathBuf>("files").is_none();
+    let eval = matches.get_one("eval").cloned();
+    let codegen_group_by_file = matches.get_flag("group-by-file");
+    let codegen = !check && !codegen_group_by_file && matches.get_flag("codegen");
+    let top_modules = matches
+        .get_many("top")
+        .map(|s| s.cloned().collect())
+        .or(project.top_modules)
+        .unwrap_or_default();
     let debug_print_module_contents = matches.get_flag("debug");
     let debug_print_latency_graph = matches.get_flag("debug-latency");
+    let debug_raw_uuids = matches.get_flag("debug-raw-uuids");
     let debug_whitelist = matches
         .get_many("debug-whitelist")
         .map(|s| s.cloned().collect());
-    let use_color = !matches.get_flag("nocolor") && !use_lsp;
-    let early_exit = *matches.get_one("upto").unwrap();
+    let color = if matches.get_flag("nocolor") || use_lsp {
+        ColorChoice::Never
+    } else {
+        *matches.get_one("color").unwrap()
+    };
+    let message_format = *matches.get_one("message-format").unwrap();
+    let timings = matches.get_one("timings").copied();
+    let timings_per_module = matches.get_flag("timings-per-module");
+    let error_limit = *matches.get_one("error-limit").unwrap();
+    let early_exit: EarlyExitUpTo = if check {
+        EarlyExitUpTo::Lint
+    } else {
+        *matches.get_one("upto").unwrap()
+    };
+    let parse_timeout_ms = matches.get_one("parse-timeout-ms").copied();
+    let dump_stage: Option<DumpStage> = matches.get_one("dump-stage").copied();
+    if let Some(dump_stage) = dump_stage {
+        if early_exit < dump_stage.required_stage() {
+            return Err(command_builder().error(
+                clap::error::ErrorKind::ArgumentConflict,
+                "--dump-stage requires --upto to include the dumped stage",
+            ));
+        }
+    }
+    let dump_flat_json = matches.get_flag("dump-flat-json");
+    if dump_flat_json && early_exit < EarlyExitUpTo::AbstractTypecheck {
+        return Err(command_builder().error(
+            clap::error::ErrorKind::ArgumentConflict,
+            "--dump-flat-json requires --upto to include abstract typechecking",
+        ));
+    }
+    let estimate = matches.get_flag("estimate");
+    if estimate && early_exit < EarlyExitUpTo::Instantiate {
+        return Err(command_builder().error(
+            clap::error::ErrorKind::ArgumentConflict,
+            "--estimate requires --upto to include instantiation",
+        ));
+    }
     let codegen_module_and_dependencies_one_file = matches.get_one("standalone").cloned();
+    let emit_hierarchy_dot = matches.get_one("emit-hierarchy-dot").cloned();
+    let emit_latency_dot = matches
+        .get_many("emit-latency-dot")
+        .map(|s| s.cloned().collect())
+        .unwrap_or_default();
+    let emit_manifest = matches.get_one("emit-manifest").cloned();
+    let emit_deps = matches.get_one("emit-deps").cloned();
     let ci = matches.get_flag("ci");
-    let target_language = *matches.get_one("target").unwrap();
+    let target_language = match project.target_language {
+        Some(target_language) if !given_on_command_line(matches, "target") => target_language,
+        _ => *matches.get_one("target").unwrap(),
+    };
+    let mangling = match project.mangling {
+        Some(mangling) if !given_on_command_line(matches, "mangle") => mangling,
+        _ => *matches.get_one("mangle").unwrap(),
+    };
+    let strict_sv = flag_or_project(matches, "strict-sv", project.strict_sv);
+    let annotate_ports = matches.get_flag("annotate-ports");
+    let emit_assertions = flag_or_project(matches, "emit-assertions", project.emit_assertions);
+    let warn_unused_modules =
+        flag_or_project(matches, "warn-unused-modules", project.warn_unused_modules);
+    let files_from_current_directory = matches.get_many::<PathBuf>("files").is_none();
     let file_paths: Vec<PathBuf> = match matches.get_many("files") {
         Some(files) => files.cloned().collect(),
-        None => std::fs::read_dir(".")
-            .unwrap()
-            .map(|file| file.unwrap().path())
-            .filter(|file_path| {
-                file_path.is_file() && file_path.extension() == Some("sus".as_ref())
-            })
-            .collect(),
+        None => sus_files_in_directory(Path::new(".")).map_err(|reason| {
+            command_builder().error(
+                clap::error::ErrorKind::Io,
+                format!("Could not list the .sus files in the current directory because {reason}"),
+            )
+        })?,
     };
-    Ok(ConfigStruct {
-        use_lsp,
-        lsp_debug_mode,
-        lsp_port,
+    let options = CompilerOptions {
+        quiet,
+        timings,
+        timings_per_module,
         codegen,
         debug_print_module_contents,
         debug_print_latency_graph,
+        debug_raw_uuids,
         debug_whitelist,
         codegen_module_and_dependencies_one_file,
+        codegen_group_by_file,
+        top_modules,
+        emit_hierarchy_dot,
+        emit_latency_dot,
+        emit_manifest,
+        emit_deps,
         early_exit,
-        use_color,
+        parse_timeout_ms,
+        dump_stage,
+        dump_flat_json,
+        estimate,
+        color,
+        message_format,
+        error_limit,
         ci,
         target_language,
+        mangling,
+        strict_sv,
+        annotate_ports,
+        emit_assertions,
+        warn_unused_modules,
+        features: project.features,
+    };
+    Ok(ConfigStruct {
+        use_lsp,
+        lsp_debug_mode,
+        lsp_stdio,
+        lsp_host,
+        lsp_port,
+        verbose,
+        check,
+        eval,
         files: file_paths,
+        files_from_current_directory,
+        options,
     })
 }
 
 /// Access the singleton [ConfigStruct] representing the CLI arguments passed to `sus_compiler`
 pub fn config() -> &'static ConfigStruct {
     static CONFIG: LazyLock<ConfigStruct> = LazyLock::new(|| {
-        parse_args(std::env::args_os())
+        parse_args_and_project(env::args_os())
             .map_err(|err| err.exit())
             .unwrap()
     });
@@ -188,7 +793,18 @@ pub fn config() -> &'static ConfigStruct {
 
 #[cfg(test)]
 mod tests {
-    use super::parse_args;
+    use super::{ConfigStruct, ProjectConfig};
+    use std::ffi::OsString;
+
+    /// Only the command line arguments. Unlike the compiler itself, this doesn't read a sus.toml from the current directory
+    fn parse_args<I, T>(itr: I) -> Result<ConfigStruct, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let matches = super::command_builder().try_get_matches_from(itr)?;
+        super::config_from_matches(&matches, ProjectConfig::default())
+    }
 
     #[test]
     fn test_socket_invalid_port() {
@@ -217,12 +833,334 @@ mod tests {
     #[test]
     fn test_lsp_no_color() {
         let config = parse_args(["", "--lsp"]).unwrap();
-        assert!(!config.use_color)
+        assert_eq!(config.options.color, super::ColorChoice::Never);
+        let config = parse_args(["", "--lsp", "--color", "always"]).unwrap();
+        assert_eq!(config.options.color, super::ColorChoice::Never);
+    }
+
+    #[test]
+    fn test_message_format() {
+        let config = parse_args(["", "--message-format", "short"]).unwrap();
+        assert_eq!(config.options.message_format, super::MessageFormat::Short);
+        let config = parse_args([""]).unwrap();
+        assert_eq!(config.options.message_format, super::MessageFormat::Human);
+    }
+
+    #[test]
+    fn test_lsp_host() {
+        let config = parse_args(["", "--lsp"]).unwrap();
+        assert_eq!(config.lsp_host.to_string(), "127.0.0.1");
+        let config = parse_args(["", "--lsp", "--lsp-host", "0.0.0.0"]).unwrap();
+        assert_eq!(config.lsp_host.to_string(), "0.0.0.0");
+        assert!(parse_args(["", "--lsp", "--lsp-host", "localhost"]).is_err());
+    }
+
+    #[test]
+    fn test_lsp_stdio() {
+        let config = parse_args(["", "--lsp-stdio"]).unwrap();
+        assert!(config.use_lsp);
+        assert!(config.lsp_stdio);
+        assert_eq!(config.options.color, super::ColorChoice::Never);
+        assert!(parse_args(["", "--lsp-stdio", "--socket", "25001"]).is_err());
+    }
+
+    #[test]
+    fn test_dump_stage() {
+        let config = parse_args(["", "--dump-stage", "flatten"]).unwrap();
+        assert_eq!(config.options.dump_stage, Some(super::DumpStage::Flatten));
+        let config = parse_args(["", "--dump-stage", "flatten", "--upto", "flatten"]).unwrap();
+        assert_eq!(config.options.early_exit, super::EarlyExitUpTo::Flatten);
+        assert!(parse_args(["", "--dump-stage", "instantiate", "--upto", "lint"]).is_err());
+    }
+
+    #[test]
+    fn test_quiet() {
+        assert!(!parse_args([""]).unwrap().options.quiet);
+        assert!(parse_args(["", "--quiet"]).unwrap().options.quiet);
+        assert!(parse_args(["", "-q"]).unwrap().options.quiet);
+        assert!(parse_args(["", "--quiet", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let config = parse_args(["", "--check"]).unwrap();
+        assert!(config.check);
+        assert!(!config.options.codegen);
+        assert_eq!(config.options.early_exit, super::EarlyExitUpTo::Lint);
+        assert!(parse_args(["", "--check", "--codegen"]).is_err());
+        assert!(parse_args(["", "--check", "--emit-deps", "out.d"]).is_err());
+        assert!(parse_args(["", "--check", "--upto", "code-gen"]).is_err());
+    }
+
+    #[test]
+    fn test_eval() {
+        assert_eq!(parse_args([""]).unwrap().eval, None);
+        let config = parse_args(["", "--eval", "clog2 #(V: 100) + 3"]).unwrap();
+        assert_eq!(config.eval.as_deref(), Some("clog2 #(V: 100) + 3"));
+        assert!(!config.options.codegen);
+        assert!(parse_args(["", "--eval", "3", "--codegen"]).is_err());
+        assert!(parse_args(["", "--eval", "3", "--check"]).is_err());
+    }
+
+    #[test]
+    fn test_debug_whitelist() {
+        let options = parse_args([""]).unwrap().options;
+        assert!(options.should_print_for_debug(true, "a"));
+        assert!(!options.should_print_for_debug(false, "a"));
+
+        let options = parse_args(["", "--debug", "--debug-whitelist", "a"])
+            .unwrap()
+            .options;
+        assert!(options.should_print_for_debug(options.debug_print_module_contents, "a"));
+        assert!(!options.should_print_for_debug(options.debug_print_module_contents, "b"));
+        assert!(!options.should_print_for_debug(options.debug_print_latency_graph, "a"));
+    }
+
+    #[test]
+    fn test_no_automatic_codegen() {
+        // Not giving any files compiles the current directory, but doesn't turn on codegen
+        let config = parse_args([""]).unwrap();
+        assert!(config.files_from_current_directory);
+        assert!(!config.options.codegen);
+        assert!(parse_args(["", "--codegen"]).unwrap().options.codegen);
+    }
+
+    #[test]
+    fn test_sus_files_in_directory() {
+        let dir = std::env::temp_dir().join(format!("sus_empty_dir_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(super::sus_files_in_directory(&dir).unwrap().is_empty());
+
+        std::fs::write(dir.join("b.sus"), "").unwrap();
+        std::fs::write(dir.join("a.sus"), "").unwrap();
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+        std::fs::create_dir_all(dir.join("dir.sus")).unwrap();
+        assert_eq!(
+            super::sus_files_in_directory(&dir).unwrap(),
+            vec![dir.join("a.sus"), dir.join("b.sus")]
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(super::sus_files_in_directory(&dir).is_err());
+    }
+
+    #[test]
+    fn test_strict_sv() {
+        assert!(!parse_args([""]).unwrap().options.strict_sv);
+        assert!(parse_args(["", "--strict-sv"]).unwrap().options.strict_sv);
+    }
+
+    #[test]
+    fn test_annotate_ports() {
+        assert!(!parse_args([""]).unwrap().options.annotate_ports);
+        assert!(
+            parse_args(["", "--annotate-ports"])
+                .unwrap()
+                .options
+                .annotate_ports
+        );
+    }
+
+    #[test]
+    fn test_error_limit() {
+        assert_eq!(parse_args([""]).unwrap().options.error_limit, 100);
+        assert_eq!(
+            parse_args(["", "--error-limit", "0"])
+                .unwrap()
+                .options
+                .error_limit,
+            0
+        );
+        assert!(parse_args(["", "--error-limit", "-1"]).is_err());
+    }
+
+    #[test]
+    fn test_timings() {
+        use super::TimingsFormat;
+        assert_eq!(parse_args([""]).unwrap().options.timings, None);
+        let config = parse_args(["", "--timings", "test.sus"]).unwrap();
+        assert_eq!(config.options.timings, Some(TimingsFormat::Human));
+        assert_eq!(config.files, vec![std::path::PathBuf::from("test.sus")]);
+        let config = parse_args(["", "--timings=json", "--timings-per-module"]).unwrap();
+        assert_eq!(config.options.timings, Some(TimingsFormat::Json));
+        assert!(config.options.timings_per_module);
+        assert!(parse_args(["", "--timings-per-module"]).is_err());
+    }
+
+    #[test]
+    fn test_dump_flat_json() {
+        assert!(!parse_args([""]).unwrap().options.dump_flat_json);
+        assert!(
+            parse_args(["", "--dump-flat-json"])
+                .unwrap()
+                .options
+                .dump_flat_json
+        );
+        assert!(parse_args(["", "--dump-flat-json", "--upto", "flatten"]).is_err());
+    }
+
+    #[test]
+    fn test_estimate() {
+        assert!(!parse_args([""]).unwrap().options.estimate);
+        assert!(parse_args(["", "--estimate"]).unwrap().options.estimate);
+        assert!(parse_args(["", "--estimate", "--upto", "lint"]).is_err());
+    }
+
+    #[test]
+    fn test_emit_assertions() {
+        assert!(!parse_args([""]).unwrap().options.emit_assertions);
+        assert!(
+            parse_args(["", "--emit-assertions"])
+                .unwrap()
+                .options
+                .emit_assertions
+        );
+    }
+
+    #[test]
+    fn test_warn_unused_modules() {
+        assert!(!parse_args([""]).unwrap().options.warn_unused_modules);
+        assert!(
+            parse_args(["", "--warn-unused-modules"])
+                .unwrap()
+                .options
+                .warn_unused_modules
+        );
+    }
+
+    #[test]
+    fn test_mangle() {
+        let config = parse_args([""]).unwrap();
+        assert_eq!(config.options.mangling, super::ManglingScheme::Readable);
+        let config = parse_args(["", "--mangle", "hash"]).unwrap();
+        assert_eq!(config.options.mangling, super::ManglingScheme::Hash);
+        assert!(parse_args(["", "--mangle", "short"]).is_err());
     }
 
     #[test]
-    fn test_automatic_codegen() {
+    fn test_parse_timeout() {
         let config = parse_args([""]).unwrap();
-        assert!(config.codegen)
+        assert_eq!(config.options.parse_timeout_ms, None);
+        let config = parse_args(["", "--parse-timeout-ms", "500"]).unwrap();
+        assert_eq!(config.options.parse_timeout_ms, Some(500));
+        assert!(parse_args(["", "--parse-timeout-ms", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_group_by_file() {
+        let config = parse_args(["", "--group-by-file"]).unwrap();
+        assert!(config.options.codegen_group_by_file);
+        // Otherwise each module would also be written to its own file
+        assert!(!config.options.codegen);
+        assert!(parse_args(["", "--group-by-file", "--codegen"]).is_err());
+    }
+
+    fn parse_args_with_project(args: &[&str], project: &str) -> ConfigStruct {
+        let matches = super::command_builder().try_get_matches_from(args).unwrap();
+        super::config_from_matches(&matches, ProjectConfig::parse(project).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_project_config() {
+        use super::{ManglingScheme, TargetLanguage};
+        use crate::linker::features::Feature;
+
+        let project = r#"
+target = "vhdl"
+top = ["cpu", "uart"]
+mangle = "hash"
+strict-sv = true
+features = ["assert_statements"]
+"#;
+        let config = parse_args_with_project(&[""], project);
+        assert_eq!(config.options.target_language, TargetLanguage::Vhdl);
+        assert_eq!(config.options.top_modules, ["cpu", "uart"]);
+        assert_eq!(config.options.mangling, ManglingScheme::Hash);
+        assert!(config.options.strict_sv);
+        assert!(!config.options.emit_assertions);
+        assert_eq!(config.options.features, [Feature::AssertStatements]);
+
+        // The command line overrides the project configuration
+        let config = parse_args_with_project(
+            &[
+                "",
+                "--target",
+                "system-verilog",
+                "--top",
+                "alu",
+                "--mangle",
+                "readable",
+                "--no-strict-sv",
+            ],
+            project,
+        );
+        assert_eq!(
+            config.options.target_language,
+            TargetLanguage::SystemVerilog
+        );
+        assert_eq!(config.options.top_modules, ["alu"]);
+        assert_eq!(config.options.mangling, ManglingScheme::Readable);
+        assert!(!config.options.strict_sv);
+        assert!(parse_args(["", "--strict-sv", "--no-strict-sv"]).is_err());
+
+        assert_eq!(ProjectConfig::parse(""), Ok(ProjectConfig::default()));
+        assert_eq!(
+            ProjectConfig::parse("out-dir = \"build\""),
+            Err("Unknown key 'out-dir'. The supported keys are: target, top, mangle, strict-sv, emit-assertions, warn-unused-modules, features".to_owned())
+        );
+        assert_eq!(
+            ProjectConfig::parse("target = \"verilog\""),
+            Err("'target' must be one of: system-verilog, vhdl".to_owned())
+        );
+        assert_eq!(
+            ProjectConfig::parse("strict-sv = \"yes\""),
+            Err("'strict-sv' must be true or false".to_owned())
+        );
+        assert_eq!(
+            ProjectConfig::parse("features = [\"arrays\"]"),
+            Err(
+                "Unknown feature 'arrays'. The available features are: assert_statements"
+                    .to_owned()
+            )
+        );
+        assert!(ProjectConfig::parse("target = ").is_err());
+    }
+
+    #[test]
+    fn test_project_config_file() {
+        let missing = std::env::temp_dir().join(format!("sus_missing_{}.toml", std::process::id()));
+        assert_eq!(ProjectConfig::load(&missing, false), Ok(None));
+        assert!(ProjectConfig::load(&missing, true)
+            .unwrap_err()
+            .starts_with("Could not read the project configuration"));
+        assert!(
+            super::parse_args_and_project(["", "--config", missing.to_str().unwrap()]).is_err()
+        );
+        // The language server doesn't read a project configuration
+        assert!(super::parse_args_and_project([
+            "",
+            "--lsp",
+            "--config",
+            missing.to_str().unwrap()
+        ])
+        .is_ok());
+
+        let file = std::env::temp_dir().join(format!("sus_project_{}.toml", std::process::id()));
+        std::fs::write(&file, "top = \"cpu\"\n").unwrap();
+        let loaded = ProjectConfig::load(&file, false);
+        std::fs::remove_file(&file).unwrap();
+        assert_eq!(
+            loaded,
+            Err(format!(
+                "Invalid project configuration '{}': 'top' must be a list of strings",
+                file.display()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_top() {
+        assert!(parse_args([""]).unwrap().options.top_modules.is_empty());
+        let config = parse_args(["", "--top", "a,b", "--top", "c"]).unwrap();
+        assert_eq!(config.options.top_modules, ["a", "b", "c"]);
     }
 }
@@ -1,7 +1,10 @@
-use std::{cell::RefCell, ops::Range};
+use std::{cell::RefCell, collections::HashMap, ops::Range, path::Path};
 
 use crate::{
-    alloc::ArenaAllocator, config::ConfigStruct, flattening::Module, linker::FileData,
+    alloc::ArenaAllocator,
+    config::{ColorChoice, CompilerOptions},
+    flattening::Module,
+    linker::{FileData, LinkInfo, Linker},
     pretty_print_spans_in_reverse_order, ModuleUUIDMarker,
 };
 
@@ -46,11 +49,7 @@ fn print_most_recent_spans(file_data: &FileData) {
 
         let mut spans_to_print: Vec<Range<usize>> = Vec::with_capacity(NUM_SPANS_TO_PRINT);
 
-        let end_at = if history.num_spans > SPAN_TOUCH_HISTORY_SIZE {
-            history.num_spans - SPAN_TOUCH_HISTORY_SIZE
-        } else {
-            0
-        };
+        let end_at = history.num_spans.saturating_sub(SPAN_TOUCH_HISTORY_SIZE);
 
         let mut cur_i = history.num_spans;
         while cur_i > end_at {
@@ -68,7 +67,8 @@ fn print_most_recent_spans(file_data: &FileData) {
     });
 
     println!("Panic unwinding. Printing the last {} spans. BEWARE: These spans may not correspond to this file, thus incorrect spans are possible!", spans_to_print.len());
-    pretty_print_spans_in_reverse_order(file_data, spans_to_print);
+    // Panics can happen anywhere, without access to the options of the linker
+    pretty_print_spans_in_reverse_order(file_data, spans_to_print, ColorChoice::Auto);
 }
 
 /// Print the last [NUM_SPANS_TO_PRINT] touched spans on panic to aid in debugging
@@ -120,8 +120,8 @@ impl Drop for SpanDebugger<'_> {
     }
 }
 
-impl ConfigStruct {
-    /// The reason we pass an explicit bool here is because it merges the "if config().debug_xyz" with the for loop.
+impl CompilerOptions {
+    /// The reason we pass an explicit bool here is because it merges the "if options.debug_xyz" with the for loop.
     pub fn for_each_debug_module<F: FnMut(&Module)>(
         &self,
         should_debug: bool,
@@ -154,3 +154,117 @@ impl ConfigStruct {
         }
     }
 }
+
+/// 32-bit FNV-1a. Unlike [std::hash::DefaultHasher], its output is guaranteed not to change between Rust versions
+fn stable_hash(text: &str) -> u32 {
+    let mut hash: u32 = 0x811c9dc5;
+    for byte in text.bytes() {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(0x01000193);
+    }
+    hash
+}
+
+/// 64-bit FNV-1a, see [stable_hash]
+pub fn stable_hash_64(text: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in text.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Replaces all identifiers in `text` that are a key of `names`. Only whole identifiers are replaced, so `type_1` doesn't match within `type_12`
+fn replace_identifiers(text: &str, names: &HashMap<String, String>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_alphanumeric() || c == '_') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        let identifier = &rest[..end];
+        result.push_str(
+            names
+                .get(identifier)
+                .map_or(identifier, |name| name.as_str()),
+        );
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+impl Linker {
+    fn stable_global_name(&self, kind: &str, link_info: &LinkInfo) -> String {
+        let file_text = &self.files[link_info.file].file_text;
+        let hash = stable_hash(&file_text[link_info.span]);
+        format!("{kind}({}@{hash:08x})", link_info.get_full_name())
+    }
+
+    /// Names for the global UUIDs as they appear in debug output, like `type_1` => `type(::int@8c3a51f0)`.
+    ///
+    /// The UUIDs themselves depend on the order in which files were added. These names only depend on the name and source text of the global,
+    /// so debug output can be diffed between runs.
+    pub fn stable_global_names(&self) -> HashMap<String, String> {
+        let mut names = HashMap::new();
+        for (id, file) in &self.files {
+            let file_name = Path::new(&file.file_identifier)
+                .file_name()
+                .map_or(file.file_identifier.as_str(), |name| {
+                    name.to_str().unwrap_or(&file.file_identifier)
+                });
+            let hash = stable_hash(&file.file_text.file_text);
+            names.insert(format!("{id:?}"), format!("file({file_name}@{hash:08x})"));
+        }
+        for (id, md) in &self.modules {
+            names.insert(
+                format!("{id:?}"),
+                self.stable_global_name("module", &md.link_info),
+            );
+        }
+        for (id, typ) in &self.types {
+            names.insert(
+                format!("{id:?}"),
+                self.stable_global_name("type", &typ.link_info),
+            );
+        }
+        for (id, cst) in &self.constants {
+            names.insert(
+                format!("{id:?}"),
+                self.stable_global_name("constant", &cst.link_info),
+            );
+        }
+        names
+    }
+
+    /// Rewrites the global UUIDs in debug output to [Self::stable_global_names], unless `--debug-raw-uuids` was given
+    pub fn stabilize_debug_text(&self, text: &str) -> String {
+        if self.options.debug_raw_uuids {
+            text.to_owned()
+        } else {
+            replace_identifiers(text, &self.stable_global_names())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::replace_identifiers;
+
+    #[test]
+    fn test_replace_identifiers() {
+        let names = HashMap::from([
+            ("type_1".to_owned(), "type(::int@1)".to_owned()),
+            ("module_0".to_owned(), "module(::a@2)".to_owned()),
+        ]);
+        assert_eq!(
+            replace_identifiers("Named(type_1), type_12, module_0: obj_1", &names),
+            "Named(type(::int@1)), type_12, module(::a@2): obj_1"
+        );
+    }
+}
@@ -1,5 +1,6 @@
+use std::io::IsTerminal;
+use std::ops::Range;
 use std::path::Path;
-use std::{ops::Range, path::PathBuf};
 
 use crate::compiler_top::LinkerExtraFileInfoManager;
 use crate::linker::FileData;
@@ -7,7 +8,7 @@ use crate::prelude::*;
 
 use crate::{
     alloc::ArenaVector,
-    config::config,
+    config::{ColorChoice, CompilerOptions, MessageFormat},
     errors::{CompileError, ErrorLevel},
 };
 
@@ -20,16 +21,22 @@ impl Cache<FileUUID> for (&Linker, &mut ArenaVector<Source<String>, FileUUIDMark
         Ok(&self.1[*id])
     }
     fn display<'a>(&self, id: &'a FileUUID) -> Option<Box<dyn std::fmt::Display + 'a>> {
-        if config().ci {
-            let filename = self.0.files[*id]
-                .file_identifier
-                .rsplit("/")
-                .next()
-                .unwrap_or(self.0.files[*id].file_identifier.as_str());
-            Some(Box::new(filename.to_string()))
-        } else {
-            Some(Box::new(self.0.files[*id].file_identifier.clone()))
-        }
+        Some(Box::new(
+            file_display_name(&self.0.files[*id], &self.0.options).to_owned(),
+        ))
+    }
+}
+
+/// With --ci, only the file name is shown, to keep the output environment agnostic
+fn file_display_name<'f>(file_data: &'f FileData, options: &CompilerOptions) -> &'f str {
+    if options.ci {
+        file_data
+            .file_identifier
+            .rsplit("/")
+            .next()
+            .unwrap_or(file_data.file_identifier.as_str())
+    } else {
+        &file_data.file_identifier
     }
 }
 
@@ -75,38 +82,11 @@ impl LinkerExtraFileInfoManager for FileSourcesManager {
     }
 }
 
-pub fn compile_all(file_paths: Vec<PathBuf>) -> (Linker, FileSourcesManager) {
-    let mut linker = Linker::new();
-    let mut file_source_manager = FileSourcesManager {
-        file_sources: ArenaVector::new(),
-    };
-    linker.add_standard_library(&mut file_source_manager);
-
-    for file_path in file_paths {
-        let file_text = match std::fs::read_to_string(&file_path) {
-            Ok(file_text) => file_text,
-            Err(reason) => {
-                let file_path_disp = file_path.display();
-                panic!("Could not open file '{file_path_disp}' for syntax highlighting because {reason}")
-            }
-        };
-
-        linker.add_file(
-            file_path.to_string_lossy().into_owned(),
-            file_text,
-            &mut file_source_manager,
-        );
-    }
-
-    linker.recompile_all();
-
-    (linker, file_source_manager)
-}
-
-fn ariadne_config() -> Config {
+/// `stream` is where the report will be printed to, for deciding on [ColorChoice::Auto]
+fn ariadne_config(color: ColorChoice, stream: &impl IsTerminal) -> Config {
     Config::default()
         .with_index_type(IndexType::Byte)
-        .with_color(config().use_color)
+        .with_color(color.use_color_for(stream))
 }
 
 pub fn pretty_print_error<AriadneCache: Cache<FileUUID>>(
@@ -127,16 +107,18 @@ pub fn pretty_print_error<AriadneCache: Cache<FileUUID>>(
 
     let error_span = error.position.as_range();
 
-    let config = ariadne_config();
+    let config = ariadne_config(linker.options.color, &std::io::stderr());
     let mut report: ReportBuilder<'_, (FileUUID, Range<usize>)> =
         Report::build(report_kind, file, error_span.start).with_config(config);
-    report = report.with_message(&error.reason).with_label(
-        Label::new((file, error_span))
-            .with_message(&error.reason)
-            .with_color(err_color),
-    );
+    report = report
+        .with_message(error.reason_with_instance_path())
+        .with_label(
+            Label::new((file, error_span))
+                .with_message(&error.reason)
+                .with_color(err_color),
+        );
 
-    for info in &error.infos {
+    for info in &error.all_infos(linker) {
         let info_span = info.position.as_range();
         // Assert that span is in file
         let _ = &linker.files[info.file].file_text[info.position];
@@ -150,19 +132,123 @@ pub fn pretty_print_error<AriadneCache: Cache<FileUUID>>(
     report.finish().eprint(file_cache).unwrap();
 }
 
+fn level_name(level: &ErrorLevel) -> &'static str {
+    match level {
+        ErrorLevel::Error => "error",
+        ErrorLevel::Warning => "warning",
+    }
+}
+
+/// Prints `file:line:col: level: message`, for [MessageFormat::Short]
+pub fn print_error_short(error: &CompileError, file: FileUUID, linker: &Linker) {
+    let file_data = &linker.files[file];
+    let (line, col) = file_data
+        .file_text
+        .byte_to_line_col(error.position.as_range().start);
+    eprintln!(
+        "{}:{line}:{col}: {}: {}",
+        file_display_name(file_data, &linker.options),
+        level_name(&error.level),
+        error.reason_with_instance_path()
+    );
+}
+
+fn span_to_json(
+    span: crate::file_position::Span,
+    file: FileUUID,
+    linker: &Linker,
+) -> serde_json::Value {
+    let file_data = &linker.files[file];
+    let rng = span.as_range();
+    let (line, col) = file_data.file_text.byte_to_line_col(rng.start);
+    let (end_line, end_col) = file_data.file_text.byte_to_line_col(rng.end);
+    serde_json::json!({
+        "file": file_display_name(file_data, &linker.options),
+        "byte_start": rng.start,
+        "byte_end": rng.end,
+        "line": line,
+        "col": col,
+        "end_line": end_line,
+        "end_col": end_col,
+    })
+}
+
+/// Prints the error as a single-line JSON object, for [MessageFormat::Json]
+pub fn print_error_json(error: &CompileError, file: FileUUID, linker: &Linker) {
+    let infos: Vec<serde_json::Value> = error
+        .all_infos(linker)
+        .iter()
+        .map(|info| {
+            serde_json::json!({
+                "message": info.info,
+                "span": span_to_json(info.position, info.file, linker),
+            })
+        })
+        .collect();
+    let json = serde_json::json!({
+        "level": level_name(&error.level),
+        "message": error.reason,
+        "instance_path": error.instance_path(),
+        "span": span_to_json(error.position, file, linker),
+        "infos": infos,
+    });
+    eprintln!("{json}");
+}
+
+/// Whether the error should be printed, given the `--error-limit` and how many errors were already printed. Warnings are always printed
+fn within_error_limit(level: &ErrorLevel, errors_printed: usize, error_limit: usize) -> bool {
+    *level == ErrorLevel::Warning || error_limit == 0 || errors_printed < error_limit
+}
+
 pub fn print_all_errors(
     linker: &Linker,
     ariadne_sources: &mut ArenaVector<Source, FileUUIDMarker>,
 ) {
+    print_diagnostics(linker, ariadne_sources, linker.collect_all_diagnostics());
+}
+
+/// Prints the given diagnostics in the requested [MessageFormat], respecting the `--error-limit`. With `--quiet`, warnings are left out
+pub fn print_diagnostics(
+    linker: &Linker,
+    ariadne_sources: &mut ArenaVector<Source, FileUUIDMarker>,
+    diagnostics: Vec<(FileUUID, CompileError)>,
+) {
+    let options = &linker.options;
+    let error_limit = match options.message_format {
+        MessageFormat::Human | MessageFormat::Short => options.error_limit,
+        // Tools reading the JSON want all of them
+        MessageFormat::Json => 0,
+    };
     let mut source_cache = (linker, ariadne_sources);
-    for (file_uuid, _f) in &linker.files {
-        linker.for_all_errors_in_file(file_uuid, |err| {
-            pretty_print_error(err, file_uuid, linker, &mut source_cache);
-        });
+    let mut errors_printed = 0;
+    let mut errors_suppressed = 0;
+    for (file_uuid, err) in diagnostics {
+        if options.quiet && err.level == ErrorLevel::Warning {
+            continue;
+        }
+        if !within_error_limit(&err.level, errors_printed, error_limit) {
+            errors_suppressed += 1;
+            continue;
+        }
+        if err.level == ErrorLevel::Error {
+            errors_printed += 1;
+        }
+        match options.message_format {
+            MessageFormat::Human => pretty_print_error(&err, file_uuid, linker, &mut source_cache),
+            MessageFormat::Short => print_error_short(&err, file_uuid, linker),
+            MessageFormat::Json => print_error_json(&err, file_uuid, linker),
+        }
+    }
+    if errors_suppressed > 0 {
+        eprintln!("{errors_suppressed} more errors suppressed (--error-limit {error_limit})");
     }
 }
 
-pub fn pretty_print_spans_in_reverse_order(file_data: &FileData, spans: Vec<Range<usize>>) {
+pub fn pretty_print_spans_in_reverse_order(
+    file_data: &FileData,
+    spans: Vec<Range<usize>>,
+    color: ColorChoice,
+) {
     let text_len = file_data.file_text.len();
     let mut source = NamedSource {
         source: Source::from(file_data.file_text.file_text.clone()),
@@ -179,7 +265,7 @@ pub fn pretty_print_spans_in_reverse_order(file_data: &FileData, spans: Vec<Rang
             return;
         }
 
-        let config = ariadne_config();
+        let config = ariadne_config(color, &std::io::stdout());
 
         let mut report: ReportBuilder<'_, Range<usize>> =
             Report::build(ReportKind::Advice, (), span.start).with_config(config);
@@ -193,14 +279,18 @@ pub fn pretty_print_spans_in_reverse_order(file_data: &FileData, spans: Vec<Rang
     }
 }
 
-pub fn pretty_print_many_spans(file_data: &FileData, spans: &[(String, Range<usize>)]) {
+pub fn pretty_print_many_spans(
+    file_data: &FileData,
+    spans: &[(String, Range<usize>)],
+    color: ColorChoice,
+) {
     let text_len = file_data.file_text.len();
     let mut source = NamedSource {
         source: Source::from(file_data.file_text.file_text.clone()),
         name: &file_data.file_identifier,
     };
 
-    let config = ariadne_config();
+    let config = ariadne_config(color, &std::io::stdout());
 
     if spans.is_empty() {
         return;
@@ -227,3 +317,19 @@ pub fn pretty_print_many_spans(file_data: &FileData, spans: &[(String, Range<usi
     }
     report.finish().print(&mut source).unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::within_error_limit;
+    use crate::errors::ErrorLevel;
+
+    #[test]
+    fn test_error_limit() {
+        assert!(within_error_limit(&ErrorLevel::Error, 1, 2));
+        assert!(!within_error_limit(&ErrorLevel::Error, 2, 2));
+        // Warnings are counted separately, and are never suppressed
+        assert!(within_error_limit(&ErrorLevel::Warning, 2, 2));
+        // 0 means no limit
+        assert!(within_error_limit(&ErrorLevel::Error, 1000, 0));
+    }
+}
@@ -0,0 +1,174 @@
+//! Graphviz DOT exports of compiler data structures, for visualization. Render with `dot -Tsvg`.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::prelude::*;
+
+use crate::codegen::sorted_instances;
+use crate::flattening::Module;
+use crate::instantiation::{InstantiatedModule, RealWireDataSource, CALCULATE_LATENCY_LATER};
+use crate::to_string::pretty_print_concrete_instance;
+
+/// Escapes a string for use within a quoted DOT ID or label. Newlines become left-justified line breaks.
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\l")
+}
+
+/// The module hierarchy. Nodes are module instances, including their concrete template arguments.
+/// Edges point from a module instance to its submodules, labeled with the submodule's instance name.
+///
+/// Modules that haven't been instantiated are shown dashed.
+pub fn hierarchy_dot(linker: &Linker) -> String {
+    let mut result = String::from("digraph hierarchy {\n    node [shape=box];\n");
+    for (_id, md) in &linker.modules {
+        let instances = sorted_instances(md);
+        if instances.is_empty() {
+            let name = dot_escape(&md.link_info.get_full_name());
+            writeln!(result, "    \"{name}\" [style=dashed];").unwrap();
+        }
+        for inst in instances {
+            let name = dot_escape(&inst.name);
+            let color = if inst.errors.did_error {
+                ", color=red"
+            } else {
+                ""
+            };
+            writeln!(result, "    \"{name}\" [label=\"{name}\"{color}];").unwrap();
+            for (_id, sm) in &inst.submodules {
+                let target = match sm.instance.get() {
+                    Some(sm_inst) => sm_inst.name.clone(),
+                    None => pretty_print_concrete_instance(
+                        &linker.modules[sm.module_uuid].link_info,
+                        &sm.template_args,
+                        &linker.types,
+                    ),
+                };
+                writeln!(
+                    result,
+                    "    \"{name}\" -> \"{}\" [label=\"{}\"];",
+                    dot_escape(&target),
+                    dot_escape(&sm.name)
+                )
+                .unwrap();
+            }
+        }
+    }
+    result.push_str("}\n");
+    result
+}
+
+/// The latency graphs of all instances of `md`, one cluster per instance.
+///
+/// Nodes are wires labeled with their absolute latency, edges are data dependencies.
+/// Registers and edges that cross registers are highlighted. The ports of each submodule are grouped in a cluster.
+pub fn latency_dot(md: &Module) -> String {
+    let mut result = String::from("digraph latency {\n    rankdir=LR;\n    node [shape=box];\n");
+    for (inst_idx, inst) in sorted_instances(md).iter().enumerate() {
+        writeln!(result, "    subgraph cluster_{inst_idx} {{").unwrap();
+        writeln!(result, "        label=\"{}\";", dot_escape(&inst.name)).unwrap();
+
+        let mut wires_in_submodules = HashSet::new();
+        for (sm_id, sm) in &inst.submodules {
+            writeln!(
+                result,
+                "        subgraph cluster_{inst_idx}_{} {{",
+                sm_id.get_hidden_value()
+            )
+            .unwrap();
+            writeln!(result, "            label=\"{}\";", dot_escape(&sm.name)).unwrap();
+            writeln!(result, "            style=dashed;").unwrap();
+            for (_port_id, port) in sm.port_map.iter_valids() {
+                wires_in_submodules.insert(port.maps_to_wire);
+                write_latency_node(
+                    &mut result,
+                    "            ",
+                    inst_idx,
+                    inst,
+                    port.maps_to_wire,
+                );
+            }
+            writeln!(result, "        }}").unwrap();
+        }
+        for (wire_id, _w) in &inst.wires {
+            if !wires_in_submodules.contains(&wire_id) {
+                write_latency_node(&mut result, "        ", inst_idx, inst, wire_id);
+            }
+        }
+
+        for (to, w) in &inst.wires {
+            let mut sources = Vec::new();
+            w.source
+                .iter_sources_with_min_latency(|from, delta_latency| {
+                    sources.push((from, delta_latency))
+                });
+            sources.sort_by_key(|(from, delta_latency)| (from.get_hidden_value(), *delta_latency));
+            sources.dedup();
+            for (from, delta_latency) in sources {
+                let from = from.get_hidden_value();
+                let to = to.get_hidden_value();
+                let style = if delta_latency > 0 {
+                    format!(" [label=\"+{delta_latency}\", color=red, penwidth=2]")
+                } else {
+                    String::new()
+                };
+                writeln!(
+                    result,
+                    "        i{inst_idx}_w{from} -> i{inst_idx}_w{to}{style};"
+                )
+                .unwrap();
+            }
+        }
+        writeln!(result, "    }}").unwrap();
+    }
+    result.push_str("}\n");
+    result
+}
+
+fn write_latency_node(
+    result: &mut String,
+    indent: &str,
+    inst_idx: usize,
+    inst: &InstantiatedModule,
+    wire_id: WireID,
+) {
+    let w = &inst.wires[wire_id];
+    let latency = if w.absolute_latency == CALCULATE_LATENCY_LATER {
+        "?".to_owned()
+    } else {
+        w.absolute_latency.to_string()
+    };
+    let is_register = matches!(
+        w.source,
+        RealWireDataSource::Multiplexer {
+            is_state: Some(_),
+            ..
+        }
+    );
+    let style = if is_register {
+        ", style=filled, fillcolor=lightblue"
+    } else {
+        ""
+    };
+    writeln!(
+        result,
+        "{indent}i{inst_idx}_w{} [label=\"{}'{latency}\"{style}];",
+        wire_id.get_hidden_value(),
+        dot_escape(&w.name)
+    )
+    .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dot_escape;
+
+    #[test]
+    fn test_dot_escape() {
+        assert_eq!(dot_escape("::a #()"), "::a #()");
+        assert_eq!(dot_escape("a\"b\\c"), "a\\\"b\\\\c");
+        assert_eq!(dot_escape("#(\n    T: 3,\n)"), "#(\\l    T: 3,\\l)");
+    }
+}
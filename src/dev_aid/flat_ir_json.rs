@@ -0,0 +1,204 @@
+//! A JSON view of the flattened IR (see [crate::flattening::Instruction]), for `--dump-flat-json`.
+//!
+//! It is read-only, and meant for inspecting the IR from other tools. [Module::dump_flattened_module] is the human-readable counterpart.
+
+use serde_json::{json, Value as JsonValue};
+
+use crate::prelude::*;
+
+use crate::flattening::{
+    DeclarationKind, ExpressionSource, IdentifierType, Instruction, Module, WireReference,
+    WireReferencePathElement, WireReferenceRoot, WriteModifiers,
+};
+use crate::typing::abstract_type::{DomainType, FullType};
+
+fn span_json(span: Span) -> JsonValue {
+    let range = span.as_range();
+    json!([range.start, range.end])
+}
+
+fn flat_id_json(id: FlatID) -> JsonValue {
+    json!(id.get_hidden_value())
+}
+
+/// The abstract type, like `::int[]`, and the name of the domain. The domain is `null` if it wasn't resolved
+fn full_type_json(typ: &FullType, md: &Module, linker: &Linker) -> (JsonValue, JsonValue) {
+    let type_text = typ
+        .typ
+        .display(&linker.types, &md.link_info.template_parameters)
+        .to_string();
+    let domain = match typ.domain {
+        DomainType::Generative => json!("gen"),
+        DomainType::Physical(domain_id) => match md.domains.get(domain_id) {
+            Some(domain) => json!(domain.name),
+            None => json!(format!("unnamed domain {}", domain_id.get_hidden_value())),
+        },
+        DomainType::Unknown(_) => JsonValue::Null,
+    };
+    (json!(type_text), domain)
+}
+
+fn wire_reference_json(wire_ref: &WireReference, linker: &Linker) -> JsonValue {
+    let root = match &wire_ref.root {
+        WireReferenceRoot::LocalDecl(decl_id, _) => json!({"local": flat_id_json(*decl_id)}),
+        WireReferenceRoot::NamedConstant(cst) => {
+            json!({"constant": linker.constants[cst.id].link_info.name})
+        }
+        WireReferenceRoot::SubModulePort(port) => json!({
+            "submodule": flat_id_json(port.submodule_decl),
+            "port": port.port.get_hidden_value(),
+        }),
+    };
+    let path: Vec<JsonValue> = wire_ref
+        .path
+        .iter()
+        .map(|elem| match elem {
+            WireReferencePathElement::ArrayAccess { idx, .. } => {
+                json!({"index": flat_id_json(*idx)})
+            }
+            WireReferencePathElement::ArraySlice { high, low, .. } => {
+                json!({"slice_high": flat_id_json(*high), "slice_low": flat_id_json(*low)})
+            }
+        })
+        .collect();
+    json!({
+        "root": root,
+        "path": path,
+        "is_generative": wire_ref.is_generative,
+    })
+}
+
+fn instruction_json(md: &Module, id: FlatID, linker: &Linker) -> JsonValue {
+    let instr = &md.link_info.instructions[id];
+    let mut result = match instr {
+        Instruction::SubModule(sm) => json!({
+            "kind": "SubModule",
+            "module": linker.modules[sm.module_ref.id].link_info.name,
+            "name": sm.name.as_ref().map(|(name, _)| name),
+        }),
+        Instruction::FuncCall(fc) => json!({
+            "kind": "FuncCall",
+            "submodule": flat_id_json(fc.interface_reference.submodule_decl),
+            "arguments": fc.arguments.iter().map(|arg| flat_id_json(*arg)).collect::<Vec<_>>(),
+        }),
+        Instruction::Declaration(decl) => {
+            let (typ, domain) = full_type_json(&decl.typ, md, linker);
+            let decl_kind = match decl.decl_kind {
+                DeclarationKind::NotPort => "local",
+                DeclarationKind::StructField { .. } => "struct_field",
+                DeclarationKind::RegularPort { is_input: true, .. } => "input",
+                DeclarationKind::RegularPort {
+                    is_input: false, ..
+                } => "output",
+                DeclarationKind::GenerativeInput(_) => "template_parameter",
+            };
+            json!({
+                "kind": "Declaration",
+                "name": decl.name,
+                "type": typ,
+                "domain": domain,
+                "decl_kind": decl_kind,
+                "identifier_type": match decl.identifier_type {
+                    IdentifierType::Local => "local",
+                    IdentifierType::State => "state",
+                    IdentifierType::Generative => "gen",
+                },
+                "latency_specifier": decl.latency_specifier.map(flat_id_json),
+            })
+        }
+        Instruction::Expression(expr) => {
+            let (typ, domain) = full_type_json(&expr.typ, md, linker);
+            let source = match &expr.source {
+                ExpressionSource::WireRef(wire_ref) => {
+                    json!({"wire_ref": wire_reference_json(wire_ref, linker)})
+                }
+                ExpressionSource::UnaryOp { op, right } => json!({
+                    "unary_op": op.op_text(),
+                    "right": flat_id_json(*right),
+                }),
+                ExpressionSource::BinaryOp { op, left, right } => json!({
+                    "binary_op": op.op_text(),
+                    "left": flat_id_json(*left),
+                    "right": flat_id_json(*right),
+                }),
+                ExpressionSource::Concatenation { elements } => json!({
+                    "concatenation": elements.iter().map(|elem| flat_id_json(*elem)).collect::<Vec<_>>(),
+                }),
+                ExpressionSource::Conditional {
+                    condition,
+                    if_true,
+                    if_false,
+                } => json!({
+                    "condition": flat_id_json(*condition),
+                    "if_true": flat_id_json(*if_true),
+                    "if_false": flat_id_json(*if_false),
+                }),
+                ExpressionSource::Literal(literal) => json!({
+                    "literal": literal.value.to_string(),
+                    "width": literal.width,
+                }),
+                ExpressionSource::Constant(value) => json!({"constant": value.to_string()}),
+            };
+            json!({
+                "kind": "Expression",
+                "type": typ,
+                "domain": domain,
+                "source": source,
+            })
+        }
+        Instruction::Write(write) => {
+            let (num_regs, is_initial) = match write.write_modifiers {
+                WriteModifiers::Connection { num_regs, .. } => (num_regs, false),
+                WriteModifiers::Initial { .. } => (0, true),
+            };
+            json!({
+                "kind": "Write",
+                "from": flat_id_json(write.from),
+                "to": wire_reference_json(&write.to, linker),
+                "num_regs": num_regs,
+                "is_initial": is_initial,
+            })
+        }
+        Instruction::IfStatement(stm) => json!({
+            "kind": "IfStatement",
+            "condition": flat_id_json(stm.condition),
+            "is_generative": stm.is_generative,
+            "then_start": flat_id_json(stm.then_start),
+            "then_end_else_start": flat_id_json(stm.then_end_else_start),
+            "else_end": flat_id_json(stm.else_end),
+        }),
+        Instruction::ForStatement(stm) => json!({
+            "kind": "ForStatement",
+            "loop_var_decl": flat_id_json(stm.loop_var_decl),
+            "start": flat_id_json(stm.start),
+            "end": flat_id_json(stm.end),
+            "loop_body_start": flat_id_json(stm.loop_body.0),
+            "loop_body_end": flat_id_json(stm.loop_body.1),
+        }),
+        Instruction::Assertion(assertion) => json!({
+            "kind": "Assertion",
+            "assertion_kind": assertion.kind.keyword(),
+            "condition": flat_id_json(assertion.condition),
+        }),
+    };
+    let fields = result.as_object_mut().unwrap();
+    fields.insert("id".to_owned(), flat_id_json(id));
+    fields.insert("span".to_owned(), span_json(md.get_instruction_span(id)));
+    result
+}
+
+/// All instructions of the module, in order. Instructions refer to each other by their `id`, and spans are byte ranges in the module's file
+pub fn flattened_module_json(md: &Module, linker: &Linker) -> JsonValue {
+    let instructions: Vec<JsonValue> = md
+        .link_info
+        .instructions
+        .iter()
+        .map(|(id, _)| instruction_json(md, id, linker))
+        .collect();
+    json!({
+        "name": md.link_info.name,
+        "file": linker.files[md.link_info.file].file_identifier,
+        "span": span_json(md.link_info.span),
+        "instructions": instructions,
+    })
+}
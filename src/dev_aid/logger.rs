@@ -0,0 +1,38 @@
+//! Progress messages of the compiler go through the [log] facade, such that library users can route them wherever they like.
+//!
+//! The command-line compiler installs [StderrLogger]. Nothing is ever logged to stdout, as in `--lsp-stdio` mode that is the LSP channel.
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+pub struct StderrLogger;
+
+static LOGGER: StderrLogger = StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("{}", record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs [StderrLogger]. Progress messages are only shown with `verbose`, warnings are shown unless `quiet`.
+///
+/// Does nothing if a logger was already installed
+pub fn init_logger(verbose: bool, quiet: bool) {
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(if verbose {
+            LevelFilter::Info
+        } else if quiet {
+            LevelFilter::Error
+        } else {
+            LevelFilter::Warn
+        });
+    }
+}
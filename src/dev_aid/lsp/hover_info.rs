@@ -8,10 +8,11 @@ use lsp_types::{LanguageString, MarkedString};
 
 use crate::flattening::{DeclarationKind, IdentifierType, InterfaceToDomainMap, Module};
 use crate::instantiation::{SubModuleOrWire, CALCULATE_LATENCY_LATER};
-use crate::linker::{Documentation, FileData, GlobalUUID, LinkInfo};
+use crate::linker::{Documentation, FileData, GlobalUUID, IsExtern, LinkInfo};
 
 use crate::typing::{
     abstract_type::DomainType,
+    concrete_type::{ConcreteGlobalReference, ConcreteType},
     template::{GenerativeParameterKind, ParameterKind, TypeParameterKind},
 };
 
@@ -254,8 +255,20 @@ pub fn hover(info: LocationInfo, linker: &Linker, file_data: &FileData) -> Vec<M
                         None,
                     ));
                 }
-                GlobalUUID::Type(_) => {}
-                GlobalUUID::Constant(_) => {}
+                GlobalUUID::Type(typ_id) => {
+                    if link_info.is_extern == IsExtern::Builtin {
+                        let size = ConcreteType::sizeof_named(&ConcreteGlobalReference {
+                            id: typ_id,
+                            template_args: FlatAlloc::new(),
+                        });
+                        hover.monospace(format!("builtin type, {size} bits wide"));
+                    }
+                }
+                GlobalUUID::Constant(_) => {
+                    if link_info.is_extern == IsExtern::Builtin {
+                        hover.monospace("builtin constant, evaluated by the compiler");
+                    }
+                }
             }
         }
         LocationInfo::Port(_sm, md, port_id) => {
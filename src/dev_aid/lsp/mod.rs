@@ -1,19 +1,30 @@
 mod hover_info;
+mod selection_range;
 mod semantic_tokens;
 mod tree_walk;
 
-use crate::{compiler_top::LinkerExtraFileInfoManager, linker::GlobalUUID, prelude::*};
+use crate::{
+    compiler_top::{FsSourceProvider, LinkerExtraFileInfoManager},
+    linker::GlobalUUID,
+    prelude::*,
+};
 
 use hover_info::hover;
 use lsp_types::{notification::*, request::Request, *};
+use selection_range::selection_ranges;
 use semantic_tokens::{make_semantic_tokens, semantic_token_capabilities};
-use std::{collections::HashMap, error::Error, net::SocketAddr, path::Path};
+use std::{
+    collections::HashMap,
+    error::Error,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+};
 
 use crate::{
     config::config,
     errors::{CompileError, ErrorLevel},
     file_position::{FileText, LineCol},
-    flattening::Instruction,
+    flattening::{ExpressionSource, Instruction, WireReference, WireReferenceRoot},
     linker::FileData,
 };
 
@@ -78,18 +89,17 @@ impl Linker {
     fn find_uri(&self, uri: &Url) -> Option<FileUUID> {
         self.find_file(uri.as_str())
     }
-    fn update_text(&mut self, uri: &Url, new_file_text: String, manager: &mut LSPFileManager) {
-        self.add_or_update_file(uri.as_str(), new_file_text, manager);
-
-        self.recompile_all();
-    }
     fn ensure_contains_file(&mut self, uri: &Url, manager: &mut LSPFileManager) -> FileUUID {
         if let Some(found) = self.find_uri(uri) {
             found
         } else {
-            let file_text = std::fs::read_to_string(uri.to_file_path().unwrap()).unwrap();
+            // Non-file:// uris can't be read, add_file_from_path then reports that as an error
+            let file_path = uri
+                .to_file_path()
+                .unwrap_or_else(|_| PathBuf::from(uri.path()));
 
-            let file_uuid = self.add_file(uri.to_string(), file_text, manager);
+            let file_uuid =
+                self.add_file_from_path(uri.to_string(), &file_path, &FsSourceProvider, manager);
             self.recompile_all();
             file_uuid
         }
@@ -128,7 +138,7 @@ fn convert_diagnostic(
         ErrorLevel::Warning => DiagnosticSeverity::WARNING,
     };
     let mut related_info = Vec::new();
-    for info in &err.infos {
+    for info in &err.all_infos(linker) {
         let info_file = &linker.files[info.file];
         let info_span = info.position;
         assert!(
@@ -154,40 +164,52 @@ fn convert_diagnostic(
         Some(severity),
         None,
         None,
-        err.reason.clone(),
+        err.reason_with_instance_path(),
         Some(related_info),
         None,
     )
 }
 
-fn push_all_errors(
+fn publish_file_diagnostics(
     connection: &lsp_server::Connection,
     linker: &Linker,
+    file_id: FileUUID,
+    diagnostics: &[CompileError],
 ) -> Result<(), Box<dyn Error + Sync + Send>> {
-    for (file_id, file_data) in &linker.files {
-        let mut diag_vec: Vec<Diagnostic> = Vec::new();
-
-        linker.for_all_errors_in_file(file_id, |err| {
-            diag_vec.push(convert_diagnostic(err, &file_data.file_text, linker));
-        });
-
-        let params = &PublishDiagnosticsParams {
-            uri: Url::parse(&file_data.file_identifier).unwrap(),
-            diagnostics: diag_vec,
-            version: None,
-        };
-        let params_json = serde_json::to_value(params)?;
-
-        connection.sender.send(lsp_server::Message::Notification(
-            lsp_server::Notification {
-                method: PublishDiagnostics::METHOD.to_owned(),
-                params: params_json,
-            },
-        ))?;
-    }
+    let file_data = &linker.files[file_id];
+    let params = &PublishDiagnosticsParams {
+        uri: Url::parse(&file_data.file_identifier).unwrap(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|err| convert_diagnostic(err, &file_data.file_text, linker))
+            .collect(),
+        version: None,
+    };
+    let params_json = serde_json::to_value(params)?;
+
+    connection.sender.send(lsp_server::Message::Notification(
+        lsp_server::Notification {
+            method: PublishDiagnostics::METHOD.to_owned(),
+            params: params_json,
+        },
+    ))?;
     Ok(())
 }
 
+/// Each file's diagnostics are published as soon as they're gathered, so the editor updates progressively on large workspaces
+fn push_all_errors(
+    connection: &lsp_server::Connection,
+    linker: &Linker,
+) -> Result<(), Box<dyn Error + Sync + Send>> {
+    let mut result = Ok(());
+    linker.for_each_file_diagnostics(|file_id, diagnostics| {
+        if result.is_ok() {
+            result = publish_file_diagnostics(connection, linker, file_id, &diagnostics);
+        }
+    });
+    result
+}
+
 struct LSPFileManager {}
 
 impl LinkerExtraFileInfoManager for LSPFileManager {
@@ -197,10 +219,10 @@ impl LinkerExtraFileInfoManager for LSPFileManager {
 }
 
 fn initialize_all_files(init_params: &InitializeParams) -> (Linker, LSPFileManager) {
-    let mut linker = Linker::new();
+    let mut linker = Linker::with_options(config().options.clone());
     let mut manager = LSPFileManager {};
 
-    linker.add_standard_library(&mut manager);
+    linker.add_standard_library(&FsSourceProvider, &mut manager);
 
     if let Some(workspace_folder) = &init_params.workspace_folders {
         for folder in workspace_folder {
@@ -208,7 +230,7 @@ fn initialize_all_files(init_params: &InitializeParams) -> (Linker, LSPFileManag
                 continue;
             };
 
-            linker.add_all_files_in_directory(&path, &mut manager);
+            linker.add_all_files_in_directory(&path, &FsSourceProvider, &mut manager);
         }
     }
     linker.recompile_all();
@@ -300,6 +322,66 @@ fn gather_all_references_in_one_file(linker: &Linker, file_id: FileUUID, pos: us
     }
 }
 
+/// Like [gather_all_references_in_one_file], but for local variables also distinguishes reads and writes.
+///
+/// Locals are resolved to their declaration, so shadowing declarations with the same name are not conflated.
+fn document_highlights(
+    linker: &Linker,
+    file_id: FileUUID,
+    pos: usize,
+) -> Vec<(Span, DocumentHighlightKind)> {
+    let Some((_location, hover_info)) = get_selected_object(linker, file_id, pos) else {
+        return Vec::new();
+    };
+    let refers_to = RefersTo::from(hover_info);
+    let local_decl = refers_to.local.filter(|(obj_id, decl_id)| {
+        !refers_to.is_global()
+            && matches!(
+                linker.get_link_info(*obj_id).instructions[*decl_id],
+                Instruction::Declaration(_)
+            )
+    });
+    let Some((obj_id, decl_id)) = local_decl else {
+        return gather_all_references_in_one_file(linker, file_id, pos)
+            .into_iter()
+            .map(|span| (span, DocumentHighlightKind::TEXT))
+            .collect();
+    };
+
+    let refers_to_decl = |wire_ref: &WireReference| match &wire_ref.root {
+        WireReferenceRoot::LocalDecl(id, span) if *id == decl_id => Some(*span),
+        _ => None,
+    };
+    let mut result = Vec::new();
+    for (id, instr) in &linker.get_link_info(obj_id).instructions {
+        match instr {
+            Instruction::Declaration(decl) => {
+                if id == decl_id && decl.declaration_itself_is_not_written_to {
+                    result.push((decl.name_span, DocumentHighlightKind::TEXT));
+                }
+            }
+            Instruction::Write(write) => {
+                if let Some(span) = refers_to_decl(&write.to) {
+                    result.push((span, DocumentHighlightKind::WRITE));
+                }
+            }
+            Instruction::Expression(expr) => {
+                if let ExpressionSource::WireRef(wire_ref) = &expr.source {
+                    if let Some(span) = refers_to_decl(wire_ref) {
+                        result.push((span, DocumentHighlightKind::READ));
+                    }
+                }
+            }
+            Instruction::SubModule(_)
+            | Instruction::FuncCall(_)
+            | Instruction::IfStatement(_)
+            | Instruction::ForStatement(_)
+            | Instruction::Assertion(_) => {}
+        }
+    }
+    result
+}
+
 fn gather_all_references_across_all_files(
     linker: &Linker,
     file_id: FileUUID,
@@ -332,6 +414,34 @@ fn gather_all_references_across_all_files(
     ref_locations
 }
 
+fn gather_code_lenses(linker: &Linker, file_id: FileUUID) -> Vec<CodeLens> {
+    let file_data = &linker.files[file_id];
+    let mut result = Vec::new();
+    for global in &file_data.associated_values {
+        let GlobalUUID::Module(md_id) = global else {
+            continue;
+        };
+        let summary = linker.instantiation_summary(*md_id);
+        let total: usize = summary.iter().map(|(_, count)| count).sum();
+        let title = match summary.len() {
+            0 => "Not instantiated".to_owned(),
+            1 => format!("Instantiated {total} times"),
+            num_sets => format!("Instantiated {total} times, with {num_sets} parameter sets"),
+        };
+        let link_info = &linker.modules[*md_id].link_info;
+        result.push(CodeLens {
+            range: span_to_lsp_range(&file_data.file_text, link_info.name_span),
+            command: Some(Command {
+                title,
+                command: String::new(),
+                arguments: None,
+            }),
+            data: None,
+        });
+    }
+    result
+}
+
 fn handle_request(
     method: &str,
     params: serde_json::Value,
@@ -342,7 +452,7 @@ fn handle_request(
         request::HoverRequest::METHOD => {
             let params: HoverParams =
                 serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
-            println!("HoverRequest");
+            eprintln!("HoverRequest");
 
             let (file_uuid, pos) =
                 linker.location_in_file(&params.text_document_position_params, manager);
@@ -368,7 +478,7 @@ fn handle_request(
         request::GotoDefinition::METHOD => {
             let params: GotoDefinitionParams =
                 serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
-            println!("GotoDefinition");
+            eprintln!("GotoDefinition");
 
             let (file_uuid, pos) =
                 linker.location_in_file(&params.text_document_position_params, manager);
@@ -416,7 +526,7 @@ fn handle_request(
             )))
         }
         request::SemanticTokensFullRequest::METHOD => {
-            println!("SemanticTokensFullRequest: {params}");
+            eprintln!("SemanticTokensFullRequest: {params}");
             let params: SemanticTokensParams =
                 serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
 
@@ -429,19 +539,17 @@ fn handle_request(
         request::DocumentHighlightRequest::METHOD => {
             let params: DocumentHighlightParams =
                 serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
-            println!("DocumentHighlight");
+            eprintln!("DocumentHighlight");
 
             let (file_id, pos) =
                 linker.location_in_file(&params.text_document_position_params, manager);
             let file_data = &linker.files[file_id];
 
-            let ref_locations = gather_all_references_in_one_file(linker, file_id, pos);
-
-            let result: Vec<DocumentHighlight> = ref_locations
+            let result: Vec<DocumentHighlight> = document_highlights(linker, file_id, pos)
                 .into_iter()
-                .map(|sp| DocumentHighlight {
+                .map(|(sp, kind)| DocumentHighlight {
                     range: span_to_lsp_range(&file_data.file_text, sp),
-                    kind: None,
+                    kind: Some(kind),
                 })
                 .collect();
             serde_json::to_value(result)
@@ -449,7 +557,7 @@ fn handle_request(
         request::References::METHOD => {
             let params: ReferenceParams =
                 serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
-            println!("FindAllReferences");
+            eprintln!("FindAllReferences");
 
             let (file_id, pos) = linker.location_in_file(&params.text_document_position, manager);
 
@@ -460,7 +568,7 @@ fn handle_request(
         request::Rename::METHOD => {
             let params: RenameParams =
                 serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
-            println!("Rename");
+            eprintln!("Rename");
 
             let (file_id, pos) = linker.location_in_file(&params.text_document_position, manager);
 
@@ -483,7 +591,7 @@ fn handle_request(
                 })
                 .collect();
 
-            println!("{changes:?}");
+            eprintln!("{changes:?}");
 
             serde_json::to_value(WorkspaceEdit {
                 changes: Some(changes),
@@ -494,7 +602,7 @@ fn handle_request(
         request::Completion::METHOD => {
             let params: CompletionParams =
                 serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
-            println!("Completion");
+            eprintln!("Completion");
 
             let (file_uuid, position) =
                 linker.location_in_file(&params.text_document_position, manager);
@@ -503,8 +611,32 @@ fn handle_request(
                 linker, file_uuid, position,
             )))
         }
+        request::SelectionRangeRequest::METHOD => {
+            let params: SelectionRangeParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            eprintln!("SelectionRange");
+
+            let file_id = linker.ensure_contains_file(&params.text_document.uri, manager);
+            let file_text = &linker.files[file_id].file_text;
+            let positions: Vec<usize> = params
+                .positions
+                .into_iter()
+                .map(|pos| file_text.linecol_to_byte_clamp(from_position(pos)))
+                .collect();
+
+            serde_json::to_value(selection_ranges(linker, file_id, &positions))
+        }
+        request::CodeLensRequest::METHOD => {
+            let params: CodeLensParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            eprintln!("CodeLens");
+
+            let file_id = linker.ensure_contains_file(&params.text_document.uri, manager);
+
+            serde_json::to_value(gather_code_lenses(linker, file_id))
+        }
         req => {
-            println!("Other request: {req:?}");
+            eprintln!("Other request: {req:?}");
             Ok(serde_json::Value::Null)
         }
     }
@@ -519,26 +651,41 @@ fn handle_notification(
 ) -> Result<(), Box<dyn Error + Sync + Send>> {
     match notification.method.as_str() {
         notification::DidChangeTextDocument::METHOD => {
-            println!("DidChangeTextDocument");
+            eprintln!("DidChangeTextDocument");
             let params: DidChangeTextDocumentParams = serde_json::from_value(notification.params)
                 .expect("JSON Encoding Error while parsing params");
 
-            let mut content_change_iter = params.content_changes.into_iter();
-            let only_change = content_change_iter.next().unwrap();
-            assert!(content_change_iter.next().is_none());
-            assert!(only_change.range.is_none());
-            linker.update_text(&params.text_document.uri, only_change.text, manager);
-
-            push_all_errors(connection, linker)?;
+            let uri = &params.text_document.uri;
+            let mut any_changed = false;
+            for change in params.content_changes {
+                if let Some(range) = change.range {
+                    let file_id = linker.ensure_contains_file(uri, manager);
+                    let file_text = &linker.files[file_id].file_text;
+                    let replace_range = file_text.linecol_to_byte_clamp(from_position(range.start))
+                        ..file_text.linecol_to_byte_clamp(from_position(range.end));
+                    let edit = crate::compiler_top::TextEdit {
+                        replace_range,
+                        new_text: change.text,
+                    };
+                    any_changed |= linker.update_file_incremental(&[edit], file_id, manager);
+                } else {
+                    any_changed |= linker.add_or_update_file(uri.as_str(), change.text, manager);
+                }
+            }
+            // Editors often resend the same text, such as on autosave
+            if any_changed {
+                linker.recompile_all();
+                push_all_errors(connection, linker)?;
+            }
         }
         notification::DidChangeWatchedFiles::METHOD => {
-            println!("Workspace Files modified");
+            eprintln!("Workspace Files modified");
             (*linker, *manager) = initialize_all_files(initialize_params);
 
             push_all_errors(connection, linker)?;
         }
         other => {
-            println!("got other notification: {other:?}");
+            eprintln!("got other notification: {other:?}");
         }
     }
     Ok(())
@@ -548,8 +695,8 @@ fn main_loop(
     connection: lsp_server::Connection,
     initialize_params: serde_json::Value,
 ) -> Result<(), Box<dyn Error + Sync + Send>> {
-    println!("initialize_params: ");
-    println!("{initialize_params}");
+    eprintln!("initialize_params: ");
+    eprintln!("{initialize_params}");
 
     let initialize_params: InitializeParams = serde_json::from_value(initialize_params).unwrap();
 
@@ -557,12 +704,12 @@ fn main_loop(
 
     push_all_errors(&connection, &linker)?;
 
-    println!("starting LSP main loop");
+    eprintln!("starting LSP main loop");
     for msg in &connection.receiver {
         match msg {
             lsp_server::Message::Request(req) => {
                 if connection.handle_shutdown(&req)? {
-                    println!("Shutdown request");
+                    eprintln!("Shutdown request");
                     return Ok(());
                 }
 
@@ -580,7 +727,7 @@ fn main_loop(
                     .send(lsp_server::Message::Response(response))?;
             }
             lsp_server::Message::Response(resp) => {
-                println!("got response: {resp:?}");
+                eprintln!("got response: {resp:?}");
             }
             lsp_server::Message::Notification(notification) => {
                 handle_notification(
@@ -593,9 +740,9 @@ fn main_loop(
             }
         }
 
-        println!("All loaded files:");
+        eprintln!("All loaded files:");
         for (_id, file) in &linker.files {
-            println!("File: {}", &file.file_identifier);
+            eprintln!("File: {}", &file.file_identifier);
         }
     }
     Ok(())
@@ -604,14 +751,19 @@ fn main_loop(
 pub fn lsp_main() -> Result<(), Box<dyn Error + Sync + Send>> {
     std::env::set_var("RUST_BACKTRACE", "1"); // Enable backtrace because I can't set it in Env vars
 
-    println!("starting LSP server");
+    eprintln!("starting LSP server");
 
     // Create the transport. Includes the stdio (stdin and stdout) versions but this could
     // also be implemented to use sockets or HTTP.
-    println!("Connecting on port {}...", config().lsp_port);
-    let (connection, io_threads) =
-        lsp_server::Connection::connect(SocketAddr::from(([127, 0, 0, 1], config().lsp_port)))?;
-    println!("connection established");
+    let (connection, io_threads) = if config().lsp_stdio {
+        eprintln!("Using stdio...");
+        lsp_server::Connection::stdio()
+    } else {
+        let addr = SocketAddr::new(config().lsp_host, config().lsp_port);
+        eprintln!("Connecting on {addr}...");
+        lsp_server::Connection::connect(addr)?
+    };
+    eprintln!("connection established");
 
     // Run the server and wait for the two threads to end (typically by trigger LSP Exit event).
     let server_capabilities = serde_json::to_value(&ServerCapabilities {
@@ -620,12 +772,20 @@ pub fn lsp_main() -> Result<(), Box<dyn Error + Sync + Send>> {
         references_provider: Some(OneOf::Left(true)),
         hover_provider: Some(HoverProviderCapability::Simple(true)),
         rename_provider: Some(OneOf::Left(true)),
+        selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+        code_lens_provider: Some(CodeLensOptions {
+            resolve_provider: Some(false),
+        }),
         semantic_tokens_provider: Some(semantic_token_capabilities()),
         completion_provider: Some(CompletionOptions {
             resolve_provider: Some(true),
             ..Default::default()
         }),
-        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(
+            TextDocumentSyncKind::INCREMENTAL,
+        )),
+        // What every client supports, and what [LineCol] counts in
+        position_encoding: Some(PositionEncodingKind::UTF16),
         ..Default::default()
     })
     .unwrap();
@@ -634,6 +794,6 @@ pub fn lsp_main() -> Result<(), Box<dyn Error + Sync + Send>> {
     io_threads.join()?;
 
     // Shut down gracefully.
-    println!("shutting down server");
+    eprintln!("shutting down server");
     Ok(())
 }
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+use lsp_types::SelectionRange;
+
+use super::span_to_lsp_range;
+
+/// The spans of all syntax nodes enclosing `pos`, innermost first. Each span strictly contains the previous one.
+///
+/// This is purely syntactic, so it also works for files with errors.
+fn enclosing_spans(linker: &Linker, file: FileUUID, pos: usize) -> Vec<Span> {
+    let mut result: Vec<Span> = Vec::new();
+    let mut cur_node = linker.node_at_offset(file, pos);
+    while let Some(node) = cur_node {
+        let span = Span::from(node.byte_range());
+        if result.last() != Some(&span) {
+            result.push(span);
+        }
+        cur_node = node.parent();
+    }
+    result
+}
+
+/// For the "expand selection" feature. One [SelectionRange] hierarchy per position.
+pub fn selection_ranges(
+    linker: &Linker,
+    file: FileUUID,
+    positions: &[usize],
+) -> Vec<SelectionRange> {
+    let file_data = &linker.files[file];
+    positions
+        .iter()
+        .map(|pos| {
+            let spans = enclosing_spans(linker, file, *pos);
+            let mut result: Option<SelectionRange> = None;
+            for span in spans.into_iter().rev() {
+                result = Some(SelectionRange {
+                    range: span_to_lsp_range(&file_data.file_text, span),
+                    parent: result.map(Box::new),
+                });
+            }
+            // The tree-sitter root node always spans the whole file, so there's at least one span
+            result.unwrap()
+        })
+        .collect()
+}
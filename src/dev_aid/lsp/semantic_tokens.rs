@@ -41,18 +41,22 @@ fn get_semantic_token_type_from_ide_token(tok: IDEIdentifierType) -> u32 {
     }
 }
 
-const TOKEN_MODIFIERS: [SemanticTokenModifier; 2] = [
+const TOKEN_MODIFIERS: [SemanticTokenModifier; 3] = [
     SemanticTokenModifier::ASYNC,        // "State"
     SemanticTokenModifier::MODIFICATION, // "Generative"
+    SemanticTokenModifier::READONLY, // "Compile-time", shared by generative values and constants
 ];
 // Produces a bitset with 'modifier bits'
+//
+// Everything known at compile time gets READONLY, so themes color generative values like constants, and can tell them apart from hardware wires
 fn get_modifiers_for_token(tok: IDEIdentifierType) -> u32 {
     match tok {
         IDEIdentifierType::Local {
             is_state: true,
             domain: _,
         } => 1, // ASYNC
-        IDEIdentifierType::Generative => 2, // MODIFICATION
+        IDEIdentifierType::Generative => 2 | 4, // MODIFICATION | READONLY
+        IDEIdentifierType::Constant => 4,       // READONLY
         _other => 0,
     }
 }
@@ -75,7 +79,7 @@ fn convert_to_semantic_tokens(
     file_data: &FileData,
     ide_tokens: &mut [(Span, IDEIdentifierType)],
 ) -> Vec<SemanticToken> {
-    ide_tokens.sort_by(|a, b| a.0.cmp(&b.0));
+    ide_tokens.sort_by_key(|a| a.0);
 
     let mut cursor = Position {
         line: 0,
@@ -131,10 +135,18 @@ impl IDEIdentifierType {
         }
     }
     fn from_identifier_typ(t: IdentifierType, domain: DomainType) -> IDEIdentifierType {
-        match t {
-            IdentifierType::Local => Self::make_local(false, domain.unwrap_physical()),
-            IdentifierType::State => Self::make_local(true, domain.unwrap_physical()),
-            IdentifierType::Generative => IDEIdentifierType::Generative,
+        let is_state = match t {
+            IdentifierType::Local => false,
+            IdentifierType::State => true,
+            IdentifierType::Generative => return IDEIdentifierType::Generative,
+        };
+        match domain {
+            DomainType::Physical(domain) => Self::make_local(is_state, domain),
+            // Domains are only known after typechecking, which doesn't run on every global (or with --upto flatten)
+            DomainType::Generative | DomainType::Unknown(_) => IDEIdentifierType::Local {
+                is_state,
+                domain: 0,
+            },
         }
     }
 }
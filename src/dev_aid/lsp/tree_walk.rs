@@ -428,7 +428,9 @@ impl<'linker, Visitor: FnMut(Span, LocationInfo<'linker>), Pruner: Fn(Span) -> b
                     Instruction::FuncCall(fc) => {
                         self.walk_interface_reference(obj_id, link_info, &fc.interface_reference);
                     }
-                    Instruction::IfStatement(_) | Instruction::ForStatement(_) => {}
+                    Instruction::IfStatement(_)
+                    | Instruction::ForStatement(_)
+                    | Instruction::Assertion(_) => {}
                 };
             }
         }
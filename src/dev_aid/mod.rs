@@ -1,4 +1,9 @@
 pub mod ariadne_interface;
+pub mod dot_graphs;
+pub mod flat_ir_json;
+pub mod logger;
+pub mod resource_estimate;
+pub mod timings;
 
 #[cfg(feature = "lsp")]
 pub mod lsp;
@@ -0,0 +1,104 @@
+//! A rough resource estimate per instantiated module, for `--estimate`.
+//!
+//! This is not synthesis, it only counts what the instantiated netlist asks for:
+//! - Registers: every `state` wire is one register, and every latency stage the code generator inserts to carry a wire to a later cycle is another one.
+//!   The bits of each register are counted too.
+//! - Adders and multipliers: every `+`, `-` or `*` between wires counts as one, regardless of its width.
+//!   Operations between compile-time values never reach the netlist, and so aren't counted.
+//! - Wire bits: the total size of all wires of the module. Submodules are counted separately, in their own instances.
+//!
+//! Wires of unknown size count as 0 bits.
+
+use std::fmt::Write;
+
+use num::ToPrimitive;
+
+use crate::flattening::BinaryOperator;
+use crate::instantiation::{InstantiatedModule, RealWire, RealWireDataSource};
+use crate::typing::concrete_type::ConcreteType;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceEstimate {
+    pub registers: u64,
+    pub register_bits: u64,
+    pub adders: u64,
+    pub multipliers: u64,
+    pub wire_bits: u64,
+}
+
+fn size_in_bits(typ: &ConcreteType) -> u64 {
+    typ.sizeof().and_then(|sz| sz.to_u64()).unwrap_or(0)
+}
+
+fn is_state(w: &RealWire) -> bool {
+    matches!(
+        w.source,
+        RealWireDataSource::Multiplexer {
+            is_state: Some(_),
+            ..
+        }
+    )
+}
+
+impl ResourceEstimate {
+    /// `instance` must not have errors, as latencies are only known for instances that were fully instantiated
+    pub fn of_instance(instance: &InstantiatedModule) -> Self {
+        let needed_untils = instance.compute_needed_untils();
+        let mut result = ResourceEstimate::default();
+        for (wire_id, w) in &instance.wires {
+            let bits = size_in_bits(&w.typ);
+            result.wire_bits += bits;
+
+            let latency_registers = (needed_untils[wire_id] - w.absolute_latency) as u64;
+            let registers = latency_registers + is_state(w) as u64;
+            result.registers += registers;
+            result.register_bits += registers * bits;
+
+            if let RealWireDataSource::BinaryOp { op, .. } = &w.source {
+                match op {
+                    BinaryOperator::Add | BinaryOperator::Subtract => result.adders += 1,
+                    BinaryOperator::Multiply => result.multipliers += 1,
+                    _ => {}
+                }
+            }
+        }
+        result
+    }
+}
+
+/// One row per instance, for all instances without errors. Instances with errors are listed without numbers.
+pub fn estimate_table<'i>(instances: impl IntoIterator<Item = &'i InstantiatedModule>) -> String {
+    let rows: Vec<(&str, Option<ResourceEstimate>)> = instances
+        .into_iter()
+        .map(|inst| {
+            let estimate = (!inst.errors.did_error).then(|| ResourceEstimate::of_instance(inst));
+            (inst.name.as_str(), estimate)
+        })
+        .collect();
+    let name_width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain(std::iter::once("Instance".len()))
+        .max()
+        .unwrap();
+
+    let mut result = String::new();
+    writeln!(
+        result,
+        "{:name_width$}  Registers  Register bits  Adders  Multipliers  Wire bits",
+        "Instance"
+    )
+    .unwrap();
+    for (name, estimate) in rows {
+        match estimate {
+            Some(e) => writeln!(
+                result,
+                "{name:name_width$}  {:>9}  {:>13}  {:>6}  {:>11}  {:>9}",
+                e.registers, e.register_bits, e.adders, e.multipliers, e.wire_bits
+            ),
+            None => writeln!(result, "{name:name_width$}  (has errors)"),
+        }
+        .unwrap();
+    }
+    result
+}
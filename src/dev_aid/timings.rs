@@ -0,0 +1,139 @@
+//! How long each compilation stage took, for `--timings`.
+//!
+//! Only measured when `--timings` is given, see [crate::linker::Linker::timings].
+//! Flattening and typechecking run modules in parallel, so the per-module times of these stages add up to more than the stage total.
+//! The instantiation time of a module includes the submodules that were first instantiated for it.
+
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use crate::config::CompilerOptions;
+
+#[derive(Debug, Clone)]
+pub struct StageTiming {
+    pub stage: &'static str,
+    pub total: Duration,
+    /// Only filled in with `--timings-per-module`, in the order the modules were processed
+    pub per_module: Vec<(String, Duration)>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Timings {
+    pub stages: Vec<StageTiming>,
+}
+
+impl Timings {
+    /// Starts a new stage, to which [Self::add_module_time] adds until the next one is started
+    pub fn start_stage(&mut self, stage: &'static str) -> Instant {
+        self.stages.push(StageTiming {
+            stage,
+            total: Duration::ZERO,
+            per_module: Vec::new(),
+        });
+        Instant::now()
+    }
+
+    /// Finishes the stage that was last started with [Self::start_stage]
+    pub fn finish_stage(&mut self, start: Instant) {
+        self.current_stage().total = start.elapsed();
+    }
+
+    pub fn add_module_time(&mut self, module_name: &str, duration: Duration) {
+        (self.current_stage().per_module).push((module_name.to_owned(), duration));
+    }
+
+    fn current_stage(&mut self) -> &mut StageTiming {
+        self.stages
+            .last_mut()
+            .expect("A stage must be started before it is timed")
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut result = String::new();
+        let total: Duration = self.stages.iter().map(|stage| stage.total).sum();
+        writeln!(result, "Timings:").unwrap();
+        for stage in &self.stages {
+            writeln!(
+                result,
+                "  {:<12} {}",
+                stage.stage,
+                format_duration(stage.total)
+            )
+            .unwrap();
+            for (module_name, duration) in &stage.per_module {
+                writeln!(
+                    result,
+                    "    {module_name:<24} {}",
+                    format_duration(*duration)
+                )
+                .unwrap();
+            }
+        }
+        writeln!(result, "  {:<12} {}", "total", format_duration(total)).unwrap();
+        result
+    }
+
+    /// All durations are in microseconds
+    pub fn to_json(&self) -> serde_json::Value {
+        let stages: Vec<serde_json::Value> = self
+            .stages
+            .iter()
+            .map(|stage| {
+                let per_module: Vec<serde_json::Value> = stage
+                    .per_module
+                    .iter()
+                    .map(|(module_name, duration)| {
+                        serde_json::json!({
+                            "module": module_name,
+                            "us": duration.as_micros() as u64,
+                        })
+                    })
+                    .collect();
+                serde_json::json!({
+                    "stage": stage.stage,
+                    "us": stage.total.as_micros() as u64,
+                    "modules": per_module,
+                })
+            })
+            .collect();
+        let total: Duration = self.stages.iter().map(|stage| stage.total).sum();
+        serde_json::json!({
+            "stages": stages,
+            "total_us": total.as_micros() as u64,
+        })
+    }
+}
+
+/// Starts measuring one module, if `--timings-per-module` was given
+pub fn start_module_timer(options: &CompilerOptions) -> Option<Instant> {
+    options.timings_per_module.then(Instant::now)
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Timings;
+
+    #[test]
+    fn test_timings_output() {
+        let mut timings = Timings::default();
+        let start = timings.start_stage("flatten");
+        timings.finish_stage(start);
+        timings.stages[0].total = Duration::from_micros(1500);
+        timings.add_module_time("fifo", Duration::from_micros(250));
+
+        assert_eq!(
+            timings.to_text(),
+            "Timings:\n  flatten      1.500 ms\n    fifo                     0.250 ms\n  total        1.500 ms\n"
+        );
+        assert_eq!(
+            timings.to_json().to_string(),
+            r#"{"stages":[{"modules":[{"module":"fifo","us":250}],"stage":"flatten","us":1500}],"total_us":1500}"#
+        );
+    }
+}
@@ -3,6 +3,8 @@ use crate::prelude::*;
 use std::cell::RefCell;
 use std::thread::panicking;
 
+use crate::to_string::pretty_print_concrete_instance;
+use crate::typing::{concrete_type::ConcreteType, template::TVec};
 use crate::{alloc::ArenaAllocator, typing::template::Parameter};
 
 use crate::flattening::{
@@ -26,6 +28,34 @@ pub struct ErrorInfo {
     pub info: String,
 }
 
+/// One step in the chain of submodule instantiations that led to an error. See [CompileError::instantiation_stack]
+#[derive(Debug, Clone)]
+pub struct InstantiationFrame {
+    /// The submodule that was being instantiated
+    pub module: ModuleUUID,
+    pub template_args: TVec<ConcreteType>,
+    /// Where the parent module instantiated this submodule
+    pub span: SpanFile,
+    /// The name of the module that contains the submodule
+    pub parent_module_name: String,
+    /// The name of the submodule within its parent, like `fifo` for `FIFO fifo`
+    pub instance_name: String,
+}
+
+impl InstantiationFrame {
+    pub fn make_info(&self, linker: &Linker) -> ErrorInfo {
+        let md = &linker.modules[self.module];
+        let instance_name =
+            pretty_print_concrete_instance(&md.link_info, &self.template_args, &linker.types);
+        let (position, file) = self.span;
+        ErrorInfo {
+            position,
+            file,
+            info: format!("Instantiated from here: {instance_name}"),
+        }
+    }
+}
+
 /// Represents an error or warning that the compiler produced. They can be shown in the IDE, or on the CLI
 ///
 /// All errors for a single file are stored together, which is why this struct does not contain a FileUUID
@@ -35,6 +65,51 @@ pub struct CompileError {
     pub reason: String,
     pub infos: Vec<ErrorInfo>,
     pub level: ErrorLevel,
+    /// For errors produced during instantiation: the chain of submodule instantiations that led here.
+    ///
+    /// Ordered from the top-level instantiation to the deepest one. Empty for all other errors.
+    pub instantiation_stack: Vec<InstantiationFrame>,
+}
+
+impl CompileError {
+    /// For errors within a submodule, the path of instance names leading to it, like `top.fifo.mem`
+    pub fn instance_path(&self) -> Option<String> {
+        let top_frame = self.instantiation_stack.first()?;
+        let mut path = top_frame.parent_module_name.clone();
+        for frame in &self.instantiation_stack {
+            path.push('.');
+            path.push_str(&frame.instance_name);
+        }
+        Some(path)
+    }
+
+    /// [Self::reason], preceded by the [Self::instance_path] if there is one
+    pub fn reason_with_instance_path(&self) -> String {
+        match self.instance_path() {
+            Some(path) => format!("In {path}: {}", self.reason),
+            None => self.reason.clone(),
+        }
+    }
+
+    /// All infos of this error, followed by "instantiated from here" notes, deepest instantiation first
+    ///
+    /// Infos with the same file, span and message as an earlier one are left out, so they're only shown once
+    pub fn all_infos(&self, linker: &Linker) -> Vec<ErrorInfo> {
+        let mut result: Vec<ErrorInfo> = Vec::with_capacity(self.infos.len());
+        let stack_infos =
+            (self.instantiation_stack.iter().rev()).map(|frame| frame.make_info(linker));
+        for info in self.infos.iter().cloned().chain(stack_infos) {
+            let is_duplicate = result.iter().any(|existing| {
+                existing.file == info.file
+                    && existing.position == info.position
+                    && existing.info == info.info
+            });
+            if !is_duplicate {
+                result.push(info);
+            }
+        }
+        result
+    }
 }
 
 /// Stores all errors gathered within a context for reporting to the user.
@@ -61,7 +136,7 @@ impl ErrorStore {
     }
 
     pub fn take(&mut self) -> Self {
-        std::mem::replace(self, ErrorStore::new())
+        std::mem::take(self)
     }
 
     pub fn checkpoint(&self) -> ErrorCheckpoint {
@@ -77,6 +152,13 @@ impl ErrorStore {
     pub fn is_untouched(&self) -> bool {
         self.errors.is_empty()
     }
+
+    /// Marks all errors as having been produced within the given chain of instantiations
+    pub fn set_instantiation_stack(&mut self, instantiation_stack: &[InstantiationFrame]) {
+        for err in &mut self.errors {
+            err.instantiation_stack = instantiation_stack.to_vec();
+        }
+    }
 }
 
 impl<'e> IntoIterator for &'e ErrorStore {
@@ -164,6 +246,7 @@ impl<'linker> ErrorCollector<'linker> {
             reason,
             infos: Vec::new(),
             level,
+            instantiation_stack: Vec::new(),
         });
         ErrorReference {
             err_collector: self,
@@ -207,7 +290,9 @@ impl Drop for ErrorCollector<'_> {
 ///
 /// Use as:
 ///
-///     errors.warn(span, "Unused Variable").info(span2, file2, "In module").info(blablabla)
+/// ```ignore
+/// errors.warn(span, "Unused Variable").info(span2, file2, "In module").info(blablabla)
+/// ```
 pub struct ErrorReference<'ec> {
     err_collector: &'ec ErrorCollector<'ec>,
     pos: usize,
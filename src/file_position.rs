@@ -104,6 +104,7 @@ impl BracketSpan {
     }
 }
 
+/// A position as the LSP sends it. Columns count UTF-16 code units, the LSP's default position encoding, so a character outside the Basic Multilingual Plane like '𝄞' takes two
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct LineCol {
     pub line: usize,
@@ -122,6 +123,10 @@ impl Ord for LineCol {
 
 pub type SpanFile = (Span, FileUUID);
 
+/// The text of a source file, with an index of where each line starts.
+///
+/// The text is stored exactly as it was given, so all byte offsets ([Span]s) and [LineCol]s refer to the original text, including any `\r` of CRLF line endings and a leading UTF-8 BOM.
+/// This way positions from editors, which send their own text, line up with ours. Only [FileText::byte_to_line_col] leaves the BOM out, as it's for display.
 pub struct FileText {
     pub file_text: String,
     lines_start_at: Vec<usize>,
@@ -143,21 +148,42 @@ impl FileText {
             lines_start_at,
         }
     }
-    /// Errors when byte is outside of file
-    pub fn byte_to_linecol(&self, byte_pos: usize) -> LineCol {
-        assert!(byte_pos <= self.file_text.len());
+    /// The line of a byte offset, and the text of that line before it
+    fn line_and_text_before(&self, byte_pos: usize) -> (usize, &str) {
         let line = match self.lines_start_at.binary_search(&byte_pos) {
             Ok(exact_newline) => exact_newline,
             Err(before_newline) => before_newline - 1,
         };
-        let text_before = &self.file_text[self.lines_start_at[line]..byte_pos];
+        (line, &self.file_text[self.lines_start_at[line]..byte_pos])
+    }
+    /// Errors when byte is outside of file
+    pub fn byte_to_linecol(&self, byte_pos: usize) -> LineCol {
+        assert!(byte_pos <= self.file_text.len());
+        let (line, text_before) = self.line_and_text_before(byte_pos);
 
         LineCol {
             line,
-            col: text_before.chars().count(),
+            col: text_before.chars().map(char::len_utf16).sum(),
+        }
+    }
+    /// 1-based `(line, column)` of a byte offset, for displaying positions to the user. Columns count chars, not bytes.
+    ///
+    /// Offsets past the end of the file are clamped to the end of the file, so cannot error.
+    pub fn byte_to_line_col(&self, byte_pos: usize) -> (usize, usize) {
+        let mut byte_pos = byte_pos.min(self.file_text.len());
+        while !self.file_text.is_char_boundary(byte_pos) {
+            byte_pos -= 1;
         }
+        let (line, text_before) = self.line_and_text_before(byte_pos);
+        let mut col = text_before.chars().count();
+        if line == 0 && byte_pos > 0 && self.file_text.starts_with('\u{FEFF}') {
+            col -= 1;
+        }
+        (line + 1, col + 1)
     }
-    /// Clamps the linecol to be within the file, so cannot error.
+    /// Clamps the linecol to be within the file, so cannot error. Columns past the end of a line clamp to before its `\r\n` or `\n`.
+    ///
+    /// A column in the middle of a surrogate pair clamps to the start of its character.
     pub fn linecol_to_byte_clamp(&self, linecol: LineCol) -> usize {
         let line_end = match (linecol.line + 1).cmp(&self.lines_start_at.len()) {
             std::cmp::Ordering::Less => self.lines_start_at[linecol.line + 1] - 1,
@@ -165,15 +191,17 @@ impl FileText {
             std::cmp::Ordering::Greater => return self.file_text.len(),
         };
         let line_start = self.lines_start_at[linecol.line];
-        let line_text = &self.file_text[line_start..line_end];
+        let line_text = self.file_text[line_start..line_end]
+            .strip_suffix('\r')
+            .unwrap_or(&self.file_text[line_start..line_end]);
+        let line_end = line_start + line_text.len();
 
         let mut cols_left = linecol.col;
-        let mut char_indices = line_text.char_indices();
-        for (byte, _) in &mut char_indices {
-            if cols_left == 0 {
+        for (byte, c) in line_text.char_indices() {
+            if cols_left < c.len_utf16() {
                 return line_start + byte;
             }
-            cols_left -= 1;
+            cols_left -= c.len_utf16();
         }
         line_end
     }
@@ -190,6 +218,10 @@ impl FileText {
     pub fn len(&self) -> usize {
         self.file_text.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.file_text.is_empty()
+    }
 }
 
 impl Index<Span> for FileText {
@@ -200,3 +232,59 @@ impl Index<Span> for FileText {
         &self.file_text[index.as_range()]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{FileText, LineCol};
+
+    #[test]
+    fn test_byte_to_line_col_multibyte() {
+        let text = FileText::new("aé b\nµx".to_owned());
+        assert_eq!(text.byte_to_line_col(0), (1, 1));
+        // 'b' comes after the 2-byte 'é'
+        assert_eq!(text.byte_to_line_col(4), (1, 4));
+        assert_eq!(text.byte_to_line_col(6), (2, 1));
+        assert_eq!(text.byte_to_line_col(8), (2, 2));
+    }
+
+    #[test]
+    fn test_byte_to_line_col_eof() {
+        let text = FileText::new("ab\n".to_owned());
+        assert_eq!(text.byte_to_line_col(3), (2, 1));
+        assert_eq!(text.byte_to_line_col(100), (2, 1));
+    }
+
+    #[test]
+    fn test_crlf() {
+        let text = FileText::new("ab\r\ncd\r\n".to_owned());
+        assert_eq!(text.byte_to_line_col(4), (2, 1));
+        assert_eq!(text.byte_to_line_col(5), (2, 2));
+        // Offsets stay those of the original text, the '\r' isn't removed
+        assert_eq!(text.linecol_to_byte_clamp(LineCol { line: 1, col: 1 }), 5);
+        // Past the end of a line clamps to before the "\r\n"
+        assert_eq!(text.linecol_to_byte_clamp(LineCol { line: 0, col: 100 }), 2);
+        assert_eq!(text.linecol_to_byte_clamp(LineCol { line: 1, col: 100 }), 6);
+    }
+
+    #[test]
+    fn test_bom() {
+        let text = FileText::new("\u{FEFF}ab\ncd".to_owned());
+        // The BOM is 3 bytes, and doesn't take a column when displayed
+        assert_eq!(text.byte_to_line_col(0), (1, 1));
+        assert_eq!(text.byte_to_line_col(3), (1, 1));
+        assert_eq!(text.byte_to_line_col(4), (1, 2));
+        assert_eq!(text.byte_to_line_col(7), (2, 2));
+    }
+
+    #[test]
+    fn test_utf16_columns() {
+        // '𝄞' is 4 bytes, a single char, and a surrogate pair of 2 UTF-16 code units
+        let text = FileText::new("a𝄞b\n".to_owned());
+        assert_eq!(text.byte_to_linecol(5).col, 3);
+        assert_eq!(text.linecol_to_byte_clamp(LineCol { line: 0, col: 3 }), 5);
+        // Halfway into the surrogate pair clamps to the start of '𝄞'
+        assert_eq!(text.linecol_to_byte_clamp(LineCol { line: 0, col: 2 }), 1);
+        // Displayed columns still count chars
+        assert_eq!(text.byte_to_line_col(5), (1, 3));
+    }
+}
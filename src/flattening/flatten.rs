@@ -2,11 +2,14 @@ use crate::alloc::{ArenaAllocator, UUIDAllocator, UUIDRange, UUID};
 use crate::typing::abstract_type::{AbstractType, DomainType};
 use crate::{alloc::UUIDRangeIter, prelude::*};
 
-use num::BigInt;
 use sus_proc_macro::{field, kind, kw};
 
-use crate::linker::{FileData, GlobalResolver, GlobalUUID, AFTER_FLATTEN_CP};
-use crate::{debug::SpanDebugger, value::Value};
+use crate::dev_aid::timings::start_module_timer;
+use crate::errors::{ErrorCollector, ErrorStore};
+use crate::linker::features::Feature;
+use crate::linker::{FileData, GlobalResolver, GlobalUUID, ResolvedGlobals, AFTER_FLATTEN_CP};
+use crate::{debug::SpanDebugger, errors::ErrorReference, value::parse_integer_literal};
+use num::{BigInt, One, Zero};
 
 use super::name_context::LocalVariableContext;
 use super::parser::Cursor;
@@ -52,6 +55,25 @@ enum PartialWireReference {
     WireReference(WireReference),
 }
 
+/// What a function call connects to
+enum FuncCallTarget {
+    Interface(ModuleInterfaceReference),
+    /// The submodule has no main interface, so the call connects its [Module::positional_ports]
+    Positional {
+        submodule_decl: FlatID,
+        name_span: Option<Span>,
+    },
+}
+
+/// The output ports of a flattened function call, in order
+struct FuncCallOutputs {
+    submodule_decl: FlatID,
+    submodule_name_span: Option<Span>,
+    /// None for a positional call
+    interface: Option<InterfaceID>,
+    ports: Vec<PortID>,
+}
+
 impl PartialWireReference {
     fn expect_wireref(self, ctx: &FlatteningContext) -> Option<WireReference> {
         match self {
@@ -249,6 +271,11 @@ struct FlatteningContext<'l, 'errs> {
     local_variable_context: LocalVariableContext<'l, NamedLocal>,
 
     default_declaration_context: DeclarationContext,
+
+    /// Submodule ports accessed by name, like `add.a`. Used to forbid mixing these with [Self::positional_port_connections]
+    named_port_connections: Vec<(FlatID, Span)>,
+    /// Calls on submodules without a main interface, which connect their [Module::positional_ports]
+    positional_port_connections: Vec<(FlatID, Span)>,
 }
 
 impl FlatteningContext<'_, '_> {
@@ -298,7 +325,7 @@ impl FlatteningContext<'_, '_> {
     fn flatten_template_args(
         &mut self,
         found_global: GlobalUUID,
-        has_template_args: bool,
+        template_span: Option<BracketSpan>,
         cursor: &mut Cursor,
     ) -> TVec<Option<TemplateArg>> {
         let link_info = self.globals.get_link_info(found_global);
@@ -307,21 +334,25 @@ impl FlatteningContext<'_, '_> {
         let mut template_arg_map: FlatAlloc<Option<TemplateArg>, TemplateIDMarker> =
             link_info.template_parameters.map(|_| None);
 
-        if !has_template_args {
+        let Some(template_span) = template_span else {
             return template_arg_map;
-        }
+        };
+
+        // Reported after all arguments have been seen, such that the number of given arguments is known
+        let mut unknown_args: Vec<Span> = Vec::new();
+        let mut num_args_given = 0;
 
         cursor.list(kind!("template_args"), |cursor| {
             cursor.go_down(kind!("template_arg"), |cursor| {
                 let name_span =
                     cursor.field_span(field!("name"), kind!("identifier"));
+                num_args_given += 1;
 
                 let name = &self.globals.file_data.file_text[name_span];
 
                 let name_found = link_info.template_parameters.iter().find(|(_id, arg)| arg.name == name);
                 if name_found.is_none() {
-                    self.errors.error(name_span, format!("{name} is not a valid template argument of {full_object_name}"))
-                        .info_obj(link_info);
+                    unknown_args.push(name_span);
                 }
 
                 let (template_arg, value_span) = if cursor.optional_field(field!("val_arg")) {
@@ -381,11 +412,11 @@ impl FlatteningContext<'_, '_> {
                             }
                         }
                         (ParameterKind::Type(_), TemplateArgKind::Value(_)) => {
-                            self.errors.error(name_span, format!("'{name}' is not a value. `type` keyword cannot be used for values"))
+                            self.errors.error(value_span, format!("Expected a type argument for '{name}', found a value. To pass a type use the `type` keyword like `{name}: type int[123]`"))
                                 .info((parameter.name_span, link_info.file), "Declared here");
                         }
                         (ParameterKind::Generative(_), TemplateArgKind::Type(_)) => {
-                            self.errors.error(name_span, format!("'{name}' is not a type. To use template type arguments use the `type` keyword like `T: type int[123]`"))
+                            self.errors.error(value_span, format!("Expected a value argument for '{name}', found a type. The `type` keyword is only for type parameters"))
                                 .info((parameter.name_span, link_info.file), "Declared here");
                         }
                     }
@@ -393,6 +424,37 @@ impl FlatteningContext<'_, '_> {
             });
         });
 
+        let num_params = link_info.template_parameters.len();
+        if num_params == 0 {
+            // One error for the whole block, instead of one for every argument
+            self.errors
+                .error(
+                    template_span.outer_span(),
+                    format!("{full_object_name} is not parameterized, it can't be given template arguments"),
+                )
+                .info_obj(link_info);
+            return template_arg_map;
+        }
+        for name_span in unknown_args {
+            let name = &self.globals.file_data.file_text[name_span];
+            let mut reason =
+                format!("{name} is not a valid template argument of {full_object_name}");
+            if num_args_given > num_params {
+                let plural = if num_params == 1 { "" } else { "s" };
+                reason.push_str(&format!(". Expected at most {num_params} template argument{plural}, but found {num_args_given}"));
+            }
+            let param_names: Vec<&str> = link_info
+                .template_parameters
+                .iter()
+                .map(|(_, param)| param.name.as_str())
+                .collect();
+            reason.push_str(&format!(
+                ". The template parameters are: {}",
+                param_names.join(", ")
+            ));
+            self.errors.error(name_span, reason).info_obj(link_info);
+        }
+
         template_arg_map
     }
 
@@ -436,8 +498,7 @@ impl FlatteningContext<'_, '_> {
                 let template_span =
                     template_args_used.then(|| BracketSpan::from_outer(cursor.span()));
 
-                let template_args =
-                    self.flatten_template_args(global_id, template_args_used, cursor);
+                let template_args = self.flatten_template_args(global_id, template_span, cursor);
 
                 let template_arg_types = template_args
                     .map(|_| AbstractType::Unknown(self.type_alloc.type_variable_alloc.alloc()));
@@ -565,32 +626,76 @@ impl FlatteningContext<'_, '_> {
         }
     }
 
+    fn info_named_local(&self, err_ref: &ErrorReference, named_local: NamedLocal) {
+        match named_local {
+            NamedLocal::Declaration(decl_id) => {
+                err_ref.info_obj_same_file(self.instructions[decl_id].unwrap_declaration());
+            }
+            NamedLocal::SubModule(submod_id) => {
+                err_ref.info_obj_same_file(self.instructions[submod_id].unwrap_submodule());
+            }
+            NamedLocal::TemplateType(template_id) => {
+                err_ref.info_obj_same_file(
+                    &self.working_on_link_info.template_parameters[template_id],
+                );
+            }
+            NamedLocal::DomainDecl(domain_id) => {
+                err_ref.info_obj_same_file(&self.domains[domain_id]);
+            }
+        }
+    }
+
+    fn is_port(&self, named_local: NamedLocal) -> bool {
+        match named_local {
+            NamedLocal::Declaration(decl_id) => self.instructions[decl_id]
+                .unwrap_declaration()
+                .decl_kind
+                .is_io_port()
+                .is_some(),
+            NamedLocal::SubModule(_) | NamedLocal::TemplateType(_) | NamedLocal::DomainDecl(_) => {
+                false
+            }
+        }
+    }
+
     fn alloc_local_name(&mut self, name_span: Span, named_local: NamedLocal) {
+        let name = &self.globals.file_data.file_text[name_span];
+        // `_` is a throwaway name. It can't be referred to, so any number of them may be declared
+        if name == "_" {
+            return;
+        }
+        let new_is_port = self.is_port(named_local);
         if let Err(conflict) = self
             .local_variable_context
-            .add_declaration(&self.globals.file_data.file_text[name_span], named_local)
+            .add_declaration(name, named_local)
         {
+            // Port-port conflicts were already reported by [super::initialization]
+            if new_is_port && self.is_port(conflict) {
+                return;
+            }
             let err_ref = self.errors.error(
                 name_span,
                 "This declaration conflicts with a previous declaration in the same scope",
             );
-
-            match conflict {
-                NamedLocal::Declaration(decl_id) => {
-                    err_ref.info_obj_same_file(self.instructions[decl_id].unwrap_declaration());
-                }
-                NamedLocal::SubModule(submod_id) => {
-                    err_ref.info_obj_same_file(self.instructions[submod_id].unwrap_submodule());
-                }
-                NamedLocal::TemplateType(template_id) => {
-                    err_ref.info_obj_same_file(
-                        &self.working_on_link_info.template_parameters[template_id],
-                    );
-                }
-                NamedLocal::DomainDecl(domain_id) => {
-                    err_ref.info_obj_same_file(&self.domains[domain_id]);
-                }
+            self.info_named_local(&err_ref, conflict);
+        } else if let Some(shadowed) = self.local_variable_context.check_shadowing(name) {
+            let shadowed_is_port = self.is_port(shadowed);
+            if new_is_port && shadowed_is_port {
+                return;
             }
+            // Ports are part of the module's interface, so a local with the same name can't just shadow it
+            let err_ref = if new_is_port || shadowed_is_port {
+                self.errors.error(
+                    name_span,
+                    format!("'{name}' conflicts with a port of the same name"),
+                )
+            } else {
+                self.errors.warn(
+                    name_span,
+                    format!("'{name}' shadows a declaration in an outer scope"),
+                )
+            };
+            self.info_named_local(&err_ref, shadowed);
         }
     }
 
@@ -767,7 +872,7 @@ impl FlatteningContext<'_, '_> {
                 name : name.to_owned(),
                 name_span,
                 decl_span,
-                declaration_runtime_depth : OnceCell::new(),
+                declaration_runtime_depth : OnceLock::new(),
                 latency_specifier : span_latency_specifier.map(|(ls, _)| ls),
                 documentation
             }));
@@ -789,6 +894,28 @@ impl FlatteningContext<'_, '_> {
         })
     }
 
+    /// `arr[high:low]`. The bounds set the size of the result, so they must be generative
+    fn flatten_array_slice(&mut self, cursor: &mut Cursor) -> WireReferencePathElement {
+        let bracket_span = BracketSpan::from_outer(cursor.span());
+        cursor.go_down(kind!("array_slice_expression"), |cursor| {
+            cursor.field(field!("high"));
+            let high_span = cursor.span();
+            let (high, high_is_generative) = self.flatten_expr(cursor);
+            self.must_be_generative(high_is_generative, "Slice bound", high_span);
+
+            cursor.field(field!("low"));
+            let low_span = cursor.span();
+            let (low, low_is_generative) = self.flatten_expr(cursor);
+            self.must_be_generative(low_is_generative, "Slice bound", low_span);
+
+            WireReferencePathElement::ArraySlice {
+                high,
+                low,
+                bracket_span,
+            }
+        })
+    }
+
     fn alloc_error(&mut self, span: Span) -> FlatID {
         self.instructions.alloc(Instruction::Expression(Expression {
             typ: self
@@ -799,12 +926,12 @@ impl FlatteningContext<'_, '_> {
         }))
     }
 
-    /// Returns the ID of the [FuncCallInstruction]
-    fn flatten_func_call(&mut self, cursor: &mut Cursor) -> Option<FlatID> {
+    /// Returns the output ports of the call, in order
+    fn flatten_func_call(&mut self, cursor: &mut Cursor) -> Option<FuncCallOutputs> {
         let whole_func_span = cursor.span();
         cursor.go_down(kind!("func_call"), |cursor| {
             cursor.field(field!("name"));
-            let interface_reference = self.get_or_alloc_module(cursor);
+            let target = self.get_or_alloc_module(cursor);
 
             cursor.field(field!("arguments"));
             let arguments_span = BracketSpan::from_outer(cursor.span());
@@ -816,31 +943,53 @@ impl FlatteningContext<'_, '_> {
                 expr
             });
 
-            let interface_reference = interface_reference?;
-
-            let (md, interface) = self.get_interface_reference(&interface_reference);
-
-            let func_call_inputs = interface.func_call_inputs;
-            let func_call_outputs = interface.func_call_outputs;
+            let target = target?;
+
+            let (submodule_decl, submodule_name_span, interface, inputs, outputs) = match &target {
+                FuncCallTarget::Interface(interface_reference) => {
+                    let (_md, interface) = self.get_interface_reference(interface_reference);
+                    (
+                        interface_reference.submodule_decl,
+                        interface_reference.name_span,
+                        Some(interface_reference.submodule_interface),
+                        interface.func_call_inputs.iter().collect(),
+                        interface.func_call_outputs.iter().collect(),
+                    )
+                }
+                FuncCallTarget::Positional { submodule_decl, name_span } => {
+                    let md = &self.globals[self.instructions[*submodule_decl].unwrap_submodule().module_ref.id];
+                    let (inputs, outputs): (Vec<PortID>, Vec<PortID>) = md
+                        .positional_ports
+                        .iter()
+                        .partition(|port| md.ports[**port].is_input);
+                    (*submodule_decl, *name_span, None, inputs, outputs)
+                }
+            };
+            let call_outputs = FuncCallOutputs {
+                submodule_decl,
+                submodule_name_span,
+                interface,
+                ports: outputs,
+            };
 
             let arg_count = arguments.len();
-            let expected_arg_count = func_call_inputs.len();
+            let expected_arg_count = inputs.len();
 
             if arg_count != expected_arg_count {
                 if arg_count > expected_arg_count {
                     // Too many args, complain about excess args at the end
                     let excess_args_span = Span::new_overarching(self.instructions[arguments[expected_arg_count]].unwrap_expression().span, self.instructions[*arguments.last().unwrap()].unwrap_expression().span);
 
-                    self.errors
-                        .error(excess_args_span, format!("Excess argument. Function takes {expected_arg_count} args, but {arg_count} were passed."))
-                        .info_obj(&(md, interface));
+                    let err = self.errors
+                        .error(excess_args_span, format!("Excess argument. Function takes {expected_arg_count} args, but {arg_count} were passed."));
+                    self.func_call_info(&err, &call_outputs);
                     // Shorten args to still get proper type checking for smaller arg array
                     arguments.truncate(expected_arg_count);
                 } else {
                     // Too few args, mention missing argument names
-                    self.errors
-                        .error(arguments_span.close_bracket(), format!("Too few arguments. Function takes {expected_arg_count} args, but {arg_count} were passed."))
-                        .info_obj(&(md, interface));
+                    let err = self.errors
+                        .error(arguments_span.close_bracket(), format!("Too few arguments. Function takes {expected_arg_count} args, but {arg_count} were passed."));
+                    self.func_call_info(&err, &call_outputs);
 
                     while arguments.len() < expected_arg_count {
                         arguments.push(self.alloc_error(arguments_span.close_bracket()));
@@ -848,38 +997,95 @@ impl FlatteningContext<'_, '_> {
                 }
             }
 
-            Some(self.instructions.alloc(Instruction::FuncCall(FuncCallInstruction{
-                interface_reference,
-                arguments,
-                func_call_inputs,
-                func_call_outputs,
-                arguments_span,
-                whole_func_span
-            })))
+            match target {
+                FuncCallTarget::Interface(interface_reference) => {
+                    let interface = self.get_interface_reference(&interface_reference).1;
+                    let func_call_inputs = interface.func_call_inputs;
+                    let func_call_outputs = interface.func_call_outputs;
+                    self.instructions.alloc(Instruction::FuncCall(FuncCallInstruction{
+                        interface_reference,
+                        arguments,
+                        func_call_inputs,
+                        func_call_outputs,
+                        arguments_span,
+                        whole_func_span
+                    }));
+                }
+                FuncCallTarget::Positional { submodule_decl, name_span } => {
+                    self.positional_port_connections.push((submodule_decl, whole_func_span));
+                    for (port, arg) in std::iter::zip(inputs, arguments) {
+                        let arg_span = self.instructions[arg].unwrap_expression().span;
+                        self.instructions.alloc(Instruction::Write(Write {
+                            from: arg,
+                            to: WireReference::simple_port(PortReference {
+                                port,
+                                port_name_span: None,
+                                is_input: true,
+                                submodule_name_span: name_span,
+                                submodule_decl,
+                            }),
+                            to_span: arg_span,
+                            to_type: self
+                                .type_alloc
+                                .alloc_unset_type(DomainAllocOption::NonGenerativeUnknown), // Module ports are always non-generative
+                            write_modifiers: WriteModifiers::Connection {
+                                num_regs: 0,
+                                regs_span: arg_span.empty_span_at_front(),
+                            },
+                        }));
+                    }
+                }
+            }
+
+            Some(call_outputs)
         })
     }
 
+    /// Points to the interface or module that was called
+    fn func_call_info(&self, err: &ErrorReference, call: &FuncCallOutputs) {
+        let md = &self.globals[self.instructions[call.submodule_decl]
+            .unwrap_submodule()
+            .module_ref
+            .id];
+        if let Some(interface) = call.interface {
+            err.info_obj(&(md, &md.interfaces[interface]));
+        } else {
+            err.info_obj(md);
+        }
+    }
+
+    /// Modules without a main interface can still be called, by connecting their [Module::positional_ports]
     fn get_main_interface(
         &self,
         submodule_decl: FlatID,
-        span: Span,
-    ) -> Option<(InterfaceID, &Interface)> {
+        name_span: Option<Span>,
+        interface_span: Span,
+    ) -> Option<FuncCallTarget> {
         let sm = self.instructions[submodule_decl].unwrap_submodule();
 
         let md = &self.globals[sm.module_ref.id];
 
-        let result = md.get_main_interface();
-
-        if result.is_none() {
-            self.errors.error(span, format!("{} does not have a main interface. You should explicitly specify an interface to access", md.link_info.get_full_name()))
+        if let Some((submodule_interface, _)) = md.get_main_interface() {
+            Some(FuncCallTarget::Interface(ModuleInterfaceReference {
+                submodule_decl,
+                submodule_interface,
+                name_span,
+                interface_span,
+            }))
+        } else if !md.positional_ports.is_empty() {
+            Some(FuncCallTarget::Positional {
+                submodule_decl,
+                name_span,
+            })
+        } else {
+            self.errors.error(interface_span, format!("{} does not have a main interface. You should explicitly specify an interface to access", md.link_info.get_full_name()))
                 .info_obj(md);
+            None
         }
-
-        result
     }
 
     /// Produces a new [SubModuleInstance] if a global was passed, or a reference to the existing instance if it's referenced by name
-    fn get_or_alloc_module(&mut self, cursor: &mut Cursor) -> Option<ModuleInterfaceReference> {
+    fn get_or_alloc_module(&mut self, cursor: &mut Cursor) -> Option<FuncCallTarget> {
         let outer_span = cursor.span();
 
         match self.flatten_wire_reference(cursor) {
@@ -889,32 +1095,22 @@ impl FlatteningContext<'_, '_> {
                 let interface_span = module_ref.get_total_span();
                 let submodule_decl =
                     self.alloc_submodule_instruction(module_ref, None, documentation);
-                Some(ModuleInterfaceReference {
-                    submodule_decl,
-                    submodule_interface: self.get_main_interface(submodule_decl, interface_span)?.0,
-                    name_span: None,
-                    interface_span,
-                })
+                self.get_main_interface(submodule_decl, None, interface_span)
             }
             PartialWireReference::ModuleButNoPort(submodule_decl, name_span) => {
-                Some(ModuleInterfaceReference {
-                    submodule_decl,
-                    submodule_interface: self.get_main_interface(submodule_decl, name_span)?.0,
-                    name_span: Some(name_span),
-                    interface_span: name_span,
-                })
+                self.get_main_interface(submodule_decl, Some(name_span), name_span)
             }
             PartialWireReference::ModuleWithInterface {
                 submodule_decl,
                 submodule_name_span,
                 interface,
                 interface_name_span,
-            } => Some(ModuleInterfaceReference {
+            } => Some(FuncCallTarget::Interface(ModuleInterfaceReference {
                 submodule_decl,
                 submodule_interface: interface,
                 name_span: Some(submodule_name_span),
                 interface_span: interface_name_span,
-            }),
+            })),
             PartialWireReference::WireReference(_wire_ref) => {
                 self.errors.error(
                     outer_span,
@@ -935,17 +1131,41 @@ impl FlatteningContext<'_, '_> {
         (md, interface)
     }
 
+    /// Sized literals are unsigned, so `-8'h01` is the bit pattern of 255 in the generated code, not -1
+    fn warn_negated_sized_literal(&mut self, right: FlatID, expr_span: Span) {
+        let right_expr = self.instructions[right].unwrap_expression();
+        let ExpressionSource::Literal(IntegerLiteral {
+            value,
+            width: Some(width),
+        }) = &right_expr.source
+        else {
+            return;
+        };
+        if value.is_zero() {
+            return;
+        }
+        let wrapped = (BigInt::one() << *width) - value;
+        let literal_text = &self.globals.file_data.file_text[right_expr.span];
+        self.errors.warn(
+            expr_span,
+            format!("Sized literals are unsigned, so '-{literal_text}' is {wrapped} when kept to its {width} bits. Use an unsized literal for negative values"),
+        );
+    }
+
     /// Returns the expression [FlatID] and if it's generative
     fn flatten_expr(&mut self, cursor: &mut Cursor) -> (FlatID, bool) {
         let (kind, expr_span) = cursor.kind_span();
 
         let (source, is_generative) = if kind == kind!("number") {
             let text = &self.globals.file_data.file_text[expr_span];
-            use std::str::FromStr;
-            (
-                ExpressionSource::Constant(Value::Integer(BigInt::from_str(text).unwrap())),
-                true,
-            )
+            let source = match parse_integer_literal(text) {
+                Ok(literal) => ExpressionSource::Literal(literal),
+                Err(reason) => {
+                    self.errors.error(expr_span, reason);
+                    ExpressionSource::new_error()
+                }
+            };
+            (source, true)
         } else if kind == kind!("unary_op") {
             cursor.go_down_no_check(|cursor| {
                 cursor.field(field!("operator"));
@@ -954,6 +1174,10 @@ impl FlatteningContext<'_, '_> {
                 cursor.field(field!("right"));
                 let (right, right_gen) = self.flatten_expr(cursor);
 
+                if op == UnaryOperator::Negate {
+                    self.warn_negated_sized_literal(right, expr_span);
+                }
+
                 (ExpressionSource::UnaryOp { op, right }, right_gen)
             })
         } else if kind == kind!("binary_op") {
@@ -974,20 +1198,18 @@ impl FlatteningContext<'_, '_> {
             })
         } else if kind == kind!("func_call") {
             (
-                if let Some(fc_id) = self.flatten_func_call(cursor) {
-                    let fc = self.instructions[fc_id].unwrap_func_call();
-                    let (md, interface) = self.get_interface_reference(&fc.interface_reference);
-                    if interface.func_call_outputs.len() != 1 {
-                        self.errors
-                        .error(expr_span, "A function called in this context may only return one result. Split this function call into a separate line instead.")
-                        .info_obj(&(md, interface));
+                if let Some(call) = self.flatten_func_call(cursor) {
+                    if call.ports.len() != 1 {
+                        let err = self.errors
+                        .error(expr_span, "A function called in this context may only return one result. Split this function call into a separate line instead.");
+                        self.func_call_info(&err, &call);
                     }
 
-                    if !interface.func_call_outputs.is_empty() {
+                    if let Some(&port) = call.ports.first() {
                         ExpressionSource::WireRef(WireReference::simple_port(PortReference {
-                            submodule_name_span: fc.interface_reference.name_span,
-                            submodule_decl: fc.interface_reference.submodule_decl,
-                            port: interface.func_call_outputs.0,
+                            submodule_name_span: call.submodule_name_span,
+                            submodule_decl: call.submodule_decl,
+                            port,
                             port_name_span: None,
                             is_input: false,
                         }))
@@ -1001,6 +1223,34 @@ impl FlatteningContext<'_, '_> {
                 },
                 false,
             ) // TODO add compile-time functions https://github.com/pc2/sus-compiler/issues/10
+        } else if kind == kind!("conditional") {
+            cursor.go_down_no_check(|cursor| {
+                cursor.field(field!("condition"));
+                let (condition, condition_gen) = self.flatten_expr(cursor);
+
+                cursor.field(field!("if_true"));
+                let (if_true, if_true_gen) = self.flatten_expr(cursor);
+
+                cursor.field(field!("if_false"));
+                let (if_false, if_false_gen) = self.flatten_expr(cursor);
+
+                (
+                    ExpressionSource::Conditional {
+                        condition,
+                        if_true,
+                        if_false,
+                    },
+                    condition_gen & if_true_gen & if_false_gen,
+                )
+            })
+        } else if kind == kind!("concatenation") {
+            let mut is_generative = true;
+            let elements = cursor.collect_list(kind!("concatenation"), |cursor| {
+                let (elem, elem_gen) = self.flatten_expr(cursor);
+                is_generative &= elem_gen;
+                elem
+            });
+            (ExpressionSource::Concatenation { elements }, is_generative)
         } else if kind == kind!("parenthesis_expression") {
             // Explicitly return so we don't alloc another WireInstance Instruction
             return cursor.go_down_content(kind!("parenthesis_expression"), |cursor| {
@@ -1028,6 +1278,7 @@ impl FlatteningContext<'_, '_> {
                             .domain
                             .is_generative()
                     }
+                    WireReferencePathElement::ArraySlice { .. } => {}
                 }
             }
             (ExpressionSource::WireRef(wr), is_comptime)
@@ -1094,7 +1345,7 @@ impl FlatteningContext<'_, '_> {
                                     domain.name
                                 ),
                             )
-                            .info_same_file(span, format!("Domain {} declared here", domain.name));
+                            .info_obj_same_file(domain);
                         PartialWireReference::Error
                     }
                 },
@@ -1121,7 +1372,12 @@ impl FlatteningContext<'_, '_> {
 
                 cursor.field(field!("arr_idx"));
                 let arr_idx_span = cursor.span();
-                let (idx, _is_generative, bracket_span) = self.flatten_array_bracket(cursor);
+                let path_elem = if cursor.kind() == kind!("array_slice_expression") {
+                    self.flatten_array_slice(cursor)
+                } else {
+                    let (idx, _is_generative, bracket_span) = self.flatten_array_bracket(cursor);
+                    WireReferencePathElement::ArrayAccess { idx, bracket_span }
+                };
 
                 // only unpack the subexpr after flattening the idx, so we catch all errors
                 match &mut flattened_arr_expr {
@@ -1137,8 +1393,7 @@ impl FlatteningContext<'_, '_> {
                     }
                     PartialWireReference::Error => {}
                     PartialWireReference::WireReference(wr) => {
-                        wr.path
-                            .push(WireReferencePathElement::ArrayAccess { idx, bracket_span });
+                        wr.path.push(path_elem);
                     }
                 }
 
@@ -1170,6 +1425,7 @@ impl FlatteningContext<'_, '_> {
 
                         match submod.get_port_or_interface_by_name(port_name_span, &self.globals.file_data.file_text, self.errors) {
                             Some(PortOrInterface::Port(port)) => {
+                                self.named_port_connections.push((submodule_decl, port_name_span));
                                 let port_info = PortReference{
                                     submodule_name_span : Some(submodule_name_span),
                                     submodule_decl,
@@ -1190,7 +1446,7 @@ impl FlatteningContext<'_, '_> {
                         }
                     }
                     PartialWireReference::WireReference(_) => {
-                        println!("TODO: Struct fields");
+                        log::warn!("TODO: Struct fields");
                         PartialWireReference::Error
                     }
                 }
@@ -1199,7 +1455,10 @@ impl FlatteningContext<'_, '_> {
             self.errors
                 .error(expr_span, "A constant is not a wire reference");
             PartialWireReference::Error
-        } else if kind == kind!("unary_op") || kind == kind!("binary_op") {
+        } else if kind == kind!("unary_op")
+            || kind == kind!("binary_op")
+            || kind == kind!("conditional")
+        {
             self.errors.error(
                 expr_span,
                 "The result of an operator is not a wire reference",
@@ -1209,6 +1468,10 @@ impl FlatteningContext<'_, '_> {
             self.errors
                 .error(expr_span, "A submodule call is not a wire reference");
             PartialWireReference::Error
+        } else if kind == kind!("concatenation") {
+            self.errors
+                .error(expr_span, "A concatenation is not a wire reference");
+            PartialWireReference::Error
         } else if kind == kind!("parenthesis_expression") {
             self.errors.error(
                 expr_span,
@@ -1226,28 +1489,14 @@ impl FlatteningContext<'_, '_> {
             let keyword_is_if = cursor.kind() == kw!("if");
             let position_statement_keyword = cursor.span();
             cursor.field(field!("condition"));
-            let (condition, condition_is_generative) = self.flatten_expr(cursor);
-            match (keyword_is_if, condition_is_generative) {
-                (true, false) => {
-                    self.errors.warn(
-                        position_statement_keyword,
-                        "Used 'if' in a non generative context, use 'when' instead",
-                    );
-                }
-                (false, true) => {
-                    self.errors.error(
-                        position_statement_keyword,
-                        "Used 'when' in a generative context, use 'if' instead",
-                    );
-                }
-                (_, _) => (),
-            }
+            let (condition, _) = self.flatten_expr(cursor);
 
             let if_id = self
                 .instructions
                 .alloc(Instruction::IfStatement(IfStatement {
                     condition,
                     is_generative: keyword_is_if,
+                    keyword_span: position_statement_keyword,
                     then_start: FlatID::PLACEHOLDER,
                     then_end_else_start: FlatID::PLACEHOLDER,
                     else_end: FlatID::PLACEHOLDER,
@@ -1291,33 +1540,25 @@ impl FlatteningContext<'_, '_> {
         }
 
         let func_call_span = cursor.span();
-        let to_iter = if let Some(fc_id) = self.flatten_func_call(cursor) {
-            let fc = self.instructions[fc_id].unwrap_func_call();
-
-            let (md, interface) = self.get_interface_reference(&fc.interface_reference);
-            let outputs = interface.func_call_outputs;
-            // Already extract some fields here, so we don't keep a ref to fc
-            let submodule_name_span = fc.interface_reference.name_span;
-            let submodule_decl = fc.interface_reference.submodule_decl;
-
-            let num_func_outputs = outputs.len();
+        let to_iter = if let Some(call) = self.flatten_func_call(cursor) {
+            let num_func_outputs = call.ports.len();
             let num_targets = to.len();
             if num_targets != num_func_outputs {
                 if num_targets > num_func_outputs {
                     let excess_results_span =
                         Span::new_overarching(to[num_func_outputs].1, to.last().unwrap().1);
-                    self.errors
-                        .error(excess_results_span, format!("Excess output targets. Function returns {num_func_outputs} results, but {num_targets} targets were given."))
-                        .info_obj(&(md, interface));
+                    let err = self.errors
+                        .error(excess_results_span, format!("Excess output targets. Function returns {num_func_outputs} results, but {num_targets} targets were given."));
+                    self.func_call_info(&err, &call);
                 } else {
-                    self.errors
-                        .error(func_call_span, format!("Too few output targets. Function returns {num_func_outputs} results, but {num_targets} targets were given."))
-                        .info_obj(&(md, interface));
+                    let err = self.errors
+                        .error(func_call_span, format!("Too few output targets. Function returns {num_func_outputs} results, but {num_targets} targets were given."));
+                    self.func_call_info(&err, &call);
                 }
             }
 
             let mut to_iter = to.into_iter();
-            for port in outputs {
+            for &port in &call.ports {
                 if let Some((Some((to, write_modifiers)), to_span)) = to_iter.next() {
                     let from = self.instructions.alloc(Instruction::Expression(Expression {
                         typ: self
@@ -1329,8 +1570,8 @@ impl FlatteningContext<'_, '_> {
                                 port,
                                 port_name_span: None,
                                 is_input: false,
-                                submodule_name_span,
-                                submodule_decl,
+                                submodule_name_span: call.submodule_name_span,
+                                submodule_decl: call.submodule_decl,
                             },
                         )),
                     }));
@@ -1500,7 +1741,7 @@ impl FlatteningContext<'_, '_> {
     /// See [Self::flatten_standalone_decls]
     /// Two cases:
     /// - Left side of assignment:
-    ///     No modules, Yes write modifiers, Only assignable expressions
+    ///   No modules, Yes write modifiers, Only assignable expressions
     fn flatten_assignment_left_side(
         &mut self,
         cursor: &mut Cursor,
@@ -1544,7 +1785,7 @@ impl FlatteningContext<'_, '_> {
 
     /// See [Self::flatten_assignment_left_side]
     /// - Standalone declarations:
-    ///     Yes modules, No write modifiers, Yes expressions (-> single expressions)
+    ///   Yes modules, No write modifiers, Yes expressions (-> single expressions)
     fn flatten_standalone_decls(&mut self, cursor: &mut Cursor) {
         let mut is_first_item = true;
         cursor.list(kind!("assign_left_side"), |cursor| {
@@ -1565,7 +1806,11 @@ impl FlatteningContext<'_, '_> {
                     let _ = self.flatten_declaration::<true>(self.default_declaration_context, false, true, cursor);
                 } else { // It's _expression
                     if kind == kind!("func_call") {
-                        self.flatten_assign_function_call(Vec::new(), cursor);
+                        if let Some(assertion_kind) = self.assertion_kind_of_call(cursor) {
+                            self.flatten_assertion(assertion_kind, cursor);
+                        } else {
+                            self.flatten_assign_function_call(Vec::new(), cursor);
+                        }
                     } else {
                         let _ = self.flatten_expr(cursor);
                     }
@@ -1574,6 +1819,66 @@ impl FlatteningContext<'_, '_> {
         })
     }
 
+    /// `assert(cond)` and `assume(cond)` statements are calls to a plain `assert` or `assume`, see [Assertion]
+    fn assertion_kind_of_call(&self, cursor: &Cursor) -> Option<AssertionKind> {
+        let mut cursor = cursor.clone();
+        cursor.go_down(kind!("func_call"), |cursor| {
+            cursor.field(field!("name"));
+            match &self.globals.file_data.file_text[cursor.span()] {
+                "assert" => Some(AssertionKind::Assert),
+                "assume" => Some(AssertionKind::Assume),
+                _ => None,
+            }
+        })
+    }
+
+    fn flatten_assertion(&mut self, kind: AssertionKind, cursor: &mut Cursor) {
+        let span = cursor.span();
+        self.require_feature(Feature::AssertStatements, span, kind.keyword());
+        cursor.go_down(kind!("func_call"), |cursor| {
+            cursor.field(field!("name"));
+            cursor.field(field!("arguments"));
+            let arguments_span = BracketSpan::from_outer(cursor.span());
+            let arguments = cursor.collect_list(kind!("parenthesis_expression_list"), |cursor| {
+                self.flatten_expr(cursor).0
+            });
+
+            if arguments.len() != 1 {
+                let keyword = kind.keyword();
+                self.errors.error(
+                    arguments_span.outer_span(),
+                    format!(
+                        "'{keyword}' takes exactly one condition, but {} arguments were given",
+                        arguments.len()
+                    ),
+                );
+            }
+            let condition = match arguments.first() {
+                Some(condition) => *condition,
+                None => self.alloc_error(arguments_span.close_bracket()),
+            };
+
+            self.instructions.alloc(Instruction::Assertion(Assertion {
+                kind,
+                condition,
+                span,
+            }));
+        })
+    }
+
+    /// Experimental constructs are an error in files that don't enable their [Feature]
+    fn require_feature(&self, feature: Feature, span: Span, construct: &str) {
+        if !self.globals.file_data.enabled_features.contains(&feature) {
+            let name = feature.name();
+            self.errors.error(
+                span,
+                format!(
+                    "'{construct}' is experimental, enable feature '{name}' with '#![feature({name})]' at the top of this file"
+                ),
+            );
+        }
+    }
+
     fn flatten_declaration_list(
         &mut self,
         declaration_context: DeclarationContext,
@@ -1604,6 +1909,29 @@ impl FlatteningContext<'_, '_> {
         })
     }
 
+    /// A submodule is either connected by port name or positionally. Mixing both obscures which ports are connected
+    fn report_mixed_port_connections(&self) {
+        for (submodule_decl, positional_span) in &self.positional_port_connections {
+            if let Some((_, named_span)) = self
+                .named_port_connections
+                .iter()
+                .find(|(decl, _)| decl == submodule_decl)
+            {
+                let sm = self.instructions[*submodule_decl].unwrap_submodule();
+                let md = &self.globals[sm.module_ref.id];
+                self.errors
+                    .error(
+                        *positional_span,
+                        format!(
+                            "Cannot mix named and positional port connections on submodule '{}'",
+                            sm.get_name(md)
+                        ),
+                    )
+                    .info_same_file(*named_span, "Connected by name here");
+            }
+        }
+    }
+
     fn flatten_global(&mut self, cursor: &mut Cursor) {
         // Skip because we covered it in initialization.
         let _ = cursor.optional_field(field!("extern_marker"));
@@ -1617,7 +1945,7 @@ impl FlatteningContext<'_, '_> {
         let name_span = cursor.field_span(field!("name"), kind!("identifier"));
         self.flatten_parameters(cursor);
         let module_name = &self.globals.file_data.file_text[name_span];
-        println!("TREE SITTER module! {module_name}");
+        log::info!("Flattening {module_name}");
 
         if let Some(mut const_type_cursor) = const_type_cursor {
             let decl_span = const_type_cursor.span();
@@ -1634,7 +1962,7 @@ impl FlatteningContext<'_, '_> {
                             decl_span,
                             name_span,
                             name: module_name.to_string(),
-                            declaration_runtime_depth: OnceCell::new(),
+                            declaration_runtime_depth: OnceLock::new(),
                             read_only: false,
                             declaration_itself_is_not_written_to: true,
                             decl_kind: DeclarationKind::NotPort,
@@ -1648,16 +1976,19 @@ impl FlatteningContext<'_, '_> {
         }
 
         cursor.field(field!("block"));
-        self.flatten_code(cursor);
+        // Same scope as the template parameters and ports, such that collisions with them are errors, not shadowing
+        self.flatten_code_keep_context(cursor);
     }
 }
 
 /// Flattens all globals in the project.
 ///
 /// Requires that first, all globals have been initialized.
+/// Globals are flattened in parallel, see [Linker::par_map]. They only read the headers of other globals, which were made in [crate::flattening::initialization]
 pub fn flatten_all_globals(linker: &mut Linker) {
     let linker_files: *const ArenaAllocator<FileData, FileUUIDMarker> = &linker.files;
     // SAFETY we won't be touching the files anywere. This is just to get the compiler to stop complaining about linker going into the closure.
+    let mut to_flatten = Vec::new();
     for (_file_id, file) in unsafe { &*linker_files } {
         let mut span_debugger = SpanDebugger::new("flatten_all_globals", file);
         let mut associated_value_iter = file.associated_values.iter();
@@ -1670,16 +2001,49 @@ pub fn flatten_all_globals(linker: &mut Linker) {
                     .next()
                     .expect("Iterator cannot be exhausted");
 
-                flatten_global(linker, global_obj, cursor);
+                // Kept from the previous compilation, see [Linker::find_reusable_globals]
+                if linker.get_link_info(global_obj).checkpoints.len() > AFTER_FLATTEN_CP {
+                    return;
+                }
+                let errors_globals = GlobalResolver::take_errors_globals(linker, global_obj);
+                // The cursor keeps the comments gathered so far, so a copy continues exactly where this one is
+                to_flatten.push((global_obj, errors_globals, cursor.clone()));
             });
         });
         span_debugger.defuse();
     }
+
+    let flattened = linker.par_map(
+        to_flatten,
+        |linker, (global_obj, errors_globals, mut cursor)| {
+            let module_timer = start_module_timer(&linker.options);
+            let flattened = flatten_global(linker, global_obj, errors_globals, &mut cursor);
+            (flattened, module_timer.map(|start| start.elapsed()))
+        },
+    );
+    for (flattened, duration) in flattened {
+        linker.add_module_time(flattened.global_obj, duration);
+        apply_flattened_global(linker, flattened);
+    }
+}
+
+/// The result of [flatten_global], which [apply_flattened_global] stores in the [Linker]
+struct FlattenedGlobal {
+    global_obj: GlobalUUID,
+    instructions: FlatAlloc<Instruction, FlatIDMarker>,
+    type_alloc: TypingAllocator,
+    errors_globals: (ErrorStore, ResolvedGlobals),
 }
 
-fn flatten_global(linker: &mut Linker, global_obj: GlobalUUID, cursor: &mut Cursor<'_>) {
-    let errors_globals = GlobalResolver::take_errors_globals(linker, global_obj);
+fn flatten_global(
+    linker: &Linker,
+    global_obj: GlobalUUID,
+    errors_globals: (ErrorStore, ResolvedGlobals),
+    cursor: &mut Cursor<'_>,
+) -> FlattenedGlobal {
     let obj_link_info = linker.get_link_info(global_obj);
+    let ctx_info_string = format!("Flattening {}", obj_link_info.name);
+    let mut span_debugger = SpanDebugger::new(&ctx_info_string, &linker.files[obj_link_info.file]);
     let globals = GlobalResolver::new(linker, obj_link_info, errors_globals);
 
     let mut local_variable_context = LocalVariableContext::new_initial();
@@ -1740,17 +2104,42 @@ fn flatten_global(linker: &mut Linker, global_obj: GlobalUUID, cursor: &mut Curs
         },
         named_domain_alloc: UUIDAllocator::new(),
         local_variable_context,
+        named_port_connections: Vec::new(),
+        positional_port_connections: Vec::new(),
     };
 
     context.flatten_global(cursor);
+    context.report_mixed_port_connections();
 
     // Make sure all ports have been visited
     assert!(context.ports_to_visit.is_empty());
 
-    let mut instructions = context.instructions;
+    let instructions = context.instructions;
     let type_alloc = context.type_alloc;
 
-    let errors_globals = globals.decommission(&linker.files);
+    let (errors, resolved_globals) = globals.decommission(&linker.files);
+    span_debugger.defuse();
+
+    FlattenedGlobal {
+        global_obj,
+        instructions,
+        type_alloc,
+        errors_globals: (errors.into_storage(), resolved_globals),
+    }
+}
+
+fn apply_flattened_global(linker: &mut Linker, flattened: FlattenedGlobal) {
+    let FlattenedGlobal {
+        global_obj,
+        mut instructions,
+        type_alloc,
+        errors_globals: (errors, resolved_globals),
+    } = flattened;
+    let file = linker.get_link_info(global_obj).file;
+    let errors_globals = (
+        ErrorCollector::from_storage(errors, file, &linker.files),
+        resolved_globals,
+    );
 
     let link_info: &mut LinkInfo = match global_obj {
         GlobalUUID::Module(module_uuid) => {
@@ -5,8 +5,9 @@ use crate::errors::ErrorStore;
 use crate::linker::{IsExtern, AFTER_INITIAL_PARSE_CP};
 use crate::prelude::*;
 
+use crate::instantiation::{ConstantValueCache, InstantiationCache};
 use crate::linker::{FileBuilder, LinkInfo, ResolvedGlobals};
-use crate::{file_position::FileText, flattening::Module, instantiation::InstantiationCache};
+use crate::{file_position::FileText, flattening::Module};
 
 use crate::typing::template::{
     GenerativeParameterKind, Parameter, ParameterKind, TVec, TypeParameterKind,
@@ -20,6 +21,7 @@ struct InitializationContext<'linker> {
 
     // module-only stuff
     ports: FlatAlloc<Port, PortIDMarker>,
+    positional_ports: Vec<PortID>,
     interfaces: FlatAlloc<Interface, InterfaceIDMarker>,
     domains: FlatAlloc<DomainInfo, DomainIDMarker>,
     /// This is initially true, but when the first `domain xyz` statement is encountered this is set to false.
@@ -77,10 +79,32 @@ impl InitializationContext<'_> {
 
         cursor.field(field!("block"));
         self.gather_all_ports_in_block(cursor);
+        self.report_duplicate_ports();
 
         (name_span, name)
     }
 
+    /// Ports make up the interface of a module, so their names must be unique, even across scopes.
+    ///
+    /// This is checked here, instead of in flattening, such that it's reported even when flattening fails.
+    fn report_duplicate_ports(&self) {
+        for (port_id, port) in &self.ports {
+            if let Some((_, earlier_port)) = self
+                .ports
+                .iter()
+                .take_while(|(id, _)| *id != port_id)
+                .find(|(_, p)| p.name == port.name)
+            {
+                self.errors
+                    .error(
+                        port.name_span,
+                        format!("Duplicate port name '{}'", port.name),
+                    )
+                    .info_same_file(earlier_port.name_span, "Conflicts with");
+            }
+        }
+    }
+
     fn gather_ports_in_if_stmt(&mut self, cursor: &mut Cursor) {
         cursor.go_down_no_check(|cursor| {
             cursor.field(field!("statement_type"));
@@ -117,7 +141,11 @@ impl InitializationContext<'_> {
                                 _ => cursor.could_not_match(),
                             },
                         );
+                        let port_id = self.ports.get_next_alloc_id();
                         self.finish_gather_decl(is_input, whole_decl_span, cursor);
+                        if self.ports.get_next_alloc_id() != port_id {
+                            self.positional_ports.push(port_id);
+                        }
                     });
                 }
             });
@@ -302,15 +330,16 @@ fn initialize_global_object(
     span: Span,
     cursor: &mut Cursor,
 ) {
-    let is_extern = match cursor
+    let marker = cursor
         .optional_field(field!("extern_marker"))
-        .then(|| cursor.kind())
-    {
-        None => IsExtern::Normal,
+        .then(|| cursor.kind());
+    let is_extern = match marker {
+        None | Some(kw!("override")) => IsExtern::Normal,
         Some(kw!("extern")) => IsExtern::Extern,
         Some(kw!("__builtin__")) => IsExtern::Builtin,
         Some(_) => cursor.could_not_match(),
     };
+    let is_override = marker == Some(kw!("override"));
 
     cursor.field(field!("object_type"));
     let global_obj_kind = match cursor.kind() {
@@ -322,6 +351,7 @@ fn initialize_global_object(
 
     let mut ctx = InitializationContext {
         ports: FlatAlloc::new(),
+        positional_ports: Vec::new(),
         interfaces: FlatAlloc::new(),
         domains: FlatAlloc::new(),
         implicit_clk_domain: true,
@@ -347,6 +377,7 @@ fn initialize_global_object(
         span,
         errors: ErrorStore::new(),
         is_extern,
+        is_override,
         resolved_globals: ResolvedGlobals::empty(),
         checkpoints: ArrayVec::new(),
     };
@@ -361,6 +392,7 @@ fn initialize_global_object(
             builder.add_module(Module {
                 link_info,
                 ports: ctx.ports,
+                positional_ports: ctx.positional_ports,
                 domains: ctx.domains,
                 implicit_clk_domain: ctx.implicit_clk_domain,
                 interfaces: ctx.interfaces,
@@ -377,6 +409,7 @@ fn initialize_global_object(
             builder.add_const(NamedConstant {
                 link_info,
                 output_decl: FlatID::PLACEHOLDER,
+                values: ConstantValueCache::default(),
             });
         }
     }
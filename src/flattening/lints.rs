@@ -1,12 +1,18 @@
+use std::collections::{hash_map::Entry, HashMap, VecDeque};
+
 use sus_proc_macro::get_builtin_const;
 
-use crate::linker::{IsExtern, LinkInfo, AFTER_LINTS_CP};
+use crate::codegen::keywords::is_system_verilog_keyword;
+use crate::linker::{GlobalUUID, IsExtern, LinkInfo, AFTER_LINTS_CP};
 use crate::prelude::*;
 use crate::typing::template::ParameterKind;
 
 use super::walk::for_each_generative_input_in_template_args;
 
-use super::{ExpressionSource, Instruction, Module, WireReferencePathElement, WireReferenceRoot};
+use super::{
+    ExpressionSource, IfStatement, Instruction, Module, WireReference, WireReferencePathElement,
+    WireReferenceRoot, Write, WriteModifiers, WrittenType,
+};
 
 pub fn perform_lints(linker: &mut Linker) {
     for (_, md) in &mut linker.modules {
@@ -18,14 +24,139 @@ pub fn perform_lints(linker: &mut Linker) {
         let resolved_globals = md.link_info.resolved_globals.take();
         find_unused_variables(md, &errors);
         extern_objects_may_not_have_type_template_args(&md.link_info, &errors);
+        check_multiple_drivers(&md.link_info, &errors);
+        warn_system_verilog_keyword_names(md, &errors);
         md.link_info
             .reabsorb_errors_globals((errors, resolved_globals), AFTER_LINTS_CP);
     }
+
+    let cycle_errors: Vec<Option<ConstantCycleError>> = (linker.constants.iter())
+        .map(|(cst_id, _)| {
+            let cycle = find_constant_cycle(linker, cst_id)?;
+            Some(ConstantCycleError::new(linker, &cycle))
+        })
+        .collect();
+    for ((_, cst), cycle_error) in linker.constants.iter_mut().zip(cycle_errors) {
+        let errors = ErrorCollector::from_storage(
+            cst.link_info.errors.take(),
+            cst.link_info.file,
+            &linker.files,
+        );
+        let resolved_globals = cst.link_info.resolved_globals.take();
+        if let Some(cycle_error) = cycle_error {
+            cycle_error.report(&cst.link_info, &errors);
+        }
+        cst.link_info
+            .reabsorb_errors_globals((errors, resolved_globals), AFTER_LINTS_CP);
+    }
 }
 
 /*
     ==== Additional Errors ====
 */
+/// The constants without template parameters that `cst_id` refers to
+fn untemplated_constant_refs(linker: &Linker, cst_id: ConstantUUID) -> Vec<ConstantUUID> {
+    let mut refs = Vec::new();
+    for global in linker.constants[cst_id]
+        .link_info
+        .resolved_globals
+        .referenced_globals()
+    {
+        if let GlobalUUID::Constant(id) = global {
+            if linker.constants[*id]
+                .link_info
+                .template_parameters
+                .is_empty()
+                && !refs.contains(id)
+            {
+                refs.push(*id);
+            }
+        }
+    }
+    refs
+}
+
+/// Constants without template parameters always evaluate the same way, so if they refer to each other in a circle, none of them can ever be evaluated.
+///
+/// Returns the shortest such circle from `cst_id` back to itself, without repeating `cst_id` at the end.
+/// Constants with template parameters may refer to themselves with other arguments, like `FACT #(N: N - 1)`, so their cycles are only found when they're evaluated
+fn find_constant_cycle(linker: &Linker, cst_id: ConstantUUID) -> Option<Vec<ConstantUUID>> {
+    if !linker.constants[cst_id]
+        .link_info
+        .template_parameters
+        .is_empty()
+    {
+        return None;
+    }
+    let mut came_from: HashMap<ConstantUUID, ConstantUUID> = HashMap::new();
+    let mut to_visit = VecDeque::from([cst_id]);
+    while let Some(cur) = to_visit.pop_front() {
+        for next in untemplated_constant_refs(linker, cur) {
+            if next == cst_id {
+                let mut cycle = vec![cur];
+                while let Some(prev) = came_from.get(cycle.last().unwrap()) {
+                    cycle.push(*prev);
+                }
+                cycle.reverse();
+                return Some(cycle);
+            }
+            if let Entry::Vacant(entry) = came_from.entry(next) {
+                entry.insert(cur);
+                to_visit.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// A cycle found by [find_constant_cycle]. Reported at every constant of the cycle, even when none of them are used
+struct ConstantCycleError {
+    path: String,
+    /// Where the constant refers to the next one of the cycle
+    next_ref: Option<(Span, String)>,
+}
+
+impl ConstantCycleError {
+    fn new(linker: &Linker, cycle: &[ConstantUUID]) -> Self {
+        let name = |id: ConstantUUID| &linker.constants[id].link_info.name;
+        let mut path = String::new();
+        for id in cycle {
+            path.push_str(name(*id));
+            path.push_str(" -> ");
+        }
+        path.push_str(name(cycle[0]));
+
+        let next = cycle.get(1).copied().unwrap_or(cycle[0]);
+        let link_info = &linker.constants[cycle[0]].link_info;
+        let next_ref = link_info
+            .instructions
+            .iter()
+            .find_map(|(_, instr)| match instr {
+                Instruction::Expression(expr) => match &expr.source {
+                    ExpressionSource::WireRef(WireReference {
+                        root: WireReferenceRoot::NamedConstant(cst),
+                        ..
+                    }) if cst.id == next => Some((
+                        cst.get_total_span(),
+                        format!("Refers to '{}' here", name(next)),
+                    )),
+                    _ => None,
+                },
+                _ => None,
+            });
+        ConstantCycleError { path, next_ref }
+    }
+
+    fn report(self, link_info: &LinkInfo, errors: &ErrorCollector) {
+        let err_ref = errors.error(
+            link_info.name_span,
+            format!("Circular constant reference: {}", self.path),
+        );
+        if let Some((span, info)) = self.next_ref {
+            err_ref.info_same_file(span, info);
+        }
+    }
+}
 fn extern_objects_may_not_have_type_template_args(link_info: &LinkInfo, errors: &ErrorCollector) {
     if link_info.is_extern == IsExtern::Extern {
         for (_id, arg) in &link_info.template_parameters {
@@ -39,6 +170,124 @@ fn extern_objects_may_not_have_type_template_args(link_info: &LinkInfo, errors:
     }
 }
 
+/// Wires and submodules end up in the generated SystemVerilog under their source name, which must not be a keyword there.
+///
+/// These still compile, because they get a suffix in the generated code. For ports this changes the interface other tools see, so it's worth a warning.
+fn warn_system_verilog_keyword_names(md: &Module, errors: &ErrorCollector) {
+    if md.link_info.is_extern != IsExtern::Normal {
+        return;
+    }
+    for (_, instr) in &md.link_info.instructions {
+        let (name, span) = match instr {
+            Instruction::Declaration(decl) if !decl.identifier_type.is_generative() => {
+                (&decl.name, decl.name_span)
+            }
+            Instruction::SubModule(sm) => match &sm.name {
+                Some((name, span)) => (name, *span),
+                None => continue,
+            },
+            _ => continue,
+        };
+        if is_system_verilog_keyword(name) {
+            errors.warn(
+                span,
+                format!("'{name}' is a SystemVerilog keyword, so it gets a suffix in the generated code"),
+            );
+        }
+    }
+}
+
+/// A non-generative, non-array wire may only be driven once.
+///
+/// Later writes take priority over earlier ones, so a later write in a `when` block is a conditional override, not a second driver.
+/// Writes in opposite branches of the same `if` are mutually exclusive. Only when a later write fully overrides an earlier one is it reported.
+///
+/// `initial` writes and writes to part of a wire (`x[3] = ...`) are not considered.
+fn check_multiple_drivers(link_info: &LinkInfo, errors: &ErrorCollector) {
+    /// The if branches a write is in, as (if_statement, is_in_then_branch)
+    type Branches = Vec<(FlatID, bool)>;
+    let mut writes_per_decl: FlatAlloc<Vec<(FlatID, Branches)>, FlatIDMarker> =
+        link_info.instructions.map(|_| Vec::new());
+    let mut if_stack: Vec<(FlatID, &IfStatement)> = Vec::new();
+    for (id, instr) in &link_info.instructions {
+        while if_stack
+            .last()
+            .is_some_and(|(if_id, stm)| !FlatIDRange::new(*if_id, stm.else_end).contains(id))
+        {
+            if_stack.pop();
+        }
+        match instr {
+            Instruction::IfStatement(stm) => if_stack.push((id, stm)),
+            Instruction::Write(Write {
+                to:
+                    WireReference {
+                        root: WireReferenceRoot::LocalDecl(decl_id, _),
+                        path,
+                        is_generative: false,
+                    },
+                write_modifiers: WriteModifiers::Connection { .. },
+                ..
+            }) if path.is_empty() => {
+                let branches = if_stack
+                    .iter()
+                    .map(|(if_id, stm)| {
+                        (
+                            *if_id,
+                            FlatIDRange::new(stm.then_start, stm.then_end_else_start).contains(id),
+                        )
+                    })
+                    .collect();
+                writes_per_decl[*decl_id].push((id, branches));
+            }
+            _ => {}
+        }
+    }
+
+    // `later` overrides `earlier` whenever `earlier` applies, if it is in no branch that `earlier` isn't also in
+    let overrides = |later: &[(FlatID, bool)], earlier: &[(FlatID, bool)]| {
+        later.iter().all(|branch| earlier.contains(branch))
+    };
+
+    for (decl_id, writes) in &writes_per_decl {
+        if writes.len() < 2 {
+            continue;
+        }
+        let decl = link_info.instructions[decl_id].unwrap_declaration();
+        if decl.identifier_type.is_generative() || matches!(decl.typ_expr, WrittenType::Array(..)) {
+            continue;
+        }
+        let mut conflicting: Vec<FlatID> = Vec::new();
+        for (idx, (earlier_id, earlier)) in writes.iter().enumerate() {
+            for (later_id, later) in &writes[idx + 1..] {
+                if overrides(later, earlier) {
+                    for id in [*earlier_id, *later_id] {
+                        if !conflicting.contains(&id) {
+                            conflicting.push(id);
+                        }
+                    }
+                }
+            }
+        }
+        conflicting.sort_unstable_by_key(|id| id.get_hidden_value());
+        if conflicting.is_empty() {
+            continue;
+        }
+        let err_ref = errors.error(
+            decl.name_span,
+            format!(
+                "'{}' has multiple drivers. The later write fully overrides the earlier one",
+                decl.name
+            ),
+        );
+        for write_id in conflicting {
+            let Instruction::Write(wr) = &link_info.instructions[write_id] else {
+                unreachable!()
+            };
+            err_ref.info_same_file(wr.to_span, "Driven here");
+        }
+    }
+}
+
 /*
     ==== Additional Warnings ====
 */
@@ -65,6 +314,10 @@ fn find_unused_variables(md: &Module, errors: &ErrorCollector) {
 
     // All asserts
     for (assert_instr_id, instr) in &md.link_info.instructions {
+        if let Instruction::Assertion(_) = instr {
+            is_instance_used_map[assert_instr_id] = true;
+            wire_to_explore_queue.push(assert_instr_id);
+        }
         if let Instruction::Expression(expr) = instr {
             if let ExpressionSource::WireRef(wr) = &expr.source {
                 if let WireReferenceRoot::NamedConstant(cst) = &wr.root {
@@ -90,6 +343,10 @@ fn find_unused_variables(md: &Module, errors: &ErrorCollector) {
     for (id, inst) in md.link_info.instructions.iter() {
         if !is_instance_used_map[id] {
             if let Instruction::Declaration(decl) = inst {
+                // Like in Rust, names starting with '_' are intentionally unused
+                if decl.name.starts_with('_') {
+                    continue;
+                }
                 errors.warn(decl.name_span, "Unused Variable: This variable does not affect the output ports of this module");
             }
         }
@@ -138,10 +395,14 @@ fn make_fanins(
             }
             Instruction::IfStatement(stm) => {
                 for id in FlatIDRange::new(stm.then_start, stm.else_end) {
-                    if let Instruction::Write(conn) = &instructions[id] {
-                        if let Some(flat_root) = conn.to.root.get_root_flat() {
-                            instruction_fanins[flat_root].push(stm.condition);
+                    match &instructions[id] {
+                        Instruction::Write(conn) => {
+                            if let Some(flat_root) = conn.to.root.get_root_flat() {
+                                instruction_fanins[flat_root].push(stm.condition);
+                            }
                         }
+                        Instruction::Assertion(_) => instruction_fanins[id].push(stm.condition),
+                        _ => {}
                     }
                 }
             }
@@ -149,6 +410,7 @@ fn make_fanins(
                 instruction_fanins[stm.loop_var_decl].push(stm.start);
                 instruction_fanins[stm.loop_var_decl].push(stm.end);
             }
+            Instruction::Assertion(assertion) => collector_func(assertion.condition),
         }
     }
     instruction_fanins
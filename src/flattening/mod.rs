@@ -11,16 +11,18 @@ use crate::prelude::*;
 use crate::typing::abstract_type::DomainType;
 use crate::typing::type_inference::{DomainVariableIDMarker, TypeVariableIDMarker};
 
-use std::cell::OnceCell;
 use std::ops::Deref;
+use std::sync::OnceLock;
 
 pub use flatten::flatten_all_globals;
 pub use initialization::gather_initial_file_data;
 pub use lints::perform_lints;
 pub use typechecking::typecheck_all_modules;
 
+use crate::file_position::FileText;
+use crate::instantiation::{ConstantValueCache, InstantiationCache};
 use crate::linker::{Documentation, LinkInfo};
-use crate::{file_position::FileText, instantiation::InstantiationCache, value::Value};
+use crate::value::{IntegerLiteral, Value};
 
 use crate::typing::{abstract_type::FullType, template::GlobalReference};
 
@@ -48,9 +50,17 @@ pub struct Module {
 
     /// Created in Stage 1: Initialization
     ///
+    /// In declaration order. Positional connections through an interface call like `sm.itf(a, b)` rely on this.
+    ///
     /// [Port::declaration_instruction] are set in Stage 2: Flattening
     pub ports: FlatAlloc<Port, PortIDMarker>,
 
+    /// Created in Stage 1: Initialization
+    ///
+    /// The ports declared outside of any interface, in declaration order.
+    /// A module without a main interface is connected positionally through these: `adder(x, y)` writes the inputs in order, and returns the outputs in order.
+    pub positional_ports: Vec<PortID>,
+
     /// Created in Stage 1: Initialization
     pub domains: FlatAlloc<DomainInfo, DomainIDMarker>,
     pub implicit_clk_domain: bool,
@@ -108,24 +118,15 @@ impl Module {
     }
 
     pub fn get_instruction_span(&self, instr_id: FlatID) -> Span {
-        match &self.link_info.instructions[instr_id] {
-            Instruction::SubModule(sm) => sm.module_ref.get_total_span(),
-            Instruction::FuncCall(fc) => fc.whole_func_span,
-            Instruction::Declaration(decl) => decl.decl_span,
-            Instruction::Expression(w) => w.span,
-            Instruction::Write(conn) => conn.to_span,
-            Instruction::IfStatement(if_stmt) => self.get_instruction_span(if_stmt.condition),
-            Instruction::ForStatement(for_stmt) => {
-                self.get_instruction_span(for_stmt.loop_var_decl)
-            }
-        }
+        self.link_info.get_instruction_span(instr_id)
     }
 
     /// Temporary upgrade such that we can name the singular clock of the module, such that weirdly-named external module clocks can be used
     ///
     /// See #7
-    pub fn get_clock_name(&self) -> &str {
-        &self.domains.iter().next().unwrap().1.name
+    /// Each domain is driven by its own clock, which has the name of the domain
+    pub fn get_clock_name(&self, domain: DomainID) -> &str {
+        &self.domains[domain].name
     }
 }
 
@@ -145,13 +146,17 @@ pub struct StructType {
     fields: FlatAlloc<StructField, FieldIDMarker>,
 }
 
-/// Global constant, like `true`, `false`, or user-defined constants (TODO #19)
+/// Global constant, like `true`, `false`, or user-defined constants like `const int WIDTH {WIDTH = 8}`
+///
+/// User-defined constants are evaluated wherever they are used, with the template arguments given there.
+/// Each set of template arguments is only evaluated once, see [ConstantValueCache].
 ///
 /// All Constants are stored in [Linker::constants] and indexed by [ConstantUUID]
 #[derive(Debug)]
 pub struct NamedConstant {
     pub link_info: LinkInfo,
     pub output_decl: FlatID,
+    pub values: ConstantValueCache,
 }
 
 /// Represents a field in a struct
@@ -181,8 +186,7 @@ pub enum PortOrInterface {
 
 /// Information about a (clock) domain.
 ///
-/// Right now this only contains the domain name, but when actual clock domains are implemented (#7),
-/// this will contain information about the Clock.
+/// Every domain is driven by its own clock. In the generated code this clock is an input port with the name of the domain.
 #[derive(Debug, Clone)]
 pub struct DomainInfo {
     pub name: String,
@@ -311,6 +315,14 @@ pub enum WireReferencePathElement {
         idx: FlatID,
         bracket_span: BracketSpan,
     },
+    /// `arr[high:low]`, selects elements `low` up to and including `high`.
+    ///
+    /// Both bounds must be generative, such that the size of the result is known at instantiation time.
+    ArraySlice {
+        high: FlatID,
+        low: FlatID,
+        bracket_span: BracketSpan,
+    },
 }
 
 /// The root of a [WireReference]. Basically where the wire reference starts.
@@ -516,6 +528,20 @@ pub enum ExpressionSource {
         left: FlatID,
         right: FlatID,
     },
+    /// `{a, b, c}`. All elements are arrays of the same content type, the result is an array of the summed size.
+    ///
+    /// As in SystemVerilog, the last element ends up at the lowest indices
+    Concatenation {
+        elements: Vec<FlatID>,
+    },
+    /// `condition ? if_true : if_false`. The expression form of [Instruction::IfStatement], for combinational multiplexers
+    Conditional {
+        condition: FlatID,
+        if_true: FlatID,
+        if_false: FlatID,
+    },
+    /// An integer literal like `1_000` or `8'hFF`. Sized literals keep their width, so it can be emitted in the generated code
+    Literal(IntegerLiteral),
     Constant(Value),
 }
 
@@ -596,7 +622,7 @@ pub struct Declaration {
     pub decl_span: Span,
     pub name_span: Span,
     pub name: String,
-    pub declaration_runtime_depth: OnceCell<usize>,
+    pub declaration_runtime_depth: OnceLock<usize>,
     /// Variables are read_only when they may not be controlled by the current block of code.
     /// This is for example, the inputs of the current module, or the outputs of nested modules.
     /// But could also be the iterator of a for loop.
@@ -668,7 +694,7 @@ pub struct ModuleInterfaceReference {
 /// That is because an Expression can only represent one output. Workarounds like putting multiple outputs
 /// together in a tuple would not work, because:
 /// - The function call syntax is just a convenient syntax sugar for connecting multiple inputs and outputs simultaneously.
-///     We want to conceptually keep the signals separate. Both input and output signals, while keeping the function call syntax that programmers are used to.
+///   We want to conceptually keep the signals separate. Both input and output signals, while keeping the function call syntax that programmers are used to.
 /// - Forcing all outputs together into one type would bind them together for latency counting, which we don't want
 /// - We don't have tuple types
 ///
@@ -725,7 +751,10 @@ impl FuncCallInstruction {
 #[derive(Debug)]
 pub struct IfStatement {
     pub condition: FlatID,
+    /// `if` rather than `when`. Whether the condition actually is generative is only known from its domain during typechecking
     pub is_generative: bool,
+    /// The span of the `if` or `when` keyword
+    pub keyword_span: Span,
     pub then_start: FlatID,
     pub then_end_else_start: FlatID,
     pub else_end: FlatID,
@@ -740,6 +769,37 @@ pub struct ForStatement {
     pub loop_body: FlatIDRange,
 }
 
+/// Whether an [Assertion] is checked, or may be taken for granted by formal tools
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssertionKind {
+    /// `assert(cond)`
+    Assert,
+    /// `assume(cond)`
+    Assume,
+}
+
+impl AssertionKind {
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            AssertionKind::Assert => "assert",
+            AssertionKind::Assume => "assume",
+        }
+    }
+}
+
+/// An `assert(cond)` or `assume(cond)` statement.
+///
+/// A generative condition is checked during instantiation. A runtime condition becomes a SystemVerilog `assert property` or `assume property` with `--emit-assertions`, and is dropped otherwise.
+///
+/// Not to be confused with the generative `assert #(C: ...)` constant
+#[derive(Debug)]
+pub struct Assertion {
+    pub kind: AssertionKind,
+    pub condition: FlatID,
+    /// The whole `assert(cond)` statement
+    pub span: Span,
+}
+
 /// When a module has been parsed and flattened, it is turned into a large list of instructions,
 /// These are stored in [LinkInfo::instructions]`: FlatAlloc<Instruction, FlatIDMarker>`
 ///
@@ -759,6 +819,7 @@ pub enum Instruction {
     Write(Write),
     IfStatement(IfStatement),
     ForStatement(ForStatement),
+    Assertion(Assertion),
 }
 
 impl Instruction {
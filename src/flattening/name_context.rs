@@ -19,13 +19,17 @@ impl<'file, Obj: Copy> LocalVariableContext<'file, Obj> {
         }
         None
     }
+    /// Returns the conflicting declaration if one with the same name already exists in the current scope.
+    ///
+    /// Declarations in outer scopes may be shadowed, see [Self::check_shadowing]
     pub fn add_declaration(
         &mut self,
         new_local_name: &'file str,
         new_local_unique_id: Obj,
     ) -> Result<(), Obj> {
-        // Returns conflicting signal declaration
-        for (existing_local_name, existing_local_id) in &self.local_stack {
+        for (existing_local_name, existing_local_id) in
+            &self.local_stack[self.current_frame_starts_at..]
+        {
             if new_local_name == *existing_local_name {
                 return Err(*existing_local_id);
             }
@@ -33,19 +37,31 @@ impl<'file, Obj: Copy> LocalVariableContext<'file, Obj> {
         self.local_stack.push((new_local_name, new_local_unique_id));
         Ok(())
     }
+    /// Returns the declaration in an outer scope that a new declaration of this name would shadow
+    pub fn check_shadowing(&self, name: &'file str) -> Option<Obj> {
+        for (decl_name, unique_id) in self.local_stack[..self.current_frame_starts_at]
+            .iter()
+            .rev()
+        {
+            if *decl_name == name {
+                return Some(*unique_id);
+            }
+        }
+        None
+    }
     pub fn new_initial() -> Self {
         Self {
             local_stack: Vec::new(),
             current_frame_starts_at: 0,
         }
     }
+    /// Returns the start of the enclosing frame, to be passed to [Self::pop_frame]
     pub fn new_frame(&mut self) -> usize {
-        self.current_frame_starts_at = self.local_stack.len();
-        self.current_frame_starts_at
+        std::mem::replace(&mut self.current_frame_starts_at, self.local_stack.len())
     }
     pub fn pop_frame(&mut self, prev_save: usize) {
         assert!(self.current_frame_starts_at >= prev_save);
+        self.local_stack.truncate(self.current_frame_starts_at);
         self.current_frame_starts_at = prev_save;
-        self.local_stack.truncate(prev_save);
     }
 }
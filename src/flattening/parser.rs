@@ -218,11 +218,17 @@ impl<'t> Cursor<'t> {
     /// Goes down the current node, checks it's kind, and then iterates through 'item' fields.
     #[track_caller]
     pub fn list(&mut self, parent_kind: u16, mut func: impl FnMut(&mut Self)) {
-        self.assert_is_kind(parent_kind);
+        let is_unrecovered_root = self.check_list_kind(parent_kind);
 
         if self.cursor.goto_first_child() {
             loop {
-                if let Some(found) = self.cursor.field_id() {
+                if is_unrecovered_root {
+                    if self.kind() == kind!("global_object") {
+                        func(self);
+                    } else {
+                        self.maybe_add_comment();
+                    }
+                } else if let Some(found) = self.cursor.field_id() {
                     if found == field!("item") {
                         func(self);
                     } else {
@@ -308,26 +314,26 @@ impl<'t> Cursor<'t> {
         let node = self.cursor.node();
         let is_error = node.is_error() || node.is_missing();
         if is_error {
-            let node_name = node.kind();
             let span = Span::from(node.byte_range());
-            let of_name = if let Some(field) = self.cursor.field_name() {
-                format!("in the field '{field}' of type '{node_name}'")
-            } else {
-                format!("in a node of type '{node_name}'")
-            };
-            let (error_type, parent_node) = if node.is_missing() {
-                ("missing field", node.parent().unwrap().parent().unwrap()) // Weird workaround because MISSING nodes can't properly parent?
+            let (reason, parent_node) = if node.is_missing() {
+                let expected = node.kind();
+                let reason = if let Some(field) = self.cursor.field_name() {
+                    format!("Expected '{expected}' for the field '{field}'")
+                } else {
+                    format!("Expected '{expected}'")
+                };
+                (reason, node.parent().unwrap().parent().unwrap()) // Weird workaround because MISSING nodes can't properly parent?
             } else {
-                ("syntax error", node.parent().unwrap())
+                let mut first_token = node;
+                while let Some(child) = first_token.child(0) {
+                    first_token = child;
+                }
+                let token_text = &self.file_text[Span::from(first_token.byte_range())];
+                (format!("Unexpected '{token_text}'"), node.parent().unwrap())
             };
             let parent_node_name = parent_node.kind();
             errors
-                .error(
-                    span,
-                    format!(
-                        "While parsing '{parent_node_name}', parser found a {error_type} {of_name}"
-                    ),
-                )
+                .error(span, format!("{reason} while parsing '{parent_node_name}'"))
                 .info_same_file(
                     Span::from(parent_node.byte_range()),
                     format!("Parent node '{parent_node_name}'"),
@@ -363,11 +369,30 @@ impl<'t> Cursor<'t> {
         errors: &ErrorCollector,
         mut func: impl FnMut(&mut Self),
     ) {
-        self.assert_is_kind(parent_kind);
+        let is_unrecovered_root = self.check_list_kind(parent_kind);
+        let mut unexpected_run: Option<Span> = None;
         if self.cursor.goto_first_child() {
             loop {
-                self.push_potential_node_error(errors);
-                if let Some(found) = self.cursor.field_id() {
+                let is_error = self.push_potential_node_error(errors);
+                if is_unrecovered_root {
+                    let kind = self.kind();
+                    if kind == kind!("global_object") {
+                        self.report_unexpected_run(&mut unexpected_run, errors);
+                        func(self);
+                    } else if is_error {
+                        self.report_unexpected_run(&mut unexpected_run, errors);
+                    } else if kind == kind!("single_line_comment")
+                        || kind == kind!("multi_line_comment")
+                    {
+                        self.maybe_add_comment();
+                    } else if kind != kw!("\n") {
+                        let span = self.span();
+                        unexpected_run = Some(match unexpected_run {
+                            Some(run) => Span::new_overarching(run, span),
+                            None => span,
+                        });
+                    }
+                } else if let Some(found) = self.cursor.field_id() {
                     if found == field!("item") {
                         func(self);
                     } else {
@@ -386,7 +411,31 @@ impl<'t> Cursor<'t> {
                     break;
                 }
             }
+            self.report_unexpected_run(&mut unexpected_run, errors);
             assert!(self.cursor.goto_parent());
         }
     }
+
+    /// Checks the kind of a list node. Returns true if this is the root of a file that tree-sitter couldn't recover into a `source_file`.
+    ///
+    /// Such a root is an ERROR node. The global objects it could parse are its children, but they've lost their 'item' field
+    #[track_caller]
+    fn check_list_kind(&mut self, parent_kind: u16) -> bool {
+        let node = self.cursor.node();
+        if parent_kind == kind!("source_file") && node.is_error() && node.parent().is_none() {
+            return true;
+        }
+        self.assert_is_kind(parent_kind);
+        false
+    }
+
+    /// The leftover pieces between the global objects of an unrecovered root aren't ERROR nodes themselves, so they're reported here
+    fn report_unexpected_run(&self, unexpected_run: &mut Option<Span>, errors: &ErrorCollector) {
+        if let Some(span) = unexpected_run.take() {
+            errors.error(
+                span,
+                "Could not parse this code, it is not part of any global object",
+            );
+        }
+    }
 }
@@ -1,12 +1,15 @@
 use crate::alloc::ArenaAllocator;
-use crate::errors::{ErrorInfo, ErrorInfoObject, FileKnowingErrorInfoObject};
+use crate::errors::{
+    ErrorCollector, ErrorInfo, ErrorInfoObject, ErrorStore, FileKnowingErrorInfoObject,
+};
 use crate::prelude::*;
 use crate::typing::abstract_type::AbstractType;
 use crate::typing::template::ParameterKind;
 use crate::typing::type_inference::{FailedUnification, HindleyMilner};
 
 use crate::debug::SpanDebugger;
-use crate::linker::{GlobalResolver, GlobalUUID, AFTER_TYPECHECK_CP};
+use crate::dev_aid::timings::start_module_timer;
+use crate::linker::{GlobalResolver, GlobalUUID, ResolvedGlobals, AFTER_TYPECHECK_CP};
 
 use crate::typing::{
     abstract_type::{DomainType, TypeUnifier, BOOL_TYPE, INT_TYPE},
@@ -15,50 +18,90 @@ use crate::typing::{
 
 use super::*;
 
+/// Modules and constants are typechecked in parallel, see [Linker::par_map]. Typechecking only uses the written types of the ports and parameters of other globals, so no global depends on the result of another
 pub fn typecheck_all_modules(linker: &mut Linker) {
-    let module_uuids: Vec<ModuleUUID> = linker.modules.iter().map(|(id, _md)| id).collect();
-    for module_uuid in module_uuids {
-        let global_id = GlobalUUID::Module(module_uuid);
-        let errs_globals = GlobalResolver::take_errors_globals(linker, global_id);
-
-        let working_on: &Module = &linker.modules[module_uuid];
-        let globals = GlobalResolver::new(linker, &working_on.link_info, errs_globals);
-
-        let ctx_info_string = format!("Typechecking {}", &working_on.link_info.name);
-        println!("{ctx_info_string}");
-        let mut span_debugger =
-            SpanDebugger::new(&ctx_info_string, &linker.files[working_on.link_info.file]);
+    // Globals that were kept from the previous compilation are already typechecked, see [Linker::find_reusable_globals]
+    let global_uuids: Vec<GlobalUUID> = (linker.modules.iter())
+        .map(|(id, _md)| GlobalUUID::Module(id))
+        .chain(
+            linker
+                .constants
+                .iter()
+                .map(|(id, _cst)| GlobalUUID::Constant(id)),
+        )
+        .filter(|global| linker.get_link_info(*global).checkpoints.len() <= AFTER_TYPECHECK_CP)
+        .collect();
+    let to_typecheck: Vec<(GlobalUUID, (ErrorStore, ResolvedGlobals))> = global_uuids
+        .into_iter()
+        .map(|global| {
+            let errs_globals = GlobalResolver::take_errors_globals(linker, global);
+            (global, errs_globals)
+        })
+        .collect();
+
+    let typechecked = linker.par_map(to_typecheck, |linker, (global, errs_globals)| {
+        let module_timer = start_module_timer(&linker.options);
+        let working_on: &LinkInfo = linker.get_link_info(global);
+        let globals = GlobalResolver::new(linker, working_on, errs_globals);
+
+        let ctx_info_string = format!("Typechecking {}", &working_on.name);
+        log::info!("{ctx_info_string}");
+        let mut span_debugger = SpanDebugger::new(&ctx_info_string, &linker.files[working_on.file]);
 
         let mut context = TypeCheckingContext {
             globals: &globals,
             errors: &globals.errors,
             type_checker: TypeUnifier::new(
-                &working_on.link_info.template_parameters,
-                working_on.link_info.type_variable_alloc.clone(),
+                &working_on.template_parameters,
+                working_on.type_variable_alloc.clone(),
             ),
             runtime_condition_stack: Vec::new(),
-            working_on: &working_on.link_info,
+            working_on,
         };
 
         context.typecheck();
 
         let type_checker = context.type_checker;
-        let errs_and_globals = globals.decommission(&linker.files);
+        let (errors, resolved_globals) = globals.decommission(&linker.files);
+
+        span_debugger.defuse();
+        (
+            global,
+            type_checker,
+            errors.into_storage(),
+            resolved_globals,
+            module_timer.map(|start| start.elapsed()),
+        )
+    });
 
-        // Grab another mutable copy of md so it doesn't force a borrow conflict
-        let working_on_mut = &mut linker.modules[module_uuid];
+    // Applied in order, so the diagnostics don't depend on which global finished first
+    for (global, type_checker, errors, resolved_globals, duration) in typechecked {
+        linker.add_module_time(global, duration);
+        // Constants are purely generative, so they don't have domains
+        let mut no_domains = FlatAlloc::new();
+        let (link_info, domains) = match global {
+            GlobalUUID::Module(md_id) => {
+                let md = &mut linker.modules[md_id];
+                (&mut md.link_info, &mut md.domains)
+            }
+            GlobalUUID::Constant(cst_id) => {
+                (&mut linker.constants[cst_id].link_info, &mut no_domains)
+            }
+            GlobalUUID::Type(_) => unreachable!("Types aren't typechecked"),
+        };
+        let errs_and_globals = (
+            ErrorCollector::from_storage(errors, link_info.file, &linker.files),
+            resolved_globals,
+        );
         apply_types(
             type_checker,
-            working_on_mut,
+            link_info,
+            domains,
             &errs_and_globals.0,
             &linker.types,
         );
 
-        working_on_mut
-            .link_info
-            .reabsorb_errors_globals(errs_and_globals, AFTER_TYPECHECK_CP);
-
-        span_debugger.defuse();
+        link_info.reabsorb_errors_globals(errs_and_globals, AFTER_TYPECHECK_CP);
     }
 }
 
@@ -135,13 +178,13 @@ impl TypeCheckingContext<'_, '_> {
     ///
     /// But the domains behave differently.
     /// - Reading:
-    ///     The domains combine to form the lowest common denominator.
-    ///     If all are generative this becomes generative
-    ///     At least one non-generative domain makes the whole thing non-generative
-    ///     It should be supplied with a generative output_typ domain when generative, and an unknown domain variable otherwise
+    ///   The domains combine to form the lowest common denominator.
+    ///   If all are generative this becomes generative
+    ///   At least one non-generative domain makes the whole thing non-generative
+    ///   It should be supplied with a generative output_typ domain when generative, and an unknown domain variable otherwise
     /// - Writing:
-    ///     The output_typ domain should be generative when wire_ref.root is generative, or a generative value is required such as with "initial"
-    ///     When wire_ref.root is not generative, it should be an unknown domain variable
+    ///   The output_typ domain should be generative when wire_ref.root is generative, or a generative value is required such as with "initial"
+    ///   When wire_ref.root is not generative, it should be an unknown domain variable
     fn typecheck_wire_reference(
         &self,
         wire_ref: &WireReference,
@@ -184,8 +227,8 @@ impl TypeCheckingContext<'_, '_> {
 
         let mut current_type_in_progress = root_type.typ;
         for p in &wire_ref.path {
-            match p {
-                &WireReferencePathElement::ArrayAccess { idx, bracket_span } => {
+            match *p {
+                WireReferencePathElement::ArrayAccess { idx, bracket_span } => {
                     let idx_expr = self.working_on.instructions[idx].unwrap_expression();
 
                     let new_resulting_variable =
@@ -207,6 +250,29 @@ impl TypeCheckingContext<'_, '_> {
                     );
                     current_type_in_progress = new_resulting_variable;
                 }
+                WireReferencePathElement::ArraySlice {
+                    high,
+                    low,
+                    bracket_span,
+                } => {
+                    for bound in [high, low] {
+                        let bound_expr = self.working_on.instructions[bound].unwrap_expression();
+                        self.type_checker.type_substitutor.unify_report_error(
+                            &bound_expr.typ.typ,
+                            &INT_TYPE,
+                            bound_expr.span,
+                            "array slice bound",
+                        );
+                    }
+
+                    // A slice of an array is an array of the same content type
+                    let content_typ = AbstractType::Unknown(self.type_checker.alloc_typ_variable());
+                    self.type_checker.unify_with_array_of(
+                        &current_type_in_progress,
+                        content_typ,
+                        bracket_span.outer_span(),
+                    );
+                }
             }
         }
 
@@ -305,7 +371,34 @@ impl TypeCheckingContext<'_, '_> {
             Instruction::IfStatement(if_stmt) => {
                 let condition_expr =
                     self.working_on.instructions[if_stmt.condition].unwrap_expression();
-                if !condition_expr.typ.domain.is_generative() {
+                let condition_is_generative = condition_expr.typ.domain.is_generative();
+                match (if_stmt.is_generative, condition_is_generative) {
+                    (true, false) => {
+                        // Still works, the 'if' becomes a multiplexer like 'when' does
+                        self.errors
+                            .warn(
+                                condition_expr.span,
+                                "This condition isn't generative, so this 'if' is a runtime 'when'. Use 'when' for runtime conditions",
+                            )
+                            .info_same_file(
+                                if_stmt.keyword_span,
+                                "'if' expects a generative condition, to pick code at compile time",
+                            );
+                    }
+                    (false, true) => {
+                        self.errors
+                            .error(
+                                condition_expr.span,
+                                "This condition is generative, so it can't be used by 'when'. Use 'if' for generative conditions",
+                            )
+                            .info_same_file(
+                                if_stmt.keyword_span,
+                                "'when' expects a runtime condition, to build a multiplexer",
+                            );
+                    }
+                    _ => {}
+                }
+                if !condition_is_generative {
                     self.runtime_condition_stack.push(ConditionStackElem {
                         ends_at: if_stmt.else_end,
                         span: condition_expr.span,
@@ -314,6 +407,14 @@ impl TypeCheckingContext<'_, '_> {
                 }
             }
             Instruction::ForStatement(_) => {}
+            Instruction::Assertion(assertion) => {
+                // A runtime assertion only has to hold when the conditions it's in hold, so they must be in the same domain
+                let condition_expr =
+                    self.working_on.instructions[assertion.condition].unwrap_expression();
+                if !condition_expr.typ.domain.is_generative() {
+                    self.join_with_condition(&condition_expr.typ.domain, condition_expr.span);
+                }
+            }
         }
     }
 
@@ -443,6 +544,16 @@ impl TypeCheckingContext<'_, '_> {
                     "if statement condition",
                 );
             }
+            Instruction::Assertion(assertion) => {
+                let condition_expr =
+                    &self.working_on.instructions[assertion.condition].unwrap_expression();
+                self.type_checker.typecheck_write_to_abstract(
+                    &condition_expr.typ.typ,
+                    &BOOL_TYPE,
+                    condition_expr.span,
+                    "assertion condition",
+                );
+            }
             Instruction::ForStatement(stm) => {
                 let loop_var = self.working_on.instructions[stm.loop_var_decl].unwrap_declaration();
                 let start = self.working_on.instructions[stm.start].unwrap_expression();
@@ -487,6 +598,69 @@ impl TypeCheckingContext<'_, '_> {
                             &expr.typ,
                         )
                     }
+                    ExpressionSource::Concatenation { elements } => {
+                        self.type_checker.typecheck_concatenation(
+                            elements.iter().map(|elem| {
+                                let elem_expr =
+                                    self.working_on.instructions[*elem].unwrap_expression();
+                                (&elem_expr.typ, elem_expr.span)
+                            }),
+                            &expr.typ,
+                            expr.span,
+                        )
+                    }
+                    &ExpressionSource::Conditional {
+                        condition,
+                        if_true,
+                        if_false,
+                    } => {
+                        let condition_expr =
+                            self.working_on.instructions[condition].unwrap_expression();
+                        let true_expr = self.working_on.instructions[if_true].unwrap_expression();
+                        let false_expr = self.working_on.instructions[if_false].unwrap_expression();
+
+                        self.type_checker.typecheck_write_to_abstract(
+                            &condition_expr.typ.typ,
+                            &BOOL_TYPE,
+                            condition_expr.span,
+                            "conditional expression condition",
+                        );
+                        self.type_checker.typecheck_write_to_abstract(
+                            &true_expr.typ.typ,
+                            &expr.typ.typ,
+                            true_expr.span,
+                            "conditional expression",
+                        );
+                        let file = self.errors.file;
+                        self.type_checker.typecheck_write_to_abstract(
+                            &false_expr.typ.typ,
+                            &true_expr.typ.typ,
+                            false_expr.span,
+                            || {
+                                (
+                                    "conditional expression arm".to_string(),
+                                    vec![ErrorInfo {
+                                        position: true_expr.span,
+                                        file,
+                                        info: "The other arm of the conditional expression"
+                                            .to_owned(),
+                                    }],
+                                )
+                            },
+                        );
+                        for arg in [condition_expr, true_expr, false_expr] {
+                            self.type_checker.unify_domains(
+                                &arg.typ.domain,
+                                &expr.typ.domain,
+                                arg.span,
+                                "conditional expression",
+                            );
+                        }
+                    }
+                    ExpressionSource::Literal(_) => self
+                        .type_checker
+                        .type_substitutor
+                        .unify_report_error(&expr.typ.typ, &INT_TYPE, expr.span, "int constant"),
                     ExpressionSource::Constant(value) => {
                         self.type_checker
                             .unify_with_constant(&expr.typ.typ, value, expr.span)
@@ -494,7 +668,7 @@ impl TypeCheckingContext<'_, '_> {
                 };
             }
             Instruction::FuncCall(fc) => {
-                for (port, arg) in std::iter::zip(fc.func_call_inputs.into_iter(), &fc.arguments) {
+                for (port, arg) in std::iter::zip(fc.func_call_inputs, &fc.arguments) {
                     let write_to_type =
                         self.get_type_of_port(port, fc.interface_reference.submodule_decl);
 
@@ -562,14 +736,14 @@ impl TypeCheckingContext<'_, '_> {
 
 pub fn apply_types(
     mut type_checker: TypeUnifier,
-    working_on: &mut Module,
+    link_info: &mut LinkInfo,
+    domains: &mut FlatAlloc<DomainInfo, DomainIDMarker>,
     errors: &ErrorCollector,
     types: &ArenaAllocator<StructType, TypeUUIDMarker>,
 ) {
     // Set the remaining domain variables that aren't associated with a module port.
     // We just find domain IDs that haven't been
-    let mut leftover_domain_alloc =
-        UUIDAllocator::new_start_from(working_on.domains.get_next_alloc_id());
+    let mut leftover_domain_alloc = UUIDAllocator::new_start_from(domains.get_next_alloc_id());
     for d in type_checker.domain_substitutor.iter() {
         if d.get().is_none() {
             assert!(d
@@ -579,8 +753,8 @@ pub fn apply_types(
     }
 
     // Assign names to all of the domains in this module
-    working_on.domains = leftover_domain_alloc.as_range().map(|id| {
-        if let Some(work_on_domain) = working_on.domains.get(id) {
+    *domains = leftover_domain_alloc.as_range().map(|id| {
+        if let Some(work_on_domain) = domains.get(id) {
             work_on_domain.clone()
         } else {
             DomainInfo {
@@ -591,7 +765,7 @@ pub fn apply_types(
     });
 
     // Post type application. Solidify types and flag any remaining AbstractType::Unknown
-    for (_id, inst) in working_on.link_info.instructions.iter_mut() {
+    for (_id, inst) in link_info.instructions.iter_mut() {
         match inst {
             Instruction::Expression(expr) => {
                 type_checker.finalize_type(types, &mut expr.typ, expr.span, errors);
@@ -667,15 +841,16 @@ pub fn apply_types(
         assert!(found.fully_substitute(&type_checker.domain_substitutor));
         assert!(expected.fully_substitute(&type_checker.domain_substitutor));
 
-        let expected_name = format!("{expected:?}");
-        let found_name = format!("{found:?}");
+        assert!(expected != found, "{expected:?} != {found:?}");
+
+        let domain_name = |domain: &DomainType| match domain {
+            DomainType::Physical(id) => format!("'{}'", domains[*id].name),
+            other => format!("{other:?}"),
+        };
+        let expected_name = domain_name(&expected);
+        let found_name = domain_name(&found);
         errors
-            .error(span, format!("Domain error: Attempting to combine domains {found_name} and {expected_name} in {context}"))
+            .warn(span, format!("Unsafe clock domain crossing: Attempting to combine domains {found_name} and {expected_name} in {context}. Crossing clock domains should be done explicitly with CrossDomain"))
             .add_info_list(infos);
-
-        assert!(
-            expected_name != found_name,
-            "{expected_name} != {found_name}"
-        );
     }
 }
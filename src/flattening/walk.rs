@@ -33,7 +33,21 @@ impl ExpressionSource {
                 func(left);
                 func(right)
             }
-            ExpressionSource::Constant(_) => {}
+            ExpressionSource::Concatenation { elements } => {
+                for elem in elements {
+                    func(*elem)
+                }
+            }
+            &ExpressionSource::Conditional {
+                condition,
+                if_true,
+                if_false,
+            } => {
+                func(condition);
+                func(if_true);
+                func(if_false)
+            }
+            ExpressionSource::Literal(_) | ExpressionSource::Constant(_) => {}
         }
     }
 }
@@ -46,6 +60,14 @@ impl WireReferencePathElement {
                     idx,
                     bracket_span: _,
                 } => f(*idx),
+                WireReferencePathElement::ArraySlice {
+                    high,
+                    low,
+                    bracket_span: _,
+                } => {
+                    f(*high);
+                    f(*low);
+                }
             }
         }
     }
@@ -1,5 +1,7 @@
 use std::ops::Deref;
 
+use num::{BigInt, One};
+
 use crate::errors::ErrorInfoObject;
 use crate::flattening::{DeclarationKind, ExpressionSource, WireReferenceRoot, WrittenType};
 use crate::linker::LinkInfo;
@@ -38,6 +40,19 @@ impl InstantiationContext<'_, '_> {
                     );
                     current_type_in_progress = typ_after_applying_array;
                 }
+                &RealWirePathElem::ArraySlice { span: _, high, low } => {
+                    // Bounds are checked in [Self::check_array_slice_bounds], once all array sizes are known
+                    let arr_size = ConcreteType::Unknown(self.type_substitutor.alloc());
+                    let arr_box = Box::new((typ_after_applying_array.clone(), arr_size));
+                    self.type_substitutor.unify_must_succeed(
+                        &current_type_in_progress,
+                        &ConcreteType::Array(arr_box),
+                    );
+                    let slice_size =
+                        ConcreteType::Value(Value::Integer(BigInt::from(high - low + 1)));
+                    current_type_in_progress =
+                        ConcreteType::Array(Box::new((typ_after_applying_array, slice_size)));
+                }
             }
         }
 
@@ -54,7 +69,9 @@ impl InstantiationContext<'_, '_> {
     fn typecheck_all_wires(&self) {
         for this_wire_id in self.wires.id_range() {
             let this_wire = &self.wires[this_wire_id];
-            let span = self.md.get_instruction_span(this_wire.original_instruction);
+            let span = self
+                .link_info
+                .get_instruction_span(this_wire.original_instruction);
             span.debug();
 
             match &this_wire.source {
@@ -175,7 +192,46 @@ impl InstantiationContext<'_, '_> {
                         "wire access",
                     );
                 }
-                RealWireDataSource::Constant { value } => {
+                RealWireDataSource::Concatenation { elements } => {
+                    // The size of the result is set by [ConcatenationSizeConstraint]
+                    let content_typ = ConcreteType::Unknown(self.type_substitutor.alloc());
+                    for elem in elements {
+                        let elem_typ = self.make_array_of(content_typ.clone());
+                        self.type_substitutor.unify_report_error(
+                            &self.wires[*elem].typ,
+                            &elem_typ,
+                            span,
+                            "concatenation element",
+                        );
+                    }
+                    self.type_substitutor.unify_report_error(
+                        &self.wires[this_wire_id].typ,
+                        &self.make_array_of(content_typ),
+                        span,
+                        "concatenation",
+                    );
+                }
+                &RealWireDataSource::Conditional {
+                    condition,
+                    if_true,
+                    if_false,
+                } => {
+                    self.type_substitutor.unify_report_error(
+                        &self.wires[condition].typ,
+                        &BOOL_CONCRETE_TYPE,
+                        span,
+                        "conditional expression condition",
+                    );
+                    for arm in [if_true, if_false] {
+                        self.type_substitutor.unify_report_error(
+                            &self.wires[arm].typ,
+                            &self.wires[this_wire_id].typ,
+                            span,
+                            "conditional expression",
+                        );
+                    }
+                }
+                RealWireDataSource::Constant { value, width: _ } => {
                     assert!(
                         value.is_of_type(&this_wire.typ),
                         "Assigned type to a constant should already be of the type"
@@ -185,17 +241,143 @@ impl InstantiationContext<'_, '_> {
         }
     }
 
+    /// Array slices with out of range bounds. Requires the wire types to be fully substituted
+    fn check_array_slice_bounds(&self) {
+        for (_id, w) in &self.wires {
+            match &w.source {
+                RealWireDataSource::Select { root, path } => {
+                    self.check_slice_bounds_along_path(&self.wires[*root].typ, path)
+                }
+                RealWireDataSource::Multiplexer {
+                    is_state: _,
+                    sources,
+                } => {
+                    for s in sources {
+                        self.check_slice_bounds_along_path(&w.typ, &s.to_path);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn check_slice_bounds_along_path(&self, typ: &ConcreteType, path: &[RealWirePathElem]) {
+        let mut typ = typ.clone();
+        for p in path {
+            // Other type errors are reported elsewhere
+            let ConcreteType::Array(arr) = typ else {
+                return;
+            };
+            let (content, size) = *arr;
+            let ConcreteType::Value(Value::Integer(size)) = size else {
+                return;
+            };
+            typ = match p {
+                RealWirePathElem::ArrayAccess { .. } => content,
+                &RealWirePathElem::ArraySlice { span, high, low } => {
+                    if BigInt::from(high) >= size {
+                        self.errors.error(
+                            span.inner_span(),
+                            format!(
+                                "Slice [{high}:{low}] is out of range for this array of size {size}"
+                            ),
+                        );
+                        return;
+                    }
+                    let slice_size =
+                        ConcreteType::Value(Value::Integer(BigInt::from(high - low + 1)));
+                    ConcreteType::Array(Box::new((content, slice_size)))
+                }
+            };
+        }
+    }
+
+    fn warn_single_bit_reductions(&self) {
+        for (_id, w) in &self.wires {
+            let &RealWireDataSource::UnaryOp {
+                op: op @ (UnaryOperator::And | UnaryOperator::Or | UnaryOperator::Xor),
+                right,
+            } = &w.source
+            else {
+                continue;
+            };
+            let ConcreteType::Array(arr) = &self.wires[right].typ else {
+                continue;
+            };
+            if let ConcreteType::Value(Value::Integer(size)) = &arr.1 {
+                if size.is_one() {
+                    let span = self.link_info.get_instruction_span(w.original_instruction);
+                    self.errors.warn(
+                        span,
+                        format!("Reduction '{op}' of a single bit does nothing. Use [0] to get the bit instead"),
+                    );
+                }
+            }
+        }
+    }
+
+    /// The width of an operand in the generated code. A sized literal like `8'hFF` keeps its own width, see [RealWireDataSource::Constant]
+    fn operand_width(&self, wire: WireID) -> Option<BigInt> {
+        let w = &self.wires[wire];
+        match &w.source {
+            RealWireDataSource::Constant {
+                value: _,
+                width: Some(width),
+            } => Some(BigInt::from(*width)),
+            _ => w.typ.sizeof(),
+        }
+    }
+
+    /// Operands of differing widths get silently zero- or sign-extended in the generated Verilog
+    fn warn_binary_op_width_mismatch(&self) {
+        for (_id, w) in &self.wires {
+            let &RealWireDataSource::BinaryOp { op, left, right } = &w.source else {
+                continue;
+            };
+            let (Some(left_size), Some(right_size)) =
+                (self.operand_width(left), self.operand_width(right))
+            else {
+                continue;
+            };
+            if left_size != right_size {
+                let expr = self.link_info.instructions[w.original_instruction].unwrap_expression();
+                // The operand wires can be declarations, so point at the operands as written instead
+                let ExpressionSource::BinaryOp {
+                    op: _,
+                    left: left_expr,
+                    right: right_expr,
+                } = expr.source
+                else {
+                    unreachable!("A BinaryOp wire comes from a BinaryOp expression")
+                };
+                let left_span = self.link_info.get_instruction_span(left_expr);
+                let right_span = self.link_info.get_instruction_span(right_expr);
+                self.errors
+                    .warn(
+                        expr.span,
+                        format!("Operands of '{op}' have different widths ({left_size} and {right_size} bits). The narrower one is implicitly extended"),
+                    )
+                    .info_same_file(left_span, format!("Left side is {left_size} bits wide"))
+                    .info_same_file(right_span, format!("Right side is {right_size} bits wide"));
+            }
+        }
+    }
+
     fn finalize(&mut self) {
         for (_id, w) in &mut self.wires {
             if !w.typ.fully_substitute(&self.type_substitutor) {
                 let typ_as_str = w.typ.display(&self.linker.types);
 
-                let span = self.md.get_instruction_span(w.original_instruction);
+                let span = self.link_info.get_instruction_span(w.original_instruction);
                 span.debug();
                 self.errors.error(span, format!("Could not finalize this type, some parameters were still unknown: {typ_as_str}"));
             }
         }
 
+        self.check_array_slice_bounds();
+        self.warn_single_bit_reductions();
+        self.warn_binary_op_width_mismatch();
+
         // Print all errors
         for FailedUnification {
             mut found,
@@ -248,6 +430,12 @@ impl InstantiationContext<'_, '_> {
             delayed_constraints.push(SubmoduleTypecheckConstraint { sm_id });
         }
 
+        for (wire_id, w) in &self.wires {
+            if let RealWireDataSource::Concatenation { .. } = &w.source {
+                delayed_constraints.push(ConcatenationSizeConstraint { wire_id });
+            }
+        }
+
         self.typecheck_all_wires();
 
         delayed_constraints.resolve_delayed_constraints(self);
@@ -260,6 +448,59 @@ struct SubmoduleTypecheckConstraint {
     sm_id: SubModuleID,
 }
 
+/// The size of a concatenation is the sum of the sizes of its elements, which are only known once they've been inferred
+struct ConcatenationSizeConstraint {
+    wire_id: WireID,
+}
+
+impl DelayedConstraint<InstantiationContext<'_, '_>> for ConcatenationSizeConstraint {
+    fn try_apply(&mut self, context: &mut InstantiationContext) -> DelayedConstraintStatus {
+        let w = &context.wires[self.wire_id];
+        let RealWireDataSource::Concatenation { elements } = &w.source else {
+            unreachable!()
+        };
+
+        let mut total_size = BigInt::ZERO;
+        for elem in elements {
+            let mut elem_typ = context.wires[*elem].typ.clone();
+            if !elem_typ.fully_substitute(&context.type_substitutor) {
+                return DelayedConstraintStatus::NoProgress;
+            }
+            let ConcreteType::Array(arr) = elem_typ else {
+                // Typing error already reported
+                return DelayedConstraintStatus::Resolved;
+            };
+            total_size += arr.1.unwrap_value().unwrap_integer();
+        }
+
+        let span = context
+            .link_info
+            .get_instruction_span(w.original_instruction);
+        let concatenated_typ = ConcreteType::Array(Box::new((
+            ConcreteType::Unknown(context.type_substitutor.alloc()),
+            ConcreteType::Value(Value::Integer(total_size)),
+        )));
+        context.type_substitutor.unify_report_error(
+            &w.typ,
+            &concatenated_typ,
+            span,
+            "concatenation size",
+        );
+        DelayedConstraintStatus::Resolved
+    }
+
+    fn report_could_not_resolve_error(&self, context: &InstantiationContext) {
+        let w = &context.wires[self.wire_id];
+        let span = context
+            .link_info
+            .get_instruction_span(w.original_instruction);
+        context.errors.error(
+            span,
+            "Could not determine the size of this concatenation, the sizes of its elements are unknown",
+        );
+    }
+}
+
 /// Part of Template Value Inference.
 ///
 /// Specifically, for code like this:
@@ -369,7 +610,7 @@ impl DelayedConstraint<InstantiationContext<'_, '_>> for SubmoduleTypecheckConst
         let sm = &mut context.submodules[self.sm_id];
 
         let submod_instr =
-            context.md.link_info.instructions[sm.original_instruction].unwrap_submodule();
+            context.link_info.instructions[sm.original_instruction].unwrap_submodule();
         let sub_module = &context.linker.modules[sm.module_uuid];
 
         // Check if there's any argument that isn't known
@@ -380,11 +621,25 @@ impl DelayedConstraint<InstantiationContext<'_, '_>> for SubmoduleTypecheckConst
             }
         }
 
+        let mut instantiation_stack = context.instantiation_stack.clone();
+        instantiation_stack.push(InstantiationFrame {
+            module: sm.module_uuid,
+            template_args: sm.template_args.clone(),
+            span: (
+                submod_instr.module_ref.get_total_span(),
+                context.link_info.file,
+            ),
+            parent_module_name: context.link_info.name.clone(),
+            instance_name: sm.name.clone(),
+        });
+
         if let Some(instance) = sub_module.instantiations.instantiate(
             sub_module,
             context.linker,
             sm.template_args.clone(),
+            &instantiation_stack,
         ) {
+            let mut unconnected_inputs = Vec::new();
             for (port_id, concrete_port) in &instance.interface_ports {
                 let connecting_wire = &sm.port_map[port_id];
 
@@ -402,8 +657,12 @@ impl DelayedConstraint<InstantiationContext<'_, '_>> for SubmoduleTypecheckConst
                                 .info_obj_same_file(submod_instr);
                         }
                     }
+                    (Some(concrete_port), None) if concrete_port.is_input => {
+                        // Nothing drives this input. Reported below, together with the other unconnected inputs
+                        unconnected_inputs.push(port_id);
+                    }
                     (Some(_concrete_port), None) => {
-                        // Port is enabled, but not used
+                        // Output port is enabled, but not used
                         let source_code_port = &sub_module.ports[port_id];
                         context
                             .errors
@@ -434,6 +693,24 @@ impl DelayedConstraint<InstantiationContext<'_, '_>> for SubmoduleTypecheckConst
                     }
                 }
             }
+            if !unconnected_inputs.is_empty() {
+                let port_names = unconnected_inputs
+                    .iter()
+                    .map(|port_id| format!("'{}'", sub_module.ports[*port_id].name))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let err_ref = context.errors.error(
+                    submod_instr.module_ref.get_total_span(),
+                    format!("Input ports of this submodule are not connected: {port_names}"),
+                );
+                for port_id in unconnected_inputs {
+                    err_ref.info_obj_different_file(
+                        &sub_module.ports[port_id],
+                        sub_module.link_info.file,
+                    );
+                }
+                err_ref.info_obj_same_file(submod_instr);
+            }
             for (interface_id, interface_references) in &sm.interface_call_sites {
                 if !interface_references.is_empty() {
                     let sm_interface = &sub_module.interfaces[interface_id];
@@ -473,10 +750,19 @@ impl DelayedConstraint<InstantiationContext<'_, '_>> for SubmoduleTypecheckConst
                 .expect("Can only set the instance of a submodule once");
             DelayedConstraintStatus::Resolved
         } else {
-            context.errors.error(
+            let err_ref = context.errors.error(
                 submod_instr.module_ref.get_total_span(),
                 "Error instantiating submodule",
             );
+            // Point at the given arguments, as they are the likely cause
+            for (template_id, arg) in submod_instr.module_ref.template_args.iter_valids() {
+                let param_name = &sub_module.link_info.template_parameters[template_id].name;
+                let value_str = match &sm.template_args[template_id] {
+                    ConcreteType::Value(value) => value.to_string(),
+                    typ => format!("type {}", typ.display(&context.linker.types)),
+                };
+                err_ref.info_same_file(arg.value_span, format!("'{param_name}' is {value_str}"));
+            }
             DelayedConstraintStatus::NoProgress
         }
     }
@@ -485,9 +771,38 @@ impl DelayedConstraint<InstantiationContext<'_, '_>> for SubmoduleTypecheckConst
         let sm = &context.submodules[self.sm_id];
 
         let submod_instr =
-            context.md.link_info.instructions[sm.original_instruction].unwrap_submodule();
+            context.link_info.instructions[sm.original_instruction].unwrap_submodule();
         let sub_module = &context.linker.modules[sm.module_uuid];
 
+        // Without a #(...) block there are no arguments to point at, so point at the name and list what couldn't be inferred
+        if submod_instr.module_ref.template_span.is_none() {
+            let uninferred: Vec<TemplateID> = sm
+                .template_args
+                .iter()
+                .filter(|(_, arg)| !(*arg).clone().fully_substitute(&context.type_substitutor))
+                .map(|(id, _)| id)
+                .collect();
+            if !uninferred.is_empty() {
+                let params = &sub_module.link_info.template_parameters;
+                let param_names: Vec<String> = uninferred
+                    .iter()
+                    .map(|id| format!("'{}'", params[*id].name))
+                    .collect();
+                let err_ref = context.errors.error(
+                    submod_instr.module_ref.name_span,
+                    format!(
+                        "{} is parameterized, but no template arguments were given, and {} could not be inferred. Pass them with #(...)",
+                        sub_module.link_info.get_full_name(),
+                        param_names.join(", ")
+                    ),
+                );
+                for id in uninferred {
+                    err_ref.info_obj_different_file(&params[id], sub_module.link_info.file);
+                }
+                return;
+            }
+        }
+
         let submodule_template_args_string = pretty_print_concrete_instance(
             &sub_module.link_info,
             &sm.template_args,
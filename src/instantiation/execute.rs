@@ -8,16 +8,16 @@ use std::ops::{Deref, Index, IndexMut};
 
 use crate::linker::IsExtern;
 use crate::prelude::*;
-use crate::typing::template::GlobalReference;
+use crate::typing::template::{GlobalReference, TemplateArg};
 
-use num::BigInt;
+use num::{BigInt, Signed};
 
 use crate::flattening::*;
 use crate::value::{compute_binary_op, compute_unary_op, Value};
 
 use crate::typing::{
     abstract_type::DomainType,
-    concrete_type::{ConcreteType, INT_CONCRETE_TYPE},
+    concrete_type::{ConcreteType, BOOL_CONCRETE_TYPE, INT_CONCRETE_TYPE},
     template::TemplateArgKind,
 };
 
@@ -36,7 +36,7 @@ pub type ExecutionResult<T> = Result<T, (Span, String)>;
 
 impl GenerationState<'_> {
     fn span_of(&self, v: FlatID) -> Span {
-        let instr = &self.md.link_info.instructions[v];
+        let instr = &self.link_info.instructions[v];
         match instr {
             Instruction::Declaration(d) => d.name_span,
             Instruction::Expression(expr) => expr.span,
@@ -50,9 +50,9 @@ impl GenerationState<'_> {
         conn_path: &[WireReferencePathElement],
         to_write: Value,
     ) -> ExecutionResult<()> {
-        for elem in conn_path {
-            match elem {
-                &WireReferencePathElement::ArrayAccess { idx, bracket_span } => {
+        for (elem_idx, elem) in conn_path.iter().enumerate() {
+            match *elem {
+                WireReferencePathElement::ArrayAccess { idx, bracket_span } => {
                     let idx = self.get_generation_integer(idx)?; // Caught by typecheck
                     let Value::Array(a_box) = target else {
                         caught_by_typecheck!("Non-array")
@@ -70,11 +70,58 @@ impl GenerationState<'_> {
                     };
                     target = tt
                 }
+                WireReferencePathElement::ArraySlice {
+                    high,
+                    low,
+                    bracket_span,
+                } => {
+                    let (high, low) = self.get_slice_bounds(high, low, bracket_span)?;
+                    let Value::Array(a_box) = target else {
+                        caught_by_typecheck!("Non-array")
+                    };
+                    let slice_range = checked_slice_range(a_box, high, low, bracket_span)?;
+
+                    // Write the rest of the path into a copy of the slice, and then copy it back
+                    let mut slice = Value::Array(a_box[slice_range.clone()].into());
+                    self.write_gen_variable(&mut slice, &conn_path[elem_idx + 1..], to_write)?;
+                    let Value::Array(slice) = slice else {
+                        caught_by_typecheck!("Non-array")
+                    };
+                    if slice.len() != slice_range.len() {
+                        return Err((
+                            bracket_span.outer_span(),
+                            format!(
+                                "Cannot assign an array of size {} to a slice of size {}",
+                                slice.len(),
+                                slice_range.len()
+                            ),
+                        ));
+                    }
+                    a_box[slice_range].clone_from_slice(&slice);
+                    return Ok(());
+                }
             }
         }
         *target = to_write;
         Ok(())
     }
+    /// The bounds of an `arr[high:low]` slice. Reversed bounds are an error
+    fn get_slice_bounds(
+        &self,
+        high: FlatID,
+        low: FlatID,
+        bracket_span: BracketSpan,
+    ) -> ExecutionResult<(usize, usize)> {
+        let high_val: usize = self.get_generation_small_int(high)?;
+        let low_val: usize = self.get_generation_small_int(low)?;
+        if low_val > high_val {
+            return Err((
+                bracket_span.inner_span(),
+                format!("Reversed slice bounds [{high_val}:{low_val}]. The high bound must come first, like [{low_val}:{high_val}]"),
+            ));
+        }
+        Ok((high_val, low_val))
+    }
     fn get_generation_value(&self, v: FlatID) -> ExecutionResult<&Value> {
         if let SubModuleOrWire::CompileTimeValue(vv) = &self.generation_state[v] {
             if let Value::Unset | Value::Error = vv {
@@ -129,14 +176,10 @@ impl IndexMut<FlatID> for GenerationState<'_> {
 }
 
 fn array_access<'v>(
-    arr_val: &'v Value,
+    arr: &'v [Value],
     idx: &BigInt,
     span: BracketSpan,
 ) -> ExecutionResult<&'v Value> {
-    let Value::Array(arr) = arr_val else {
-        caught_by_typecheck!("Value must be an array")
-    };
-
     if let Some(elem) = usize::try_from(idx).ok().and_then(|idx| arr.get(idx)) {
         Ok(elem)
     } else {
@@ -150,6 +193,26 @@ fn array_access<'v>(
     }
 }
 
+/// The range of `arr[high:low]`, with `low <= high`
+fn checked_slice_range<T>(
+    arr: &[T],
+    high: usize,
+    low: usize,
+    span: BracketSpan,
+) -> ExecutionResult<std::ops::Range<usize>> {
+    if high < arr.len() {
+        Ok(low..high + 1)
+    } else {
+        Err((
+            span.inner_span(),
+            format!(
+                "Slice [{high}:{low}] is out of range for this array of size {}",
+                arr.len()
+            ),
+        ))
+    }
+}
+
 fn add_to_small_set<T: Eq>(set_vec: &mut Vec<T>, elem: T) {
     if !set_vec.contains(&elem) {
         set_vec.push(elem);
@@ -192,6 +255,12 @@ impl InstantiationContext<'_, '_> {
                 let arr_size = self
                     .generation_state
                     .get_generation_integer(*arr_size_wire)?;
+                if arr_size.is_negative() {
+                    return Err((
+                        self.link_info.get_instruction_span(*arr_size_wire),
+                        format!("Array size cannot be negative: {arr_size}"),
+                    ));
+                }
                 ConcreteType::Array(Box::new((
                     inner_typ,
                     ConcreteType::Value(Value::Integer(arr_size.clone())),
@@ -214,16 +283,34 @@ impl InstantiationContext<'_, '_> {
         }
     }
 
+    /// Builtin constants can't infer their argument, so it must be given explicitly
+    fn get_first_template_argument<'r>(
+        &self,
+        cst_ref: &'r GlobalReference<ConstantUUID>,
+    ) -> ExecutionResult<&'r TemplateArg> {
+        cst_ref.get_first_template_argument().ok_or_else(|| {
+            let link_info = &self.linker.constants[cst_ref.id].link_info;
+            let param_name = &link_info.template_parameters[TemplateID::from_hidden_value(0)].name;
+            (
+                cst_ref.get_total_span(),
+                format!(
+                    "{} requires the template argument '{param_name}'",
+                    link_info.name
+                ),
+            )
+        })
+    }
+
     fn get_first_template_argument_value(
         &self,
         cst_ref: &GlobalReference<ConstantUUID>,
-    ) -> (&Value, Span) {
-        let first_arg = cst_ref.unwrap_first_template_argument();
+    ) -> ExecutionResult<(&Value, Span)> {
+        let first_arg = self.get_first_template_argument(cst_ref)?;
         let value_instruction = first_arg.kind.unwrap_value();
-        (
+        Ok((
             self.generation_state[value_instruction].unwrap_generation_value(),
             first_arg.value_span,
-        )
+        ))
     }
 
     /// TODO make builtins that depend on parameters
@@ -238,7 +325,7 @@ impl InstantiationContext<'_, '_> {
                 "true" => Value::Bool(true),
                 "false" => Value::Bool(false),
                 "clog2" => {
-                    let (val, span) = self.get_first_template_argument_value(cst_ref);
+                    let (val, span) = self.get_first_template_argument_value(cst_ref)?;
                     let int_val = val.unwrap_integer();
                     if *int_val > BigInt::ZERO {
                         let int_val_minus_one: BigInt = int_val - 1;
@@ -249,7 +336,7 @@ impl InstantiationContext<'_, '_> {
                     }
                 }
                 "assert" => {
-                    let (condition, span) = self.get_first_template_argument_value(cst_ref);
+                    let (condition, span) = self.get_first_template_argument_value(cst_ref)?;
 
                     if condition.unwrap_bool() {
                         Value::Bool(true)
@@ -258,7 +345,7 @@ impl InstantiationContext<'_, '_> {
                     }
                 }
                 "sizeof" => {
-                    let first_arg = cst_ref.unwrap_first_template_argument();
+                    let first_arg = self.get_first_template_argument(cst_ref)?;
                     let wr_typ = first_arg.kind.unwrap_type();
 
                     let concrete_typ = self.concretize_type(wr_typ)?;
@@ -276,10 +363,88 @@ impl InstantiationContext<'_, '_> {
                 other => unreachable!("{other} is not a known builtin constant"),
             }
         } else {
-            todo!("Custom Constants");
+            self.evaluate_user_constant(cst_ref)?
         })
     }
 
+    /// Runs the code of a user-defined constant in a context of its own, and returns the value it assigned to itself
+    fn evaluate_user_constant(
+        &self,
+        cst_ref: &GlobalReference<ConstantUUID>,
+    ) -> ExecutionResult<Value> {
+        let linker_cst = &self.linker.constants[cst_ref.id];
+        let name = &linker_cst.link_info.name;
+        let span = cst_ref.get_total_span();
+        if linker_cst.link_info.errors.did_error {
+            return Err((span, format!("Constant '{name}' has errors")));
+        }
+
+        let mut template_args = FlatAlloc::with_capacity(cst_ref.template_args.len());
+        for (id, arg) in &cst_ref.template_args {
+            template_args.alloc(match arg {
+                Some(arg) => match &arg.kind {
+                    TemplateArgKind::Type(typ) => self.concretize_type(typ)?,
+                    TemplateArgKind::Value(v) => {
+                        ConcreteType::Value(self.generation_state.get_generation_value(*v)?.clone())
+                    }
+                },
+                None => {
+                    let param_name = &linker_cst.link_info.template_parameters[id].name;
+                    return Err((
+                        span,
+                        format!("{name} requires the template argument '{param_name}'"),
+                    ));
+                }
+            });
+        }
+
+        if let Some(value) = linker_cst.values.get(&template_args) {
+            return Ok(value);
+        }
+
+        let mut constant_stack = self.constant_stack.clone();
+        if let Some(cycle_start) = constant_stack
+            .iter()
+            .position(|(id, args)| *id == cst_ref.id && *args == template_args)
+        {
+            let mut path = String::new();
+            for (id, _) in &constant_stack[cycle_start..] {
+                path.push_str(&self.linker.constants[*id].link_info.name);
+                path.push_str(" -> ");
+            }
+            path.push_str(name);
+            return Err((span, format!("Circular constant reference: {path}")));
+        }
+        constant_stack.push((cst_ref.id, template_args.clone()));
+
+        let mut context = InstantiationContext::new_for_constant(
+            linker_cst,
+            self.linker,
+            &template_args,
+            constant_stack,
+        );
+        if let Err((_, reason)) = context.execute_module() {
+            // Only the outermost constant says which one failed, errors deeper down are passed on as-is
+            return Err(if self.constant_stack.is_empty() {
+                (span, format!("Could not evaluate '{name}': {reason}"))
+            } else {
+                (span, reason)
+            });
+        }
+        match &context.generation_state[linker_cst.output_decl] {
+            SubModuleOrWire::CompileTimeValue(Value::Unset) => {
+                Err((span, format!("Constant '{name}' is never assigned a value")))
+            }
+            SubModuleOrWire::CompileTimeValue(value) => {
+                linker_cst
+                    .values
+                    .insert(template_args.clone(), value.clone());
+                Ok(value.clone())
+            }
+            _ => unreachable!("The value of a constant is generative"),
+        }
+    }
+
     // Points to the wire in the hardware that corresponds to the root of this.
     fn determine_wire_ref_root(
         &mut self,
@@ -314,10 +479,10 @@ impl InstantiationContext<'_, '_> {
         mut preamble: Vec<RealWirePathElem>,
         path: &[WireReferencePathElement],
         domain: DomainID,
-    ) -> Vec<RealWirePathElem> {
+    ) -> ExecutionResult<Vec<RealWirePathElem>> {
         for v in path {
-            match v {
-                &WireReferencePathElement::ArrayAccess { idx, bracket_span } => {
+            match *v {
+                WireReferencePathElement::ArrayAccess { idx, bracket_span } => {
                     let idx_wire = self.get_wire_or_constant_as_wire(idx, domain);
                     assert_eq!(
                         self.wires[idx_wire].typ, INT_CONCRETE_TYPE,
@@ -328,10 +493,24 @@ impl InstantiationContext<'_, '_> {
                         idx_wire,
                     });
                 }
+                WireReferencePathElement::ArraySlice {
+                    high,
+                    low,
+                    bracket_span,
+                } => {
+                    let (high, low) =
+                        self.generation_state
+                            .get_slice_bounds(high, low, bracket_span)?;
+                    preamble.push(RealWirePathElem::ArraySlice {
+                        span: bracket_span,
+                        high,
+                        low,
+                    });
+                }
             }
         }
 
-        preamble
+        Ok(preamble)
     }
 
     fn instantiate_write_to_wire(
@@ -378,7 +557,7 @@ impl InstantiationContext<'_, '_> {
                     let domain = self.wires[target_wire].domain;
                     let from = self.get_wire_or_constant_as_wire(conn_from, domain);
                     let instantiated_path =
-                        self.instantiate_wire_ref_path(preamble, &target_wire_ref.path, domain);
+                        self.instantiate_wire_ref_path(preamble, &target_wire_ref.path, domain)?;
                     self.instantiate_write_to_wire(
                         target_wire,
                         instantiated_path,
@@ -439,34 +618,63 @@ impl InstantiationContext<'_, '_> {
         }
         Ok(())
     }
+    /// Only the part of the value that is read is cloned, so `arr[i]` in a loop doesn't copy all of `arr` on every iteration
     fn compute_compile_time_wireref(&self, wire_ref: &WireReference) -> ExecutionResult<Value> {
-        let mut work_on_value: Value = match &wire_ref.root {
+        let named_constant_value;
+        let mut work_on_value: &Value = match &wire_ref.root {
             &WireReferenceRoot::LocalDecl(decl_id, _span) => {
-                self.generation_state.get_generation_value(decl_id)?.clone()
+                self.generation_state.get_generation_value(decl_id)?
+            }
+            WireReferenceRoot::NamedConstant(cst) => {
+                named_constant_value = self.get_named_constant_value(cst)?;
+                &named_constant_value
             }
-            WireReferenceRoot::NamedConstant(cst) => self.get_named_constant_value(cst)?,
             &WireReferenceRoot::SubModulePort(_) => {
                 todo!("Don't yet support compile time functions")
             }
         };
+        // Set when the last path element was a slice, which is a part of the array in work_on_value
+        let mut work_on_slice: Option<&[Value]> = None;
 
         for path_elem in &wire_ref.path {
-            work_on_value = match path_elem {
-                &WireReferencePathElement::ArrayAccess { idx, bracket_span } => {
+            let arr: &[Value] = match work_on_slice {
+                Some(slice) => slice,
+                None => {
+                    let Value::Array(arr) = work_on_value else {
+                        caught_by_typecheck!("Value must be an array")
+                    };
+                    arr
+                }
+            };
+            match *path_elem {
+                WireReferencePathElement::ArrayAccess { idx, bracket_span } => {
                     let idx = self.generation_state.get_generation_integer(idx)?;
 
-                    array_access(&work_on_value, idx, bracket_span)?.clone()
+                    work_on_value = array_access(arr, idx, bracket_span)?;
+                    work_on_slice = None;
+                }
+                WireReferencePathElement::ArraySlice {
+                    high,
+                    low,
+                    bracket_span,
+                } => {
+                    let (high, low) =
+                        self.generation_state
+                            .get_slice_bounds(high, low, bracket_span)?;
+                    let slice_range = checked_slice_range(arr, high, low, bracket_span)?;
+                    work_on_slice = Some(&arr[slice_range]);
                 }
             }
         }
 
-        Ok(work_on_value)
+        Ok(match work_on_slice {
+            Some(slice) => Value::Array(slice.into()),
+            None => work_on_value.clone(),
+        })
     }
     fn compute_compile_time(&mut self, expression: &Expression) -> ExecutionResult<Value> {
         Ok(match &expression.source {
-            ExpressionSource::WireRef(wire_ref) => {
-                self.compute_compile_time_wireref(wire_ref)?.clone()
-            }
+            ExpressionSource::WireRef(wire_ref) => self.compute_compile_time_wireref(wire_ref)?,
             &ExpressionSource::UnaryOp { op, right } => {
                 let right_val = self.generation_state.get_generation_value(right)?;
                 compute_unary_op(op, right_val)
@@ -493,6 +701,31 @@ impl InstantiationContext<'_, '_> {
 
                 compute_binary_op(left_val, op, right_val)
             }
+            ExpressionSource::Concatenation { elements } => {
+                let mut result = Vec::new();
+                for elem in elements.iter().rev() {
+                    let Value::Array(arr) = self.generation_state.get_generation_value(*elem)?
+                    else {
+                        caught_by_typecheck!("Concatenation element must be an array")
+                    };
+                    result.extend_from_slice(arr);
+                }
+                Value::Array(result.into_boxed_slice())
+            }
+            &ExpressionSource::Conditional {
+                condition,
+                if_true,
+                if_false,
+            } => {
+                let condition_val = self.generation_state.get_generation_value(condition)?;
+                let chosen = if condition_val.unwrap_bool() {
+                    if_true
+                } else {
+                    if_false
+                };
+                self.generation_state.get_generation_value(chosen)?.clone()
+            }
+            ExpressionSource::Literal(literal) => Value::Integer(literal.value.clone()),
             ExpressionSource::Constant(value) => value.clone(),
         })
     }
@@ -500,12 +733,13 @@ impl InstantiationContext<'_, '_> {
     fn alloc_wire_for_const(
         &mut self,
         value: Value,
+        width: Option<u64>,
         original_instruction: FlatID,
         domain: DomainID,
     ) -> WireID {
         self.wires.alloc(RealWire {
             typ: value.get_type_best_effort(&mut self.type_substitutor),
-            source: RealWireDataSource::Constant { value },
+            source: RealWireDataSource::Constant { value, width },
             original_instruction,
             domain,
             name: self.unique_name_producer.get_unique_name(""),
@@ -524,8 +758,16 @@ impl InstantiationContext<'_, '_> {
             SubModuleOrWire::Wire(w) => *w,
             SubModuleOrWire::CompileTimeValue(v) => {
                 let value = v.clone();
+                // Only a literal used directly keeps its width. A generative variable holding it is a plain int
+                let width = match &self.link_info.instructions[original_instruction] {
+                    Instruction::Expression(Expression {
+                        source: ExpressionSource::Literal(literal),
+                        ..
+                    }) => literal.width,
+                    _ => None,
+                };
 
-                self.alloc_wire_for_const(value, original_instruction, domain)
+                self.alloc_wire_for_const(value, width, original_instruction, domain)
             }
         }
     }
@@ -548,7 +790,7 @@ impl InstantiationContext<'_, '_> {
             wire_found.maps_to_wire
         } else {
             let port_data = &self.linker.modules[submod_instance.module_uuid].ports[port_id];
-            let submodule_instruction = self.md.link_info.instructions
+            let submodule_instruction = self.link_info.instructions
                 [submod_instance.original_instruction]
                 .unwrap_submodule();
             let source = if port_data.is_input {
@@ -600,12 +842,12 @@ impl InstantiationContext<'_, '_> {
                     .unwrap_generation_value()
                     .clone();
                 (
-                    self.alloc_wire_for_const(value, decl_id, domain),
+                    self.alloc_wire_for_const(value, None, decl_id, domain),
                     Vec::new(),
                 )
             }
             RealWireRefRoot::Constant(value) => (
-                self.alloc_wire_for_const(value, original_instruction, domain),
+                self.alloc_wire_for_const(value, None, original_instruction, domain),
                 Vec::new(),
             ),
         })
@@ -620,7 +862,7 @@ impl InstantiationContext<'_, '_> {
             ExpressionSource::WireRef(wire_ref) => {
                 let (root_wire, path_preamble) =
                     self.get_wire_ref_root_as_wire(&wire_ref.root, original_instruction, domain)?;
-                let path = self.instantiate_wire_ref_path(path_preamble, &wire_ref.path, domain);
+                let path = self.instantiate_wire_ref_path(path_preamble, &wire_ref.path, domain)?;
 
                 if path.is_empty() {
                     // Little optimization reduces instructions
@@ -641,7 +883,22 @@ impl InstantiationContext<'_, '_> {
                 let right = self.get_wire_or_constant_as_wire(right, domain);
                 RealWireDataSource::BinaryOp { op, left, right }
             }
-            ExpressionSource::Constant(_) => {
+            ExpressionSource::Concatenation { elements } => RealWireDataSource::Concatenation {
+                elements: elements
+                    .iter()
+                    .map(|elem| self.get_wire_or_constant_as_wire(*elem, domain))
+                    .collect(),
+            },
+            &ExpressionSource::Conditional {
+                condition,
+                if_true,
+                if_false,
+            } => RealWireDataSource::Conditional {
+                condition: self.get_wire_or_constant_as_wire(condition, domain),
+                if_true: self.get_wire_or_constant_as_wire(if_true, domain),
+                if_false: self.get_wire_or_constant_as_wire(if_false, domain),
+            },
+            ExpressionSource::Literal(_) | ExpressionSource::Constant(_) => {
                 unreachable!("Constant cannot be non-compile-time");
             }
         };
@@ -705,20 +962,80 @@ impl InstantiationContext<'_, '_> {
         })
     }
 
+    /// An assertion inside `when` blocks only has to hold when their conditions do, so it becomes `!c1 | (!c2 | cond)`
+    fn instantiate_assertion_wire(
+        &mut self,
+        condition: FlatID,
+        domain: DomainID,
+        original_instruction: FlatID,
+    ) -> WireID {
+        let mut wire = self.get_wire_or_constant_as_wire(condition, domain);
+        for elem in self.condition_stack.clone().iter().rev() {
+            let guard = if elem.inverse {
+                elem.condition_wire
+            } else {
+                self.alloc_bool_wire(
+                    RealWireDataSource::UnaryOp {
+                        op: UnaryOperator::Not,
+                        right: elem.condition_wire,
+                    },
+                    original_instruction,
+                    domain,
+                )
+            };
+            wire = self.alloc_bool_wire(
+                RealWireDataSource::BinaryOp {
+                    op: BinaryOperator::Or,
+                    left: guard,
+                    right: wire,
+                },
+                original_instruction,
+                domain,
+            );
+        }
+        wire
+    }
+
+    fn alloc_bool_wire(
+        &mut self,
+        source: RealWireDataSource,
+        original_instruction: FlatID,
+        domain: DomainID,
+    ) -> WireID {
+        self.wires.alloc(RealWire {
+            name: self.unique_name_producer.get_unique_name(""),
+            typ: BOOL_CONCRETE_TYPE,
+            original_instruction,
+            domain,
+            source,
+            specified_latency: CALCULATE_LATENCY_LATER,
+            absolute_latency: CALCULATE_LATENCY_LATER,
+        })
+    }
+
     fn instantiate_code_block(&mut self, block_range: FlatIDRange) -> ExecutionResult<()> {
         let mut instruction_range = block_range.into_iter();
         while let Some(original_instruction) = instruction_range.next() {
-            let instr = &self.md.link_info.instructions[original_instruction];
-            self.md.get_instruction_span(original_instruction).debug();
+            let instr = &self.link_info.instructions[original_instruction];
+            self.link_info
+                .get_instruction_span(original_instruction)
+                .debug();
             let instance_to_add: SubModuleOrWire = match instr {
                 Instruction::SubModule(submodule) => {
                     let sub_module = &self.linker.modules[submodule.module_ref.id];
 
-                    let name_origin = if let Some((name, _span)) = &submodule.name {
-                        name
+                    let mut name_origin = if let Some((name, _span)) = &submodule.name {
+                        name.clone()
                     } else {
-                        ""
+                        String::new()
                     };
+                    if !name_origin.is_empty() {
+                        for idx in &self.loop_indices {
+                            // '-' can't be part of an identifier
+                            name_origin.push('_');
+                            name_origin.push_str(&idx.to_string().replace('-', "n"));
+                        }
+                    }
                     let port_map = sub_module.ports.map(|_| None);
                     let interface_call_sites = sub_module.interfaces.map(|_| Vec::new());
                     let mut template_args =
@@ -737,7 +1054,7 @@ impl InstantiationContext<'_, '_> {
                     }
                     SubModuleOrWire::SubModule(self.submodules.alloc(SubModule {
                         original_instruction,
-                        instance: OnceCell::new(),
+                        instance: OnceLock::new(),
                         port_map,
                         interface_call_sites,
                         name: self.unique_name_producer.get_unique_name(name_origin),
@@ -774,7 +1091,7 @@ impl InstantiationContext<'_, '_> {
                 Instruction::FuncCall(fc) => {
                     let submod_id = self.generation_state[fc.interface_reference.submodule_decl]
                         .unwrap_submodule_instance();
-                    let original_submod_instr = self.md.link_info.instructions
+                    let original_submod_instr = self.link_info.instructions
                         [fc.interface_reference.submodule_decl]
                         .unwrap_submodule();
                     let submod_md = &self.linker.modules[original_submod_instr.module_ref.id];
@@ -809,7 +1126,7 @@ impl InstantiationContext<'_, '_> {
                     let then_range = FlatIDRange::new(stm.then_start, stm.then_end_else_start);
                     let else_range = FlatIDRange::new(stm.then_end_else_start, stm.else_end);
                     let if_condition_expr =
-                        self.md.link_info.instructions[stm.condition].unwrap_expression();
+                        self.link_info.instructions[stm.condition].unwrap_expression();
                     match if_condition_expr.typ.domain {
                         DomainType::Generative => {
                             let condition_val =
@@ -858,8 +1175,8 @@ impl InstantiationContext<'_, '_> {
                         .clone();
                     if start_val > end_val {
                         let start_flat =
-                            &self.md.link_info.instructions[stm.start].unwrap_expression();
-                        let end_flat = &self.md.link_info.instructions[stm.end].unwrap_expression();
+                            &self.link_info.instructions[stm.start].unwrap_expression();
+                        let end_flat = &self.link_info.instructions[stm.end].unwrap_expression();
                         return Err((
                             Span::new_overarching(start_flat.span, end_flat.span),
                             format!("for loop range end is before begin: {start_val}:{end_val}"),
@@ -875,13 +1192,51 @@ impl InstantiationContext<'_, '_> {
                             unreachable!()
                         };
                         *v = Value::Integer(current_val.clone());
+                        self.loop_indices.push(current_val.clone());
                         current_val += 1;
-                        self.instantiate_code_block(stm.loop_body)?;
+                        let body_result = self.instantiate_code_block(stm.loop_body);
+                        self.loop_indices.pop();
+                        body_result?;
                     }
 
                     instruction_range.skip_to(stm.loop_body.1);
                     continue;
                 }
+                Instruction::Assertion(assertion) => {
+                    let condition_expr =
+                        self.link_info.instructions[assertion.condition].unwrap_expression();
+                    match condition_expr.typ.domain {
+                        DomainType::Generative => {
+                            let holds = self
+                                .generation_state
+                                .get_generation_value(assertion.condition)?
+                                .unwrap_bool();
+                            if !holds {
+                                let reason = match assertion.kind {
+                                    AssertionKind::Assert => "Assertion failed",
+                                    AssertionKind::Assume => "Assumption failed",
+                                };
+                                return Err((condition_expr.span, reason.into()));
+                            }
+                        }
+                        DomainType::Physical(domain) => {
+                            let wire = self.instantiate_assertion_wire(
+                                assertion.condition,
+                                domain,
+                                original_instruction,
+                            );
+                            self.assertions.push(RealAssertion {
+                                kind: assertion.kind,
+                                wire,
+                                original_instruction,
+                            });
+                        }
+                        DomainType::Unknown(_) => {
+                            unreachable!("Domain variables have been eliminated by type checking")
+                        }
+                    }
+                    continue;
+                }
             };
             self.generation_state[original_instruction] = instance_to_add;
         }
@@ -889,7 +1244,11 @@ impl InstantiationContext<'_, '_> {
     }
 
     fn make_interface(&mut self) {
-        for (port_id, port) in &self.md.ports {
+        // Constants don't have ports
+        let Some(md) = self.md else {
+            return;
+        };
+        for (port_id, port) in &md.ports {
             let port_decl_id = port.declaration_instruction;
             if let SubModuleOrWire::Wire(wire_id) = &self.generation_state[port_decl_id] {
                 let wire = &self.wires[*wire_id];
@@ -905,7 +1264,7 @@ impl InstantiationContext<'_, '_> {
     }
 
     pub fn execute_module(&mut self) -> ExecutionResult<()> {
-        let result = self.instantiate_code_block(self.md.link_info.instructions.id_range());
+        let result = self.instantiate_code_block(self.link_info.instructions.id_range());
         self.make_interface();
         result
     }
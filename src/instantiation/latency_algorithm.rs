@@ -1,5 +1,3 @@
-use crate::config::config;
-
 use super::list_of_lists::ListOfLists;
 
 /// A wire for which a latency has been specified.
@@ -322,10 +320,6 @@ pub fn solve_latencies(
     outputs: &[usize],
     mut specified_latencies: Vec<SpecifiedLatency>,
 ) -> Result<Vec<i64>, LatencyCountingError> {
-    if config().debug_print_latency_graph {
-        print_latency_test_case(fanins, inputs, outputs, &specified_latencies);
-    }
-
     if fanins.len() == 0 {
         return Ok(Vec::new());
     }
@@ -440,7 +434,7 @@ pub fn solve_latencies(
         .collect())
 }
 
-fn print_latency_test_case(
+pub fn print_latency_test_case(
     fanins: &ListOfLists<FanInOut>,
     inputs: &[usize],
     outputs: &[usize],
@@ -5,7 +5,8 @@ use crate::prelude::*;
 use crate::{
     flattening::{Instruction, WriteModifiers},
     instantiation::latency_algorithm::{
-        convert_fanin_to_fanout, solve_latencies, FanInOut, LatencyCountingError,
+        convert_fanin_to_fanout, print_latency_test_case, solve_latencies, FanInOut,
+        LatencyCountingError,
     },
 };
 
@@ -97,7 +98,8 @@ struct LatencyDomainInfo {
 }
 
 impl RealWireDataSource {
-    fn iter_sources_with_min_latency(&self, mut f: impl FnMut(WireID, i64)) {
+    /// The wires this wire directly depends on, with the number of registers in between. These are the edges of the latency graph.
+    pub fn iter_sources_with_min_latency(&self, mut f: impl FnMut(WireID, i64)) {
         match self {
             RealWireDataSource::ReadOnly => {}
             RealWireDataSource::Multiplexer {
@@ -123,7 +125,21 @@ impl RealWireDataSource {
                 f(*root, 0);
                 RealWirePathElem::for_each_wire_in_path(path, |w| f(w, 0));
             }
-            RealWireDataSource::Constant { value: _ } => {}
+            RealWireDataSource::Concatenation { elements } => {
+                for elem in elements {
+                    f(*elem, 0);
+                }
+            }
+            RealWireDataSource::Conditional {
+                condition,
+                if_true,
+                if_false,
+            } => {
+                f(*condition, 0);
+                f(*if_true, 0);
+                f(*if_false, 0);
+            }
+            RealWireDataSource::Constant { .. } => {}
         }
     }
 }
@@ -137,6 +153,10 @@ impl InstantiatedModule {
 
         for (_id, w) in &self.wires {
             w.source.iter_sources_with_min_latency(|other, _| {
+                // Reading from another domain is an unsafe crossing, which doesn't keep the source wire around longer
+                if self.wires[other].domain != w.domain {
+                    return;
+                }
                 let nu = &mut result[other];
 
                 *nu = max(*nu, w.absolute_latency);
@@ -147,6 +167,38 @@ impl InstantiatedModule {
     }
 }
 
+/// Breadth-first search over `fanins`, such that the loop found is a minimal reproduction. `start` must be part of a loop.
+///
+/// Returns the loop in the direction of the data flow, starting at `start`
+fn shortest_loop_through(
+    fanins: &FlatAlloc<Vec<WireID>, WireIDMarker>,
+    start: WireID,
+) -> Vec<WireID> {
+    let mut reached_from: FlatAlloc<Option<WireID>, WireIDMarker> =
+        FlatAlloc::with_size(fanins.len(), None);
+    let mut queue = std::collections::VecDeque::from([start]);
+    while let Some(wire) = queue.pop_front() {
+        for &from in &fanins[wire] {
+            if reached_from[from].is_some() {
+                continue;
+            }
+            reached_from[from] = Some(wire);
+            if from == start {
+                // Following reached_from goes along the data flow, back to start
+                let mut comb_loop = vec![start];
+                let mut cur = reached_from[start].unwrap();
+                while cur != start {
+                    comb_loop.push(cur);
+                    cur = reached_from[cur].unwrap();
+                }
+                return comb_loop;
+            }
+            queue.push_back(from);
+        }
+    }
+    unreachable!("start must be part of a loop")
+}
+
 impl InstantiationContext<'_, '_> {
     fn make_wire_to_latency_map(&self) -> WireToLatencyMap {
         const PLACEHOLDER: usize = usize::MAX;
@@ -155,7 +207,7 @@ impl InstantiationContext<'_, '_> {
             self.wires.map(|_| PLACEHOLDER);
 
         let mut domain_infos: FlatAlloc<LatencyDomainInfo, DomainIDMarker> =
-            self.md.domains.id_range().map(|domain| {
+            self.md().domains.id_range().map(|domain| {
                 let mut latency_node_meanings = Vec::with_capacity(self.wires.len());
                 let mut initial_values = Vec::new();
 
@@ -193,6 +245,13 @@ impl InstantiationContext<'_, '_> {
             }
             .push(latency_node);
         }
+        // Assertions observe wires like output ports do, such that the wires only they observe are still reached
+        for assertion in &self.assertions {
+            let domain = self.wires[assertion.wire].domain;
+            domain_infos[domain]
+                .output_ports
+                .push(map_wire_to_latency_node[assertion.wire]);
+        }
 
         let mut next_port_chain: FlatAlloc<Option<(WireID, i64)>, WireIDMarker> =
             self.wires.map(|_| None);
@@ -271,7 +330,10 @@ impl InstantiationContext<'_, '_> {
             self.wires[*wire_id]
                 .source
                 .iter_sources_with_min_latency(|from, delta_latency| {
-                    assert_eq!(self.wires[from].domain, domain_id);
+                    // Latencies of different domains are unrelated. Such an unsafe crossing was already warned about in typechecking
+                    if self.wires[from].domain != domain_id {
+                        return;
+                    }
                     fanins.push_to_last_group(FanInOut {
                         other: latency_node_mapper.map_wire_to_latency_node[from],
                         delta_latency,
@@ -289,9 +351,115 @@ impl InstantiationContext<'_, '_> {
         fanins
     }
 
+    /// Reports wires that depend on themselves without a register in between. Writes to `state` wires are registered, so they break such loops.
+    ///
+    /// Loops through submodules are not found, as the connections within a submodule are not known here.
+    ///
+    /// Returns true if any loop was found
+    fn report_combinational_loops(&self) -> bool {
+        let comb_fanins = self.wires.map(|(_id, w)| {
+            let mut fanin = Vec::new();
+            if !matches!(
+                w.source,
+                RealWireDataSource::Multiplexer {
+                    is_state: Some(_),
+                    ..
+                }
+            ) {
+                w.source
+                    .iter_sources_with_min_latency(|from, delta_latency| {
+                        if delta_latency == 0 {
+                            fanin.push(from);
+                        }
+                    });
+            }
+            fanin
+        });
+
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum VisitState {
+            Unvisited,
+            OnStack,
+            Done,
+        }
+        let mut visit_state = self.wires.map(|_| VisitState::Unvisited);
+        let mut is_in_reported_loop = self.wires.map(|_| false);
+        let mut found_any_loop = false;
+
+        for (start, _) in &self.wires {
+            if visit_state[start] != VisitState::Unvisited {
+                continue;
+            }
+            visit_state[start] = VisitState::OnStack;
+            let mut stack: Vec<(WireID, usize)> = vec![(start, 0)];
+            while let Some((wire, fanin_idx)) = stack.last_mut() {
+                let Some(&from) = comb_fanins[*wire].get(*fanin_idx) else {
+                    visit_state[*wire] = VisitState::Done;
+                    stack.pop();
+                    continue;
+                };
+                *fanin_idx += 1;
+                match visit_state[from] {
+                    VisitState::Unvisited => {
+                        visit_state[from] = VisitState::OnStack;
+                        stack.push((from, 0));
+                    }
+                    VisitState::OnStack => {
+                        if !is_in_reported_loop[from] {
+                            let comb_loop = shortest_loop_through(&comb_fanins, from);
+                            for w in &comb_loop {
+                                is_in_reported_loop[*w] = true;
+                            }
+                            self.report_combinational_loop(&comb_loop);
+                            found_any_loop = true;
+                        }
+                    }
+                    VisitState::Done => {}
+                }
+            }
+        }
+
+        found_any_loop
+    }
+
+    /// `comb_loop` is in the direction of the data flow, and doesn't repeat its first wire
+    fn report_combinational_loop(&self, comb_loop: &[WireID]) {
+        let mut path_message = String::new();
+        for w in comb_loop.iter().chain(std::iter::once(&comb_loop[0])) {
+            if !path_message.is_empty() {
+                path_message.push_str(" -> ");
+            }
+            path_message.push_str(&self.wires[*w].name);
+        }
+
+        let first_wire = &self.wires[comb_loop[0]];
+        let err_ref = self.errors.error(
+            self.link_info
+                .get_instruction_span(first_wire.original_instruction),
+            format!(
+                "Combinational loop: '{}' depends on itself without a register in between
+
+{path_message}",
+                first_wire.name
+            ),
+        );
+        for w in &comb_loop[1..] {
+            let wire = &self.wires[*w];
+            err_ref.info_same_file(
+                self.link_info
+                    .get_instruction_span(wire.original_instruction),
+                format!("'{}' is part of the loop", wire.name),
+            );
+        }
+    }
+
     // Returns a proper interface if all ports involved did not produce an error. If a port did produce an error then returns None.
     // Computes all latencies involved
     pub fn compute_latencies(&mut self) {
+        if self.report_combinational_loops() {
+            return; // Latency counting errors on these wires would only distract from the loop
+        }
+
         let mut any_invalid_port = false;
         for (port_id, p) in self.interface_ports.iter_valids() {
             if !p.is_input {
@@ -305,7 +473,7 @@ impl InstantiationContext<'_, '_> {
                 };
                 if sources.is_empty() && port_wire.specified_latency == CALCULATE_LATENCY_LATER {
                     any_invalid_port = true;
-                    let port = &self.md.ports[port_id];
+                    let port = &self.md().ports[port_id];
                     self.errors.error(port.name_span, format!("Pre-emptive error because latency-unspecified '{}' is never written to. \n(This is because work-in-progress code would get a lot of latency counting errors while unfinished)", port.name));
                 }
             }
@@ -326,6 +494,18 @@ impl InstantiationContext<'_, '_> {
             // Process fanouts
             let fanouts = convert_fanin_to_fanout(&fanins);
 
+            if (self.linker.options).should_print_for_debug(
+                self.linker.options.debug_print_latency_graph,
+                &self.link_info.name,
+            ) {
+                print_latency_test_case(
+                    &fanins,
+                    &domain_info.input_ports,
+                    &domain_info.output_ports,
+                    &domain_info.initial_values,
+                );
+            }
+
             match solve_latencies(
                 &fanins,
                 &fanouts,
@@ -340,8 +520,9 @@ impl InstantiationContext<'_, '_> {
                         let wire = &mut self.wires[*node];
                         wire.absolute_latency = *lat;
                         if *lat == CALCULATE_LATENCY_LATER {
-                            let source_location =
-                                self.md.get_instruction_span(wire.original_instruction);
+                            let source_location = self
+                                .link_info
+                                .get_instruction_span(wire.original_instruction);
                             self.errors.error(
                                 source_location,
                                 "Latency Counting couldn't reach this node".to_string(),
@@ -425,7 +606,7 @@ impl InstantiationContext<'_, '_> {
                     writes_involved.last().unwrap().to_latency,
                 );
                 let unique_write_instructions =
-                    filter_unique_write_flats(&writes_involved, &self.md.link_info.instructions);
+                    filter_unique_write_flats(&writes_involved, &self.link_info.instructions);
                 let rest_of_message = format!(" part of a net-positive latency cycle of +{net_roundtrip_latency}\n\n{path_message}\nWhich conflicts with the starting latency");
 
                 let mut did_place_error = false;
@@ -463,7 +644,7 @@ impl InstantiationContext<'_, '_> {
             }
             LatencyCountingError::IndeterminablePortLatency { bad_ports } => {
                 for port in bad_ports {
-                    let port_decl = self.md.link_info.instructions
+                    let port_decl = self.link_info.instructions
                         [self.wires[latency_node_meanings[port.0]].original_instruction]
                         .unwrap_declaration();
                     self.errors.error(port_decl.name_span, format!("Cannot determine port latency. Options are {} and {}\nTry specifying an explicit latency or rework the module to remove this ambiguity", port.1, port.2));
@@ -474,11 +655,11 @@ impl InstantiationContext<'_, '_> {
                     &self.wires[latency_node_meanings[conflict_path.first().unwrap().wire]];
                 let end_wire =
                     &self.wires[latency_node_meanings[conflict_path.last().unwrap().wire]];
-                let start_decl = self.md.link_info.instructions[start_wire.original_instruction]
-                    .unwrap_declaration();
-                let end_decl = self.md.link_info.instructions[end_wire.original_instruction]
+                let start_decl = self.link_info.instructions[start_wire.original_instruction]
                     .unwrap_declaration();
-                let end_latency_decl = self.md.link_info.instructions
+                let end_decl =
+                    self.link_info.instructions[end_wire.original_instruction].unwrap_declaration();
+                let end_latency_decl = self.link_info.instructions
                     [end_decl.latency_specifier.unwrap()]
                 .unwrap_expression();
 
@@ -491,11 +672,49 @@ impl InstantiationContext<'_, '_> {
                 );
                 //assert!(!writes_involved.is_empty());
 
+                let start_name = &start_wire.name;
+                let start_latency = start_wire.specified_latency;
                 let end_name = &end_wire.name;
                 let specified_end_latency = end_wire.specified_latency;
-                self.errors
-                    .error(end_latency_decl.span, format!("Conflicting specified latency\n\n{path_message}\nBut this was specified as {end_name}'{specified_end_latency}"))
-                    .info_obj_same_file(start_decl);
+                let reached_end_latency = conflict_path.last().unwrap().latency;
+                let err_ref = self.errors.error(
+                    end_latency_decl.span,
+                    format!("Conflicting specified latency: '{end_name}' is specified as {end_name}'{specified_end_latency}, but coming from {start_name}'{start_latency} it can't be earlier than {end_name}'{reached_end_latency}\n\n{path_message}\nRemove registers from this path, or specify a later latency for '{end_name}' or an earlier one for '{start_name}'"),
+                );
+                match start_decl.latency_specifier {
+                    Some(start_latency_specifier) => {
+                        let start_latency_expr = self.link_info.instructions
+                            [start_latency_specifier]
+                            .unwrap_expression();
+                        err_ref.info_same_file(
+                            start_latency_expr.span,
+                            format!("'{start_name}' is specified as {start_name}'{start_latency}"),
+                        );
+                    }
+                    None => {
+                        err_ref.info_obj_same_file(start_decl);
+                    }
+                }
+                // Label every write along the path with the latency it forces
+                let mut labeled_writes = Vec::new();
+                for wr in &writes_involved {
+                    let Instruction::Write(original_write) =
+                        &self.link_info.instructions[wr.mux_input.original_connection]
+                    else {
+                        continue;
+                    };
+                    if labeled_writes.contains(&wr.mux_input.original_connection) {
+                        continue;
+                    }
+                    labeled_writes.push(wr.mux_input.original_connection);
+                    err_ref.info_same_file(
+                        original_write.to_span,
+                        format!(
+                            "'{}' is at latency {} at the earliest",
+                            wr.to_wire.name, wr.to_latency
+                        ),
+                    );
+                }
             }
         }
     }
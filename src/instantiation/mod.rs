@@ -3,6 +3,7 @@ mod execute;
 mod latency_algorithm;
 mod latency_count;
 mod list_of_lists;
+pub mod netlist;
 mod unique_names;
 
 use unique_names::UniqueNames;
@@ -11,13 +12,19 @@ use crate::prelude::*;
 use crate::typing::template::TVec;
 use crate::typing::type_inference::{ConcreteTypeVariableIDMarker, TypeSubstitutor};
 
-use std::cell::OnceCell;
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use num::BigInt;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::{Arc, OnceLock, RwLock};
 
-use crate::flattening::{BinaryOperator, Module, UnaryOperator};
+use crate::flattening::{
+    AssertionKind, BinaryOperator, Instruction, Module, NamedConstant, UnaryOperator,
+};
+use crate::linker::LinkInfo;
 use crate::{
-    config,
-    errors::{CompileError, ErrorStore},
+    config::ManglingScheme,
+    debug::stable_hash_64,
+    errors::{CompileError, ErrorInfo, ErrorLevel, ErrorStore, InstantiationFrame},
     to_string::pretty_print_concrete_instance,
     value::Value,
 };
@@ -34,7 +41,16 @@ pub const CALCULATE_LATENCY_LATER: i64 = i64::MIN;
 /// This is the post-instantiation equivalent of [crate::flattening::WireReferencePathElement]
 #[derive(Debug, Clone)]
 pub enum RealWirePathElem {
-    ArrayAccess { span: BracketSpan, idx_wire: WireID },
+    ArrayAccess {
+        span: BracketSpan,
+        idx_wire: WireID,
+    },
+    /// The bounds are known at instantiation time. `high >= low` is checked while executing
+    ArraySlice {
+        span: BracketSpan,
+        high: usize,
+        low: usize,
+    },
 }
 
 impl RealWirePathElem {
@@ -44,6 +60,7 @@ impl RealWirePathElem {
                 RealWirePathElem::ArrayAccess { span: _, idx_wire } => {
                     f(*idx_wire);
                 }
+                RealWirePathElem::ArraySlice { .. } => {}
             }
         }
     }
@@ -84,8 +101,20 @@ pub enum RealWireDataSource {
         root: WireID,
         path: Vec<RealWirePathElem>,
     },
+    /// See [crate::flattening::ExpressionSource::Concatenation]
+    Concatenation {
+        elements: Vec<WireID>,
+    },
+    /// See [crate::flattening::ExpressionSource::Conditional]
+    Conditional {
+        condition: WireID,
+        if_true: WireID,
+        if_false: WireID,
+    },
     Constant {
         value: Value,
+        /// The width of a sized literal like `8'hFF`, see [crate::flattening::ExpressionSource::Literal]
+        width: Option<u64>,
     },
 }
 
@@ -127,7 +156,7 @@ pub struct SubModulePort {
 #[derive(Debug)]
 pub struct SubModule {
     pub original_instruction: FlatID,
-    pub instance: OnceCell<Rc<InstantiatedModule>>,
+    pub instance: OnceLock<Arc<InstantiatedModule>>,
     pub port_map: FlatAlloc<Option<SubModulePort>, PortIDMarker>,
     pub interface_call_sites: FlatAlloc<Vec<Span>, InterfaceIDMarker>,
     pub name: String,
@@ -164,6 +193,17 @@ pub struct InstantiatedModule {
     pub submodules: FlatAlloc<SubModule, SubModuleIDMarker>,
     /// See [GenerationState]
     pub generation_state: FlatAlloc<SubModuleOrWire, FlatIDMarker>,
+    /// The runtime `assert(cond)`s and `assume(cond)`s, in source order
+    pub assertions: Vec<RealAssertion>,
+}
+
+/// A runtime `assert(cond)` or `assume(cond)` of an [InstantiatedModule], see [crate::flattening::Assertion]
+#[derive(Debug)]
+pub struct RealAssertion {
+    pub kind: AssertionKind,
+    /// A `bool` wire that is `true` whenever the assertion holds. Includes the `when` conditions the assertion is in, like `!c | cond`
+    pub wire: WireID,
+    pub original_instruction: FlatID,
 }
 
 /// See [GenerationState]
@@ -207,7 +247,7 @@ impl SubModuleOrWire {
 /// Also, with incremental builds (#49) this will be a prime area for investigation
 #[derive(Debug)]
 pub struct InstantiationCache {
-    cache: RefCell<HashMap<TVec<ConcreteType>, Rc<InstantiatedModule>>>,
+    cache: RwLock<HashMap<TVec<ConcreteType>, Arc<InstantiatedModule>>>,
 }
 
 impl Default for InstantiationCache {
@@ -219,17 +259,20 @@ impl Default for InstantiationCache {
 impl InstantiationCache {
     pub fn new() -> Self {
         Self {
-            cache: RefCell::new(HashMap::new()),
+            cache: RwLock::new(HashMap::new()),
         }
     }
 
+    /// `instantiation_stack` is the chain of submodule instantiations leading up to this one, see [InstantiationFrame].
+    /// It is only used when the instance is not yet cached.
     pub fn instantiate(
         &self,
         md: &Module,
         linker: &Linker,
         template_args: TVec<ConcreteType>,
-    ) -> Option<Rc<InstantiatedModule>> {
-        let cache_borrow = self.cache.borrow();
+        instantiation_stack: &[InstantiationFrame],
+    ) -> Option<Arc<InstantiatedModule>> {
+        let cache_borrow = self.cache.read().unwrap();
 
         // Temporary, no template arguments yet
         let instance = if let Some(found) = cache_borrow.get(&template_args) {
@@ -237,22 +280,38 @@ impl InstantiationCache {
         } else {
             std::mem::drop(cache_borrow);
 
-            let result = perform_instantiation(md, linker, &template_args);
+            let mut result = perform_instantiation(md, linker, &template_args, instantiation_stack);
 
-            if config().should_print_for_debug(config().debug_print_module_contents, &result.name) {
-                println!("[[Instantiated {}]]", result.name);
+            // Readable names drop some characters of the template arguments, so different arguments could end up with the same name
+            if self
+                .cache
+                .read()
+                .unwrap()
+                .values()
+                .any(|existing| existing.mangled_name == result.mangled_name)
+            {
+                result.mangled_name = hash_mangled_name(&result.mangled_name, &result.name);
+            }
+
+            if (linker.options).should_print_for_debug(
+                linker.options.debug_print_module_contents,
+                &md.link_info.name,
+            ) {
+                let mut text = format!("[[Instantiated {}]]\n", result.name);
                 for (id, w) in &result.wires {
-                    println!("{id:?} -> {w:?}");
+                    writeln!(text, "{id:?} -> {w:?}").unwrap();
                 }
                 for (id, sm) in &result.submodules {
-                    println!("SubModule {id:?}: {sm:?}");
+                    writeln!(text, "SubModule {id:?}: {sm:?}").unwrap();
                 }
+                print!("{}", linker.stabilize_debug_text(&text));
             }
 
-            let result_ref = Rc::new(result);
+            let result_ref = Arc::new(result);
             assert!(self
                 .cache
-                .borrow_mut()
+                .write()
+                .unwrap()
                 .insert(template_args, result_ref.clone())
                 .is_none());
             result_ref
@@ -266,7 +325,7 @@ impl InstantiationCache {
     }
 
     pub fn for_each_error(&self, func: &mut impl FnMut(&CompileError)) {
-        let cache_borrow = self.cache.borrow();
+        let cache_borrow = self.cache.read().unwrap();
         for inst in cache_borrow.values() {
             for err in &inst.errors {
                 func(err)
@@ -275,29 +334,261 @@ impl InstantiationCache {
     }
 
     pub fn clear_instances(&mut self) {
-        self.cache.borrow_mut().clear()
+        self.cache.get_mut().unwrap().clear()
+    }
+
+    /// The cached instance for these template arguments, valid or not
+    pub fn get(&self, template_args: &TVec<ConcreteType>) -> Option<Arc<InstantiatedModule>> {
+        self.cache.read().unwrap().get(template_args).cloned()
     }
 
     // Also passes over invalid instances. Instance validity should not be assumed!
     // Only used for things like syntax highlighting
     pub fn for_each_instance(
         &self,
-        mut f: impl FnMut(&TVec<ConcreteType>, &Rc<InstantiatedModule>),
+        mut f: impl FnMut(&TVec<ConcreteType>, &Arc<InstantiatedModule>),
     ) {
-        let borrow = self.cache.borrow();
+        let borrow = self.cache.read().unwrap();
         for (k, v) in borrow.iter() {
             f(k, v)
         }
     }
 }
 
+/// The values of a user-defined constant for each set of template arguments it was evaluated with, like [InstantiationCache] for modules.
+///
+/// Only successful evaluations are kept. Errors name the place the constant is used, so they are made anew each time
+#[derive(Debug, Default)]
+pub struct ConstantValueCache {
+    cache: RwLock<HashMap<TVec<ConcreteType>, Value>>,
+}
+
+impl ConstantValueCache {
+    pub fn get(&self, template_args: &TVec<ConcreteType>) -> Option<Value> {
+        self.cache.read().unwrap().get(template_args).cloned()
+    }
+
+    fn insert(&self, template_args: TVec<ConcreteType>, value: Value) {
+        self.cache.write().unwrap().insert(template_args, value);
+    }
+
+    /// Constants can depend on each other, so all of them are cleared whenever any file changes
+    pub fn clear(&mut self) {
+        self.cache.get_mut().unwrap().clear()
+    }
+}
+
+impl Linker {
+    /// For each set of template arguments the given module is instantiated with, how many times it is instantiated project-wide.
+    ///
+    /// Counts the submodule instantiations in all instances. Instances that aren't a submodule of anything (top-level modules) count once.
+    ///
+    /// Returns an empty list for modules that aren't instantiated.
+    pub fn instantiation_summary(&self, module: ModuleUUID) -> Vec<(TVec<ConcreteType>, usize)> {
+        let mut counts: HashMap<TVec<ConcreteType>, usize> = HashMap::new();
+        for (_, md) in &self.modules {
+            md.instantiations.for_each_instance(|_, inst| {
+                for (_, sm) in &inst.submodules {
+                    if sm.module_uuid == module {
+                        *counts.entry(sm.template_args.clone()).or_default() += 1;
+                    }
+                }
+            });
+        }
+        self.modules[module]
+            .instantiations
+            .for_each_instance(|template_args, _| {
+                counts.entry(template_args.clone()).or_insert(1);
+            });
+
+        let mut result: Vec<_> = counts.into_iter().collect();
+        result.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        result
+    }
+
+    /// The ports of `module` when instantiated with `template_args`, for tools like simulator harnesses.
+    ///
+    /// Instantiates the module if it wasn't yet (reusing the instantiation cache).
+    /// If the instantiation fails, returns the errors that caused it.
+    pub fn module_interface(
+        &self,
+        module: ModuleUUID,
+        template_args: &TVec<ConcreteType>,
+    ) -> Result<Vec<PortInfo>, Vec<CompileError>> {
+        let md = &self.modules[module];
+        let instance = self.instantiate_or_errors(md, template_args)?;
+
+        Ok(instance
+            .interface_ports
+            .iter()
+            .filter_map(|(port_id, port)| {
+                let port = port.as_ref()?;
+                Some(PortInfo {
+                    name: md.ports[port_id].name.clone(),
+                    is_input: port.is_input,
+                    bit_width: port.typ.sizeof().and_then(|size| u64::try_from(size).ok()),
+                    absolute_latency: port.absolute_latency,
+                })
+            })
+            .collect())
+    }
+
+    /// Instantiate the module named `name`, and the submodules it uses, without instantiating any unrelated modules.
+    ///
+    /// Like `--standalone`, but returns the instance instead of generating code. Requires the linker to have been run up to [crate::config::EarlyExitUpTo::Lint].
+    /// Template parameters have no defaults, so modules with template parameters can't be compiled this way.
+    pub fn compile_module(
+        &self,
+        name: &str,
+    ) -> Result<Arc<InstantiatedModule>, CompileModuleError> {
+        let Some((_, md)) = self
+            .modules
+            .iter()
+            .find(|(_, md)| md.link_info.name == name)
+        else {
+            return Err(CompileModuleError::UnknownModule(name.to_owned()));
+        };
+        if !md.link_info.template_parameters.is_empty() {
+            let infos = md
+                .link_info
+                .template_parameters
+                .iter()
+                .map(|(_, param)| ErrorInfo {
+                    position: param.name_span,
+                    file: md.link_info.file,
+                    info: format!("'{}' has no default value", param.name),
+                })
+                .collect();
+            return Err(CompileModuleError::Errors(vec![CompileError {
+                position: md.link_info.name_span,
+                reason: format!(
+                    "'{name}' can't be compiled on its own, because it requires template arguments"
+                ),
+                infos,
+                level: ErrorLevel::Error,
+                instantiation_stack: Vec::new(),
+            }]));
+        }
+
+        self.instantiate_or_errors(md, &FlatAlloc::new())
+            .map_err(CompileModuleError::Errors)
+    }
+
+    /// Instantiates the module if it wasn't yet (reusing the instantiation cache). If the instantiation fails, returns the errors that caused it.
+    fn instantiate_or_errors(
+        &self,
+        md: &Module,
+        template_args: &TVec<ConcreteType>,
+    ) -> Result<Arc<InstantiatedModule>, Vec<CompileError>> {
+        check_template_arg_count(md, template_args)?;
+
+        md.instantiations
+            .instantiate(md, self, template_args.clone(), &[]);
+        let instance = md.instantiations.get(template_args).unwrap();
+
+        if instance.errors.did_error {
+            let mut errors: Vec<CompileError> = instance.errors.into_iter().cloned().collect();
+            // Modules with flattening or typechecking errors aren't instantiated at all
+            if errors.is_empty() {
+                errors = md.link_info.errors.into_iter().cloned().collect();
+            }
+            return Err(errors);
+        }
+        Ok(instance)
+    }
+
+    /// Evaluate the generative expression or declaration `expr` of `module`, for tools like an interactive "what's `clog2(DEPTH)`?" query.
+    ///
+    /// Only runs the generative code of the module with the given `template_args`, no typechecking or latency counting is done.
+    /// Evaluation errors, such as division by zero, are the same as those of a real instantiation.
+    pub fn eval_generative(
+        &self,
+        module: ModuleUUID,
+        expr: FlatID,
+        template_args: &TVec<ConcreteType>,
+    ) -> Result<Value, Vec<CompileError>> {
+        let md = &self.modules[module];
+        check_template_arg_count(md, template_args)?;
+        if md.link_info.errors.did_error {
+            return Err(md.link_info.errors.into_iter().cloned().collect());
+        }
+
+        let mut context = InstantiationContext::new(md, self, template_args, &[]);
+        if let Err((span, reason)) = context.execute_module() {
+            context.errors.error(span, reason);
+        }
+        let instance = context.extract();
+        if instance.errors.did_error {
+            return Err(instance.errors.into_iter().cloned().collect());
+        }
+
+        match &instance.generation_state[expr] {
+            SubModuleOrWire::CompileTimeValue(value) => Ok(value.clone()),
+            _ => {
+                let position = match &md.link_info.instructions[expr] {
+                    Instruction::Declaration(decl) => decl.name_span,
+                    Instruction::Expression(expression) => expression.span,
+                    _ => md.link_info.name_span,
+                };
+                Err(vec![CompileError {
+                    position,
+                    reason: "This is not a generative value".to_owned(),
+                    infos: Vec::new(),
+                    level: ErrorLevel::Error,
+                    instantiation_stack: Vec::new(),
+                }])
+            }
+        }
+    }
+}
+
+fn check_template_arg_count(
+    md: &Module,
+    template_args: &TVec<ConcreteType>,
+) -> Result<(), Vec<CompileError>> {
+    if template_args.len() != md.link_info.template_parameters.len() {
+        return Err(vec![CompileError {
+            position: md.link_info.name_span,
+            reason: format!(
+                "'{}' has {} template parameters, but {} template arguments were given",
+                md.link_info.name,
+                md.link_info.template_parameters.len(),
+                template_args.len()
+            ),
+            infos: Vec::new(),
+            level: ErrorLevel::Error,
+            instantiation_stack: Vec::new(),
+        }]);
+    }
+    Ok(())
+}
+
+/// Why [Linker::compile_module] failed
+#[derive(Debug)]
+pub enum CompileModuleError {
+    /// There is no module with this name
+    UnknownModule(String),
+    /// The module or one of its submodules has errors, or it requires template arguments
+    Errors(Vec<CompileError>),
+}
+
+/// A port of an instantiated module, see [Linker::module_interface]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortInfo {
+    pub name: String,
+    pub is_input: bool,
+    /// [None] if the size of the port's type can't be determined
+    pub bit_width: Option<u64>,
+    pub absolute_latency: i64,
+}
+
 /// Every [crate::flattening::Instruction] has an associated value (See [SubModuleOrWire]).
 /// They are either what this local name is currently referencing (either a wire instance or a submodule instance).
 /// Or in the case of Generative values, the current value in the generative variable.
 #[derive(Debug)]
 struct GenerationState<'fl> {
     generation_state: FlatAlloc<SubModuleOrWire, FlatIDMarker>,
-    md: &'fl Module,
+    link_info: &'fl LinkInfo,
 }
 
 /// Runtime conditions applied to a [crate::flattening::Write]
@@ -323,76 +614,156 @@ struct InstantiationContext<'fl, 'l> {
     generation_state: GenerationState<'fl>,
     wires: FlatAlloc<RealWire, WireIDMarker>,
     submodules: FlatAlloc<SubModule, SubModuleIDMarker>,
+    assertions: Vec<RealAssertion>,
 
     type_substitutor: TypeSubstitutor<ConcreteType, ConcreteTypeVariableIDMarker>,
 
     // Used for Execution
     unique_name_producer: UniqueNames,
     condition_stack: Vec<ConditionStackElem>,
+    /// The current iteration of each generative for loop around the code being executed, outermost first.
+    /// Named submodules in loops are named after it, like `x_0_1`
+    loop_indices: Vec<BigInt>,
 
     interface_ports: FlatAlloc<Option<InstantiatedPort>, PortIDMarker>,
     errors: ErrorCollector<'l>,
 
+    /// The chain of submodule instantiations that led to this one. Attached to all errors, see [InstantiationFrame]
+    instantiation_stack: Vec<InstantiationFrame>,
+    /// The user-defined constants being evaluated, innermost last. Evaluating one of these again with the same arguments is a circular reference
+    constant_stack: Vec<(ConstantUUID, TVec<ConcreteType>)>,
+
     template_args: &'fl TVec<ConcreteType>,
-    md: &'fl Module,
+    link_info: &'fl LinkInfo,
+    /// [None] when evaluating a [NamedConstant]
+    md: Option<&'fl Module>,
     linker: &'l Linker,
 }
 
-/// Mangle the module name for use in code generation
-fn mangle_name(str: &str) -> String {
-    let mut result = String::with_capacity(str.len());
-    for c in str.chars() {
-        if c.is_whitespace() || c == ':' {
-            continue;
+/// Mangle the instance name for use in code generation, see [ManglingScheme]
+pub fn mangle_name(scheme: ManglingScheme, md_name: &str, instance_name: &str) -> String {
+    match scheme {
+        ManglingScheme::Readable => {
+            let mut result = String::with_capacity(instance_name.len());
+            for c in instance_name.chars() {
+                if c.is_whitespace() || c == ':' {
+                    continue;
+                }
+                result.push(if c.is_alphanumeric() { c } else { '_' });
+            }
+            result
         }
-        result.push(if c.is_alphanumeric() { c } else { '_' });
+        ManglingScheme::Hash => hash_mangled_name(md_name, instance_name),
     }
-    result
+}
+
+fn hash_mangled_name(md_name: &str, instance_name: &str) -> String {
+    format!("{md_name}_{:016x}", stable_hash_64(instance_name))
 }
 
 impl InstantiationContext<'_, '_> {
     fn extract(self) -> InstantiatedModule {
+        let mut errors = self.errors.into_storage();
+        errors.set_instantiation_stack(&self.instantiation_stack);
+        let mut mangled_name = mangle_name(
+            self.linker.options.mangling,
+            &self.link_info.name,
+            &self.name,
+        );
+        // An `override` module has the same name as the module it shadows, which may be generated too
+        if self.link_info.is_override {
+            let file_identifier = &self.linker.files[self.link_info.file].file_identifier;
+            mangled_name =
+                hash_mangled_name(&mangled_name, &format!("{file_identifier} {}", self.name));
+        }
         InstantiatedModule {
-            mangled_name: mangle_name(&self.name),
+            mangled_name,
             name: self.name,
             wires: self.wires,
             submodules: self.submodules,
             interface_ports: self.interface_ports,
             generation_state: self.generation_state.generation_state,
-            errors: self.errors.into_storage(),
+            assertions: self.assertions,
+            errors,
+        }
+    }
+}
+
+impl<'fl, 'l> InstantiationContext<'fl, 'l> {
+    fn new(
+        md: &'fl Module,
+        linker: &'l Linker,
+        template_args: &'fl TVec<ConcreteType>,
+        instantiation_stack: &[InstantiationFrame],
+    ) -> Self {
+        let mut context = Self::new_for_link_info(&md.link_info, linker, template_args);
+        context.interface_ports = md.ports.map(|_| None);
+        context.instantiation_stack = instantiation_stack.to_vec();
+        context.md = Some(md);
+        context
+    }
+
+    /// Constants are only executed, see [Self::md]. `constant_stack` includes `cst` itself
+    fn new_for_constant(
+        cst: &'fl NamedConstant,
+        linker: &'l Linker,
+        template_args: &'fl TVec<ConcreteType>,
+        constant_stack: Vec<(ConstantUUID, TVec<ConcreteType>)>,
+    ) -> Self {
+        let mut context = Self::new_for_link_info(&cst.link_info, linker, template_args);
+        context.constant_stack = constant_stack;
+        context
+    }
+
+    fn new_for_link_info(
+        link_info: &'fl LinkInfo,
+        linker: &'l Linker,
+        template_args: &'fl TVec<ConcreteType>,
+    ) -> Self {
+        InstantiationContext {
+            name: pretty_print_concrete_instance(link_info, template_args, &linker.types),
+            generation_state: GenerationState {
+                link_info,
+                generation_state: link_info
+                    .instructions
+                    .map(|(_, _)| SubModuleOrWire::Unnasigned),
+            },
+            type_substitutor: TypeSubstitutor::new(),
+            condition_stack: Vec::new(),
+            loop_indices: Vec::new(),
+            wires: FlatAlloc::new(),
+            submodules: FlatAlloc::new(),
+            assertions: Vec::new(),
+            interface_ports: FlatAlloc::new(),
+            errors: ErrorCollector::new_empty(link_info.file, &linker.files),
+            unique_name_producer: UniqueNames::new(),
+            instantiation_stack: Vec::new(),
+            constant_stack: Vec::new(),
+            template_args,
+            link_info,
+            md: None,
+            linker,
         }
     }
+
+    /// Only modules have ports and domains, and only modules get typechecked and latency counted
+    fn md(&self) -> &'fl Module {
+        self.md
+            .expect("Only modules are instantiated past execution, constants are just evaluated")
+    }
 }
 
 fn perform_instantiation(
     md: &Module,
     linker: &Linker,
     template_args: &TVec<ConcreteType>,
+    instantiation_stack: &[InstantiationFrame],
 ) -> InstantiatedModule {
-    let mut context = InstantiationContext {
-        name: pretty_print_concrete_instance(&md.link_info, template_args, &linker.types),
-        generation_state: GenerationState {
-            md,
-            generation_state: md
-                .link_info
-                .instructions
-                .map(|(_, _)| SubModuleOrWire::Unnasigned),
-        },
-        type_substitutor: TypeSubstitutor::new(),
-        condition_stack: Vec::new(),
-        wires: FlatAlloc::new(),
-        submodules: FlatAlloc::new(),
-        interface_ports: md.ports.map(|_| None),
-        errors: ErrorCollector::new_empty(md.link_info.file, &linker.files),
-        unique_name_producer: UniqueNames::new(),
-        template_args,
-        md,
-        linker,
-    };
+    let mut context = InstantiationContext::new(md, linker, template_args, instantiation_stack);
 
     // Don't instantiate modules that already errored. Otherwise instantiator may crash
     if md.link_info.errors.did_error {
-        println!(
+        log::info!(
             "Not Instantiating {} due to flattening errors",
             md.link_info.name
         );
@@ -400,7 +771,7 @@ fn perform_instantiation(
         return context.extract();
     }
 
-    println!("Instantiating {}", md.link_info.name);
+    log::info!("Instantiating {}", md.link_info.name);
 
     if let Err(e) = context.execute_module() {
         context.errors.error(e.0, e.1);
@@ -408,20 +779,24 @@ fn perform_instantiation(
         return context.extract();
     }
 
-    if config().should_print_for_debug(config().debug_print_module_contents, &context.name) {
-        println!("[[Executed {}]]", &context.name);
+    if (linker.options).should_print_for_debug(
+        linker.options.debug_print_module_contents,
+        &md.link_info.name,
+    ) {
+        let mut text = format!("[[Executed {}]]\n", &context.name);
         for (id, w) in &context.wires {
-            println!("{id:?} -> {w:?}");
+            writeln!(text, "{id:?} -> {w:?}").unwrap();
         }
         for (id, sm) in &context.submodules {
-            println!("SubModule {id:?}: {sm:?}");
+            writeln!(text, "SubModule {id:?}: {sm:?}").unwrap();
         }
+        print!("{}", linker.stabilize_debug_text(&text));
     }
 
-    println!("Concrete Typechecking {}", md.link_info.name);
+    log::info!("Concrete Typechecking {}", md.link_info.name);
     context.typecheck();
 
-    println!("Latency Counting {}", md.link_info.name);
+    log::info!("Latency Counting {}", md.link_info.name);
     context.compute_latencies();
 
     context.extract()
@@ -0,0 +1,166 @@
+//! The connectivity of an [InstantiatedModule] as a graph of nets, for tools like routers that don't want to walk [RealWireDataSource] themselves.
+//!
+//! Every [super::RealWire] of the instance is one [Net]. Each net has exactly one [NetDriver], and any number of [NetSink]s.
+
+use num::ToPrimitive;
+
+use crate::flattening::Module;
+use crate::prelude::*;
+use crate::typing::concrete_type::ConcreteType;
+use crate::typing::template::TVec;
+
+use super::InstantiatedModule;
+
+/// What puts a value on a [Net]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetDriver {
+    /// An input port of the instantiated module itself
+    Port(PortID),
+    /// An output port of a submodule
+    SubModulePort {
+        submodule: SubModuleID,
+        port: PortID,
+    },
+    /// Logic of this module: an operator, a selection, a multiplexer or a register. Constants have no inputs.
+    ///
+    /// The inputs are the nets it is computed from, in declaration order and without duplicates
+    Logic { inputs: Vec<WireID> },
+}
+
+/// What reads a [Net]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetSink {
+    /// An output port of the instantiated module itself
+    Port(PortID),
+    /// An input port of a submodule
+    SubModulePort {
+        submodule: SubModuleID,
+        port: PortID,
+    },
+    /// The [NetDriver::Logic] of another net
+    Net(WireID),
+}
+
+#[derive(Debug, Clone)]
+pub struct Net {
+    pub name: String,
+    pub typ: ConcreteType,
+    /// The size of [Self::typ] in bits, if it is known
+    pub width: Option<u64>,
+    pub driver: NetDriver,
+    /// In the order the ports and wires are declared
+    pub sinks: Vec<NetSink>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetlistPort {
+    pub name: String,
+    pub is_input: bool,
+    pub net: WireID,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetlistSubModule {
+    pub name: String,
+    pub module_uuid: ModuleUUID,
+    /// The [InstantiatedModule::name] of the submodule instance, which includes its template arguments
+    pub instance_name: String,
+}
+
+/// The nets of one [InstantiatedModule], see [Linker::netlist]
+#[derive(Debug, Clone)]
+pub struct Netlist {
+    /// The [InstantiatedModule::name] of the instance
+    pub name: String,
+    pub nets: FlatAlloc<Net, WireIDMarker>,
+    /// The ports that are part of this instance
+    pub ports: FlatAlloc<Option<NetlistPort>, PortIDMarker>,
+    pub submodules: FlatAlloc<NetlistSubModule, SubModuleIDMarker>,
+}
+
+impl Netlist {
+    /// `md` must be the [crate::flattening::Module] that `instance` is an instance of
+    pub fn of_instance(instance: &InstantiatedModule, md: &Module, linker: &Linker) -> Self {
+        let mut nets = instance.wires.map(|(_id, w)| {
+            let mut inputs = Vec::new();
+            w.source
+                .iter_sources_with_min_latency(|from, _num_regs| inputs.push(from));
+            inputs.sort_by_key(|w| w.get_hidden_value());
+            inputs.dedup();
+            Net {
+                name: w.name.clone(),
+                typ: w.typ.clone(),
+                width: w.typ.sizeof().and_then(|sz| sz.to_u64()),
+                driver: NetDriver::Logic { inputs },
+                sinks: Vec::new(),
+            }
+        });
+
+        for (port, p) in instance.interface_ports.iter_valids() {
+            if p.is_input {
+                nets[p.wire].driver = NetDriver::Port(port);
+            } else {
+                nets[p.wire].sinks.push(NetSink::Port(port));
+            }
+        }
+        for (submodule, sm) in &instance.submodules {
+            let sm_md = &linker.modules[sm.module_uuid];
+            for (port, sm_port) in sm.port_map.iter_valids() {
+                let net = &mut nets[sm_port.maps_to_wire];
+                if sm_md.ports[port].is_input {
+                    net.sinks.push(NetSink::SubModulePort { submodule, port });
+                } else {
+                    net.driver = NetDriver::SubModulePort { submodule, port };
+                }
+            }
+        }
+        for (wire_id, _) in &instance.wires {
+            if let NetDriver::Logic { inputs } = nets[wire_id].driver.clone() {
+                for input in inputs {
+                    nets[input].sinks.push(NetSink::Net(wire_id));
+                }
+            }
+        }
+
+        let ports = instance.interface_ports.map(|(port, p)| {
+            p.as_ref().map(|p| NetlistPort {
+                name: md.ports[port].name.clone(),
+                is_input: p.is_input,
+                net: p.wire,
+            })
+        });
+        let submodules = instance.submodules.map(|(_id, sm)| NetlistSubModule {
+            name: sm.name.clone(),
+            module_uuid: sm.module_uuid,
+            instance_name: sm
+                .instance
+                .get()
+                .map(|inst| inst.name.clone())
+                .unwrap_or_default(),
+        });
+
+        Netlist {
+            name: instance.name.clone(),
+            nets,
+            ports,
+            submodules,
+        }
+    }
+}
+
+impl Linker {
+    /// The [Netlist] of `module` instantiated with `template_args`. Instantiates it if it wasn't yet.
+    ///
+    /// Returns `None` if the instance has errors. The module must have been compiled at least up to [crate::config::EarlyExitUpTo::Lint]
+    pub fn netlist(
+        &self,
+        module: ModuleUUID,
+        template_args: TVec<ConcreteType>,
+    ) -> Option<Netlist> {
+        let md = &self.modules[module];
+        let instance = md
+            .instantiations
+            .instantiate(md, self, template_args, &[])?;
+        Some(Netlist::of_instance(&instance, md, self))
+    }
+}
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::codegen::keywords::SYSTEM_VERILOG_KEYWORDS;
+
 /// Generates ascending IDs for locals, while keeping the name information as much as possible.
 ///
 /// For example, when generating multiple names for the string "beep" it returns:
@@ -7,6 +9,9 @@ use std::collections::HashMap;
 /// - beep_2
 /// - beep_3
 /// - ...
+///
+/// Generated names never collide with each other, even if the source already uses a name like "beep_2",
+/// and never collide with a SystemVerilog keyword (`reg` becomes `reg_1`)
 pub struct UniqueNames {
     name_map: HashMap<String, i64>,
 }
@@ -15,18 +20,47 @@ impl UniqueNames {
     pub fn new() -> Self {
         let mut name_map: HashMap<String, i64> = HashMap::new();
         name_map.insert(String::new(), 1);
+        for keyword in SYSTEM_VERILOG_KEYWORDS {
+            name_map.insert((*keyword).to_owned(), 1);
+        }
         Self { name_map }
     }
     pub fn get_unique_name<S: Into<String> + AsRef<str>>(&mut self, name: S) -> String {
         let name_ref = name.as_ref();
-        if let Some(found_id) = self.name_map.get_mut(name_ref) {
-            let result = format!("{name_ref}_{found_id}");
-            *found_id += 1;
+        let result = if let Some(found_id) = self.name_map.get(name_ref) {
+            let mut id = *found_id;
+            let mut result = format!("{name_ref}_{id}");
+            while self.name_map.contains_key(&result) {
+                id += 1;
+                result = format!("{name_ref}_{id}");
+            }
+            self.name_map.insert(name_ref.to_owned(), id + 1);
             result
         } else {
-            let result: String = name.into();
-            self.name_map.insert(result.clone(), 2);
-            result
-        }
+            name.into()
+        };
+        self.name_map.insert(result.clone(), 2);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UniqueNames;
+
+    #[test]
+    fn test_unique_names() {
+        let mut names = UniqueNames::new();
+        assert_eq!(names.get_unique_name("beep"), "beep");
+        assert_eq!(names.get_unique_name("beep"), "beep_2");
+        // Already generated for the second "beep"
+        assert_eq!(names.get_unique_name("beep_2"), "beep_2_2");
+        assert_eq!(names.get_unique_name("boop_2"), "boop_2");
+        assert_eq!(names.get_unique_name("boop"), "boop");
+        assert_eq!(names.get_unique_name("boop"), "boop_3");
+        assert_eq!(names.get_unique_name(""), "_1");
+        assert_eq!(names.get_unique_name(""), "_2");
+        assert_eq!(names.get_unique_name("reg"), "reg_1");
+        assert_eq!(names.get_unique_name("reg"), "reg_2");
     }
 }
@@ -0,0 +1,31 @@
+#![doc = include_str!("../README.md")]
+
+pub mod alloc;
+pub mod block_vector;
+
+pub mod config;
+pub mod debug;
+pub mod errors;
+pub mod file_position;
+pub mod flattening;
+pub mod instantiation;
+pub mod prelude;
+pub mod to_string;
+pub mod typing;
+pub mod value;
+
+pub mod codegen;
+
+pub mod dev_aid;
+pub mod linker;
+
+pub mod compiler_top;
+
+use prelude::*;
+
+use dev_aid::ariadne_interface::*;
+use flattening::Module;
+use instantiation::InstantiatedModule;
+
+pub use compiler_top::{compile_sources, CompileResult, Compiler};
+pub use config::CompilerOptions;
@@ -0,0 +1,130 @@
+//! Per-file feature gates, enabled with `#![feature(name, ...)]` pragmas at the top of a file.
+//!
+//! Experimental constructs are only allowed in files that enable their [Feature], such that code that doesn't opt in isn't affected by their in-progress semantics.
+//!
+//! The grammar doesn't know about pragmas. They are read from the text before parsing, and replaced by spaces for tree-sitter, such that all spans stay the same.
+//! Pragmas may only be preceded by empty lines and `//` comments.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    /// `assert(cond)` and `assume(cond)` statements, see [crate::flattening::Assertion]
+    AssertStatements,
+}
+
+impl Feature {
+    pub const ALL: &'static [Feature] = &[Feature::AssertStatements];
+
+    /// The name used in `#![feature(name)]`
+    pub fn name(self) -> &'static str {
+        match self {
+            Feature::AssertStatements => "assert_statements",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Feature> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|feature| feature.name() == name)
+    }
+}
+
+/// A `#![...]` line at the top of a file, see [find_pragmas]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pragma {
+    /// The byte range of the whole pragma
+    pub range: Range<usize>,
+    /// The byte ranges of the names between the parentheses of `#![feature(...)]`. `None` if the pragma isn't a `#![feature(...)]`
+    pub feature_names: Option<Vec<Range<usize>>>,
+}
+
+/// Finds the `#![...]` pragmas at the top of `text`
+pub fn find_pragmas(text: &str) -> Vec<Pragma> {
+    let mut pragmas = Vec::new();
+    let mut line_start = 0;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end();
+        let indent = content.len() - content.trim_start().len();
+        let content = content.trim_start();
+        let start = line_start + indent;
+        line_start += line.len();
+
+        if content.is_empty() || content.starts_with("//") {
+            continue;
+        }
+        if !content.starts_with("#![") {
+            break;
+        }
+        let feature_names = content
+            .strip_prefix("#![feature(")
+            .and_then(|rest| rest.strip_suffix(")]"))
+            .map(|names| {
+                let names_start = start + "#![feature(".len();
+                let mut name_ranges = Vec::new();
+                let mut offset = 0;
+                for name in names.split(',') {
+                    let trimmed = name.trim();
+                    if !trimmed.is_empty() {
+                        let name_start =
+                            names_start + offset + (name.len() - name.trim_start().len());
+                        name_ranges.push(name_start..name_start + trimmed.len());
+                    }
+                    offset += name.len() + 1;
+                }
+                name_ranges
+            });
+        pragmas.push(Pragma {
+            range: start..start + content.len(),
+            feature_names,
+        });
+    }
+    pragmas
+}
+
+/// `text` with all `pragmas` replaced by spaces, to be given to tree-sitter
+pub fn blank_out_pragmas<'t>(text: &'t str, pragmas: &[Pragma]) -> Cow<'t, str> {
+    if pragmas.is_empty() {
+        return Cow::Borrowed(text);
+    }
+    let mut bytes = text.as_bytes().to_vec();
+    for pragma in pragmas {
+        bytes[pragma.range.clone()].fill(b' ');
+    }
+    // Pragmas are replaced as a whole, so no multi-byte character is cut in half
+    Cow::Owned(String::from_utf8(bytes).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blank_out_pragmas, find_pragmas, Feature};
+
+    #[test]
+    fn test_find_pragmas() {
+        let text = "// A file\n\n  #![feature(assert_statements, arrays )]\n#![inline]\nmodule m {}\n#![feature(late)]\n";
+        let pragmas = find_pragmas(text);
+        assert_eq!(pragmas.len(), 2);
+
+        let names: Vec<&str> = (pragmas[0].feature_names.as_ref().unwrap().iter())
+            .map(|range| &text[range.clone()])
+            .collect();
+        assert_eq!(names, ["assert_statements", "arrays"]);
+        assert_eq!(
+            &text[pragmas[0].range.clone()],
+            "#![feature(assert_statements, arrays )]"
+        );
+        assert_eq!(
+            Feature::from_name(names[0]),
+            Some(Feature::AssertStatements)
+        );
+        assert_eq!(Feature::from_name(names[1]), None);
+        assert_eq!(pragmas[1].feature_names, None);
+
+        let blanked = blank_out_pragmas(text, &pragmas);
+        assert_eq!(blanked.len(), text.len());
+        assert!(blanked.starts_with("// A file\n\n  "));
+        assert!(blanked.ends_with("      \nmodule m {}\n#![feature(late)]\n"));
+    }
+}
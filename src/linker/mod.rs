@@ -1,14 +1,20 @@
 use crate::{
+    codegen::sorted_instances,
+    config::{CompilerOptions, EarlyExitUpTo},
     flattening::{Instruction, NamedConstant},
     prelude::*,
+    typing::abstract_type::DomainType,
     typing::template::{
         GenerativeParameterKind, Parameter, ParameterKind, TVec, TypeParameterKind,
     },
 };
 
 pub mod checkpoint;
+pub mod features;
 mod resolver;
+pub mod tokens;
 use arrayvec::ArrayVec;
+use rayon::prelude::*;
 pub use resolver::*;
 
 use std::{
@@ -16,15 +22,17 @@ use std::{
     collections::{HashMap, HashSet},
 };
 
-use tree_sitter::Tree;
+use tree_sitter::{Node, Tree};
 
 use crate::{alloc::ArenaAllocator, file_position::FileText, flattening::Module};
 
+use crate::dev_aid::timings::Timings;
 use crate::errors::{CompileError, ErrorInfo, ErrorLevel, ErrorStore};
 
 use crate::flattening::{StructType, TypingAllocator};
 
 use self::checkpoint::CheckPoint;
+use self::features::Feature;
 
 /// Documentation can be attached to [Module], [StructType], [NamedConstant], [crate::flattening::Declaration]
 #[derive(Debug, Clone)]
@@ -40,7 +48,8 @@ impl Documentation {
         }
         let mut result = String::with_capacity(total_length);
         for s in self.gathered.iter() {
-            result.push_str(&file_text[*s]);
+            // Leave out the '\r' of CRLF line endings
+            result.extend(file_text[*s].chars().filter(|c| *c != '\r'));
             result.push('\n');
         }
         result
@@ -74,6 +83,50 @@ pub const AFTER_INITIAL_PARSE_CP: usize = 0;
 pub const AFTER_FLATTEN_CP: usize = 1;
 pub const AFTER_TYPECHECK_CP: usize = 2;
 pub const AFTER_LINTS_CP: usize = 3;
+pub const AFTER_UNUSED_MODULES_CP: usize = 4;
+
+/// The signature of a [Module], for tools like documentation generators. See [Linker::all_modules]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleInfo {
+    pub id: ModuleUUID,
+    pub name: String,
+    pub file: FileUUID,
+    pub documentation: String,
+    pub template_parameters: Vec<TemplateParameterInfo>,
+    pub ports: Vec<PortSignature>,
+}
+
+/// See [ModuleInfo]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateParameterInfo {
+    pub name: String,
+    /// The type of a value parameter as written, like `int`. [None] for type parameters
+    pub value_type: Option<String>,
+}
+
+/// See [ModuleInfo]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortSignature {
+    pub name: String,
+    pub is_input: bool,
+    /// The type as written, like `int[DEPTH]`
+    pub typ: String,
+    pub domain: String,
+}
+
+/// The type of a declaration or expression, see [Linker::type_at]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeInfo {
+    /// The name of the declaration, or the whole expression, that covers the queried span
+    pub span: Span,
+    /// Like `::int[]` or `T`
+    pub abstract_type: String,
+    /// The name of the domain, or `gen` for compile-time values. [None] if it isn't known
+    pub domain: Option<String>,
+    /// Like `::int[3]`, for each wire that the instances of the module generated for it.
+    /// Empty for compile-time values, and for modules without instances
+    pub concrete_types: Vec<String>,
+}
 
 /// Represents any global. Stored in [Linker] and each is uniquely indexed by [GlobalUUID]
 ///
@@ -88,6 +141,8 @@ pub struct LinkInfo {
     pub errors: ErrorStore,
     pub resolved_globals: ResolvedGlobals,
     pub is_extern: IsExtern,
+    /// Declared with `override`, so it shadows the builtin or standard library declaration of the same name. Only within its own file, see [LinkInfo::is_visible_from]
+    pub is_override: bool,
 
     /// Created in Stage 2: Flattening
     ///
@@ -101,16 +156,34 @@ pub struct LinkInfo {
 
     /// Reset checkpoints. These are to reset errors and resolved_globals for incremental compilation.
     ///
-    /// TODO the system is there, just need to actually do incremental compilation (#49)
+    /// [Linker::recompile_up_to] resets unchanged globals only up to their [Linker::reusable_checkpoint], instead of reflattening them (#49)
     ///
-    /// Right now it already functions as a sanity check, to make sure no steps in building modules/types are skipped
-    pub checkpoints: ArrayVec<CheckPoint, 4>,
+    /// It also functions as a sanity check, to make sure no steps in building modules/types are skipped
+    pub checkpoints: ArrayVec<CheckPoint, 5>,
 }
 
 impl LinkInfo {
     pub fn get_full_name(&self) -> String {
         format!("::{}", self.name)
     }
+    /// `override` declarations only exist for the file they're declared in. Everything else is visible everywhere
+    pub fn is_visible_from(&self, file: FileUUID) -> bool {
+        !self.is_override || self.file == file
+    }
+    pub fn get_instruction_span(&self, instr_id: FlatID) -> Span {
+        match &self.instructions[instr_id] {
+            Instruction::SubModule(sm) => sm.module_ref.get_total_span(),
+            Instruction::FuncCall(fc) => fc.whole_func_span,
+            Instruction::Declaration(decl) => decl.decl_span,
+            Instruction::Expression(w) => w.span,
+            Instruction::Write(conn) => conn.to_span,
+            Instruction::IfStatement(if_stmt) => self.get_instruction_span(if_stmt.condition),
+            Instruction::ForStatement(for_stmt) => {
+                self.get_instruction_span(for_stmt.loop_var_decl)
+            }
+            Instruction::Assertion(assertion) => assertion.span,
+        }
+    }
     pub fn get_span_file(&self) -> SpanFile {
         (self.name_span, self.file)
     }
@@ -136,10 +209,16 @@ impl LinkInfo {
 pub struct FileData {
     pub file_identifier: String,
     pub file_text: FileText,
+    /// [crate::debug::stable_hash_64] of the text, such that setting a file to the text it already has can be skipped cheaply
+    pub content_hash: u64,
     pub parsing_errors: ErrorStore,
+    /// Enabled with `#![feature(...)]` pragmas, see [features]
+    pub enabled_features: Vec<Feature>,
     /// In source file order
     pub associated_values: Vec<GlobalUUID>,
     pub tree: tree_sitter::Tree,
+    /// Set for the files added by [Linker::add_standard_library]
+    pub is_standard_library: bool,
 }
 
 /// Globally references any [Module], [StructType], or [NamedConstant] in [Linker]
@@ -212,6 +291,13 @@ pub struct Linker {
     pub constants: ArenaAllocator<NamedConstant, ConstantUUIDMarker>,
     pub files: ArenaAllocator<FileData, FileUUIDMarker>,
     global_namespace: HashMap<String, NamespaceElement>,
+    /// The stage the last [Self::recompile_up_to] ran up to.
+    ///
+    /// Instantiation errors are only reported if instantiation was part of it, even if instances were created on demand afterwards
+    pub reached_stage: EarlyExitUpTo,
+    /// How long each stage of the last [Self::recompile_up_to] took. Only measured with `--timings`
+    pub timings: Option<Timings>,
+    pub options: CompilerOptions,
 }
 
 impl Default for Linker {
@@ -221,13 +307,21 @@ impl Default for Linker {
 }
 
 impl Linker {
+    /// A linker with the default [CompilerOptions]
     pub fn new() -> Linker {
+        Self::with_options(CompilerOptions::default())
+    }
+
+    pub fn with_options(options: CompilerOptions) -> Linker {
         Linker {
             types: ArenaAllocator::new(),
             modules: ArenaAllocator::new(),
             constants: ArenaAllocator::new(),
             files: ArenaAllocator::new(),
             global_namespace: HashMap::new(),
+            reached_stage: EarlyExitUpTo::Initialize,
+            timings: None,
+            options,
         }
     }
 
@@ -250,11 +344,78 @@ impl Linker {
             GlobalUUID::Constant(cst_id) => &mut constants[cst_id].link_info,
         }
     }
+    /// Runs `f` on every item of `work` in parallel, with read access to the [Linker]. Used by the passes that work on each global separately, like flattening and typechecking.
+    ///
+    /// The results come back in the order of `work`, so applying them one by one keeps the diagnostics the same regardless of thread scheduling.
+    ///
+    /// `f` should only read the data of the global it works on, and the headers (ports, parameters, flattened instructions) of other globals, so its result doesn't depend on which items ran first.
+    pub fn par_map<W: Send, R: Send>(
+        &self,
+        work: Vec<W>,
+        f: impl Fn(&Linker, W) -> R + Sync,
+    ) -> Vec<R> {
+        work.into_par_iter().map(|w| f(self, w)).collect()
+    }
+    /// `override` declarations may only shadow builtins and the declarations of the standard library
+    fn is_override_target(&self, info: &LinkInfo) -> bool {
+        !info.is_override
+            && (info.is_extern == IsExtern::Builtin || self.files[info.file].is_standard_library)
+    }
+    /// Whether both can't be told apart by name. An `override` declaration doesn't conflict with the target it shadows, nor with the `override`s of other files
+    fn conflicts_with(&self, a: &LinkInfo, b: &LinkInfo) -> bool {
+        match (a.is_override, b.is_override) {
+            (false, false) => true,
+            (true, true) => a.file == b.file,
+            (true, false) => !self.is_override_target(b),
+            (false, true) => !self.is_override_target(a),
+        }
+    }
+    /// Which of the `colliding` globals the name refers to from `file`, if `override` declarations settle it.
+    ///
+    /// An `override` declaration of `file` wins over the builtin it shadows, and those of other files aren't visible at all
+    fn resolve_override(&self, colliding: &[GlobalUUID], file: FileUUID) -> Option<GlobalUUID> {
+        let visible: Vec<GlobalUUID> = (colliding.iter().copied())
+            .filter(|global| self.get_link_info(*global).is_visible_from(file))
+            .collect();
+        let local_overrides: Vec<GlobalUUID> = (visible.iter().copied())
+            .filter(|global| self.get_link_info(*global).is_override)
+            .collect();
+        match (local_overrides.as_slice(), visible.as_slice()) {
+            ([found], _) | ([], [found]) => Some(*found),
+            _ => None,
+        }
+    }
     fn for_all_duplicate_declaration_errors(
         &self,
         file_uuid: FileUUID,
         f: &mut impl FnMut(&CompileError),
     ) {
+        // 'override' without anything to shadow
+        for global in &self.files[file_uuid].associated_values {
+            let info = self.get_link_info(*global);
+            if !info.is_override {
+                continue;
+            }
+            let has_target = match self.global_namespace.get(&info.name) {
+                Some(NamespaceElement::Colission(colission)) => (colission.iter())
+                    .any(|other| self.is_override_target(self.get_link_info(*other))),
+                _ => false,
+            };
+            if !has_target {
+                f(&CompileError {
+                    position: info.name_span,
+                    reason: format!(
+                        "'{}' is declared 'override', but there is no builtin or standard library declaration {} to shadow",
+                        info.name,
+                        info.get_full_name()
+                    ),
+                    infos: Vec::new(),
+                    level: ErrorLevel::Error,
+                    instantiation_stack: Vec::new(),
+                });
+            }
+        }
+
         // Conflicting Declarations
         for item in &self.global_namespace {
             let NamespaceElement::Colission(colission) = &item.1 else {
@@ -267,14 +428,42 @@ impl Linker {
                 if info.file != file_uuid {
                     continue;
                 }
+                let this_object_name = &info.name;
+                let shadowed_target = infos.iter().find(|other| self.is_override_target(other));
+                if let (true, Some(target)) = (info.is_override, shadowed_target) {
+                    let (target_kind, declared_here) = if target.is_extern == IsExtern::Builtin {
+                        ("builtin", "Builtin declared here")
+                    } else {
+                        (
+                            "standard library's",
+                            "Declared in the standard library here",
+                        )
+                    };
+                    f(&CompileError {
+                        position: info.name_span,
+                        reason: format!(
+                            "'{this_object_name}' shadows the {target_kind} {} within this file",
+                            target.get_full_name()
+                        ),
+                        infos: vec![ErrorInfo {
+                            position: target.name_span,
+                            file: target.file,
+                            info: declared_here.to_owned(),
+                        }],
+                        level: ErrorLevel::Warning,
+                        instantiation_stack: Vec::new(),
+                    });
+                }
                 let mut conflict_infos = Vec::new();
                 for (idx_2, conflicts_with) in infos.iter().enumerate() {
-                    if idx_2 == idx {
+                    if idx_2 == idx || !self.conflicts_with(info, conflicts_with) {
                         continue;
                     }
                     conflict_infos.push(conflicts_with);
                 }
-                let this_object_name = &info.name;
+                if conflict_infos.is_empty() {
+                    continue;
+                }
                 let infos = conflict_infos
                     .iter()
                     .map(|conf_info| ErrorInfo {
@@ -284,13 +473,26 @@ impl Linker {
                     })
                     .collect();
 
-                let reason = format!("'{this_object_name}' conflicts with other declarations:");
+                let shadows_builtin = info.is_extern != IsExtern::Builtin
+                    && conflict_infos
+                        .iter()
+                        .any(|conf_info| conf_info.is_extern == IsExtern::Builtin);
+                let involves_override = info.is_override
+                    || conflict_infos.iter().any(|conf_info| conf_info.is_override);
+                let reason = if shadows_builtin {
+                    format!("Cannot redeclare the builtin '{this_object_name}'. Declare it as 'override' to shadow the builtin within this file")
+                } else if involves_override {
+                    format!("'{this_object_name}' conflicts with other declarations. 'override' can only shadow builtins and standard library declarations:")
+                } else {
+                    format!("'{this_object_name}' conflicts with other declarations:")
+                };
 
                 f(&CompileError {
                     position: info.name_span,
                     reason,
                     infos,
                     level: ErrorLevel::Error,
+                    instantiation_stack: Vec::new(),
                 });
             }
         }
@@ -303,19 +505,37 @@ impl Linker {
     ) {
         for v in &self.files[file_uuid].associated_values {
             match v {
-                GlobalUUID::Module(md_id) => {
-                    let md = &self.modules[*md_id];
-                    for e in &md.link_info.errors {
+                GlobalUUID::Module(md_id) => self.for_all_errors_of_module(*md_id, func),
+                GlobalUUID::Type(_) => {}
+                GlobalUUID::Constant(cst_id) => {
+                    for e in &self.constants[*cst_id].link_info.errors {
                         func(e)
                     }
-                    md.instantiations.for_each_error(func);
                 }
-                GlobalUUID::Type(_) => {}
-                GlobalUUID::Constant(_) => {}
             }
         }
     }
 
+    fn for_all_errors_of_module(&self, md_id: ModuleUUID, func: &mut impl FnMut(&CompileError)) {
+        let md = &self.modules[md_id];
+        for e in &md.link_info.errors {
+            func(e)
+        }
+        if self.reached_stage >= EarlyExitUpTo::Instantiate {
+            md.instantiations.for_each_error(func);
+        }
+    }
+
+    /// The errors and warnings of a single module, for showing a problem badge per module.
+    ///
+    /// Includes the flattening, typechecking and lint diagnostics of the module, and those of its instances once instantiated.
+    /// Unlike [Self::for_all_errors_in_file], parsing errors and the diagnostics of other globals in the same file are left out.
+    pub fn diagnostics_for_module(&self, md_id: ModuleUUID) -> Vec<CompileError> {
+        let mut diagnostics = Vec::new();
+        self.for_all_errors_of_module(md_id, &mut |err| diagnostics.push(err.clone()));
+        diagnostics
+    }
+
     pub fn for_all_errors_in_file(&self, file_uuid: FileUUID, mut f: impl FnMut(&CompileError)) {
         for err in &self.files[file_uuid].parsing_errors {
             f(err);
@@ -324,6 +544,267 @@ impl Linker {
         self.for_all_errors_after_compile(file_uuid, &mut f);
     }
 
+    /// Calls `f` once per file with all of its errors and warnings, as soon as they are gathered.
+    ///
+    /// Files without diagnostics are included too, such that stale diagnostics can be cleared.
+    pub fn for_each_file_diagnostics(&self, mut f: impl FnMut(FileUUID, Vec<CompileError>)) {
+        for (file_uuid, _f) in &self.files {
+            let mut diagnostics = Vec::new();
+            self.for_all_errors_in_file(file_uuid, |err| diagnostics.push(err.clone()));
+            f(file_uuid, diagnostics);
+        }
+    }
+
+    /// Gathers all errors and warnings of all files, in the order they would be printed. Does not render them.
+    pub fn collect_all_diagnostics(&self) -> Vec<(FileUUID, CompileError)> {
+        let mut all_diagnostics = Vec::new();
+        self.for_each_file_diagnostics(|file_uuid, diagnostics| {
+            all_diagnostics.extend(diagnostics.into_iter().map(|err| (file_uuid, err)));
+        });
+        all_diagnostics
+    }
+
+    /// The smallest named tree-sitter node containing `offset`, for "what's under the cursor" queries.
+    ///
+    /// When `offset` lies on the boundary between two nodes, the node starting at `offset` is returned.
+    /// Returns [None] for offsets past the end of the file.
+    pub fn node_at_offset(&self, file: FileUUID, offset: usize) -> Option<Node<'_>> {
+        let file_data = &self.files[file];
+        if offset > file_data.file_text.len() {
+            return None;
+        }
+        file_data
+            .tree
+            .root_node()
+            .named_descendant_for_byte_range(offset, offset)
+    }
+
+    /// The other files whose globals are referenced by the globals of `file`, in order of first reference.
+    ///
+    /// Builtins are excluded, as is `file` itself.
+    pub fn file_dependencies(&self, file: FileUUID) -> Vec<FileUUID> {
+        let mut dependencies = Vec::new();
+        for global in &self.files[file].associated_values {
+            let link_info = self.get_link_info(*global);
+            for referenced in link_info.resolved_globals.referenced_globals() {
+                let referenced_info = self.get_link_info(*referenced);
+                if referenced_info.is_extern == IsExtern::Builtin
+                    || referenced_info.file == file
+                    || dependencies.contains(&referenced_info.file)
+                {
+                    continue;
+                }
+                dependencies.push(referenced_info.file);
+            }
+        }
+        dependencies
+    }
+
+    /// The reverse of [Self::file_dependencies]: all files that reference a global of `file`.
+    pub fn files_depending_on(&self, file: FileUUID) -> Vec<FileUUID> {
+        self.files
+            .iter()
+            .map(|(other_file, _)| other_file)
+            .filter(|other_file| {
+                *other_file != file && self.file_dependencies(*other_file).contains(&file)
+            })
+            .collect()
+    }
+
+    /// The files of `global`, and of all globals it references directly or through other globals. Generated code for `global` has to be remade when any of them change.
+    ///
+    /// Unlike [Self::file_dependencies], this includes the standard library files that declare builtins. The file of `global` comes first.
+    pub fn transitive_source_files(&self, global: GlobalUUID) -> Vec<FileUUID> {
+        let mut files = Vec::new();
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![global];
+        while let Some(global) = to_visit.pop() {
+            if !visited.insert(global) {
+                continue;
+            }
+            let link_info = self.get_link_info(global);
+            if !files.contains(&link_info.file) {
+                files.push(link_info.file);
+            }
+            to_visit.extend_from_slice(link_info.resolved_globals.referenced_globals());
+        }
+        files
+    }
+
+    /// The checkpoint up to which the results of `global` stay valid as long as neither it nor its dependencies change.
+    /// Modules and constants are typechecked, types are only flattened.
+    pub fn reusable_checkpoint(global: GlobalUUID) -> usize {
+        match global {
+            GlobalUUID::Module(_) | GlobalUUID::Constant(_) => AFTER_TYPECHECK_CP,
+            GlobalUUID::Type(_) => AFTER_FLATTEN_CP,
+        }
+    }
+
+    /// The globals that [Linker::recompile_up_to] doesn't have to flatten and typecheck again, see [Self::reusable_checkpoint].
+    ///
+    /// The globals of edited files are removed and added anew, so they never reached any checkpoint past [AFTER_INITIAL_PARSE_CP].
+    /// Any other global that did can be kept, if all names it used resolved, and if all globals it references can be kept as well
+    /// and still own their name. A newly added global of the same name would have made that name ambiguous.
+    pub fn find_reusable_globals(&self) -> HashSet<GlobalUUID> {
+        let all_globals = (self.modules.iter().map(|(id, _)| GlobalUUID::Module(id)))
+            .chain(self.types.iter().map(|(id, _)| GlobalUUID::Type(id)))
+            .chain(
+                self.constants
+                    .iter()
+                    .map(|(id, _)| GlobalUUID::Constant(id)),
+            );
+        let mut reusable: HashSet<GlobalUUID> = all_globals
+            .filter(|global| {
+                let link_info = self.get_link_info(*global);
+                link_info.checkpoints.len() > Self::reusable_checkpoint(*global)
+                    && link_info.resolved_globals.all_resolved()
+            })
+            .collect();
+
+        // Invalidation spreads through references, so repeat until nothing changes anymore
+        loop {
+            let num_reusable = reusable.len();
+            let still_reusable: HashSet<GlobalUUID> = reusable
+                .iter()
+                .copied()
+                .filter(|global| {
+                    let link_info = self.get_link_info(*global);
+                    link_info
+                        .resolved_globals
+                        .referenced_globals()
+                        .iter()
+                        .all(|referenced| {
+                            reusable.contains(referenced)
+                                && matches!(
+                                    self.global_namespace.get(&self.get_link_info(*referenced).name),
+                                    Some(NamespaceElement::Global(owner)) if owner == referenced
+                                )
+                        })
+                })
+                .collect();
+            reusable = still_reusable;
+            if reusable.len() == num_reusable {
+                return reusable;
+            }
+        }
+    }
+
+    /// The signatures of all modules, including those of the standard library. Doesn't require instantiation,
+    /// so modules with template parameters are listed too. Requires flattening, such as by [Self::recompile_all]
+    pub fn all_modules(&self) -> impl Iterator<Item = ModuleInfo> + '_ {
+        self.modules.iter().map(|(id, md)| {
+            let file_text = &self.files[md.link_info.file].file_text;
+            let written_type_of = |decl_id: FlatID| {
+                let decl = md.link_info.instructions[decl_id].unwrap_declaration();
+                file_text[decl.typ_expr.get_span()].to_owned()
+            };
+            ModuleInfo {
+                id,
+                name: md.link_info.name.clone(),
+                file: md.link_info.file,
+                documentation: md.link_info.documentation.to_string(file_text),
+                template_parameters: md
+                    .link_info
+                    .template_parameters
+                    .iter()
+                    .map(|(_, param)| TemplateParameterInfo {
+                        name: param.name.clone(),
+                        value_type: match &param.kind {
+                            ParameterKind::Type(_) => None,
+                            ParameterKind::Generative(kind) => {
+                                Some(written_type_of(kind.declaration_instruction))
+                            }
+                        },
+                    })
+                    .collect(),
+                ports: md
+                    .ports
+                    .iter()
+                    .map(|(_, port)| PortSignature {
+                        name: port.name.clone(),
+                        is_input: port.is_input,
+                        typ: written_type_of(port.declaration_instruction),
+                        domain: md.domains[port.domain].name.clone(),
+                    })
+                    .collect(),
+            }
+        })
+    }
+
+    /// The type of the innermost declaration name or expression in `file` that covers `span`. This includes wires, ports,
+    /// uses of constants and template arguments that are values. Requires typechecking, such as by [Self::recompile_all].
+    ///
+    /// Returns [None] if nothing typed covers `span`, or if its type isn't known (yet).
+    pub fn type_at(&self, file: FileUUID, span: Span) -> Option<TypeInfo> {
+        let query = span.as_range();
+        let covers = |outer: Span| {
+            let outer = outer.as_range();
+            outer.start <= query.start && query.end <= outer.end
+        };
+
+        let mut innermost: Option<(GlobalUUID, FlatID, Span)> = None;
+        for global in &self.files[file].associated_values {
+            let link_info = self.get_link_info(*global);
+            if !covers(link_info.span) {
+                continue;
+            }
+            for (id, instr) in &link_info.instructions {
+                let instr_span = match instr {
+                    Instruction::Declaration(decl) => decl.name_span,
+                    Instruction::Expression(expr) => expr.span,
+                    _ => continue,
+                };
+                if covers(instr_span)
+                    && !innermost.is_some_and(|(_, _, found)| found.size() <= instr_span.size())
+                {
+                    innermost = Some((*global, id, instr_span));
+                }
+            }
+        }
+        let (global, id, span) = innermost?;
+
+        let link_info = self.get_link_info(global);
+        let typ = match &link_info.instructions[id] {
+            Instruction::Declaration(decl) => &decl.typ,
+            Instruction::Expression(expr) => &expr.typ,
+            _ => unreachable!(),
+        };
+        if typ.typ.contains_unknown() {
+            return None;
+        }
+        let md = match global {
+            GlobalUUID::Module(md_id) => Some(&self.modules[md_id]),
+            GlobalUUID::Type(_) | GlobalUUID::Constant(_) => None,
+        };
+        let domain = match typ.domain {
+            DomainType::Generative => Some("gen".to_owned()),
+            DomainType::Physical(domain_id) => md
+                .and_then(|md| md.domains.get(domain_id))
+                .map(|domain| domain.name.clone()),
+            DomainType::Unknown(_) => None,
+        };
+        let mut concrete_types = Vec::new();
+        if let Some(md) = md {
+            for inst in sorted_instances(md) {
+                for (_, wire) in &inst.wires {
+                    if wire.original_instruction == id {
+                        concrete_types.push(wire.typ.display(&self.types).to_string());
+                    }
+                }
+            }
+        }
+
+        Some(TypeInfo {
+            span,
+            abstract_type: typ
+                .typ
+                .display(&self.types, &link_info.template_parameters)
+                .to_string(),
+            domain,
+            concrete_types,
+        })
+    }
+
     pub fn remove_everything_in_file(&mut self, file_uuid: FileUUID) -> &mut FileData {
         // For quick lookup if a reference disappears
         let mut to_remove_set = HashSet::new();
@@ -354,7 +835,7 @@ impl Linker {
                     std::mem::replace::<Box<[GlobalUUID]>>(colission, Box::new([])).into_vec();
                 retain_vec.retain(|g| !to_remove_set.contains(g));
                 *colission = retain_vec.into_boxed_slice();
-                colission.len() > 0
+                !colission.is_empty()
             }
         });
 
@@ -369,8 +850,7 @@ impl Linker {
 
     pub fn with_file_builder(&mut self, file_id: FileUUID, f: impl FnOnce(FileBuilder<'_>)) {
         let mut associated_values = Vec::new();
-        let mut parsing_errors =
-            std::mem::replace(&mut self.files[file_id].parsing_errors, ErrorStore::new());
+        let mut parsing_errors = std::mem::take(&mut self.files[file_id].parsing_errors);
         let file_data = &self.files[file_id];
         let other_parsing_errors =
             ErrorCollector::from_storage(parsing_errors.take(), file_id, &self.files);
@@ -388,6 +868,14 @@ impl Linker {
             constants: &mut self.constants,
         });
 
+        // Such as skeleton files created by an editor. If the file failed to parse, the parse errors already explain it.
+        if associated_values.is_empty() && !other_parsing_errors.did_error() {
+            other_parsing_errors.warn(
+                Span::from(0..0),
+                "File contains no module/type/constant declarations",
+            );
+        }
+
         let parsing_errors = other_parsing_errors.into_storage();
         let file_data = &mut self.files[file_id];
         file_data.parsing_errors = parsing_errors;
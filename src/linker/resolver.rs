@@ -25,6 +25,13 @@ impl ResolvedGlobals {
     pub fn take(&mut self) -> ResolvedGlobals {
         std::mem::replace(self, ResolvedGlobals::empty())
     }
+    pub fn referenced_globals(&self) -> &[GlobalUUID] {
+        &self.referenced_globals
+    }
+    /// False if any name failed to resolve, or resolved ambiguously
+    pub fn all_resolved(&self) -> bool {
+        self.all_resolved
+    }
     pub fn is_untouched(&self) -> bool {
         self.referenced_globals.is_empty() && self.all_resolved
     }
@@ -123,6 +130,10 @@ impl<'linker> GlobalResolver<'linker> {
                 Some(*found)
             }
             Some(NamespaceElement::Colission(coll)) => {
+                if let Some(found) = self.linker.resolve_override(coll, self.errors.file) {
+                    resolved_globals.referenced_globals.push(found);
+                    return Some(found);
+                }
                 resolved_globals.all_resolved = false;
 
                 let err_ref = self.errors.error(name_span, format!("There were colliding imports for the name '{name}'. Pick one and import it by name."));
@@ -140,18 +151,64 @@ impl<'linker> GlobalResolver<'linker> {
             None => {
                 resolved_globals.all_resolved = false;
 
-                self.errors.error(
-                    name_span,
-                    format!(
-                        "No Global of the name '{name}' was found. Did you forget to import it?"
-                    ),
-                );
+                if let Some(suggestion) = self.closest_global(name) {
+                    let err_loc = self.get_linking_error_location(suggestion);
+                    let in_other_file = if err_loc.location.1 != self.errors.file {
+                        ", declared in another file"
+                    } else {
+                        ""
+                    };
+                    self.errors
+                        .error(
+                            name_span,
+                            format!(
+                                "No Global of the name '{name}' was found. Did you mean the {} {}{in_other_file}?",
+                                err_loc.named_type, err_loc.full_name
+                            ),
+                        )
+                        .info(
+                            err_loc.location,
+                            format!("{} {} declared here", err_loc.named_type, err_loc.full_name),
+                        );
+                } else {
+                    self.errors.error(
+                        name_span,
+                        format!(
+                            "No Global of the name '{name}' was found. Did you forget to import it?"
+                        ),
+                    );
+                }
 
                 None
             }
         }
     }
 
+    /// The global with the name closest to `name`, for "Did you mean" suggestions. Capitalization differences are ignored.
+    ///
+    /// Only names within an edit distance of a third of the length of `name` count as close, so short names like `z` don't suggest unrelated globals.
+    fn closest_global(&self, name: &str) -> Option<GlobalUUID> {
+        let name_lower = name.to_lowercase();
+        let max_distance = name.chars().count() / 3;
+        self.linker
+            .global_namespace
+            .iter()
+            .filter_map(|(candidate, elem)| match elem {
+                NamespaceElement::Global(global) => Some((candidate, *global)),
+                NamespaceElement::Colission(_) => None,
+            })
+            .map(|(candidate, global)| {
+                let distance = edit_distance(&name_lower, &candidate.to_lowercase());
+                (distance, candidate, global)
+            })
+            .filter(|(distance, _, _)| *distance <= max_distance)
+            // The namespace is a HashMap, so ties are broken by name to keep the suggestion stable
+            .min_by(|(dist_a, name_a, _), (dist_b, name_b, _)| {
+                dist_a.cmp(dist_b).then_with(|| name_a.cmp(name_b))
+            })
+            .map(|(_, _, global)| global)
+    }
+
     pub fn not_expected_global_error<ID: Copy>(
         &self,
         global_ref: &GlobalReference<ID>,
@@ -234,3 +291,19 @@ impl LinkInfo {
             .push(CheckPoint::new(&self.errors, &self.resolved_globals));
     }
 }
+
+/// The Levenshtein distance between `a` and `b`, in chars
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = Vec::with_capacity(b.len() + 1);
+        row.push(i + 1);
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = prev_row[j] + (a_char != *b_char) as usize;
+            row.push(substitution.min(prev_row[j + 1] + 1).min(row[j] + 1));
+        }
+        prev_row = row;
+    }
+    prev_row[b.len()]
+}
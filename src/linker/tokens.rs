@@ -0,0 +1,77 @@
+//! A flat stream of the tokens in a file, for syntax highlighting outside of the LSP.
+
+use tree_sitter::TreeCursor;
+
+use crate::prelude::*;
+
+use super::Linker;
+
+/// The lexical kind of a token, as returned by [Linker::tokens]
+///
+/// This is independent of the tree-sitter grammar, such that it remains stable when the grammar changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// `module`, `reg`, `if`, `interface`, etc
+    Keyword,
+    Identifier,
+    Number,
+    /// `+`, `==`, `->`, `=`, etc
+    Operator,
+    /// Brackets, `,`, `:`, `::`, `'`, `#(`
+    Punctuation,
+    Comment,
+    /// Text the parser couldn't make sense of
+    Invalid,
+}
+
+impl TokenKind {
+    fn from_leaf_kind(kind: &str, is_named: bool) -> TokenKind {
+        match kind {
+            "identifier" => TokenKind::Identifier,
+            "number" => TokenKind::Number,
+            "single_line_comment" | "multi_line_comment" => TokenKind::Comment,
+            "(" | ")" | "{" | "}" | "[" | "]" | "," | ":" | "::" | "'" | "#(" => {
+                TokenKind::Punctuation
+            }
+            _ if !is_named && kind.starts_with(|c: char| c.is_alphabetic() || c == '_') => {
+                TokenKind::Keyword
+            }
+            _ if !is_named => TokenKind::Operator,
+            _ => TokenKind::Invalid,
+        }
+    }
+}
+
+fn collect_leaves(cursor: &mut TreeCursor, result: &mut Vec<(TokenKind, Span)>) {
+    let node = cursor.node();
+    if node.child_count() == 0 {
+        let range = node.byte_range();
+        // Missing nodes are inserted by error recovery, and don't correspond to any text. Newlines aren't highlighted
+        if node.is_missing() || range.is_empty() || node.kind() == "\n" {
+            return;
+        }
+        let kind = if node.is_error() {
+            TokenKind::Invalid
+        } else {
+            TokenKind::from_leaf_kind(node.kind(), node.is_named())
+        };
+        result.push((kind, Span::from(range)));
+    } else if cursor.goto_first_child() {
+        loop {
+            collect_leaves(cursor, result);
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+        cursor.goto_parent();
+    }
+}
+
+impl Linker {
+    /// All tokens of the file in source order, with their [TokenKind]. Whitespace and newlines are not included.
+    pub fn tokens(&self, file: FileUUID) -> impl Iterator<Item = (TokenKind, Span)> {
+        let mut result = Vec::new();
+        collect_leaves(&mut self.files[file].tree.walk(), &mut result);
+        result.into_iter()
+    }
+}
@@ -1,82 +1,92 @@
-#![doc = include_str!("../README.md")]
-
-mod alloc;
-mod block_vector;
-
-mod config;
-mod debug;
-mod errors;
-mod file_position;
-mod flattening;
-mod instantiation;
-mod prelude;
-mod to_string;
-mod typing;
-mod value;
-
-mod codegen;
-
-mod dev_aid;
-mod linker;
-
-mod compiler_top;
-
 use std::error::Error;
 use std::io::Write;
 
-use prelude::*;
+use sus_compiler::config::config;
+use sus_compiler::dev_aid::logger::init_logger;
+use sus_compiler::Compiler;
 
-use codegen::{CodeGenBackend, VHDLCodegenBackend, VerilogCodegenBackend};
-use config::{config, EarlyExitUpTo};
-use dev_aid::ariadne_interface::*;
-use flattening::Module;
-use instantiation::InstantiatedModule;
+/// Exit code when no files were given, and the current directory has no `.sus` files either.
+///
+/// Distinct from 1 for compile errors and 2 for invalid arguments, such that scripts can tell an empty project apart
+const NOTHING_TO_DO_EXIT_CODE: i32 = 3;
 
 fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
     let config = config();
-
-    let file_paths = config.files.clone();
-
-    let codegen_backend = match config.target_language {
-        config::TargetLanguage::SystemVerilog => {
-            Box::new(VerilogCodegenBackend) as Box<dyn CodeGenBackend>
-        }
-        config::TargetLanguage::Vhdl => Box::new(VHDLCodegenBackend) as Box<dyn CodeGenBackend>,
-    };
+    init_logger(config.verbose, config.options.quiet);
 
     if config.use_lsp {
         #[cfg(feature = "lsp")]
-        return dev_aid::lsp::lsp_main();
+        return sus_compiler::dev_aid::lsp::lsp_main();
 
         #[cfg(not(feature = "lsp"))]
         panic!("LSP not enabled!")
     }
 
-    let (linker, mut paths_arena) = compile_all(file_paths);
-    print_all_errors(&linker, &mut paths_arena.file_sources);
+    if config.files_from_current_directory {
+        let names: Vec<_> = config
+            .files
+            .iter()
+            .map(|path| path.to_string_lossy())
+            .collect();
+        if names.is_empty() {
+            // `--eval` can still evaluate expressions on the standard library
+            if config.eval.is_none() {
+                let dir = std::env::current_dir()
+                    .map(|dir| dir.display().to_string())
+                    .unwrap_or_else(|_| ".".to_owned());
+                eprintln!("no .sus files found in {dir}; nothing to do");
+                std::process::exit(NOTHING_TO_DO_EXIT_CODE);
+            }
+        } else if !config.options.quiet {
+            eprintln!(
+                "No files given, compiling the .sus files in the current directory: {}",
+                names.join(", ")
+            );
+        }
+    }
+
+    let mut compiler = Compiler::with_options(config.options.clone());
 
-    if config.early_exit != EarlyExitUpTo::CodeGen {
-        return Ok(());
+    for file_path in &config.files {
+        compiler.add_file_from_path(file_path);
     }
 
-    if config.codegen {
-        for (_id, md) in &linker.modules {
-            codegen_backend.codegen_to_file(md, &linker);
+    if let Some(expression) = &config.eval {
+        match compiler.eval_expression(expression) {
+            Ok(value) => println!("{value}"),
+            Err(errors) => {
+                compiler.print_diagnostics(errors);
+                std::process::exit(1);
+            }
         }
+        return Ok(());
     }
 
-    if let Some(md_name) = &config.codegen_module_and_dependencies_one_file {
-        let Some(md) = linker
-            .modules
-            .iter()
-            .find(|(_, md)| &md.link_info.name == md_name)
-        else {
-            let mut err_lock = std::io::stderr().lock();
-            writeln!(err_lock, "Unknown module {md_name}").unwrap();
+    compiler.compile();
+    if let Some(json) = compiler.flat_json() {
+        println!("{json}");
+    }
+    if let Some(table) = compiler.estimate_table() {
+        print!("{table}");
+    }
+    compiler.print_errors();
+    compiler.print_timings();
+
+    if config.check {
+        if compiler.has_errors() {
             std::process::exit(1);
-        };
+        }
+        return Ok(());
+    }
+
+    if let Err(err) = compiler.codegen() {
+        let mut err_lock = std::io::stderr().lock();
+        writeln!(err_lock, "{err}").unwrap();
+        std::process::exit(1);
+    }
 
-        codegen_backend.codegen_with_dependencies(&linker, md.1, &format!("{md_name}_standalone"));
+    if config.options.quiet && compiler.has_errors() {
+        std::process::exit(1);
     }
 
     Ok(())
@@ -6,7 +6,8 @@ use crate::{file_position::FileText, pretty_print_many_spans, value::Value};
 use crate::flattening::{
     DomainInfo, Interface, InterfaceToDomainMap, Module, StructType, WrittenType,
 };
-use crate::linker::{FileData, LinkInfo};
+use crate::instantiation::InstantiatedModule;
+use crate::linker::{LinkInfo, Linker};
 use crate::typing::{
     abstract_type::{AbstractType, DomainType},
     concrete_type::ConcreteType,
@@ -271,24 +272,40 @@ impl Module {
         result
     }
 
-    pub fn print_flattened_module(&self, file_data: &FileData) {
-        println!("[[{}]]:", self.link_info.name);
-        println!("Interface:");
+    /// Textual form of the flattened module for `--dump-stage`. Unlike [Module::print_flattened_module], this contains no source snippets, so it can be diffed between runs.
+    pub fn dump_flattened_module(&self, file_text: &FileText) -> String {
+        let mut result = format!("[[{}]]:\n", self.link_info.name);
+        result.push_str("Interface:\n");
         for (port_id, port) in &self.ports {
-            println!(
+            writeln!(
+                result,
                 "    {} -> {:?}",
-                self.make_port_info_string(port_id, &file_data.file_text),
+                self.make_port_info_string(port_id, file_text),
                 port
-            );
+            )
+            .unwrap();
         }
-        println!("Instructions:");
-        let mut spans_print = Vec::new();
+        result.push_str("Instructions:\n");
         for (id, inst) in &self.link_info.instructions {
-            println!("    {id:?}: {inst:?}");
-            let span = self.get_instruction_span(id);
-            spans_print.push((format!("{id:?}"), span.as_range()));
+            writeln!(result, "    {id:?}: {inst:?}").unwrap();
         }
-        pretty_print_many_spans(file_data, &spans_print);
+        result
+    }
+
+    /// [Module::dump_flattened_module], followed by the source snippets of all instructions
+    pub fn print_flattened_module(&self, linker: &Linker) {
+        let file_data = &linker.files[self.link_info.file];
+        print!(
+            "{}",
+            linker.stabilize_debug_text(&self.dump_flattened_module(&file_data.file_text))
+        );
+        let spans_print: Vec<_> = self
+            .link_info
+            .instructions
+            .iter()
+            .map(|(id, _)| (format!("{id:?}"), self.get_instruction_span(id).as_range()))
+            .collect();
+        pretty_print_many_spans(file_data, &spans_print, linker.options.color);
     }
 }
 
@@ -323,3 +340,19 @@ pub fn pretty_print_concrete_instance(
     result.push(')');
     result
 }
+
+impl InstantiatedModule {
+    /// Textual form of the instance for `--dump-stage`
+    pub fn dump_instance(&self) -> String {
+        let mut result = format!("[[{}]]:\n", self.name);
+        result.push_str("Wires:\n");
+        for (id, w) in &self.wires {
+            writeln!(result, "    {id:?} -> {w:?}").unwrap();
+        }
+        result.push_str("SubModules:\n");
+        for (id, sm) in &self.submodules {
+            writeln!(result, "    {id:?}: {sm:?}").unwrap();
+        }
+        result
+    }
+}
@@ -70,6 +70,16 @@ pub enum DomainType {
     Unknown(DomainVariableID),
 }
 
+impl AbstractType {
+    pub fn contains_unknown(&self) -> bool {
+        match self {
+            AbstractType::Template(_) | AbstractType::Named(_) => false,
+            AbstractType::Array(content) => content.contains_unknown(),
+            AbstractType::Unknown(_) => true,
+        }
+    }
+}
+
 impl DomainType {
     pub fn unwrap_physical(&self) -> DomainID {
         let Self::Physical(w) = self else {
@@ -382,6 +392,30 @@ impl TypeUnifier {
         );
     }
 
+    pub fn typecheck_concatenation<'t>(
+        &self,
+        elements: impl Iterator<Item = (&'t FullType, Span)>,
+        output_typ: &FullType,
+        span: Span,
+    ) {
+        let content_typ = AbstractType::Unknown(self.alloc_typ_variable());
+        self.unify_with_array_of(&output_typ.typ, content_typ.clone(), span);
+        for (elem_typ, elem_span) in elements {
+            self.type_substitutor.unify_report_error(
+                &elem_typ.typ,
+                &AbstractType::Array(Box::new(content_typ.clone())),
+                elem_span,
+                "concatenation element",
+            );
+            self.unify_domains(
+                &elem_typ.domain,
+                &output_typ.domain,
+                elem_span,
+                "concatenation element",
+            );
+        }
+    }
+
     pub fn typecheck_array_access(
         &self,
         arr_type: &AbstractType,
@@ -99,7 +99,7 @@ impl ConcreteType {
         } else if type_ref.id == get_builtin_type!("bool") {
             1
         } else {
-            println!("TODO Named Structs Size");
+            log::warn!("TODO Named Structs Size");
             1 // todo!() // Named structs are not implemented yet
         }
     }
@@ -26,11 +26,9 @@ impl<ID> GlobalReference<ID> {
         }
         result
     }
-    /// Used for builtins, like clog2, assert, sizeof, etc
-    pub fn unwrap_first_template_argument(&self) -> &TemplateArg {
-        self.template_args[UUID::from_hidden_value(0)]
-            .as_ref()
-            .unwrap()
+    /// Used for builtins, like clog2, assert, sizeof, etc. [None] if the user didn't pass it
+    pub fn get_first_template_argument(&self) -> Option<&TemplateArg> {
+        self.template_args[UUID::from_hidden_value(0)].as_ref()
     }
 }
 
@@ -67,6 +65,7 @@ pub enum ParameterKind {
     Generative(GenerativeParameterKind),
 }
 
+/// The unwraps are for invariants only. Arguments are matched to parameters by name, so code that looks up a [Parameter] knows its kind.
 impl ParameterKind {
     #[track_caller]
     pub fn unwrap_type(&self) -> &TypeParameterKind {
@@ -100,13 +99,15 @@ pub struct TemplateArg {
 
 /// See [TemplateArg]
 ///
-/// The argument kind passed to [ParameterKind], which it must match
+/// The argument kind passed to [ParameterKind], which it must match.
+/// Flattening reports arguments of the wrong kind as errors, and doesn't store them in [GlobalReference::template_args]
 #[derive(Debug)]
 pub enum TemplateArgKind {
     Type(WrittenType),
     Value(FlatID),
 }
 
+/// The unwraps are for invariants only. A stored [TemplateArg] always has the kind of its [Parameter], see [TemplateArgKind]
 impl TemplateArgKind {
     #[track_caller]
     pub fn unwrap_type(&self) -> &WrittenType {
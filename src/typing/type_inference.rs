@@ -546,7 +546,9 @@ pub enum ConcreteTypeHMInfo<'slf> {
 impl HindleyMilner<ConcreteTypeVariableIDMarker> for ConcreteType {
     type TypeFuncIdent<'slf> = ConcreteTypeHMInfo<'slf>;
 
-    fn get_hm_info(&self) -> HindleyMilnerInfo<ConcreteTypeHMInfo, ConcreteTypeVariableIDMarker> {
+    fn get_hm_info(
+        &self,
+    ) -> HindleyMilnerInfo<ConcreteTypeHMInfo<'_>, ConcreteTypeVariableIDMarker> {
         match self {
             ConcreteType::Unknown(var_id) => HindleyMilnerInfo::TypeVar(*var_id),
             ConcreteType::Named(named_id) => {
@@ -645,6 +647,12 @@ pub trait DelayedConstraint<T> {
 /// For convenience, a &mut T is provided such that a shared mutable object can be used
 pub struct DelayedConstraintsList<T>(Vec<Box<dyn DelayedConstraint<T>>>);
 
+impl<T> Default for DelayedConstraintsList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> DelayedConstraintsList<T> {
     pub fn new() -> Self {
         Self(Vec::new())
@@ -9,6 +9,61 @@ use crate::typing::{
     type_inference::{ConcreteTypeVariableIDMarker, TypeSubstitutor},
 };
 
+/// An integer literal as written in the source, see [parse_integer_literal]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegerLiteral {
+    pub value: BigInt,
+    /// The width of sized literals like `8'hFF`
+    pub width: Option<u64>,
+}
+
+/// Parses decimal literals like `1_000`, and Verilog-style sized literals like `8'hFF`, `4'b1010`, `12'd100` and `6'o17`.
+///
+/// Underscores are allowed anywhere after the first digit.
+///
+/// The value of a sized literal must fit in its width. Literals are never truncated, like `8'h1FF` would be in Verilog
+pub fn parse_integer_literal(text: &str) -> Result<IntegerLiteral, String> {
+    let (width, radix, digits) = match text.split_once('\'') {
+        None => (None, 10, text),
+        Some((width_text, based)) => {
+            let width: u64 = width_text
+                .parse()
+                .map_err(|_| format!("'{width_text}' is not a valid literal width"))?;
+            if width == 0 {
+                return Err("Sized literals must be at least 1 bit wide".to_owned());
+            }
+            let mut based_chars = based.chars();
+            let radix = match based_chars.next().map(|c| c.to_ascii_lowercase()) {
+                Some('b') => 2,
+                Some('o') => 8,
+                Some('d') => 10,
+                Some('h') => 16,
+                _ => {
+                    return Err(format!(
+                        "Expected a base of 'b', 'o', 'd' or 'h' after the width in '{text}'"
+                    ))
+                }
+            };
+            (Some(width), radix, based_chars.as_str())
+        }
+    };
+    if !digits.starts_with(|c: char| c.is_digit(radix)) {
+        return Err(format!("'{text}' is not a valid integer literal"));
+    }
+    let digits: String = digits.chars().filter(|c| *c != '_').collect();
+    let value = BigInt::parse_bytes(digits.as_bytes(), radix)
+        .ok_or_else(|| format!("'{text}' is not a valid integer literal"))?;
+    if let Some(width) = width {
+        let needed_width = value.bits();
+        if needed_width > width {
+            return Err(format!(
+                "'{text}' doesn't fit in its declared width of {width} bits, {value} needs at least {needed_width} bits"
+            ));
+        }
+    }
+    Ok(IntegerLiteral { value, width })
+}
+
 /// Top type for any kind of compiletime value while executing.
 ///
 /// These are used during execution ([crate::instantiation::execute])
@@ -101,6 +156,14 @@ impl Value {
         };
         *b
     }
+
+    #[track_caller]
+    pub fn unwrap_bool_array(&self) -> impl Iterator<Item = bool> + '_ {
+        let Self::Array(arr) = self else {
+            panic!("{:?} is not an array!", self)
+        };
+        arr.iter().map(|b| b.unwrap_bool())
+    }
 }
 
 pub fn compute_unary_op(op: UnaryOperator, v: &Value) -> Value {
@@ -109,15 +172,9 @@ pub fn compute_unary_op(op: UnaryOperator, v: &Value) -> Value {
         //return Value::Error
     }
     match op {
-        UnaryOperator::Or => {
-            todo!("Array Values")
-        }
-        UnaryOperator::And => {
-            todo!("Array Values")
-        }
-        UnaryOperator::Xor => {
-            todo!("Array Values")
-        }
+        UnaryOperator::Or => Value::Bool(v.unwrap_bool_array().any(|b| b)),
+        UnaryOperator::And => Value::Bool(v.unwrap_bool_array().all(|b| b)),
+        UnaryOperator::Xor => Value::Bool(v.unwrap_bool_array().fold(false, |a, b| a ^ b)),
         UnaryOperator::Not => {
             let Value::Bool(b) = v else {
                 unreachable!("Only not bool supported, should be caught by abstract typecheck")
@@ -180,3 +237,44 @@ impl ConcreteType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_integer_literal, IntegerLiteral};
+    use num::BigInt;
+
+    fn literal(value: i64, width: Option<u64>) -> IntegerLiteral {
+        IntegerLiteral {
+            value: BigInt::from(value),
+            width,
+        }
+    }
+
+    #[test]
+    fn test_parse_integer_literal() {
+        assert_eq!(parse_integer_literal("1_000"), Ok(literal(1000, None)));
+        assert_eq!(parse_integer_literal("8'hFF"), Ok(literal(255, Some(8))));
+        assert_eq!(parse_integer_literal("4'b1010"), Ok(literal(10, Some(4))));
+        assert_eq!(
+            parse_integer_literal("12'd1_00"),
+            Ok(literal(100, Some(12)))
+        );
+        assert_eq!(parse_integer_literal("6'o17"), Ok(literal(15, Some(6))));
+
+        assert_eq!(parse_integer_literal("1'b1"), Ok(literal(1, Some(1))));
+        assert_eq!(parse_integer_literal("1'b0"), Ok(literal(0, Some(1))));
+        assert_eq!(
+            parse_integer_literal("8'h1FF"),
+            Err(
+                "'8'h1FF' doesn't fit in its declared width of 8 bits, 511 needs at least 9 bits"
+                    .to_owned()
+            )
+        );
+        assert!(parse_integer_literal("4'd16").is_err());
+
+        assert!(parse_integer_literal("8'x12").is_err());
+        assert!(parse_integer_literal("0'b0").is_err());
+        assert!(parse_integer_literal("4'b102").is_err());
+        assert!(parse_integer_literal("8'h_F").is_err());
+    }
+}
@@ -0,0 +1,469 @@
+//! End-to-end tests of the generated code
+mod common;
+
+use common::{error_reasons, eval_generative};
+use sus_compiler::errors::ErrorLevel;
+use sus_compiler::value::Value;
+use sus_compiler::{compile_sources, CompilerOptions};
+
+#[test]
+fn test_codegen_is_deterministic() {
+    let source = "
+module tinyTestMod #(int beep) {
+    output int o = beep
+}
+module testTinyTestMod {
+    tinyTestMod #(beep: 3) a
+    tinyTestMod #(beep: 4) b
+    tinyTestMod #(beep: 5) c
+    tinyTestMod #(beep: 6) d
+}
+";
+    let generate = || {
+        let result = compile_sources(
+            CompilerOptions::default(),
+            vec![("test.sus".to_owned(), source.to_owned())],
+        );
+        result.verilog("tinyTestMod").unwrap()
+    };
+    let first = generate();
+    assert!(first.contains("module tinyTestMod__beep6__"));
+    for _ in 0..5 {
+        assert_eq!(generate(), first);
+    }
+}
+
+#[test]
+fn test_clock_per_domain() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "clocks.sus".to_owned(),
+            "
+module twoClocks {
+    domain fast
+    interface fast_side : int a -> int a_out
+    domain slow
+    interface slow_side : int b -> int b_out
+    reg a_out = a
+    reg b_out = b
+}
+module useTwo {
+    domain main
+    interface useTwo : int x -> int y
+    domain other
+    interface other_side : int z -> int w
+    twoClocks tc
+    y = tc.fast_side(x)
+    w = tc.slow_side(z)
+}
+module fastAndIdle {
+    domain fast
+    interface fast_side : int a -> int a_out
+    domain idle
+    interface idle_side : -> int c
+    reg a_out = a
+    c = 3
+}
+module useFast {
+    interface useFast : int x -> int y
+    fastAndIdle fi
+    y = fi.fast_side(x)
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+
+    let two_clocks = result.verilog("twoClocks").unwrap();
+    assert!(two_clocks.contains("\tinput wire fast,\n\tinput wire slow,"));
+    assert!(two_clocks.contains("always_ff @(posedge fast) begin _a_D1 <= a; end"));
+    assert!(two_clocks.contains("always_ff @(posedge slow) begin _b_D1 <= b; end"));
+
+    let use_two = result.verilog("useTwo").unwrap();
+    assert!(use_two.contains("\t.fast(main),\n\t.slow(other)"));
+
+    // A submodule domain that isn't used here still gets its own clock, instead of being left unconnected
+    let use_fast = result.verilog("useFast").unwrap();
+    assert!(use_fast.contains("\tinput wire clk,\n\tinput wire domain_1,"));
+    assert!(use_fast.contains("\t.fast(clk),\n\t.idle(domain_1)"));
+}
+
+#[test]
+fn test_system_verilog_keyword_names() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "keywords.sus".to_owned(),
+            "
+module keywords {
+    interface keywords : int begin -> int logic
+    gen int always = 3
+    int end = begin + always
+    logic = end
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+
+    let mut warnings: Vec<&str> = result
+        .diagnostics
+        .iter()
+        .filter(|(_, d)| d.level == ErrorLevel::Warning && d.reason.contains("SystemVerilog"))
+        .map(|(_, d)| d.reason.as_str())
+        .collect();
+    warnings.sort();
+    // Generative values don't end up in the generated code
+    assert_eq!(
+        warnings,
+        [
+            "'begin' is a SystemVerilog keyword, so it gets a suffix in the generated code",
+            "'end' is a SystemVerilog keyword, so it gets a suffix in the generated code",
+            "'logic' is a SystemVerilog keyword, so it gets a suffix in the generated code",
+        ]
+    );
+
+    let verilog = result.verilog("keywords").unwrap();
+    assert!(verilog.contains("begin_1"));
+    assert!(verilog.contains("end_1"));
+    assert!(verilog.contains("logic_1"));
+}
+
+#[test]
+fn test_generative_assertions() {
+    use sus_compiler::codegen::system_verilog::generative_assertions;
+
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "asserts.sus".to_owned(),
+            "
+module buffer #(int DEPTH) {
+    gen int HALF = DEPTH / 2
+    assert #(C: DEPTH > 0)
+    assert #(C: (HALF * 2 == DEPTH) & (clog2 #(V: DEPTH) < 8))
+    for int I in 0..DEPTH {
+        assert #(C: I < DEPTH)
+    }
+}
+module top {
+    buffer #(DEPTH: 4) b
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+    let md = result.find_module("buffer").unwrap();
+    let instances = result.instances("buffer");
+    let assertions = generative_assertions(md, &instances[0], &result.linker);
+    assert_eq!(
+            assertions,
+            "
+// Generative assertions
+localparam int DEPTH = 4;
+initial assert((DEPTH > 0)) else $error(\"Assertion failed: DEPTH > 0\");
+initial assert((((2 * 2) == DEPTH) & (2 < 8))) else $error(\"Assertion failed: (HALF * 2 == DEPTH) & (clog2 #(V: DEPTH) < 8)\");
+"
+        );
+    // Without asserts nothing is added
+    let top = result.find_module("top").unwrap();
+    assert_eq!(
+        generative_assertions(top, &result.instances("top")[0], &result.linker),
+        ""
+    );
+}
+
+#[test]
+fn test_emit_assertions_codegen() {
+    let options = CompilerOptions {
+        emit_assertions: true,
+        ..Default::default()
+    };
+    let result = compile_sources(
+        options,
+        vec![(
+            "asserts.sus".to_owned(),
+            "
+module wide #(int SIZE) {
+    assert #(C: SIZE > 4294967296)
+}
+module top {
+    wide #(SIZE: 8589934592) w
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+    let wide = result.verilog("wide").unwrap();
+    // Doesn't fit in an int, so it gets a wide enough type instead of being truncated
+    assert!(wide.contains(
+        "
+// Generative assertions
+localparam logic signed [34:0] SIZE = 35'sd8589934592;
+initial assert((SIZE > 4294967296)) else $error(\"Assertion failed: SIZE > 4294967296\");
+endmodule"
+    ));
+}
+
+#[test]
+fn test_port_annotation() {
+    use sus_compiler::codegen::system_verilog::port_annotation;
+
+    let text = "
+module delayed {
+    interface delayed : bool[8] a -> bool[8] b
+    reg bool[8] x = a
+    b = x
+}
+";
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![("annotate.sus".to_owned(), text.to_owned())],
+    );
+    let instances = result.instances("delayed");
+    let annotations: Vec<String> = (instances[0].interface_ports.iter_valids())
+        .map(|(_, port)| port_annotation(port))
+        .collect();
+    assert_eq!(
+        annotations,
+        [
+            "input [7:0] width=8 latency=0",
+            "output [7:0] width=8 latency=1"
+        ]
+    );
+
+    // Each comment goes on its own line, above the port it describes
+    let options = CompilerOptions {
+        annotate_ports: true,
+        ..Default::default()
+    };
+    let result = compile_sources(options, vec![("annotate.sus".to_owned(), text.to_owned())]);
+    let verilog = result.verilog("delayed").unwrap();
+    assert!(verilog.contains(
+        "module delayed___(
+\tinput wire clk,
+\t// input [7:0] width=8 latency=0
+\tinput wire [7:0] a,
+\t// output [7:0] width=8 latency=1
+\toutput /*mux_wire*/ logic [7:0] b
+);
+"
+    ));
+}
+
+#[test]
+fn test_compilers_with_different_options() {
+    use sus_compiler::config::ManglingScheme;
+
+    let text = "
+module passOn #(int OFFSET) {
+    interface passOn : int i -> int o
+    o = i + OFFSET
+}
+module top {
+    interface top : int i -> int o
+    o = passOn #(OFFSET: 6)(i)
+}
+";
+    // Each compilation has its own options, so both can exist in the same process
+    let compile = |options| {
+        let result = compile_sources(options, vec![("options.sus".to_owned(), text.to_owned())]);
+        assert_eq!(error_reasons(&result), Vec::<&str>::new());
+        result.verilog("top").unwrap()
+    };
+    let readable_top = compile(CompilerOptions::default());
+    let hashed_top = compile(CompilerOptions {
+        mangling: ManglingScheme::Hash,
+        strict_sv: true,
+        ..Default::default()
+    });
+    assert!(readable_top.contains("passOn__OFFSET6__ "));
+    assert!(!readable_top.contains("`default_nettype"));
+    assert!(!hashed_top.contains("passOn__OFFSET6__"));
+    assert!(hashed_top.contains("`default_nettype none\nmodule "));
+    assert!(hashed_top.ends_with("`default_nettype wire\n"));
+}
+
+#[test]
+fn test_unsafe_clock_domain_crossing() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "crossing.sus".to_owned(),
+            "
+module cross {
+    domain fast
+    input int x
+    reg int y = x
+    domain slow
+    output int z
+    z = y
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+    let crossing_file = result.find_module("cross").unwrap().link_info.file;
+    let warnings: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|(file, _)| *file == crossing_file)
+        .map(|(_, err)| err)
+        .collect();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].level, ErrorLevel::Warning);
+    assert_eq!(
+        warnings[0].reason,
+        "Unsafe clock domain crossing: Attempting to combine domains 'fast' and 'slow' in connection. Crossing clock domains should be done explicitly with CrossDomain"
+    );
+
+    // The crossing is a plain assignment, without latency registers across domains
+    let cross = result.verilog("cross").unwrap();
+    assert!(cross.contains("always_ff @(posedge fast) begin _x_D1 <= x; end"));
+    assert!(cross.contains("\tz = y;\n"));
+}
+
+#[test]
+fn test_concatenation() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "concat.sus".to_owned(),
+            "
+module concat {
+    interface concat : bool[2] a, bool[3] b -> bool[5] ab, bool[5] aba
+    ab = {a, b}
+    aba = {
+        a,
+        b[0:0],
+        a
+    }
+}
+module genConcat {
+    gen int[2] lo
+    lo[0] = 3
+    lo[1] = 4
+    gen int[1] hi
+    hi[0] = 5
+    gen int[3] all = {hi, lo}
+}
+module concatWrongSize {
+    interface concatWrongSize : bool[2] a -> bool[3] c
+    c = {a, a}
+}
+module concatOfScalars {
+    interface concatOfScalars : bool a -> bool[2] c
+    c = {a, a}
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(
+        error_reasons(&result),
+        vec![
+            "Typing Error: concatenation size expects a ::bool[4] but was given a ::bool[3]",
+            "Typing Error: concatenation element expects a ::bool[] but was given a ::bool",
+            "Typing Error: concatenation element expects a ::bool[] but was given a ::bool",
+        ]
+    );
+
+    let verilog = result.verilog("concat").unwrap();
+    assert!(verilog.contains("wire [4:0] _1 = {a, b};"));
+    assert!(verilog.contains("wire [4:0] _3 = {a, _2, a};"));
+
+    // As in SystemVerilog, the last element ends up at the lowest indices
+    let int = |v: i32| Value::Integer(v.into());
+    assert_eq!(
+        eval_generative(&result.linker, "genConcat", "all").unwrap(),
+        Value::Array(vec![int(3), int(4), int(5)].into_boxed_slice())
+    );
+}
+
+#[test]
+fn test_conditional_expression() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "conditional.sus".to_owned(),
+            "
+module pick {
+    interface pick : bool sel, int a, int b -> int o, int p
+    o = sel ? a : b
+    p = sel ? a : sel ? b : 3
+}
+module genPick {
+    gen int N = 3
+    gen int A = N > 2 ? 10 : 20
+    gen int B = N + 2 > 5 ? 10 : 20
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+
+    // Nested conditionals group to the right
+    let verilog = result.verilog("pick").unwrap();
+    assert!(verilog.contains("wire [31:0] _1 = sel ? a : b;"));
+    assert!(verilog.contains("wire [31:0] _3 = sel ? b : 3;"));
+    assert!(verilog.contains("wire [31:0] _4 = sel ? a : _3;"));
+
+    // The conditional binds looser than the comparison and the addition
+    let eval = |name: &str| eval_generative(&result.linker, "genPick", name).unwrap();
+    assert_eq!(eval("A"), Value::Integer(10.into()));
+    assert_eq!(eval("B"), Value::Integer(20.into()));
+}
+
+#[test]
+fn test_sized_literal_codegen() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "sized.sus".to_owned(),
+            "
+module sized {
+    interface sized : int a -> int b, bool d
+    gen int G = 8'hFF
+    b = a + 8'hFF + G
+    d = a == 4'b1010
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+    // Literals keep their width, but a generative variable holding one is a plain int
+    let verilog = result.verilog("sized").unwrap();
+    assert!(verilog.ends_with(
+        "module sized___(
+\tinput wire clk,
+\tinput wire [31:0] a,
+\toutput /*mux_wire*/ logic [31:0] b,
+\toutput /*mux_wire*/ logic  d
+);
+
+wire [31:0] _2 = a + 8'd255;
+wire [31:0] _4 = _2 + 255;
+wire  _6 = a == 4'd10;
+always_comb begin
+\t// Combinatorial wires are not defined when not valid. This is just so that the synthesis tool doesn't generate latches
+\tb = 'x;
+\tb = _4;
+end
+always_comb begin
+\t// Combinatorial wires are not defined when not valid. This is just so that the synthesis tool doesn't generate latches
+\td = 'x;
+\td = _6;
+end
+endmodule
+
+"
+    ));
+}
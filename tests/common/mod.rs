@@ -0,0 +1,53 @@
+//! Shared by the integration tests. Not every test file uses every helper
+#![allow(dead_code)]
+
+use sus_compiler::compiler_top::MemorySourceProvider;
+use sus_compiler::errors::CompileError;
+use sus_compiler::flattening::{Instruction, Module};
+use sus_compiler::prelude::{FlatAlloc, Linker, ModuleUUID};
+use sus_compiler::value::Value;
+use sus_compiler::{CompileResult, Compiler, CompilerOptions};
+
+/// The reasons of all errors, in order. Comparing these instead of counting errors shows what went wrong when a test fails
+pub fn error_reasons(result: &CompileResult) -> Vec<&str> {
+    result.errors().map(|err| err.reason.as_str()).collect()
+}
+
+/// A [Linker] with the standard library embedded in the compiler, for tests that run the stages themselves
+pub fn linker_with_std() -> Linker {
+    let mut linker = Linker::new();
+    linker.add_standard_library(&MemorySourceProvider::with_standard_library(), &mut ());
+    linker
+}
+
+pub fn find_module<'l>(linker: &'l Linker, name: &str) -> (ModuleUUID, &'l Module) {
+    linker
+        .modules
+        .iter()
+        .find(|(_, md)| md.link_info.name == name)
+        .unwrap()
+}
+
+/// Evaluates the generative declaration `name` of the unparameterized module `md_name`
+pub fn eval_generative(
+    linker: &Linker,
+    md_name: &str,
+    name: &str,
+) -> Result<Value, Vec<CompileError>> {
+    let (md_id, md) = find_module(linker, md_name);
+    let (decl, _) = md
+        .link_info
+        .instructions
+        .iter()
+        .find(|(_, instr)| matches!(instr, Instruction::Declaration(d) if d.name == name))
+        .unwrap();
+    linker.eval_generative(md_id, decl, &FlatAlloc::new())
+}
+
+/// A [Compiler] with the default options and the standard library embedded in the compiler, so nothing is read from disk
+pub fn compiler_with_std() -> Compiler {
+    Compiler::with_source_provider(
+        CompilerOptions::default(),
+        Box::new(MemorySourceProvider::with_standard_library()),
+    )
+}
@@ -0,0 +1,135 @@
+//! Tests of the developer aids that inspect the compilation results
+use sus_compiler::compiler_top::MemorySourceProvider;
+use sus_compiler::{compile_sources, Compiler, CompilerOptions};
+
+#[test]
+fn test_resource_estimate() {
+    use sus_compiler::dev_aid::resource_estimate::ResourceEstimate;
+
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "estimate.sus".to_owned(),
+            "
+module mac {
+    interface mac : int a, int b -> int c
+    state int acc
+    reg int p = a * b
+    acc = acc + p
+    c = acc - a
+}
+"
+            .to_owned(),
+        )],
+    );
+    let instances = result.instances("mac");
+    let estimate = ResourceEstimate::of_instance(&instances[0]);
+    assert_eq!(
+        estimate,
+        ResourceEstimate {
+            // acc, the reg on p, and a delayed by one cycle to line up with acc
+            registers: 3,
+            register_bits: 3 * 32,
+            adders: 2,
+            multipliers: 1,
+            // a, b, c, acc, a * b, p, acc + p, acc - a
+            wire_bits: 8 * 32,
+        }
+    );
+}
+
+#[test]
+fn test_flattened_module_json() {
+    use sus_compiler::dev_aid::flat_ir_json::flattened_module_json;
+
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "json.sus".to_owned(),
+            "
+module adder {
+    interface adder : int a, int b -> int c
+    c = a + b
+}
+"
+            .to_owned(),
+        )],
+    );
+    let md = result.find_module("adder").unwrap();
+    let json = flattened_module_json(md, &result.linker);
+    assert_eq!(json["name"], "adder");
+    assert_eq!(json["file"], "json.sus");
+    let instructions = json["instructions"].as_array().unwrap();
+    let a = &instructions[0];
+    assert_eq!(a["kind"], "Declaration");
+    assert_eq!(a["name"], "a");
+    assert_eq!(a["type"], "::int");
+    assert_eq!(a["domain"], "clk");
+    assert_eq!(a["decl_kind"], "input");
+    let sum = instructions
+        .iter()
+        .find(|instr| instr["source"]["binary_op"] == "+")
+        .unwrap();
+    assert_eq!(sum["type"], "::int");
+    let write = instructions.last().unwrap();
+    assert_eq!(write["kind"], "Write");
+    assert_eq!(write["from"], sum["id"]);
+    // Spans are byte ranges in the file
+    let text = &result.linker.files[result.files[0]].file_text.file_text;
+    let span = &sum["span"];
+    let range = span[0].as_u64().unwrap() as usize..span[1].as_u64().unwrap() as usize;
+    assert_eq!(&text[range], "a + b");
+}
+
+/// Compiles a single module with `options`, for the outputs the CLI prints
+fn compile_with(options: CompilerOptions) -> Compiler {
+    let mut compiler = Compiler::with_source_provider(
+        options,
+        Box::new(MemorySourceProvider::with_standard_library()),
+    );
+    compiler.add_file(
+        "outputs.sus".to_owned(),
+        "module passOn {\n    interface passOn : int a -> int b\n    reg b = a\n}\n".to_owned(),
+    );
+    compiler.compile();
+    compiler
+}
+
+#[test]
+fn test_flat_json_is_returned_not_printed() {
+    assert_eq!(compile_with(CompilerOptions::default()).flat_json(), None);
+
+    let json = compile_with(CompilerOptions {
+        dump_flat_json: true,
+        ..Default::default()
+    })
+    .flat_json()
+    .unwrap();
+    let modules: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let names: Vec<&str> = (modules.as_array().unwrap().iter())
+        .map(|md| md["name"].as_str().unwrap())
+        .collect();
+    assert!(names.contains(&"passOn"));
+}
+
+#[test]
+fn test_estimate_table_is_returned_not_printed() {
+    assert_eq!(
+        compile_with(CompilerOptions::default()).estimate_table(),
+        None
+    );
+
+    let table = compile_with(CompilerOptions {
+        estimate: true,
+        ..Default::default()
+    })
+    .estimate_table()
+    .unwrap();
+    let pass_on_row = table
+        .lines()
+        .find(|line| line.starts_with("::passOn"))
+        .unwrap();
+    // b is a register, and a and b are the only wires
+    let numbers: Vec<&str> = pass_on_row.split_whitespace().skip(2).collect();
+    assert_eq!(numbers, ["1", "32", "0", "0", "64"]);
+}
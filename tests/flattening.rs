@@ -0,0 +1,691 @@
+//! Tests of the errors and warnings found while flattening and typechecking modules
+use sus_compiler::errors::ErrorLevel;
+use sus_compiler::prelude::Span;
+use sus_compiler::{compile_sources, CompilerOptions};
+
+#[test]
+fn test_combinational_loop() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "loop.sus".to_owned(),
+            "
+module combLoop {
+    interface combLoop : int x -> int y
+    int a
+    int b
+    a = b + x
+    b = a
+    y = a
+}
+module stateLoop {
+    interface stateLoop : int x -> int y
+    state int count
+    initial count = 0
+    count = count + x
+    y = count
+}
+"
+            .to_owned(),
+        )],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].reason.starts_with("Combinational loop: 'a'"));
+    assert!(errors[0].reason.contains("a -> b -> _1 -> a"));
+}
+
+#[test]
+fn test_unconnected_submodule_inputs() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "unconnected.sus".to_owned(),
+            "
+module adder {
+    input int a
+    input int b
+    input int c
+    output int sum = a + b + c
+}
+module useAdder {
+    interface useAdder : int x -> int y
+    adder add
+    add.b = x
+    y = add.sum
+}
+"
+            .to_owned(),
+        )],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].reason,
+        "Input ports of this submodule are not connected: 'a', 'c'"
+    );
+    let infos: Vec<_> = errors[0].infos.iter().map(|i| i.info.as_str()).collect();
+    assert_eq!(
+        infos,
+        [
+            "Port 'a' declared here",
+            "Port 'c' declared here",
+            "add declared here"
+        ]
+    );
+}
+
+#[test]
+fn test_too_many_template_args() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "template_args.sus".to_owned(),
+            "
+module withParam #(int N) {
+    interface withParam : -> int o
+    o = N
+}
+module withTwo #(int A, int B) {
+    interface withTwo : -> int o
+    o = A + B
+}
+module useIt {
+    interface useIt : -> int o, int p
+    withParam #(N: 3, M: 4) w
+    o = w.o
+    withTwo #(A: 1, B: 2, C: 3) t
+    p = t.o
+}
+"
+            .to_owned(),
+        )],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+            errors[0].reason,
+            "M is not a valid template argument of ::withParam. Expected at most 1 template argument, but found 2. The template parameters are: N"
+        );
+    assert_eq!(
+            errors[1].reason,
+            "C is not a valid template argument of ::withTwo. Expected at most 2 template arguments, but found 3. The template parameters are: A, B"
+        );
+}
+
+#[test]
+fn test_did_you_mean() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "typo.sus".to_owned(),
+            "
+module adder {
+    interface adder : int a, int b -> int c
+    c = a + b
+}
+module useIt {
+    interface useIt : int a -> int c
+    addr ad
+    Fifo #(T: type int, DEPTH: 3, READY_SLACK: 1) f
+    completelyUnrelated x
+    c = ad(a, z)
+}
+"
+            .to_owned(),
+        )],
+    );
+    let reasons: Vec<&str> = result.errors().map(|err| err.reason.as_str()).collect();
+    assert!(reasons
+        .contains(&"No Global of the name 'addr' was found. Did you mean the Module ::adder?"));
+    assert!(reasons.contains(&"No Global of the name 'Fifo' was found. Did you mean the Module ::FIFO, declared in another file?"));
+    assert!(reasons.contains(
+        &"No Global of the name 'completelyUnrelated' was found. Did you forget to import it?"
+    ));
+    // A single char is one edit away from any other single char, that's not a useful suggestion
+    assert!(reasons.contains(&"No Global of the name 'z' was found. Did you forget to import it?"));
+}
+
+#[test]
+fn test_template_args_on_unparameterized_module() {
+    let text = "
+module noParams {
+    interface noParams : -> int o
+    o = 3
+}
+module useIt {
+    interface useIt : -> int o
+    noParams #(N: 3, M: 4) w
+    o = w.o
+}
+";
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![("template_args.sus".to_owned(), text.to_owned())],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].reason,
+        "::noParams is not parameterized, it can't be given template arguments"
+    );
+    let brackets = "#(N: 3, M: 4)";
+    let start = text.find(brackets).unwrap();
+    assert_eq!(errors[0].position.as_range(), start..start + brackets.len());
+}
+
+#[test]
+fn test_uninferrable_template_args_without_brackets() {
+    let text = "
+module withParam #(int N) {
+    interface withParam : -> int o
+    o = N
+}
+module useIt {
+    interface useIt : -> int o
+    withParam w
+    o = w.o
+}
+";
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![("template_args.sus".to_owned(), text.to_owned())],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+            errors[0].reason,
+            "::withParam is parameterized, but no template arguments were given, and 'N' could not be inferred. Pass them with #(...)"
+        );
+    let start = text.find("withParam w").unwrap();
+    assert_eq!(
+        errors[0].position.as_range(),
+        start..start + "withParam".len()
+    );
+}
+
+#[test]
+fn test_template_arg_kind_mismatch() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "template_args.sus".to_owned(),
+            "
+module withParams #(T, int N) {
+    interface withParams : -> int o
+    o = N
+}
+module useIt {
+    interface useIt : -> int o
+    withParams #(T: 3, N: type int) w
+    o = w.o
+}
+"
+            .to_owned(),
+        )],
+    );
+    let reasons: Vec<&str> = result.errors().map(|err| err.reason.as_str()).collect();
+    assert!(reasons.contains(&"Expected a type argument for 'T', found a value. To pass a type use the `type` keyword like `T: type int[123]`"));
+    assert!(reasons.contains(&"Expected a value argument for 'N', found a type. The `type` keyword is only for type parameters"));
+}
+
+#[test]
+fn test_template_arg_kinds_dont_panic() {
+    // (global, name of its first parameter)
+    let globals = [
+        ("clog2", "V"),
+        ("assert", "C"),
+        ("sizeof", "T"),
+        ("withParams", "T"),
+        ("withParams", "N"),
+    ];
+    for (global, param) in globals {
+        let arg_lists = [
+            String::new(),
+            "#()".to_owned(),
+            format!("#({param}: 3)"),
+            format!("#({param}: true)"),
+            format!("#({param}: type int)"),
+            format!("#({param}: type bool[2])"),
+            format!("#({param})"),
+            format!("#({param}: 3, {param}: 4)"),
+            "#(unknownParam: 3)".to_owned(),
+            format!("#({param}: 3, unknownParam: type int)"),
+        ];
+        for args in arg_lists {
+            let usage = if global == "withParams" {
+                format!("    {global} {args} w\n    o = w.o\n")
+            } else {
+                format!("    gen int x = {global} {args}\n    o = 3\n")
+            };
+            let source = format!(
+                "
+module withParams #(T, int N) {{
+    interface withParams : -> int o
+    o = N
+}}
+module useIt {{
+    interface useIt : -> int o
+    gen int {param} = 5
+{usage}}}
+"
+            );
+            compile_sources(
+                CompilerOptions::default(),
+                vec![("fuzz.sus".to_owned(), source)],
+            );
+        }
+    }
+}
+
+#[test]
+fn test_positional_port_connection() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "positional.sus".to_owned(),
+            "
+module subtract {
+    interface subtract : int a, int b -> int diff
+    diff = a - b
+}
+module useIt {
+    interface useIt : int x, int y -> int o, int p
+    o = subtract(x, y)
+    p = subtract(x, y, 3)
+}
+"
+            .to_owned(),
+        )],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].reason,
+        "Excess argument. Function takes 2 args, but 3 were passed."
+    );
+
+    let subtract = result.find_module("subtract").unwrap();
+    let port_names: Vec<&str> = subtract
+        .ports
+        .iter()
+        .map(|(_, port)| port.name.as_str())
+        .collect();
+    assert_eq!(port_names, ["a", "b", "diff"]);
+}
+
+#[test]
+fn test_positional_module_connection() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "positional.sus".to_owned(),
+            "
+module adder {
+    input int a
+    output int sum
+    input int b
+    sum = a + b
+}
+module useIt {
+    input int x
+    input int y
+    output int o
+    output int p
+    output int q
+    o = adder(x, y)
+    p = adder(x)
+    adder mixed
+    mixed.a = x
+    q = mixed(x, y)
+}
+"
+            .to_owned(),
+        )],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    let reasons: Vec<&str> = errors.iter().map(|err| err.reason.as_str()).collect();
+    assert_eq!(
+        reasons,
+        [
+            "Too few arguments. Function takes 2 args, but 1 were passed.",
+            "Cannot mix named and positional port connections on submodule 'mixed'",
+        ]
+    );
+    assert_eq!(errors[1].infos[0].info, "Connected by name here");
+
+    let adder = result.find_module("adder").unwrap();
+    let positional: Vec<&str> = adder
+        .positional_ports
+        .iter()
+        .map(|port| adder.ports[*port].name.as_str())
+        .collect();
+    assert_eq!(positional, ["a", "sum", "b"]);
+}
+
+#[test]
+fn test_if_when_condition_kind() {
+    let text = "
+module conditions {
+    interface conditions : bool a -> int o
+    gen bool G = true
+    o = 0
+    if a {
+        o = 1
+    }
+    when G {
+        o = 2
+    }
+}
+";
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![("conditions.sus".to_owned(), text.to_owned())],
+    );
+    let diagnostics: Vec<_> = result
+        .diagnostics
+        .iter()
+        .map(|(_, d)| d)
+        .filter(|d| d.reason.starts_with("This condition"))
+        .collect();
+    assert_eq!(diagnostics.len(), 2);
+
+    let runtime_if = diagnostics[0];
+    assert_eq!(runtime_if.level, ErrorLevel::Warning);
+    assert_eq!(&text[runtime_if.position.as_range()], "a");
+    assert_eq!(&text[runtime_if.infos[0].position.as_range()], "if");
+
+    let generative_when = diagnostics[1];
+    assert_eq!(generative_when.level, ErrorLevel::Error);
+    assert_eq!(&text[generative_when.position.as_range()], "G");
+    assert_eq!(&text[generative_when.infos[0].position.as_range()], "when");
+    assert!(generative_when.reason.contains("Use 'if'"));
+}
+
+#[test]
+fn test_underscore_names_are_intentionally_unused() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "unused.sus".to_owned(),
+            "
+module u {
+    interface u : int a -> int c
+    int _debug = a + 1
+    int _ = a
+    int _ = a + 2
+    int unused = a
+    c = a
+}
+"
+            .to_owned(),
+        )],
+    );
+    let md = result.find_module("u").unwrap();
+    let diagnostics: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|(file, _)| *file == md.link_info.file)
+        .map(|(_, err)| err)
+        .collect();
+    // Only 'unused' is reported, and the two '_' don't conflict
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].level, ErrorLevel::Warning);
+    assert!(diagnostics[0].reason.starts_with("Unused Variable"));
+    // Throwaways still get unique wire names
+    let verilog = result.verilog("u").unwrap();
+    assert!(verilog.contains("\t_ = a;"));
+    assert!(verilog.contains("\t__2 = "));
+}
+
+#[test]
+fn test_multiple_drivers() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "drivers.sus".to_owned(),
+            "
+module doubleDriven {
+    interface doubleDriven : int a, int b -> int o
+    o = a
+    o = b
+}
+module priorityOverride {
+    interface priorityOverride : bool c, bool d, int a, int b -> int o
+    o = a
+    when c {
+        o = b
+    }
+    when d {
+        o = 3
+    } else {
+        o = 4
+    }
+}
+"
+            .to_owned(),
+        )],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    // The overrides in priorityOverride are conditional, so only doubleDriven errors
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].reason,
+        "'o' has multiple drivers. The later write fully overrides the earlier one"
+    );
+    let infos: Vec<&str> = errors[0].infos.iter().map(|i| i.info.as_str()).collect();
+    assert_eq!(infos, ["Driven here", "Driven here"]);
+}
+
+#[test]
+fn test_type_at() {
+    let text = "
+module sum #(int N) {
+    interface sum : int[N] values -> int total
+    total = values[0] + N
+}
+module useSum {
+    interface useSum : int[3] values -> int total
+    sum #(N: 3) s
+    total = s(values)
+}
+";
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![("types.sus".to_owned(), text.to_owned())],
+    );
+    let file = result.files[0];
+    let span_of = |needle: &str, nth: usize| {
+        let start = text.match_indices(needle).nth(nth).unwrap().0;
+        Span::from(start..start + needle.len())
+    };
+
+    // A port of a module with a single instance, sum #(N: 3)
+    let port = result.linker.type_at(file, span_of("values", 0)).unwrap();
+    assert_eq!(port.span, span_of("values", 0));
+    assert_eq!(port.abstract_type, "::int[]");
+    assert_eq!(port.domain.as_deref(), Some("clk"));
+    assert_eq!(port.concrete_types, ["::int[3]"]);
+
+    // The innermost expression wins, 'values[0]' over 'values[0] + N'
+    let index = result
+        .linker
+        .type_at(file, span_of("values[0]", 0))
+        .unwrap();
+    assert_eq!(index.abstract_type, "::int");
+    let addition = result
+        .linker
+        .type_at(file, span_of("values[0] + N", 0))
+        .unwrap();
+    assert_eq!(addition.span, span_of("values[0] + N", 0));
+
+    // A template argument value is a compile-time value
+    let template_arg = result.linker.type_at(file, span_of("3", 0)).unwrap();
+    assert_eq!(template_arg.abstract_type, "::int");
+    assert_eq!(template_arg.domain.as_deref(), Some("gen"));
+    assert!(template_arg.concrete_types.is_empty());
+
+    // Keywords aren't typed
+    assert_eq!(result.linker.type_at(file, span_of("interface", 0)), None);
+}
+
+#[test]
+fn test_feature_gates() {
+    let gated = "
+module gated {
+    interface gated : int data
+    assert(data >= 0)
+}
+";
+    let enabled = "// Enables the statements below
+#![feature(assert_statements)]
+#![feature(arrays, assert_statements)]
+#![inline]
+module enabled {
+    interface enabled : int data
+    assume(data >= 0)
+}
+";
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![
+            ("gated.sus".to_owned(), gated.to_owned()),
+            ("enabled.sus".to_owned(), enabled.to_owned()),
+        ],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    assert_eq!(errors.len(), 3, "{errors:?}");
+    assert_eq!(
+            errors[0].reason,
+            "'assert' is experimental, enable feature 'assert_statements' with '#![feature(assert_statements)]' at the top of this file"
+        );
+    assert_eq!(&gated[errors[0].position.as_range()], "assert(data >= 0)");
+    assert_eq!(
+        errors[1].reason,
+        "Unknown feature 'arrays'. The available features are: assert_statements"
+    );
+    assert_eq!(&enabled[errors[1].position.as_range()], "arrays");
+    assert_eq!(
+        errors[2].reason,
+        "Unknown pragma, only '#![feature(name, ...)]' is supported"
+    );
+    assert_eq!(&enabled[errors[2].position.as_range()], "#![inline]");
+}
+
+#[test]
+fn test_assert_assume_statements() {
+    use sus_compiler::flattening::{AssertionKind, BinaryOperator};
+    use sus_compiler::instantiation::RealWireDataSource;
+
+    let text = "#![feature(assert_statements)]
+module checked {
+    interface checked : bool valid, int data -> int o
+    o = data
+    assert(data >= 0)
+    when valid {
+        assume(data < 100)
+    }
+    gen int N = 3
+    assert(N > 2)
+}
+module failing {
+    gen int N = 3
+    assert(N > 5)
+}
+module wrong_args {
+    assert()
+}
+";
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![("assertions.sus".to_owned(), text.to_owned())],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert_eq!(errors[0].reason, "Assertion failed");
+    assert_eq!(&text[errors[0].position.as_range()], "N > 5");
+    assert_eq!(
+        errors[1].reason,
+        "'assert' takes exactly one condition, but 0 arguments were given"
+    );
+
+    // The generative assert is checked during instantiation, the runtime ones are kept
+    let instance = &result.instances("checked")[0];
+    assert_eq!(instance.assertions.len(), 2);
+    assert_eq!(instance.assertions[0].kind, AssertionKind::Assert);
+    assert_eq!(instance.assertions[1].kind, AssertionKind::Assume);
+    // The assume only has to hold when 'valid' does
+    let guarded = &instance.wires[instance.assertions[1].wire];
+    assert!(matches!(
+        guarded.source,
+        RealWireDataSource::BinaryOp {
+            op: BinaryOperator::Or,
+            ..
+        }
+    ));
+    // Dropped without --emit-assertions
+    assert!(!result.verilog("checked").unwrap().contains("property"));
+}
+
+#[test]
+fn test_unrecovered_syntax_error() {
+    // tree-sitter can't recover this file into a source_file, the root of the tree is an ERROR node
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "unrecovered.sus".to_owned(),
+            "
+module good {
+    interface good : int a -> int b
+    b = a
+}
+module broken #(int W) {
+    when W <= 5 {
+        for int I in 0 {
+    } else when W > 5 {
+"
+            .to_owned(),
+        )],
+    );
+    let reasons: Vec<_> = result.errors().map(|err| err.reason.as_str()).collect();
+    assert!(reasons.contains(&"Could not parse this code, it is not part of any global object"));
+    // The global objects before the error are still compiled
+    assert!(result.verilog("good").unwrap().contains("module good"));
+}
+
+#[test]
+fn test_conditional_expression_errors() {
+    let text = "
+module armMismatch {
+    interface armMismatch : bool sel, int a, bool b -> int o
+    o = sel ? a : b
+}
+module condNotBool {
+    interface condNotBool : int sel, int a -> int o
+    o = sel ? a : a
+}
+";
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![("conditional.sus".to_owned(), text.to_owned())],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    assert_eq!(errors.len(), 2, "{errors:?}");
+
+    assert_eq!(
+        errors[0].reason,
+        "Typing Error: conditional expression arm expects a ::int but was given a ::bool"
+    );
+    assert_eq!(&text[errors[0].position.as_range()], "b");
+    assert_eq!(
+        errors[0].infos[0].info,
+        "The other arm of the conditional expression"
+    );
+    assert_eq!(&text[errors[0].infos[0].position.as_range()], "a");
+
+    assert_eq!(
+        errors[1].reason,
+        "Typing Error: conditional expression condition expects a ::bool but was given a ::int"
+    );
+    assert_eq!(&text[errors[1].position.as_range()], "sel");
+}
@@ -0,0 +1,957 @@
+//! Tests of instantiation: executing modules for their template arguments, and the latency, netlist and generative values that come out of it
+mod common;
+
+use common::{compiler_with_std, error_reasons, eval_generative, find_module, linker_with_std};
+use sus_compiler::errors::ErrorLevel;
+use sus_compiler::flattening::Instruction;
+use sus_compiler::instantiation::CompileModuleError;
+use sus_compiler::prelude::{FlatAlloc, Linker};
+use sus_compiler::value::Value;
+use sus_compiler::{compile_sources, CompilerOptions};
+
+#[test]
+fn test_reduction_operators() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "reductions.sus".to_owned(),
+            "
+module reductions {
+    interface reductions : bool[4] a -> bool all_a, bool any_a, bool parity_a
+    all_a = &a
+    any_a = |a
+    parity_a = ^a
+}
+module singleBitReduction {
+    interface singleBitReduction : bool[1] b -> bool any_b
+    any_b = |b
+}
+module genReductions {
+    gen bool[3] flags
+    flags[0] = true
+    flags[1] = false
+    flags[2] = true
+    gen bool ALL = &flags
+    gen bool ANY = |flags
+    gen bool PARITY = ^flags
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+
+    let warnings: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|(_, d)| d.level == ErrorLevel::Warning && d.reason.starts_with("Reduction"))
+        .collect();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].1.reason,
+        "Reduction '|' of a single bit does nothing. Use [0] to get the bit instead"
+    );
+
+    let verilog = result.verilog("reductions").unwrap();
+    assert!(verilog.contains(" = &a;"));
+    assert!(verilog.contains(" = |a;"));
+    assert!(verilog.contains(" = ^a;"));
+
+    let gen_value = |name: &str| eval_generative(&result.linker, "genReductions", name).unwrap();
+    assert_eq!(gen_value("ALL"), Value::Bool(false));
+    assert_eq!(gen_value("ANY"), Value::Bool(true));
+    assert_eq!(gen_value("PARITY"), Value::Bool(false));
+}
+
+#[test]
+fn test_sized_integer_literals() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "literals.sus".to_owned(),
+            "
+module sizedLiterals {
+    interface sizedLiterals : int x'0 -> int y'2
+    gen int HEX = 8'hFF
+    gen int BIN = 4'b1010 + 1_000
+    gen int DEC = 12'd100
+    gen int OCT = 6'O17
+    reg reg y = x + HEX
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+
+    let gen_value = |name: &str| eval_generative(&result.linker, "sizedLiterals", name).unwrap();
+    assert_eq!(gen_value("HEX"), Value::Integer(255.into()));
+    assert_eq!(gen_value("BIN"), Value::Integer(1010.into()));
+    assert_eq!(gen_value("DEC"), Value::Integer(100.into()));
+    assert_eq!(gen_value("OCT"), Value::Integer(15.into()));
+
+    // The latency specifiers next to the literals still parse as latencies
+    let verilog = result.verilog("sizedLiterals").unwrap();
+    assert!(verilog.contains("wire [31:0] _2 = x + 255;"));
+    assert!(verilog.contains("y = __2_D2;"));
+}
+
+#[test]
+fn test_sized_integer_literal_overflow() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "overflow.sus".to_owned(),
+            "
+module literalOverflow {
+    gen int TOO_BIG = 8'h1FF
+    gen int FITS = 9'h1FF
+    gen int NEGATIVE_SIZED = -8'h01
+    gen int NEGATIVE = -1
+    gen int NEGATIVE_ZERO = -8'h00
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(
+        error_reasons(&result),
+        ["'8'h1FF' doesn't fit in its declared width of 8 bits, 511 needs at least 9 bits"]
+    );
+
+    // Only the negated sized literal can't keep its sign, the unsized one and zero are fine
+    let warnings: Vec<&str> = result
+        .diagnostics
+        .iter()
+        .filter(|(_, d)| d.level == ErrorLevel::Warning && d.reason.starts_with("Sized literals"))
+        .map(|(_, d)| d.reason.as_str())
+        .collect();
+    assert_eq!(
+        warnings,
+        ["Sized literals are unsigned, so '-8'h01' is 255 when kept to its 8 bits. Use an unsized literal for negative values"]
+    );
+}
+
+#[test]
+fn test_operand_width_mismatch_warning() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "widths.sus".to_owned(),
+            "
+module widths {
+    interface widths : int a -> int sized, int unsized
+    sized = a + 8'hFF
+    unsized = a + 3
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+
+    // Only the sized literal has a width to disagree with, a plain 3 takes on the width of int
+    let warnings: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|(_, d)| d.level == ErrorLevel::Warning && d.reason.starts_with("Operands of"))
+        .collect();
+    assert_eq!(warnings.len(), 1);
+    let warning = &warnings[0].1;
+    assert_eq!(
+        warning.reason,
+        "Operands of '+' have different widths (32 and 8 bits). The narrower one is implicitly extended"
+    );
+    let infos: Vec<&str> = warning.infos.iter().map(|i| i.info.as_str()).collect();
+    assert_eq!(
+        infos,
+        ["Left side is 32 bits wide", "Right side is 8 bits wide"]
+    );
+}
+
+#[test]
+fn test_module_interface() {
+    let source = "
+module delayed_add {
+    interface delayed_add : int a, bool[3] b -> int c, bool d
+    reg c = a
+    d = b[1] & (a == 3)
+}
+";
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![("test.sus".to_owned(), source.to_owned())],
+    );
+    let linker = &result.linker;
+    let (md_id, _md) = find_module(linker, "delayed_add");
+
+    let ports = linker.module_interface(md_id, &FlatAlloc::new()).unwrap();
+    let summary: Vec<_> = ports
+        .iter()
+        .map(|p| (p.name.as_str(), p.is_input, p.bit_width, p.absolute_latency))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("a", true, Some(32), 0),
+            ("b", true, Some(3), 0),
+            ("c", false, Some(32), 1),
+            ("d", false, Some(1), 0)
+        ]
+    );
+}
+
+#[test]
+fn test_eval_generative() {
+    use sus_compiler::typing::concrete_type::ConcreteType;
+
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "eval.sus".to_owned(),
+            "
+module fifo #(int DEPTH) {
+    gen int DOUBLE = DEPTH * 2 + 1
+    gen int RATIO = 12 / (DEPTH - 4)
+}
+"
+            .to_owned(),
+        )],
+    );
+    let linker = &result.linker;
+    let (md_id, md) = find_module(linker, "fifo");
+    let decl = |name: &str| {
+        md.link_info
+            .instructions
+            .iter()
+            .find(|(_, instr)| matches!(instr, Instruction::Declaration(d) if d.name == name))
+            .unwrap()
+            .0
+    };
+    let depth = |v: i32| {
+        let mut args = FlatAlloc::new();
+        args.alloc(ConcreteType::Value(Value::Integer(v.into())));
+        args
+    };
+
+    assert_eq!(
+        linker
+            .eval_generative(md_id, decl("DOUBLE"), &depth(10))
+            .unwrap(),
+        Value::Integer(21.into())
+    );
+    assert_eq!(
+        linker
+            .eval_generative(md_id, decl("RATIO"), &depth(7))
+            .unwrap(),
+        Value::Integer(4.into())
+    );
+
+    let errors = linker
+        .eval_generative(md_id, decl("DOUBLE"), &depth(4))
+        .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].reason, "Divide or Modulo by zero: 12 / 0");
+
+    let errors = linker
+        .eval_generative(md_id, decl("DOUBLE"), &FlatAlloc::new())
+        .unwrap_err();
+    assert!(errors[0].reason.contains("has 1 template parameters"));
+}
+
+#[test]
+fn test_generative_array_access() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "arrays.sus".to_owned(),
+            "
+module arrays {
+    gen int[8] squares
+    gen int[3][2] grid
+    for int i in 0..8 {
+        squares[i] = i * i
+    }
+    for int x in 0..2 {
+        for int y in 0..3 {
+            grid[x][y] = squares[x + y]
+        }
+    }
+    gen int A = squares[5]
+    gen int B = grid[1][2]
+    gen int[3] C = grid[1]
+}
+module outOfRange {
+    gen int[8] arr
+    gen int D = arr[8]
+}
+"
+            .to_owned(),
+        )],
+    );
+    let linker = &result.linker;
+    let eval = |md_name: &str, name: &str| eval_generative(linker, md_name, name);
+    let int = |v: i32| Value::Integer(v.into());
+
+    assert_eq!(eval("arrays", "A").unwrap(), int(25));
+    assert_eq!(eval("arrays", "B").unwrap(), int(9));
+    assert_eq!(
+        eval("arrays", "C").unwrap(),
+        Value::Array(vec![int(1), int(4), int(9)].into_boxed_slice())
+    );
+    let errors = eval("outOfRange", "D").unwrap_err();
+    assert_eq!(
+        errors[0].reason,
+        "Compile-Time Array index is out of range: idx: 8, array size: 8"
+    );
+}
+
+#[test]
+fn test_array_slices() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "slices.sus".to_owned(),
+            "
+module slices {
+    interface slices : bool[8] a -> bool[4] mid, bool[2] top
+    mid = a[5:2]
+    top[1:0] = a[7:6]
+}
+module genSlices {
+    gen int[5] vals
+    for int i in 0..5 {
+        vals[i] = i * 10
+    }
+    gen int[3] middle = vals[3:1]
+    gen int[5] written = vals
+    written[4:3] = middle[1:0]
+}
+module reversedSlice {
+    interface reversedSlice : bool[8] a -> bool[2] b
+    b = a[2:3]
+}
+module outOfRangeSlice {
+    interface outOfRangeSlice : bool[8] a -> bool[2] b
+    b = a[8:7]
+}
+module runtimeSliceBound {
+    interface runtimeSliceBound : bool[8] a, int i -> bool[2] b
+    b = a[i:0]
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(
+        error_reasons(&result),
+        vec![
+            "Reversed slice bounds [2:3]. The high bound must come first, like [3:2]",
+            "Slice [8:7] is out of range for this array of size 8",
+            "Slice bound must be a compile-time expression",
+        ]
+    );
+
+    let verilog = result.verilog("slices").unwrap();
+    assert!(verilog.contains("wire [3:0] _1 = a[5:2];"));
+    assert!(verilog.contains("wire [1:0] _2 = a[7:6];"));
+
+    let int = |v: i32| Value::Integer(v.into());
+    let ints = |vs: &[i32]| Value::Array(vs.iter().map(|v| int(*v)).collect());
+    let eval = |name: &str| eval_generative(&result.linker, "genSlices", name).unwrap();
+    assert_eq!(eval("middle"), ints(&[10, 20, 30]));
+    assert_eq!(eval("written"), ints(&[0, 10, 20, 10, 20]));
+}
+
+#[test]
+fn test_eval_expression() {
+    let mut compiler = compiler_with_std();
+    compiler.add_file(
+        "constants.sus".to_owned(),
+        "const int WIDTH {\n    WIDTH = 8\n}\n".to_owned(),
+    );
+    let int = |v: i32| Value::Integer(v.into());
+
+    assert_eq!(
+        compiler.eval_expression("clog2 #(V: 100) + 3").unwrap(),
+        int(10)
+    );
+    assert_eq!(compiler.eval_expression("WIDTH * 2").unwrap(), int(16));
+    assert_eq!(
+        compiler.eval_expression("(3 > 2) & true").unwrap(),
+        Value::Bool(true)
+    );
+
+    let mut eval_errors = |expression: &str| -> Vec<String> {
+        let errors = compiler.eval_expression(expression).unwrap_err();
+        errors.into_iter().map(|(_, err)| err.reason).collect()
+    };
+    assert_eq!(
+        eval_errors("nope + 1"),
+        ["No Global of the name 'nope' was found. Did you forget to import it?"]
+    );
+    assert_eq!(eval_errors("5 / 0"), ["Divide or Modulo by zero: 5 / 0"]);
+    assert_eq!(eval_errors(""), ["Expected an expression"]);
+}
+
+#[test]
+fn test_user_constants() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "constants.sus".to_owned(),
+            "
+const int WIDTH {
+    WIDTH = 8
+}
+const int SQUARE #(int V) {
+    SQUARE = V * V
+}
+const int FACT #(int N) {
+    if N == 0 {
+        FACT = 1
+    } else {
+        FACT = N * FACT #(N: N - 1)
+    }
+}
+const int CYC_A {
+    CYC_A = CYC_B
+}
+const int CYC_B {
+    CYC_B = CYC_A
+}
+const int NEVER {}
+const int LOOP #(int N) {
+    LOOP = LOOP #(N: N)
+}
+module sized #(int W) {
+    interface sized : int[W] a -> int[W] b
+    b = a
+}
+module useConsts {
+    interface useConsts : int[WIDTH] a -> int[SQUARE #(V: 2)] b
+    sized #(W: WIDTH) s
+    int[WIDTH] c = s(a)
+    for int i in 0..SQUARE #(V: 2) {
+        b[i] = c[i]
+    }
+    gen int A = WIDTH
+    gen int B = SQUARE #(V: WIDTH)
+    gen int C = FACT #(N: 5)
+}
+module useCycle {
+    gen int D = CYC_A
+}
+module useNever {
+    gen int E = NEVER
+}
+module useLoop {
+    gen int L = LOOP #(N: 3)
+}
+"
+            .to_owned(),
+        )],
+    );
+    // Cycles between constants without template parameters are found where they're declared. The rest fails where it's used
+    let errors: Vec<_> = result.errors().map(|err| err.reason.as_str()).collect();
+    assert_eq!(
+        errors,
+        [
+            "Circular constant reference: CYC_A -> CYC_B -> CYC_A",
+            "Circular constant reference: CYC_B -> CYC_A -> CYC_B",
+            "Constant 'CYC_A' has errors",
+            "Constant 'NEVER' is never assigned a value",
+            "Could not evaluate 'LOOP': Circular constant reference: LOOP -> LOOP"
+        ]
+    );
+    let linker = &result.linker;
+    let eval = |md_name: &str, name: &str| eval_generative(linker, md_name, name);
+    let int = |v: i32| Value::Integer(v.into());
+
+    assert_eq!(eval("useConsts", "A").unwrap(), int(8));
+    assert_eq!(eval("useConsts", "B").unwrap(), int(64));
+    assert_eq!(eval("useConsts", "C").unwrap(), int(120));
+    // Usable as array sizes and template arguments
+    let (md_id, _) = find_module(linker, "useConsts");
+    let ports: Vec<_> = linker
+        .module_interface(md_id, &FlatAlloc::new())
+        .unwrap()
+        .into_iter()
+        .map(|port| (port.name, port.bit_width))
+        .collect();
+    assert_eq!(
+        ports,
+        [
+            ("a".to_owned(), Some(8 * 32)),
+            ("b".to_owned(), Some(4 * 32))
+        ]
+    );
+
+    let errors = eval("useCycle", "D").unwrap_err();
+    assert_eq!(errors[0].reason, "Constant 'CYC_A' has errors");
+    let errors = eval("useLoop", "L").unwrap_err();
+    assert_eq!(
+        errors[0].reason,
+        "Could not evaluate 'LOOP': Circular constant reference: LOOP -> LOOP"
+    );
+}
+
+#[test]
+fn test_unused_constant_cycle() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "cycle.sus".to_owned(),
+            "
+const int PING {
+    PING = PONG + 1
+}
+const int PONG {
+    PONG = PING
+}
+"
+            .to_owned(),
+        )],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    let reasons: Vec<&str> = errors.iter().map(|err| err.reason.as_str()).collect();
+    assert_eq!(
+        reasons,
+        [
+            "Circular constant reference: PING -> PONG -> PING",
+            "Circular constant reference: PONG -> PING -> PONG"
+        ]
+    );
+    assert_eq!(errors[0].infos[0].info, "Refers to 'PONG' here");
+    assert_eq!(errors[1].infos[0].info, "Refers to 'PING' here");
+}
+
+#[test]
+fn test_user_constant_values_are_reused() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "fib.sus".to_owned(),
+            "
+const int FIB #(int N) {
+    if N < 2 {
+        FIB = N
+    } else {
+        FIB = FIB #(N: N - 1) + FIB #(N: N - 2)
+    }
+}
+module useFib {
+    gen int F = FIB #(N: 80)
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+    // Evaluating each FIB #(N) anew would take 2^80 steps, so this only finishes if the values are reused
+    let f = eval_generative(&result.linker, "useFib", "F").unwrap();
+    assert_eq!(f, Value::Integer(23416728348467685u64.into()));
+}
+
+#[test]
+fn test_no_instantiation_errors_before_instantiation() {
+    use sus_compiler::config::EarlyExitUpTo;
+
+    let mut linker = linker_with_std();
+    linker.add_file(
+        "div.sus".to_owned(),
+        "module div {\n    gen int X = 3 / 0\n}\n".to_owned(),
+        &mut (),
+    );
+    let errors = |linker: &Linker| {
+        linker
+            .collect_all_diagnostics()
+            .into_iter()
+            .filter(|(_, err)| err.level == ErrorLevel::Error)
+            .map(|(_, err)| err.reason)
+            .collect::<Vec<_>>()
+    };
+
+    linker.recompile_up_to(EarlyExitUpTo::Lint);
+    assert_eq!(errors(&linker), Vec::<String>::new());
+    // An instance created on demand, like for a hover, must not leak its errors into the diagnostics
+    let (md_id, _) = find_module(&linker, "div");
+    assert!(linker.module_interface(md_id, &FlatAlloc::new()).is_err());
+    assert_eq!(errors(&linker), Vec::<String>::new());
+
+    linker.recompile_up_to(EarlyExitUpTo::Instantiate);
+    assert_eq!(errors(&linker), ["Divide or Modulo by zero: 3 / 0"]);
+}
+
+#[test]
+fn test_diagnostics_for_module() {
+    use sus_compiler::config::EarlyExitUpTo;
+
+    let mut linker = linker_with_std();
+    linker.add_file(
+        "siblings.sus".to_owned(),
+        "
+module divides {
+    gen int X = 3 / 0
+}
+module unknown {
+    int x = missing
+}
+module clean {}
+"
+        .to_owned(),
+        &mut (),
+    );
+    linker.recompile_up_to(EarlyExitUpTo::Instantiate);
+
+    let reasons = |name: &str| {
+        let (md_id, _) = find_module(&linker, name);
+        linker
+            .diagnostics_for_module(md_id)
+            .into_iter()
+            .filter(|err| err.level == ErrorLevel::Error)
+            .map(|err| err.reason)
+            .collect::<Vec<_>>()
+    };
+    // Errors from the module's own instantiation
+    assert_eq!(reasons("divides"), ["Divide or Modulo by zero: 3 / 0"]);
+    assert_eq!(
+        reasons("unknown"),
+        ["No Global of the name 'missing' was found. Did you forget to import it?"]
+    );
+    assert_eq!(reasons("clean"), Vec::<String>::new());
+}
+
+#[test]
+fn test_instance_path_of_submodule_errors() {
+    use sus_compiler::config::EarlyExitUpTo;
+    let mut linker = linker_with_std();
+    linker.add_file(
+        "nested.sus".to_owned(),
+        "
+module inner {
+    gen int X = 3 / 0
+}
+module mid {
+    inner mem
+}
+module top {
+    mid fifo
+}
+"
+        .to_owned(),
+        &mut (),
+    );
+    linker.recompile_up_to(EarlyExitUpTo::Lint);
+
+    assert!(linker.compile_module("top").is_err());
+    // Only 'top' was instantiated directly, so 'inner' was first reached through 'fifo' and 'mem'
+    let (_, inner) = find_module(&linker, "inner");
+    let instance = inner.instantiations.get(&FlatAlloc::new()).unwrap();
+    let error = instance.errors.into_iter().next().unwrap();
+    assert_eq!(error.instance_path().as_deref(), Some("top.fifo.mem"));
+    assert!(error
+        .reason_with_instance_path()
+        .starts_with("In top.fifo.mem: "));
+}
+
+#[test]
+fn test_mangled_names() {
+    use sus_compiler::config::ManglingScheme;
+    use sus_compiler::instantiation::mangle_name;
+
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "mangle.sus".to_owned(),
+            "
+module passOn #(int OFFSET) {
+    interface passOn : int i -> int o
+    o = i + OFFSET
+}
+module top {
+    interface top : int i -> int a, int b
+    a = passOn #(OFFSET: 6)(i)
+    b = passOn #(OFFSET: -6)(i)
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+    let mut names: Vec<String> = result
+        .instances("passOn")
+        .iter()
+        .map(|inst| inst.mangled_name.clone())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["passOn__OFFSET6__", "passOn__OFFSET_6__"]);
+
+    let instance_name = "passOn #(\n    OFFSET: 6,\n)";
+    assert_eq!(
+        mangle_name(ManglingScheme::Readable, "passOn", instance_name),
+        "passOn__OFFSET6__"
+    );
+    let hashed = mangle_name(ManglingScheme::Hash, "passOn", instance_name);
+    assert!(hashed.starts_with("passOn_"));
+    assert_eq!(hashed.len(), "passOn_".len() + 16);
+    assert_ne!(
+        hashed,
+        mangle_name(
+            ManglingScheme::Hash,
+            "passOn",
+            "passOn #(\n    OFFSET: -6,\n)"
+        )
+    );
+}
+
+#[test]
+fn test_template_arg_error_spans() {
+    let text = "
+module m #(int W) {
+    interface m : bool[W] x -> bool y
+    y = x[0]
+}
+module top {
+    interface top : bool[3] a -> bool b
+    b = m #(W: -3)(a)
+}
+module pass #(T) {
+    interface pass : T x -> T y
+    y = x
+}
+module top2 {
+    interface top2 : int[2] a -> int[2] b
+    b = pass #(T: type int[-2])(a)
+}
+";
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![("args.sus".to_owned(), text.to_owned())],
+    );
+    let errors: Vec<_> = result.errors().collect();
+
+    let negative_type_arg = errors
+        .iter()
+        .find(|err| err.reason == "Array size cannot be negative: -2")
+        .unwrap();
+    assert_eq!(&text[negative_type_arg.position.as_range()], "-2");
+
+    let failed_instance = errors
+        .iter()
+        .find(|err| err.reason == "Error instantiating submodule")
+        .unwrap();
+    assert_eq!(failed_instance.infos.len(), 1);
+    assert_eq!(failed_instance.infos[0].info, "'W' is -3");
+    assert_eq!(&text[failed_instance.infos[0].position.as_range()], "-3");
+}
+
+#[test]
+fn test_submodules_in_generative_loops() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "loops.sus".to_owned(),
+            "
+module inc {
+    interface inc : int a -> int b
+    b = a + 1
+}
+module grid {
+    interface grid : int[2][3] a -> int[2][3] b
+    for int y in 0..2 {
+        for int x in 0..3 {
+            inc cell
+            b[y][x] = cell(a[y][x])
+        }
+    }
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+    let inst = result.instances("grid").into_iter().next().unwrap();
+    let names: Vec<&str> = inst
+        .submodules
+        .iter()
+        .map(|(_, sm)| sm.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        ["cell_0_0", "cell_0_1", "cell_0_2", "cell_1_0", "cell_1_1", "cell_1_2"]
+    );
+    let verilog = result.verilog("grid").unwrap();
+    assert!(verilog.contains("inc___ cell_1_2("));
+}
+
+#[test]
+fn test_output_port_latency() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "latency.sus".to_owned(),
+            "
+module tooShallow {
+    input int a'0
+    output int result'3
+    reg reg reg reg reg int x = a
+    result = x
+}
+module padded {
+    input int a'0
+    output int result'3
+    reg int x = a
+    result = x
+}
+"
+            .to_owned(),
+        )],
+    );
+    let errors: Vec<_> = result.errors().collect();
+    assert_eq!(errors.len(), 1);
+    // Both the declared latency, and the latency computed along the path
+    assert_eq!(
+        errors[0].reason,
+        "Conflicting specified latency: 'result' is specified as result'3, but coming from a'0 it can't be earlier than result'5
+
+a'0
+-> x'5 (+5)
+-> result'5 (+0)
+
+Remove registers from this path, or specify a later latency for 'result' or an earlier one for 'a'"
+    );
+    // The other side of the conflict, and each write along the path, are labeled
+    let infos: Vec<&str> = errors[0].infos.iter().map(|i| i.info.as_str()).collect();
+    assert_eq!(
+        infos,
+        [
+            "'a' is specified as a'0",
+            "'x' is at latency 5 at the earliest",
+            "'result' is at latency 5 at the earliest"
+        ]
+    );
+
+    // A larger declared latency than the pipeline needs is met by adding registers
+    let padded = &result.instances("padded")[0];
+    let result_wire = padded
+        .wires
+        .iter()
+        .find(|(_, w)| w.name == "result")
+        .unwrap()
+        .1;
+    assert_eq!(result_wire.absolute_latency, 3);
+}
+
+#[test]
+fn test_compile_module() {
+    use sus_compiler::config::EarlyExitUpTo;
+
+    let mut linker = linker_with_std();
+    linker.add_file(
+        "top.sus".to_owned(),
+        "
+module leaf {
+    interface leaf : int a -> int b
+    b = a + 1
+}
+module top {
+    interface top : int a -> int b
+    leaf l
+    b = l(a)
+}
+module unrelated {
+    output int o = 3
+}
+module withParam #(int W) {
+    output int o = W
+}
+"
+        .to_owned(),
+        &mut (),
+    );
+    linker.recompile_up_to(EarlyExitUpTo::Lint);
+
+    let instance = linker.compile_module("top").unwrap();
+    assert_eq!(instance.name, "::top #()");
+    let instance_count = |name: &str| {
+        let (_, md) = find_module(&linker, name);
+        let mut count = 0;
+        md.instantiations.for_each_instance(|_, _| count += 1);
+        count
+    };
+    assert_eq!(instance_count("leaf"), 1);
+    assert_eq!(instance_count("unrelated"), 0);
+
+    assert!(matches!(
+        linker.compile_module("doesNotExist"),
+        Err(CompileModuleError::UnknownModule(_))
+    ));
+    let Err(CompileModuleError::Errors(errors)) = linker.compile_module("withParam") else {
+        panic!("withParam requires template arguments")
+    };
+    assert!(errors[0].reason.contains("requires template arguments"));
+    assert_eq!(errors[0].infos[0].info, "'W' has no default value");
+}
+
+#[test]
+fn test_netlist() {
+    use sus_compiler::instantiation::netlist::{NetDriver, NetSink};
+
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "netlist.sus".to_owned(),
+            "
+module inc {
+    interface inc : int[2] a -> int[2] b
+    b = a
+}
+module top {
+    interface top : int[2] x -> int[2] y
+    inc i
+    y = i(x)
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+    let (top_id, _) = find_module(&result.linker, "top");
+    let netlist = result.linker.netlist(top_id, FlatAlloc::new()).unwrap();
+
+    let (sm_id, sm) = netlist.submodules.iter().next().unwrap();
+    assert_eq!(sm.name, "i");
+    let port_named = |name: &str| {
+        (netlist.ports.iter_valids())
+            .find(|(_, p)| p.name == name)
+            .unwrap()
+    };
+    let (x_port, x) = port_named("x");
+    let (y_port, y) = port_named("y");
+
+    // x drives the input port a of i
+    let x_net = &netlist.nets[x.net];
+    assert_eq!(x_net.driver, NetDriver::Port(x_port));
+    assert_eq!(x_net.width, Some(64));
+    let [NetSink::Net(i_a)] = x_net.sinks[..] else {
+        panic!("{:?}", x_net.sinks)
+    };
+    assert!(matches!(
+        netlist.nets[i_a].sinks[..],
+        [NetSink::SubModulePort { submodule, .. }] if submodule == sm_id
+    ));
+
+    // The output port b of i drives y
+    let y_net = &netlist.nets[y.net];
+    assert_eq!(y_net.sinks, vec![NetSink::Port(y_port)]);
+    let NetDriver::Logic { inputs } = &y_net.driver else {
+        panic!("{:?}", y_net.driver)
+    };
+    let i_b = &netlist.nets[inputs[0]];
+    assert!(matches!(
+        i_b.driver,
+        NetDriver::SubModulePort { submodule, .. } if submodule == sm_id
+    ));
+    assert_eq!(i_b.sinks, vec![NetSink::Net(y.net)]);
+}
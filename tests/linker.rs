@@ -0,0 +1,895 @@
+//! Tests of the linker: adding, updating and recompiling files, and the information about them it gives to tools
+mod common;
+
+use common::{compiler_with_std, error_reasons, linker_with_std};
+use std::path::{Path, PathBuf};
+use sus_compiler::compiler_top::{
+    FsSourceProvider, MemorySourceProvider, SourceProvider, SpliceError, TextEdit,
+};
+use sus_compiler::errors::{ErrorInfo, ErrorLevel};
+use sus_compiler::file_position::LineCol;
+use sus_compiler::prelude::{FileUUID, Linker, Span};
+use sus_compiler::{compile_sources, Compiler, CompilerOptions};
+
+#[test]
+fn test_node_at_offset() {
+    let mut linker = Linker::new();
+    let text = "module ab {}\n";
+    let file = linker.add_file("test.sus".to_owned(), text.to_owned(), &mut ());
+
+    let kind_at = |offset| linker.node_at_offset(file, offset).map(|node| node.kind());
+    assert_eq!(kind_at(0), Some("global_object"));
+    assert_eq!(kind_at(7), Some("identifier"));
+    assert_eq!(kind_at(8), Some("identifier"));
+    // The identifier ends at 9, so the enclosing node is returned
+    assert_eq!(kind_at(9), Some("global_object"));
+    // The block starts at 10
+    assert_eq!(kind_at(10), Some("block"));
+    assert_eq!(kind_at(text.len()), Some("source_file"));
+    assert_eq!(kind_at(text.len() + 1), None);
+}
+
+#[test]
+fn test_file_without_declarations_warns() {
+    let mut linker = Linker::new();
+    for (name, text) in [("empty.sus", ""), ("comment.sus", "// just a comment\n")] {
+        let file = linker.add_file(name.to_owned(), text.to_owned(), &mut ());
+        let file_data = &linker.files[file];
+        assert!(file_data.associated_values.is_empty());
+        let warnings: Vec<_> = (&file_data.parsing_errors).into_iter().collect();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].level, ErrorLevel::Warning);
+        assert_eq!(
+            warnings[0].reason,
+            "File contains no module/type/constant declarations"
+        );
+    }
+}
+
+#[test]
+fn test_unreadable_file_is_an_error() {
+    let mut linker = Linker::new();
+    let file = linker.add_file_from_path(
+        "missing.sus".to_owned(),
+        Path::new("this/file/does/not/exist.sus"),
+        &FsSourceProvider,
+        &mut (),
+    );
+    let errors: Vec<_> = (&linker.files[file].parsing_errors).into_iter().collect();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].level, ErrorLevel::Error);
+    assert!(errors[0].reason.starts_with("Could not read file"));
+}
+
+/// Lists one file in every directory except /missing, but can't read any of them
+struct UnreadableSources;
+
+impl SourceProvider for UnreadableSources {
+    fn read_file(&self, _path: &Path) -> std::io::Result<String> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "it is locked",
+        ))
+    }
+    fn files_in_directory(&self, directory: &Path) -> std::io::Result<Vec<PathBuf>> {
+        if directory == Path::new("/missing") {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "it doesn't exist",
+            ))
+        } else {
+            Ok(vec![directory.join("locked.sus")])
+        }
+    }
+}
+
+#[test]
+fn test_unreadable_directory_is_an_error() {
+    let mut linker = Linker::new();
+    linker.add_all_files_in_directory(Path::new("/missing"), &UnreadableSources, &mut ());
+    linker.add_all_files_in_directory(Path::new("/project"), &UnreadableSources, &mut ());
+
+    let reasons: Vec<String> = linker
+        .files
+        .iter()
+        .flat_map(|(_, file)| (&file.parsing_errors).into_iter())
+        .map(|err| err.reason.clone())
+        .collect();
+    assert_eq!(
+        reasons,
+        [
+            "Could not read directory '/missing' because it doesn't exist",
+            "Could not read file '/project/locked.sus' because it is locked"
+        ]
+    );
+}
+
+#[test]
+fn test_memory_source_provider() {
+    // The standard library has to come from the provider too
+    let mut sources = MemorySourceProvider::with_standard_library();
+    sources.files.insert(
+        PathBuf::from("/virtual/memTop.sus"),
+        "module memTop {\n    interface memTop : int x -> int y\n    y = x\n}\n".to_owned(),
+    );
+
+    let mut compiler =
+        Compiler::with_source_provider(CompilerOptions::default(), Box::new(sources));
+    let file = compiler.add_file_from_path(Path::new("/virtual/memTop.sus"));
+    let missing = compiler.add_file_from_path(Path::new("/virtual/missing.sus"));
+    compiler.compile();
+
+    assert!((&compiler.linker.files[file].parsing_errors)
+        .into_iter()
+        .next()
+        .is_none());
+    assert!(compiler
+        .linker
+        .modules
+        .iter()
+        .any(|(_, md)| md.link_info.name == "memTop"));
+    let errors: Vec<_> = (&compiler.linker.files[missing].parsing_errors)
+        .into_iter()
+        .collect();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].reason.starts_with("Could not read file"));
+    // Everything else, the standard library included, compiled cleanly
+    let all_errors: Vec<String> = compiler
+        .linker
+        .collect_all_diagnostics()
+        .into_iter()
+        .filter(|(_, err)| err.level == ErrorLevel::Error)
+        .map(|(_, err)| err.reason)
+        .collect();
+    assert_eq!(all_errors, [errors[0].reason.clone()]);
+}
+
+#[test]
+fn test_compile_sources() {
+    let result =
+        compile_sources(
+            CompilerOptions::default(),
+            vec![
+            (
+                "a.sus".to_owned(),
+                "module passThrough {\n    interface passThrough : int x -> int y\n    y = x\n}\n"
+                    .to_owned(),
+            ),
+            ("b.sus".to_owned(), "module broken {\n    int z = q\n}\n".to_owned()),
+        ],
+        );
+    assert_eq!(result.files.len(), 2);
+    assert_eq!(result.instances("passThrough").len(), 1);
+    assert!(result
+        .verilog("passThrough")
+        .unwrap()
+        .contains("module passThrough"));
+    assert!(result.verilog("nonexistent").is_none());
+
+    assert_eq!(
+        error_reasons(&result),
+        ["No Global of the name 'q' was found. Did you forget to import it?"]
+    );
+    assert!(result
+        .diagnostics
+        .iter()
+        .filter(|(_, err)| err.level == ErrorLevel::Error)
+        .all(|(file, _)| *file == result.files[1]));
+}
+
+#[test]
+fn test_tokens() {
+    use sus_compiler::linker::tokens::TokenKind;
+
+    let mut compiler = compiler_with_std();
+    let text = "// Adds one\nmodule addOne {\n    interface addOne : int a -> int b\n    reg b = a + 1\n}\n";
+    let file = compiler.add_file("tokens.sus".to_owned(), text.to_owned());
+    let tokens: Vec<(TokenKind, &str)> = compiler
+        .linker
+        .tokens(file)
+        .map(|(kind, span)| (kind, &text[span.as_range()]))
+        .collect();
+    assert_eq!(
+        tokens[..6],
+        [
+            (TokenKind::Comment, "// Adds one"),
+            (TokenKind::Keyword, "module"),
+            (TokenKind::Identifier, "addOne"),
+            (TokenKind::Punctuation, "{"),
+            (TokenKind::Keyword, "interface"),
+            (TokenKind::Identifier, "addOne"),
+        ]
+    );
+    assert!(tokens.contains(&(TokenKind::Operator, "->")));
+    assert!(tokens.contains(&(TokenKind::Number, "1")));
+    assert_eq!(tokens.last(), Some(&(TokenKind::Punctuation, "}")));
+}
+
+#[test]
+fn test_crlf_and_bom() {
+    let lf_text = "
+/// Adds one
+module addOne {
+    interface addOne : int a -> int b
+    b = a + z
+}
+";
+    let crlf_bom_text = format!("\u{FEFF}{}", lf_text.replace('\n', "\r\n"));
+
+    let error_positions = |text: &str| {
+        let result = compile_sources(
+            CompilerOptions::default(),
+            vec![("crlf.sus".to_owned(), text.to_owned())],
+        );
+        let file_text = &result.linker.files[result.files[0]].file_text;
+        let positions: Vec<(usize, usize)> = result
+            .errors()
+            .map(|err| file_text.byte_to_line_col(err.position.as_range().start))
+            .collect();
+        let md = result.find_module("addOne").unwrap();
+        let docs = md.link_info.documentation.to_string(file_text);
+        (positions, docs)
+    };
+
+    let (lf_positions, lf_docs) = error_positions(lf_text);
+    let (crlf_positions, crlf_docs) = error_positions(&crlf_bom_text);
+    // Only the error for 'z'
+    assert_eq!(lf_positions, vec![(5, 13)]);
+    assert_eq!(crlf_positions, lf_positions);
+    assert_eq!(crlf_docs, lf_docs);
+}
+
+#[test]
+fn test_parallel_diagnostics_are_deterministic() {
+    // Enough independent modules that flattening and typechecking are spread over threads
+    let text: String = (0..64)
+        .map(|i| {
+            format!(
+                "
+module m{i} {{
+    interface m{i} : int a -> bool b
+    b = a + missing{i}
+    int unused{i}
+}}
+"
+            )
+        })
+        .collect();
+    let diagnostics = || {
+        let result = compile_sources(
+            CompilerOptions::default(),
+            vec![("many.sus".to_owned(), text.clone())],
+        );
+        result
+            .diagnostics
+            .into_iter()
+            .map(|(file, err)| (file, err.position, err.reason))
+            .collect::<Vec<_>>()
+    };
+
+    let first = diagnostics();
+    assert!(first
+        .iter()
+        .any(|(_, _, reason)| reason.contains("missing63")));
+    for _ in 0..4 {
+        assert_eq!(diagnostics(), first);
+    }
+}
+
+#[test]
+fn test_for_each_file_diagnostics() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![
+            (
+                "good.sus".to_owned(),
+                "module good {\n    interface good : int x -> int y\n    y = x\n}\n".to_owned(),
+            ),
+            (
+                "bad.sus".to_owned(),
+                "module bad {\n    int z = q\n}\n".to_owned(),
+            ),
+        ],
+    );
+    let mut files_seen = Vec::new();
+    result
+        .linker
+        .for_each_file_diagnostics(|file, diagnostics| {
+            if file == result.files[0] {
+                assert!(diagnostics.is_empty());
+            }
+            if file == result.files[1] {
+                assert!(diagnostics.iter().any(|err| err.level == ErrorLevel::Error));
+            }
+            files_seen.push(file);
+        });
+    assert_eq!(files_seen.len(), result.linker.files.iter().count());
+}
+
+#[test]
+fn test_info_in_other_file() {
+    let file_b_text = "module subtract {\n    interface subtract : int a, int b -> int diff\n    diff = a - b\n}\n";
+    let mut compiler = compiler_with_std();
+    let file_a = compiler.add_file(
+        "a.sus".to_owned(),
+        "module useIt {\n    interface useIt : int x -> int o\n    o = subtract(x, x, x)\n}\n"
+            .to_owned(),
+    );
+    let file_b = compiler.add_file("b.sus".to_owned(), file_b_text.to_owned());
+    compiler.compile();
+
+    let (error_file, error) = compiler
+        .linker
+        .collect_all_diagnostics()
+        .into_iter()
+        .find(|(_, err)| err.level == ErrorLevel::Error)
+        .unwrap();
+    assert_eq!(error_file, file_a);
+    let infos = error.all_infos(&compiler.linker);
+    assert_eq!(infos.len(), 1);
+    assert_eq!(infos[0].info, "Interface 'subtract' defined here");
+    assert_eq!(infos[0].file, file_b);
+    assert_eq!(&file_b_text[infos[0].position.as_range()], "subtract");
+
+    // Rendering must look up the info's span in its own file
+    compiler.print_errors();
+}
+
+#[test]
+fn test_duplicate_infos_are_shown_once() {
+    let mut compiler = compiler_with_std();
+    let file_text = "module dup {}\nmodule dup {}\n";
+    let file = compiler.add_file("dup.sus".to_owned(), file_text.to_owned());
+    compiler.compile();
+    let (_, mut error) = compiler
+        .linker
+        .collect_all_diagnostics()
+        .into_iter()
+        .find(|(_, err)| err.level == ErrorLevel::Error)
+        .unwrap();
+    let conflict = error.infos[0].clone();
+    let other = ErrorInfo {
+        info: "Something else".to_owned(),
+        ..conflict.clone()
+    };
+    error.infos = vec![conflict.clone(), other, conflict];
+
+    let infos: Vec<_> = (error.all_infos(&compiler.linker).into_iter())
+        .map(|info| (info.file, &file_text[info.position.as_range()], info.info))
+        .collect();
+    assert_eq!(
+        infos,
+        [
+            (file, "dup", "Conflicts with".to_owned()),
+            (file, "dup", "Something else".to_owned())
+        ]
+    );
+}
+
+#[test]
+fn test_file_dependencies() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![
+            (
+                "a.sus".to_owned(),
+                "module passA {\n    interface passA : int x -> int y\n    y = x\n}\n".to_owned(),
+            ),
+            (
+                "b.sus".to_owned(),
+                "module passB {\n    interface passB : int x -> int y\n    y = passA(x)\n}\n"
+                    .to_owned(),
+            ),
+            (
+                "c.sus".to_owned(),
+                "module passC {\n    interface passC : int x -> int y\n    y = passB(passA(x))\n}\n"
+                    .to_owned(),
+            ),
+        ],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+    let linker = &result.linker;
+    let &[a, b, c] = result.files.as_slice() else {
+        unreachable!()
+    };
+
+    // References to builtins like 'int' don't count
+    assert_eq!(linker.file_dependencies(a), vec![]);
+    assert_eq!(linker.file_dependencies(b), vec![a]);
+    assert_eq!(linker.file_dependencies(c), vec![b, a]);
+
+    assert_eq!(linker.files_depending_on(a), vec![b, c]);
+    assert_eq!(linker.files_depending_on(b), vec![c]);
+    assert_eq!(linker.files_depending_on(c), vec![]);
+}
+
+#[test]
+fn test_compilation_observer() {
+    use sus_compiler::compiler_top::CompilationObserver;
+    use sus_compiler::config::EarlyExitUpTo;
+    use sus_compiler::flattening::Module;
+    use sus_compiler::instantiation::InstantiatedModule;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Vec<String>,
+    }
+    impl CompilationObserver for Recorder {
+        fn after_flatten(&mut self, md: &Module, _linker: &Linker) {
+            if md.link_info.name.starts_with("obs") {
+                self.events.push(format!(
+                    "flatten {} {}",
+                    md.link_info.name,
+                    md.link_info.instructions.len()
+                ));
+            }
+        }
+        fn after_typecheck(&mut self, md: &Module, _linker: &Linker) {
+            if md.link_info.name.starts_with("obs") {
+                self.events.push(format!("typecheck {}", md.link_info.name));
+            }
+        }
+        fn after_instantiate(&mut self, instance: &InstantiatedModule, _linker: &Linker) {
+            if instance.name.contains("obs") {
+                self.events.push(format!("instantiate {}", instance.name));
+            }
+        }
+    }
+
+    let mut linker = linker_with_std();
+    linker.add_file(
+        "obs.sus".to_owned(),
+        "
+module obsInner {
+    interface obsInner : int a -> int b
+    b = a
+}
+module obsOuter {
+    interface obsOuter : int a -> int b
+    b = obsInner(a)
+}
+"
+        .to_owned(),
+        &mut (),
+    );
+
+    let mut recorder = Recorder::default();
+    linker.recompile_up_to_observed(EarlyExitUpTo::Flatten, &mut recorder);
+    assert_eq!(
+        recorder.events,
+        ["flatten obsInner 4", "flatten obsOuter 7"]
+    );
+
+    let mut recorder = Recorder::default();
+    linker.recompile_up_to_observed(EarlyExitUpTo::Instantiate, &mut recorder);
+    let stages: Vec<&str> = recorder
+        .events
+        .iter()
+        .map(|e| e.split(' ').next().unwrap())
+        .collect();
+    assert_eq!(
+        stages,
+        [
+            "flatten",
+            "flatten",
+            "typecheck",
+            "typecheck",
+            "instantiate",
+            "instantiate"
+        ]
+    );
+    assert!(recorder
+        .events
+        .contains(&"instantiate ::obsOuter #()".to_owned()));
+}
+
+#[test]
+fn test_reuse_unchanged_globals() {
+    use sus_compiler::config::EarlyExitUpTo;
+    use sus_compiler::linker::GlobalUUID;
+
+    let leaf_text = "
+module leaf {
+    interface leaf : int a -> int b
+    b = a
+}
+module useLeaf {
+    interface useLeaf : int a -> int b
+    leaf l
+    b = l(a)
+}
+";
+    let other_text = "
+module other {
+    interface other : int a -> int b
+    leaf l
+    b = l(a)
+}
+module lonely {
+    interface lonely : int a -> int b
+    b = a
+}
+";
+    // The output of leaf becomes a bool, which breaks both of its users
+    let changed_leaf_text = leaf_text.replace("-> int b\n    b = a", "-> bool b\n    b = a == 3");
+
+    let mut linker = linker_with_std();
+    linker.add_file("leaf.sus".to_owned(), leaf_text.to_owned(), &mut ());
+    linker.add_file("other.sus".to_owned(), other_text.to_owned(), &mut ());
+    linker.recompile_up_to(EarlyExitUpTo::Instantiate);
+
+    let reusable_names = |linker: &Linker| {
+        let mut names: Vec<String> = linker
+            .find_reusable_globals()
+            .into_iter()
+            .filter_map(|global| match global {
+                GlobalUUID::Module(md_id) => Some(linker.modules[md_id].link_info.name.clone()),
+                _ => None,
+            })
+            .filter(|name| ["leaf", "useLeaf", "other", "lonely"].contains(&name.as_str()))
+            .collect();
+        names.sort();
+        names
+    };
+
+    // Only the globals of the edited file are redone
+    linker.add_or_update_file("other.sus", format!("{other_text}\n"), &mut ());
+    assert_eq!(reusable_names(&linker), ["leaf", "useLeaf"]);
+    linker.recompile_up_to(EarlyExitUpTo::Instantiate);
+
+    // Editing leaf also invalidates other, which uses it
+    linker.add_or_update_file("leaf.sus", changed_leaf_text.clone(), &mut ());
+    assert_eq!(reusable_names(&linker), ["lonely"]);
+    linker.recompile_up_to(EarlyExitUpTo::Instantiate);
+
+    // Exactly the same diagnostics as compiling from scratch
+    let diagnostics = |linker: &Linker| {
+        let mut result: Vec<(String, String, usize, usize)> = linker
+            .collect_all_diagnostics()
+            .into_iter()
+            .map(|(file, err)| {
+                (
+                    linker.files[file].file_identifier.clone(),
+                    err.reason,
+                    err.position.as_range().start,
+                    err.position.as_range().end,
+                )
+            })
+            .collect();
+        result.sort();
+        result
+    };
+    let mut fresh = linker_with_std();
+    fresh.add_file("leaf.sus".to_owned(), changed_leaf_text, &mut ());
+    fresh.add_file("other.sus".to_owned(), format!("{other_text}\n"), &mut ());
+    fresh.recompile_up_to(EarlyExitUpTo::Instantiate);
+    let fresh_diagnostics = diagnostics(&fresh);
+    assert!(fresh_diagnostics
+        .iter()
+        .any(|(file, _, _, _)| file == "other.sus"));
+    assert_eq!(diagnostics(&linker), fresh_diagnostics);
+}
+
+#[test]
+fn test_all_modules() {
+    use sus_compiler::linker::{PortSignature, TemplateParameterInfo};
+
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "docs.sus".to_owned(),
+            "
+// Delays by DEPTH cycles
+module delay #(T, int DEPTH) {
+    interface delay : T[DEPTH] i -> T o
+    o = i[0]
+}
+"
+            .to_owned(),
+        )],
+    );
+    let info = result
+        .linker
+        .all_modules()
+        .find(|info| info.name == "delay")
+        .unwrap();
+    assert_eq!(info.file, result.files[0]);
+    assert_eq!(info.documentation.trim(), "Delays by DEPTH cycles");
+    assert_eq!(
+        info.template_parameters,
+        [
+            TemplateParameterInfo {
+                name: "T".to_owned(),
+                value_type: None
+            },
+            TemplateParameterInfo {
+                name: "DEPTH".to_owned(),
+                value_type: Some("int".to_owned())
+            }
+        ]
+    );
+    assert_eq!(
+        info.ports[0],
+        PortSignature {
+            name: "i".to_owned(),
+            is_input: true,
+            typ: "T[DEPTH]".to_owned(),
+            domain: "clk".to_owned()
+        }
+    );
+    assert_eq!(info.ports[1].typ, "T");
+    // The standard library is included
+    assert!(result.linker.all_modules().any(|info| info.name == "FIFO"));
+}
+
+#[test]
+fn test_update_with_same_text_is_skipped() {
+    let mut linker = Linker::new();
+    let text = "module a {}\n";
+    let file = linker.add_file("a.sus".to_owned(), text.to_owned(), &mut ());
+    let associated_values = linker.files[file].associated_values.clone();
+
+    assert!(!linker.add_or_update_file("a.sus", text.to_owned(), &mut ()));
+    // Nothing was removed and re-added
+    assert_eq!(linker.files[file].associated_values, associated_values);
+
+    let no_op_edit = TextEdit {
+        replace_range: 0..0,
+        new_text: String::new(),
+    };
+    assert!(!linker.update_file_incremental(&[no_op_edit], file, &mut ()));
+
+    assert!(linker.add_or_update_file("a.sus", "module b {}\n".to_owned(), &mut ()));
+    assert!(linker
+        .modules
+        .iter()
+        .any(|(_, md)| md.link_info.name == "b"));
+}
+
+#[test]
+fn test_incremental_edit_after_surrogate_pair() {
+    // '𝒜' is a single char, but 2 UTF-16 code units, the columns the LSP sends
+    let mut linker = Linker::new();
+    let file = linker.add_file("a.sus".to_owned(), "module 𝒜b {}\n".to_owned(), &mut ());
+
+    let file_text = &linker.files[file].file_text;
+    let replace_range = file_text.linecol_to_byte_clamp(LineCol { line: 0, col: 9 })
+        ..file_text.linecol_to_byte_clamp(LineCol { line: 0, col: 10 });
+    let edit = TextEdit {
+        replace_range,
+        new_text: "c".to_owned(),
+    };
+    assert!(linker.update_file_incremental(&[edit], file, &mut ()));
+    assert_eq!(linker.files[file].file_text.file_text, "module 𝒜c {}\n");
+    assert!(linker
+        .modules
+        .iter()
+        .any(|(_, md)| md.link_info.name == "𝒜c"));
+}
+
+#[test]
+fn test_incremental_edit_changing_pragmas() {
+    let text = "#![feature(arrays)]\n\n// Comment\n\nmodule a {}\nmodule c {}\n";
+    // Removing the first line or adding it back moves the pragma to or from the top of the file, so tree-sitter sees it differently even though no edit touched it
+    for (old_text, edit) in [
+        (
+            format!("module b {{}}\n{text}"),
+            TextEdit {
+                replace_range: 0..12,
+                new_text: String::new(),
+            },
+        ),
+        (
+            text.to_owned(),
+            TextEdit {
+                replace_range: 0..0,
+                new_text: "module b {}\n".to_owned(),
+            },
+        ),
+    ] {
+        let mut linker = Linker::new();
+        let file = linker.add_file("a.sus".to_owned(), old_text, &mut ());
+        assert!(linker.update_file_incremental(&[edit], file, &mut ()));
+
+        let mut fresh = Linker::new();
+        let new_text = linker.files[file].file_text.file_text.clone();
+        let fresh_file = fresh.add_file("a.sus".to_owned(), new_text, &mut ());
+        assert_eq!(
+            linker.files[file].tree.root_node().to_sexp(),
+            fresh.files[fresh_file].tree.root_node().to_sexp()
+        );
+    }
+}
+
+#[test]
+fn test_splice_edits() {
+    let mut linker = Linker::new();
+    let file = linker.add_file("a.sus".to_owned(), "module a {}\n".to_owned(), &mut ());
+
+    // Given out of order, and all spans refer to the original text
+    let rename = vec![
+        (Span::from(10..10), "int x ".to_owned()),
+        (Span::from(7..8), "renamed".to_owned()),
+        (Span::from(0..0), "// Hi\n".to_owned()),
+    ];
+    assert_eq!(
+        linker.splice_edits(file, &rename).unwrap(),
+        "// Hi\nmodule renamed {int x }\n"
+    );
+    // The file itself is untouched
+    assert_eq!(linker.files[file].file_text.file_text, "module a {}\n");
+
+    let overlapping = vec![
+        (Span::from(0..8), String::new()),
+        (Span::from(7..9), String::new()),
+    ];
+    assert_eq!(
+        linker.splice_edits(file, &overlapping),
+        Err(SpliceError::Overlapping(Span::from(0..8), Span::from(7..9)))
+    );
+    let same_insertion_point = vec![
+        (Span::from(3..3), "x".to_owned()),
+        (Span::from(3..3), "y".to_owned()),
+    ];
+    assert!(linker.splice_edits(file, &same_insertion_point).is_err());
+    assert_eq!(
+        linker.splice_edits(file, &[(Span::from(5..100), String::new())]),
+        Err(SpliceError::OutOfBounds(Span::from(5..100)))
+    );
+}
+
+#[test]
+fn test_override_builtin() {
+    let local = "
+override module IntToBits {
+    interface IntToBits : int value -> int bits
+    bits = value
+}
+module useLocal {
+    interface useLocal : int x -> int y
+    y = IntToBits(x)
+}
+";
+    // Other files still see the builtin, which outputs bool[32]
+    let other = "
+module useBuiltin {
+    interface useBuiltin : int x -> bool[32] y
+    y = IntToBits(x)
+}
+";
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![
+            ("local.sus".to_owned(), local.to_owned()),
+            ("other.sus".to_owned(), other.to_owned()),
+        ],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+    let warnings: Vec<_> = (result.diagnostics.iter())
+        .filter(|(file, _)| *file == result.files[0])
+        .map(|(_, err)| err)
+        .collect();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].level, ErrorLevel::Warning);
+    assert_eq!(
+        warnings[0].reason,
+        "'IntToBits' shadows the builtin ::IntToBits within this file"
+    );
+    assert_eq!(warnings[0].infos[0].info, "Builtin declared here");
+
+    // Without 'override' it's an error
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "redeclare.sus".to_owned(),
+            local.replace("override module", "module"),
+        )],
+    );
+    let reasons = error_reasons(&result);
+    assert!(reasons.contains(&"Cannot redeclare the builtin 'IntToBits'. Declare it as 'override' to shadow the builtin within this file"), "{reasons:?}");
+}
+
+#[test]
+fn test_override_errors() {
+    // Nothing named 'Helper' in the builtins or the standard library
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![(
+            "noTarget.sus".to_owned(),
+            "
+override module Helper {
+    interface Helper : int a -> int o
+    o = a
+}
+"
+            .to_owned(),
+        )],
+    );
+    assert_eq!(
+        error_reasons(&result),
+        ["'Helper' is declared 'override', but there is no builtin or standard library declaration ::Helper to shadow"]
+    );
+
+    // An 'override' can't shadow the declaration of another user file, so both sides are an error
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![
+            (
+                "overriding.sus".to_owned(),
+                "
+override module Helper {
+    interface Helper : int a -> int o
+    o = a
+}
+"
+                .to_owned(),
+            ),
+            (
+                "helper.sus".to_owned(),
+                "
+module Helper {
+    interface Helper : int a -> int o
+    o = a + 1
+}
+"
+                .to_owned(),
+            ),
+        ],
+    );
+    let conflict = "'Helper' conflicts with other declarations. 'override' can only shadow builtins and standard library declarations:";
+    let file_errors = |file: FileUUID| -> Vec<&str> {
+        (result.diagnostics.iter())
+            .filter(|(f, err)| *f == file && err.level == ErrorLevel::Error)
+            .map(|(_, err)| err.reason.as_str())
+            .collect()
+    };
+    assert_eq!(
+        file_errors(result.files[0]),
+        [
+            "'Helper' is declared 'override', but there is no builtin or standard library declaration ::Helper to shadow",
+            conflict
+        ]
+    );
+    assert_eq!(file_errors(result.files[1]), [conflict]);
+}
+
+#[test]
+fn test_override_codegen_names() {
+    let result = compile_sources(
+        CompilerOptions::default(),
+        vec![
+            (
+                "local.sus".to_owned(),
+                "
+override module Abs {
+    interface Abs : int a -> int o
+    o = a
+}
+module useLocal {
+    interface useLocal : int x -> int y
+    y = Abs(x)
+}
+"
+                .to_owned(),
+            ),
+            (
+                "other.sus".to_owned(),
+                "
+module useStd {
+    interface useStd : int x -> int y
+    y = Abs(x)
+}
+"
+                .to_owned(),
+            ),
+        ],
+    );
+    assert_eq!(error_reasons(&result), Vec::<&str>::new());
+
+    // The override and the standard library's Abs must not generate the same module
+    let local_sv = result.verilog("useLocal").unwrap();
+    let std_sv = result.verilog("useStd").unwrap();
+    assert!(std_sv.contains("\nAbs___ "), "{std_sv}");
+    assert!(!local_sv.contains("\nAbs___ "), "{local_sv}");
+    assert!(local_sv.contains("\nAbs____"), "{local_sv}");
+}
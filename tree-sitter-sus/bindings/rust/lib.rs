@@ -6,7 +6,7 @@
 //! ```
 //! let code = "";
 //! let mut parser = tree_sitter::Parser::new();
-//! parser.set_language(tree_sitter_sus::language()).expect("Error loading sus grammar");
+//! parser.set_language(&tree_sitter_sus::language()).expect("Error loading sus grammar");
 //! let tree = parser.parse(code, None).unwrap();
 //! ```
 //!
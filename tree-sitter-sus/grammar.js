@@ -41,6 +41,7 @@ function commaSepSeq($, rule) {
 }
 
 const PREC = {
+    conditional: 1,
     compare : 2,
     xor: 3,
     or: 4,
@@ -60,7 +61,8 @@ module.exports = grammar({
         source_file: $ => newlineSepSeq($, $.global_object),
 
         global_object: $ => seq(
-            optional(field('extern_marker', choice('__builtin__', 'extern'))),
+            // 'override' lets a declaration shadow the builtin or standard library declaration of the same name, but only within its own file
+            optional(field('extern_marker', choice('__builtin__', 'extern', 'override'))),
             // Because we want to reuse our "generative code", we parse them under the same umbrella. 
             // Their differences are their semantic meaning, and therefore what constructs are allowed in each
             // For instance, modules have no restrictions
@@ -117,13 +119,14 @@ module.exports = grammar({
             field('assign_value', $._expression)
         ),
         assign_left_side: $ => sepSeq1($.assign_to, $._comma),
-        assign_to: $ => seq(
+        // A '{' that starts a statement always opens a block, never a concatenation
+        assign_to: $ => prec(1, seq(
             optional(field('write_modifiers', $.write_modifiers)),
             field('expr_or_decl', choice(
                 $.declaration,
                 $._expression
             ))
-        ),
+        )),
         write_modifiers: $ => choice(
             repeat1(field('item', 'reg')),
             field('item', 'initial')
@@ -231,7 +234,9 @@ module.exports = grammar({
             $.unary_op,
             $.binary_op,
             $.func_call,
-            $.field_access
+            $.field_access,
+            $.concatenation,
+            $.conditional
         ),
 
         unary_op: $ => prec(PREC.unary, seq(
@@ -256,9 +261,18 @@ module.exports = grammar({
             ))));
         },
 
+        // c ? a : b, binds looser than any binary operator. Nested conditionals group to the right
+        conditional: $ => prec.right(PREC.conditional, seq(
+            field('condition', $._expression),
+            '?',
+            field('if_true', $._expression),
+            ':',
+            field('if_false', $._expression)
+        )),
+
         array_op: $ => prec(PREC.postscript_op, seq(
             field('arr', $._expression),
-            field('arr_idx', $.array_bracket_expression)
+            field('arr_idx', choice($.array_bracket_expression, $.array_slice_expression))
         )),
 
         func_call: $ => prec(PREC.postscript_op, seq(
@@ -290,6 +304,24 @@ module.exports = grammar({
             ']'
         ),
 
+        // arr[high:low]
+        array_slice_expression: $ => seq(
+            '[',
+            field('high', $._expression),
+            ':',
+            field('low', $._expression),
+            ']'
+        ),
+
+        // {a, b, c}
+        concatenation: $ => seq(
+            '{',
+            optional($._linebreak),
+            sepSeq1($._expression, $._comma),
+            optional($._linebreak),
+            '}'
+        ),
+
         // Utilities
 
         namespace_list: $ => sepSeq1($.identifier, '::'),
@@ -321,7 +353,8 @@ module.exports = grammar({
         ),
 
         identifier: $ => /[\p{Alphabetic}_][\p{Alphabetic}_\p{Decimal_Number}]*/,
-        number: $ => /\d[\d_]*/,
+        // Decimal like 1_000, or sized like 8'hFF. The base letter sets it apart from a latency specifier like a'3
+        number: $ => /\d[\d_]*('[bBoOdDhH][\da-fA-F_]+)?/,
 
         _comma: $ => seq(
             ',',
@@ -5,116 +5,121 @@
 #endif
 
 #define LANGUAGE_VERSION 14
-#define STATE_COUNT 230
+#define STATE_COUNT 317
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 100
+#define SYMBOL_COUNT 105
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 55
+#define TOKEN_COUNT 57
 #define EXTERNAL_TOKEN_COUNT 0
-#define FIELD_COUNT 37
+#define FIELD_COUNT 41
 #define MAX_ALIAS_SEQUENCE_LENGTH 7
-#define PRODUCTION_ID_COUNT 51
+#define PRODUCTION_ID_COUNT 53
 
 enum ts_symbol_identifiers {
   sym_identifier = 1,
   anon_sym___builtin__ = 2,
   anon_sym_extern = 3,
-  anon_sym_module = 4,
-  anon_sym_struct = 5,
-  anon_sym_const = 6,
-  anon_sym_POUND_LPAREN = 7,
-  anon_sym_RPAREN = 8,
-  anon_sym_LBRACE = 9,
-  anon_sym_RBRACE = 10,
-  anon_sym_EQ = 11,
-  anon_sym_reg = 12,
-  anon_sym_initial = 13,
-  anon_sym_when = 14,
-  anon_sym_if = 15,
-  anon_sym_else = 16,
-  anon_sym_for = 17,
-  anon_sym_in = 18,
-  anon_sym_DOT_DOT = 19,
-  anon_sym_domain = 20,
-  anon_sym_interface = 21,
-  anon_sym_COLON = 22,
-  anon_sym_DASH_GT = 23,
-  anon_sym_input = 24,
-  anon_sym_output = 25,
-  anon_sym_state = 26,
-  anon_sym_gen = 27,
-  anon_sym_SQUOTE = 28,
-  anon_sym_PLUS = 29,
-  anon_sym_DASH = 30,
-  anon_sym_STAR = 31,
-  anon_sym_BANG = 32,
-  anon_sym_PIPE = 33,
-  anon_sym_AMP = 34,
-  anon_sym_CARET = 35,
-  anon_sym_EQ_EQ = 36,
-  anon_sym_BANG_EQ = 37,
-  anon_sym_LT = 38,
-  anon_sym_LT_EQ = 39,
-  anon_sym_GT = 40,
-  anon_sym_GT_EQ = 41,
-  anon_sym_SLASH = 42,
-  anon_sym_PERCENT = 43,
-  anon_sym_DOT = 44,
-  anon_sym_LPAREN = 45,
-  anon_sym_LBRACK = 46,
-  anon_sym_RBRACK = 47,
-  anon_sym_COLON_COLON = 48,
-  anon_sym_type = 49,
-  sym_number = 50,
-  anon_sym_COMMA = 51,
-  anon_sym_LF = 52,
-  sym_single_line_comment = 53,
-  sym_multi_line_comment = 54,
-  sym_source_file = 55,
-  sym_global_object = 56,
-  sym_const_and_type = 57,
-  sym_template_declaration_arguments = 58,
-  sym_template_declaration_type = 59,
-  sym_block = 60,
-  sym_decl_assign_statement = 61,
-  sym_assign_left_side = 62,
-  sym_assign_to = 63,
-  sym_write_modifiers = 64,
-  sym_if_statement = 65,
-  sym_for_statement = 66,
-  sym_domain_statement = 67,
-  sym_interface_statement = 68,
-  sym_interface_ports = 69,
-  sym__interface_ports_output = 70,
-  sym_declaration_list = 71,
-  sym_declaration = 72,
-  sym_latency_specifier = 73,
-  sym__type = 74,
-  sym_array_type = 75,
-  sym__expression = 76,
-  sym_unary_op = 77,
-  sym_binary_op = 78,
-  sym_array_op = 79,
-  sym_func_call = 80,
-  sym_field_access = 81,
-  sym_parenthesis_expression_list = 82,
-  sym_parenthesis_expression = 83,
-  sym_array_bracket_expression = 84,
-  sym_namespace_list = 85,
-  sym_template_global = 86,
-  sym_template_args = 87,
-  sym_template_arg = 88,
-  sym__comma = 89,
-  aux_sym__linebreak = 90,
-  aux_sym_source_file_repeat1 = 91,
-  aux_sym_template_declaration_arguments_repeat1 = 92,
-  aux_sym_block_repeat1 = 93,
-  aux_sym_assign_left_side_repeat1 = 94,
-  aux_sym_write_modifiers_repeat1 = 95,
-  aux_sym_declaration_list_repeat1 = 96,
-  aux_sym_parenthesis_expression_list_repeat1 = 97,
-  aux_sym_namespace_list_repeat1 = 98,
-  aux_sym_template_args_repeat1 = 99,
+  anon_sym_override = 4,
+  anon_sym_module = 5,
+  anon_sym_struct = 6,
+  anon_sym_const = 7,
+  anon_sym_POUND_LPAREN = 8,
+  anon_sym_RPAREN = 9,
+  anon_sym_LBRACE = 10,
+  anon_sym_RBRACE = 11,
+  anon_sym_EQ = 12,
+  anon_sym_reg = 13,
+  anon_sym_initial = 14,
+  anon_sym_when = 15,
+  anon_sym_if = 16,
+  anon_sym_else = 17,
+  anon_sym_for = 18,
+  anon_sym_in = 19,
+  anon_sym_DOT_DOT = 20,
+  anon_sym_domain = 21,
+  anon_sym_interface = 22,
+  anon_sym_COLON = 23,
+  anon_sym_DASH_GT = 24,
+  anon_sym_input = 25,
+  anon_sym_output = 26,
+  anon_sym_state = 27,
+  anon_sym_gen = 28,
+  anon_sym_SQUOTE = 29,
+  sym_number = 30,
+  anon_sym_PLUS = 31,
+  anon_sym_DASH = 32,
+  anon_sym_STAR = 33,
+  anon_sym_BANG = 34,
+  anon_sym_PIPE = 35,
+  anon_sym_AMP = 36,
+  anon_sym_CARET = 37,
+  anon_sym_EQ_EQ = 38,
+  anon_sym_BANG_EQ = 39,
+  anon_sym_LT = 40,
+  anon_sym_LT_EQ = 41,
+  anon_sym_GT = 42,
+  anon_sym_GT_EQ = 43,
+  anon_sym_SLASH = 44,
+  anon_sym_PERCENT = 45,
+  anon_sym_QMARK = 46,
+  anon_sym_DOT = 47,
+  anon_sym_LPAREN = 48,
+  anon_sym_LBRACK = 49,
+  anon_sym_RBRACK = 50,
+  anon_sym_COLON_COLON = 51,
+  anon_sym_type = 52,
+  anon_sym_COMMA = 53,
+  anon_sym_LF = 54,
+  sym_single_line_comment = 55,
+  sym_multi_line_comment = 56,
+  sym_source_file = 57,
+  sym_global_object = 58,
+  sym_const_and_type = 59,
+  sym_template_declaration_arguments = 60,
+  sym_template_declaration_type = 61,
+  sym_block = 62,
+  sym_decl_assign_statement = 63,
+  sym_assign_left_side = 64,
+  sym_assign_to = 65,
+  sym_write_modifiers = 66,
+  sym_if_statement = 67,
+  sym_for_statement = 68,
+  sym_domain_statement = 69,
+  sym_interface_statement = 70,
+  sym_interface_ports = 71,
+  sym__interface_ports_output = 72,
+  sym_declaration_list = 73,
+  sym_declaration = 74,
+  sym_latency_specifier = 75,
+  sym__type = 76,
+  sym_array_type = 77,
+  sym__expression = 78,
+  sym_unary_op = 79,
+  sym_binary_op = 80,
+  sym_conditional = 81,
+  sym_array_op = 82,
+  sym_func_call = 83,
+  sym_field_access = 84,
+  sym_parenthesis_expression_list = 85,
+  sym_parenthesis_expression = 86,
+  sym_array_bracket_expression = 87,
+  sym_array_slice_expression = 88,
+  sym_concatenation = 89,
+  sym_namespace_list = 90,
+  sym_template_global = 91,
+  sym_template_args = 92,
+  sym_template_arg = 93,
+  sym__comma = 94,
+  aux_sym__linebreak = 95,
+  aux_sym_source_file_repeat1 = 96,
+  aux_sym_template_declaration_arguments_repeat1 = 97,
+  aux_sym_block_repeat1 = 98,
+  aux_sym_assign_left_side_repeat1 = 99,
+  aux_sym_write_modifiers_repeat1 = 100,
+  aux_sym_declaration_list_repeat1 = 101,
+  aux_sym_parenthesis_expression_list_repeat1 = 102,
+  aux_sym_namespace_list_repeat1 = 103,
+  aux_sym_template_args_repeat1 = 104,
 };
 
 static const char * const ts_symbol_names[] = {
@@ -122,6 +127,7 @@ static const char * const ts_symbol_names[] = {
   [sym_identifier] = "identifier",
   [anon_sym___builtin__] = "__builtin__",
   [anon_sym_extern] = "extern",
+  [anon_sym_override] = "override",
   [anon_sym_module] = "module",
   [anon_sym_struct] = "struct",
   [anon_sym_const] = "const",
@@ -147,6 +153,7 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_state] = "state",
   [anon_sym_gen] = "gen",
   [anon_sym_SQUOTE] = "'",
+  [sym_number] = "number",
   [anon_sym_PLUS] = "+",
   [anon_sym_DASH] = "-",
   [anon_sym_STAR] = "*",
@@ -162,13 +169,13 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_GT_EQ] = ">=",
   [anon_sym_SLASH] = "/",
   [anon_sym_PERCENT] = "%",
+  [anon_sym_QMARK] = "?",
   [anon_sym_DOT] = ".",
   [anon_sym_LPAREN] = "(",
   [anon_sym_LBRACK] = "[",
   [anon_sym_RBRACK] = "]",
   [anon_sym_COLON_COLON] = "::",
   [anon_sym_type] = "type",
-  [sym_number] = "number",
   [anon_sym_COMMA] = ",",
   [anon_sym_LF] = "\n",
   [sym_single_line_comment] = "single_line_comment",
@@ -197,12 +204,15 @@ static const char * const ts_symbol_names[] = {
   [sym__expression] = "_expression",
   [sym_unary_op] = "unary_op",
   [sym_binary_op] = "binary_op",
+  [sym_conditional] = "conditional",
   [sym_array_op] = "array_op",
   [sym_func_call] = "func_call",
   [sym_field_access] = "field_access",
   [sym_parenthesis_expression_list] = "parenthesis_expression_list",
   [sym_parenthesis_expression] = "parenthesis_expression",
   [sym_array_bracket_expression] = "array_bracket_expression",
+  [sym_array_slice_expression] = "array_slice_expression",
+  [sym_concatenation] = "concatenation",
   [sym_namespace_list] = "namespace_list",
   [sym_template_global] = "template_global",
   [sym_template_args] = "template_args",
@@ -225,6 +235,7 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_identifier] = sym_identifier,
   [anon_sym___builtin__] = anon_sym___builtin__,
   [anon_sym_extern] = anon_sym_extern,
+  [anon_sym_override] = anon_sym_override,
   [anon_sym_module] = anon_sym_module,
   [anon_sym_struct] = anon_sym_struct,
   [anon_sym_const] = anon_sym_const,
@@ -250,6 +261,7 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_state] = anon_sym_state,
   [anon_sym_gen] = anon_sym_gen,
   [anon_sym_SQUOTE] = anon_sym_SQUOTE,
+  [sym_number] = sym_number,
   [anon_sym_PLUS] = anon_sym_PLUS,
   [anon_sym_DASH] = anon_sym_DASH,
   [anon_sym_STAR] = anon_sym_STAR,
@@ -265,13 +277,13 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_GT_EQ] = anon_sym_GT_EQ,
   [anon_sym_SLASH] = anon_sym_SLASH,
   [anon_sym_PERCENT] = anon_sym_PERCENT,
+  [anon_sym_QMARK] = anon_sym_QMARK,
   [anon_sym_DOT] = anon_sym_DOT,
   [anon_sym_LPAREN] = anon_sym_LPAREN,
   [anon_sym_LBRACK] = anon_sym_LBRACK,
   [anon_sym_RBRACK] = anon_sym_RBRACK,
   [anon_sym_COLON_COLON] = anon_sym_COLON_COLON,
   [anon_sym_type] = anon_sym_type,
-  [sym_number] = sym_number,
   [anon_sym_COMMA] = anon_sym_COMMA,
   [anon_sym_LF] = anon_sym_LF,
   [sym_single_line_comment] = sym_single_line_comment,
@@ -300,12 +312,15 @@ static const TSSymbol ts_symbol_map[] = {
   [sym__expression] = sym__expression,
   [sym_unary_op] = sym_unary_op,
   [sym_binary_op] = sym_binary_op,
+  [sym_conditional] = sym_conditional,
   [sym_array_op] = sym_array_op,
   [sym_func_call] = sym_func_call,
   [sym_field_access] = sym_field_access,
   [sym_parenthesis_expression_list] = sym_parenthesis_expression_list,
   [sym_parenthesis_expression] = sym_parenthesis_expression,
   [sym_array_bracket_expression] = sym_array_bracket_expression,
+  [sym_array_slice_expression] = sym_array_slice_expression,
+  [sym_concatenation] = sym_concatenation,
   [sym_namespace_list] = sym_namespace_list,
   [sym_template_global] = sym_template_global,
   [sym_template_args] = sym_template_args,
@@ -340,6 +355,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_override] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_module] = {
     .visible = true,
     .named = false,
@@ -440,6 +459,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [sym_number] = {
+    .visible = true,
+    .named = true,
+  },
   [anon_sym_PLUS] = {
     .visible = true,
     .named = false,
@@ -500,6 +523,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_QMARK] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_DOT] = {
     .visible = true,
     .named = false,
@@ -524,10 +551,6 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [sym_number] = {
-    .visible = true,
-    .named = true,
-  },
   [anon_sym_COMMA] = {
     .visible = true,
     .named = false,
@@ -640,6 +663,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_conditional] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_array_op] = {
     .visible = true,
     .named = true,
@@ -664,6 +691,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_array_slice_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_concatenation] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_namespace_list] = {
     .visible = true,
     .named = true,
@@ -742,28 +777,32 @@ enum ts_field_identifiers {
   field_extern_marker = 13,
   field_for_decl = 14,
   field_from = 15,
-  field_inputs = 16,
-  field_interface_ports = 17,
-  field_io_port_modifiers = 18,
-  field_is_global_path = 19,
-  field_item = 20,
-  field_latency_specifier = 21,
-  field_left = 22,
-  field_name = 23,
-  field_namespace_list = 24,
-  field_object_type = 25,
-  field_operator = 26,
-  field_outputs = 27,
-  field_right = 28,
-  field_statement_type = 29,
-  field_template_args = 30,
-  field_template_declaration_arguments = 31,
-  field_then_block = 32,
-  field_to = 33,
-  field_type = 34,
-  field_type_arg = 35,
-  field_val_arg = 36,
-  field_write_modifiers = 37,
+  field_high = 16,
+  field_if_false = 17,
+  field_if_true = 18,
+  field_inputs = 19,
+  field_interface_ports = 20,
+  field_io_port_modifiers = 21,
+  field_is_global_path = 22,
+  field_item = 23,
+  field_latency_specifier = 24,
+  field_left = 25,
+  field_low = 26,
+  field_name = 27,
+  field_namespace_list = 28,
+  field_object_type = 29,
+  field_operator = 30,
+  field_outputs = 31,
+  field_right = 32,
+  field_statement_type = 33,
+  field_template_args = 34,
+  field_template_declaration_arguments = 35,
+  field_then_block = 36,
+  field_to = 37,
+  field_type = 38,
+  field_type_arg = 39,
+  field_val_arg = 40,
+  field_write_modifiers = 41,
 };
 
 static const char * const ts_field_names[] = {
@@ -783,6 +822,9 @@ static const char * const ts_field_names[] = {
   [field_extern_marker] = "extern_marker",
   [field_for_decl] = "for_decl",
   [field_from] = "from",
+  [field_high] = "high",
+  [field_if_false] = "if_false",
+  [field_if_true] = "if_true",
   [field_inputs] = "inputs",
   [field_interface_ports] = "interface_ports",
   [field_io_port_modifiers] = "io_port_modifiers",
@@ -790,6 +832,7 @@ static const char * const ts_field_names[] = {
   [field_item] = "item",
   [field_latency_specifier] = "latency_specifier",
   [field_left] = "left",
+  [field_low] = "low",
   [field_name] = "name",
   [field_namespace_list] = "namespace_list",
   [field_object_type] = "object_type",
@@ -808,220 +851,229 @@ static const char * const ts_field_names[] = {
 };
 
 static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
-  [1] = {.index = 0, .length = 1},
-  [2] = {.index = 1, .length = 1},
-  [3] = {.index = 2, .length = 1},
-  [4] = {.index = 3, .length = 2},
-  [5] = {.index = 5, .length = 1},
-  [6] = {.index = 6, .length = 3},
-  [7] = {.index = 9, .length = 2},
-  [8] = {.index = 11, .length = 2},
-  [9] = {.index = 13, .length = 2},
-  [10] = {.index = 15, .length = 2},
-  [11] = {.index = 17, .length = 2},
-  [12] = {.index = 19, .length = 4},
-  [13] = {.index = 23, .length = 1},
-  [14] = {.index = 24, .length = 1},
-  [15] = {.index = 25, .length = 1},
-  [16] = {.index = 26, .length = 4},
-  [17] = {.index = 30, .length = 3},
-  [18] = {.index = 33, .length = 5},
-  [19] = {.index = 38, .length = 2},
-  [20] = {.index = 40, .length = 1},
-  [21] = {.index = 41, .length = 2},
-  [22] = {.index = 43, .length = 2},
-  [23] = {.index = 45, .length = 2},
-  [24] = {.index = 47, .length = 1},
-  [25] = {.index = 48, .length = 3},
-  [26] = {.index = 51, .length = 3},
-  [27] = {.index = 54, .length = 3},
-  [28] = {.index = 57, .length = 1},
-  [29] = {.index = 58, .length = 3},
-  [30] = {.index = 61, .length = 2},
-  [31] = {.index = 63, .length = 2},
-  [32] = {.index = 65, .length = 3},
-  [33] = {.index = 68, .length = 2},
-  [34] = {.index = 70, .length = 2},
-  [35] = {.index = 72, .length = 4},
-  [36] = {.index = 76, .length = 4},
-  [37] = {.index = 80, .length = 4},
-  [38] = {.index = 84, .length = 2},
-  [39] = {.index = 86, .length = 1},
-  [40] = {.index = 87, .length = 1},
-  [41] = {.index = 88, .length = 2},
-  [42] = {.index = 90, .length = 5},
-  [43] = {.index = 95, .length = 4},
-  [44] = {.index = 99, .length = 1},
+  [1] = {.index = 0, .length = 2},
+  [2] = {.index = 2, .length = 1},
+  [3] = {.index = 3, .length = 2},
+  [4] = {.index = 5, .length = 1},
+  [5] = {.index = 6, .length = 5},
+  [6] = {.index = 11, .length = 4},
+  [7] = {.index = 15, .length = 4},
+  [8] = {.index = 19, .length = 3},
+  [9] = {.index = 22, .length = 1},
+  [10] = {.index = 23, .length = 2},
+  [11] = {.index = 25, .length = 1},
+  [12] = {.index = 26, .length = 1},
+  [13] = {.index = 27, .length = 2},
+  [14] = {.index = 29, .length = 2},
+  [15] = {.index = 31, .length = 1},
+  [16] = {.index = 32, .length = 1},
+  [17] = {.index = 33, .length = 4},
+  [18] = {.index = 37, .length = 3},
+  [19] = {.index = 40, .length = 4},
+  [20] = {.index = 44, .length = 1},
+  [21] = {.index = 45, .length = 2},
+  [22] = {.index = 47, .length = 2},
+  [23] = {.index = 49, .length = 1},
+  [24] = {.index = 50, .length = 1},
+  [25] = {.index = 51, .length = 2},
+  [26] = {.index = 53, .length = 1},
+  [27] = {.index = 54, .length = 1},
+  [28] = {.index = 55, .length = 1},
+  [29] = {.index = 56, .length = 1},
+  [30] = {.index = 57, .length = 5},
+  [31] = {.index = 62, .length = 4},
+  [32] = {.index = 66, .length = 4},
+  [33] = {.index = 70, .length = 3},
+  [34] = {.index = 73, .length = 4},
+  [35] = {.index = 77, .length = 3},
+  [36] = {.index = 80, .length = 3},
+  [37] = {.index = 83, .length = 2},
+  [38] = {.index = 85, .length = 1},
+  [39] = {.index = 86, .length = 2},
+  [40] = {.index = 88, .length = 2},
+  [41] = {.index = 90, .length = 3},
+  [42] = {.index = 93, .length = 3},
+  [43] = {.index = 96, .length = 2},
+  [44] = {.index = 98, .length = 2},
   [45] = {.index = 100, .length = 2},
-  [46] = {.index = 102, .length = 1},
-  [47] = {.index = 103, .length = 1},
-  [48] = {.index = 104, .length = 1},
-  [49] = {.index = 105, .length = 2},
-  [50] = {.index = 107, .length = 4},
+  [46] = {.index = 102, .length = 3},
+  [47] = {.index = 105, .length = 2},
+  [48] = {.index = 107, .length = 2},
+  [49] = {.index = 109, .length = 1},
+  [50] = {.index = 110, .length = 2},
+  [51] = {.index = 112, .length = 2},
+  [52] = {.index = 114, .length = 2},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
   [0] =
-    {field_item, 0},
-  [1] =
-    {field_const_type, 1},
+    {field_item, 1},
+    {field_item, 2, .inherited = true},
   [2] =
-    {field_namespace_list, 0},
+    {field_item, 1},
   [3] =
     {field_item, 0},
     {field_item, 1, .inherited = true},
   [5] =
-    {field_item, 1},
+    {field_item, 0},
   [6] =
-    {field_block, 2},
-    {field_name, 1},
-    {field_object_type, 0},
-  [9] =
-    {field_is_global_path, 0},
-    {field_namespace_list, 1},
+    {field_block, 4},
+    {field_extern_marker, 0},
+    {field_name, 2},
+    {field_object_type, 1},
+    {field_template_declaration_arguments, 3},
   [11] =
-    {field_arr, 0},
-    {field_arr_idx, 1},
-  [13] =
-    {field_namespace_list, 0},
-    {field_template_args, 1},
-  [15] =
-    {field_item, 0, .inherited = true},
-    {field_item, 1, .inherited = true},
-  [17] =
-    {field_item, 1},
-    {field_item, 2, .inherited = true},
-  [19] =
     {field_block, 3},
     {field_extern_marker, 0},
     {field_name, 2},
     {field_object_type, 1},
-  [23] =
-    {field_name, 0},
-  [24] =
-    {field_expr_or_decl, 0},
-  [25] =
-    {field_item, 0, .inherited = true},
-  [26] =
+  [15] =
     {field_block, 3},
     {field_name, 1},
     {field_object_type, 0},
     {field_template_declaration_arguments, 2},
-  [30] =
-    {field_is_global_path, 0},
-    {field_namespace_list, 1},
-    {field_template_args, 2},
-  [33] =
-    {field_block, 4},
-    {field_extern_marker, 0},
-    {field_name, 2},
-    {field_object_type, 1},
-    {field_template_declaration_arguments, 3},
-  [38] =
-    {field_name, 1},
-    {field_type, 0},
-  [40] =
+  [19] =
+    {field_block, 2},
     {field_name, 1},
-  [41] =
-    {field_operator, 0},
-    {field_right, 1},
-  [43] =
+    {field_object_type, 0},
+  [22] =
+    {field_const_type, 1},
+  [23] =
+    {field_item, 2},
+    {field_item, 3, .inherited = true},
+  [25] =
+    {field_item, 2},
+  [26] =
+    {field_name, 0},
+  [27] =
+    {field_assign_left, 0},
+    {field_assign_value, 2},
+  [29] =
     {field_expr_or_decl, 1},
     {field_write_modifiers, 0},
-  [45] =
-    {field_arguments, 1},
-    {field_name, 0},
-  [47] =
-    {field_content, 1},
-  [48] =
-    {field_io_port_modifiers, 0},
-    {field_name, 2},
-    {field_type, 1},
-  [51] =
-    {field_declaration_modifiers, 0},
-    {field_name, 2},
-    {field_type, 1},
-  [54] =
-    {field_latency_specifier, 2},
-    {field_name, 1},
-    {field_type, 0},
-  [57] =
-    {field_item, 2},
-  [58] =
+  [31] =
+    {field_expr_or_decl, 0},
+  [32] =
+    {field_item, 0, .inherited = true},
+  [33] =
+    {field_condition, 1},
+    {field_else_block, 4},
+    {field_statement_type, 0},
+    {field_then_block, 2},
+  [37] =
     {field_condition, 1},
     {field_statement_type, 0},
     {field_then_block, 2},
-  [61] =
+  [40] =
+    {field_block, 6},
+    {field_for_decl, 1},
+    {field_from, 3},
+    {field_to, 5},
+  [44] =
+    {field_name, 1},
+  [45] =
     {field_interface_ports, 2},
     {field_name, 1},
-  [63] =
-    {field_assign_left, 0},
-    {field_assign_value, 2},
-  [65] =
-    {field_left, 0},
-    {field_operator, 1},
-    {field_right, 2},
-  [68] =
-    {field_left, 0},
-    {field_name, 2},
-  [70] =
-    {field_name, 0},
-    {field_val_arg, 2},
-  [72] =
+  [47] =
+    {field_inputs, 2},
+    {field_outputs, 3, .inherited = true},
+  [49] =
+    {field_inputs, 2},
+  [50] =
+    {field_outputs, 2, .inherited = true},
+  [51] =
+    {field_inputs, 1},
+    {field_outputs, 2, .inherited = true},
+  [53] =
+    {field_inputs, 1},
+  [54] =
+    {field_outputs, 1, .inherited = true},
+  [55] =
+    {field_outputs, 2},
+  [56] =
+    {field_outputs, 1},
+  [57] =
+    {field_declaration_modifiers, 1},
+    {field_io_port_modifiers, 0},
+    {field_latency_specifier, 4},
+    {field_name, 3},
+    {field_type, 2},
+  [62] =
     {field_declaration_modifiers, 1},
     {field_io_port_modifiers, 0},
     {field_name, 3},
     {field_type, 2},
-  [76] =
+  [66] =
     {field_io_port_modifiers, 0},
     {field_latency_specifier, 3},
     {field_name, 2},
     {field_type, 1},
-  [80] =
+  [70] =
+    {field_io_port_modifiers, 0},
+    {field_name, 2},
+    {field_type, 1},
+  [73] =
     {field_declaration_modifiers, 0},
     {field_latency_specifier, 3},
     {field_name, 2},
     {field_type, 1},
-  [84] =
-    {field_item, 2},
-    {field_item, 3, .inherited = true},
+  [77] =
+    {field_declaration_modifiers, 0},
+    {field_name, 2},
+    {field_type, 1},
+  [80] =
+    {field_latency_specifier, 2},
+    {field_name, 1},
+    {field_type, 0},
+  [83] =
+    {field_name, 1},
+    {field_type, 0},
+  [85] =
+    {field_content, 1},
   [86] =
-    {field_outputs, 1, .inherited = true},
-  [87] =
-    {field_inputs, 1},
+    {field_arr, 0},
+    {field_arr_idx, 1},
   [88] =
-    {field_name, 0},
-    {field_type_arg, 3},
+    {field_operator, 0},
+    {field_right, 1},
   [90] =
-    {field_declaration_modifiers, 1},
-    {field_io_port_modifiers, 0},
-    {field_latency_specifier, 4},
-    {field_name, 3},
-    {field_type, 2},
-  [95] =
-    {field_condition, 1},
-    {field_else_block, 4},
-    {field_statement_type, 0},
-    {field_then_block, 2},
-  [99] =
-    {field_outputs, 1},
+    {field_left, 0},
+    {field_operator, 1},
+    {field_right, 2},
+  [93] =
+    {field_condition, 0},
+    {field_if_false, 4},
+    {field_if_true, 2},
+  [96] =
+    {field_arguments, 1},
+    {field_name, 0},
+  [98] =
+    {field_left, 0},
+    {field_name, 2},
   [100] =
-    {field_inputs, 1},
-    {field_outputs, 2, .inherited = true},
+    {field_high, 1},
+    {field_low, 3},
   [102] =
-    {field_outputs, 2, .inherited = true},
-  [103] =
-    {field_inputs, 2},
-  [104] =
-    {field_outputs, 2},
+    {field_is_global_path, 0},
+    {field_namespace_list, 1},
+    {field_template_args, 2},
   [105] =
-    {field_inputs, 2},
-    {field_outputs, 3, .inherited = true},
+    {field_is_global_path, 0},
+    {field_namespace_list, 1},
   [107] =
-    {field_block, 6},
-    {field_for_decl, 1},
-    {field_from, 3},
-    {field_to, 5},
+    {field_namespace_list, 0},
+    {field_template_args, 1},
+  [109] =
+    {field_namespace_list, 0},
+  [110] =
+    {field_name, 0},
+    {field_type_arg, 3},
+  [112] =
+    {field_name, 0},
+    {field_val_arg, 2},
+  [114] =
+    {field_item, 0, .inherited = true},
+    {field_item, 1, .inherited = true},
 };
 
 static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
@@ -1131,7 +1183,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [95] = 95,
   [96] = 96,
   [97] = 97,
-  [98] = 25,
+  [98] = 98,
   [99] = 99,
   [100] = 100,
   [101] = 101,
@@ -1263,6 +1315,93 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [227] = 227,
   [228] = 228,
   [229] = 229,
+  [230] = 230,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 236,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 241,
+  [242] = 242,
+  [243] = 243,
+  [244] = 244,
+  [245] = 245,
+  [246] = 246,
+  [247] = 247,
+  [248] = 248,
+  [249] = 249,
+  [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 262,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 290,
+  [291] = 291,
+  [292] = 292,
+  [293] = 293,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 298,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
+  [315] = 315,
+  [316] = 316,
 };
 
 static TSCharacterRange sym_identifier_character_set_1[] = {
@@ -1462,240 +1601,302 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(9);
+      if (eof) ADVANCE(12);
       ADVANCE_MAP(
-        '\n', 43,
-        '!', 23,
-        '#', 3,
-        '%', 34,
-        '&', 25,
-        '\'', 18,
-        '(', 36,
-        ')', 11,
-        '*', 21,
-        '+', 19,
-        ',', 42,
-        '-', 20,
-        '.', 35,
-        '/', 33,
-        ':', 16,
-        '<', 29,
-        '=', 14,
-        '>', 31,
-        '[', 37,
-        ']', 38,
-        '^', 26,
-        '{', 12,
-        '|', 24,
-        '}', 13,
+        '\n', 49,
+        '!', 29,
+        '#', 4,
+        '%', 41,
+        '&', 32,
+        '\'', 23,
+        '(', 44,
+        ')', 15,
+        '*', 28,
+        '+', 26,
+        ',', 48,
+        '-', 27,
+        '.', 43,
+        '/', 40,
+        ':', 20,
+        '<', 36,
+        '=', 18,
+        '>', 38,
+        '?', 42,
+        '[', 45,
+        ']', 46,
+        '^', 33,
+        '{', 16,
+        '|', 31,
+        '}', 17,
       );
       if (lookahead == '\t' ||
           lookahead == '\r' ||
           lookahead == ' ') SKIP(0);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(41);
-      if (set_contains(sym_identifier_character_set_1, 734, lookahead)) ADVANCE(40);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(24);
+      if (set_contains(sym_identifier_character_set_1, 734, lookahead)) ADVANCE(13);
       END_STATE();
     case 1:
       ADVANCE_MAP(
-        '\n', 43,
-        '!', 22,
-        '#', 3,
-        '&', 25,
-        '(', 36,
-        ')', 11,
-        '*', 21,
-        '+', 19,
-        ',', 42,
-        '-', 20,
-        '/', 4,
-        ':', 7,
-        '[', 37,
-        '^', 26,
-        '{', 12,
-        '|', 24,
-        '}', 13,
+        '\n', 49,
+        '!', 30,
+        '&', 32,
+        '(', 44,
+        ')', 15,
+        '*', 28,
+        '+', 26,
+        '-', 27,
+        '/', 5,
+        ':', 6,
+        '^', 33,
+        '{', 16,
+        '|', 31,
+        '}', 17,
       );
       if (lookahead == '\t' ||
           lookahead == '\r' ||
           lookahead == ' ') SKIP(1);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(41);
-      if (set_contains(sym_identifier_character_set_1, 734, lookahead)) ADVANCE(40);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(24);
+      if (set_contains(sym_identifier_character_set_1, 734, lookahead)) ADVANCE(13);
       END_STATE();
     case 2:
       ADVANCE_MAP(
-        '\n', 43,
-        '!', 8,
-        '#', 3,
-        '%', 34,
-        '&', 25,
-        '(', 36,
-        ')', 11,
-        '*', 21,
-        '+', 19,
-        ',', 42,
-        '-', 20,
-        '.', 35,
-        '/', 33,
-        ':', 7,
-        '<', 29,
-        '=', 14,
-        '>', 31,
-        '[', 37,
-        ']', 38,
-        '^', 26,
-        '{', 12,
-        '|', 24,
-        '}', 13,
+        '\n', 49,
+        '!', 7,
+        '#', 4,
+        '%', 41,
+        '&', 32,
+        '(', 44,
+        ')', 15,
+        '*', 28,
+        '+', 26,
+        ',', 48,
+        '-', 27,
+        '.', 43,
+        '/', 40,
+        ':', 20,
+        '<', 36,
+        '=', 18,
+        '>', 38,
+        '?', 42,
+        '[', 45,
+        ']', 46,
+        '^', 33,
+        '{', 16,
+        '|', 31,
+        '}', 17,
       );
       if (lookahead == '\t' ||
           lookahead == '\r' ||
           lookahead == ' ') SKIP(2);
-      if (set_contains(sym_identifier_character_set_1, 734, lookahead)) ADVANCE(40);
+      if (set_contains(sym_identifier_character_set_1, 734, lookahead)) ADVANCE(13);
       END_STATE();
     case 3:
-      if (lookahead == '(') ADVANCE(10);
+      ADVANCE_MAP(
+        '\n', 49,
+        '!', 7,
+        '#', 4,
+        '%', 41,
+        '&', 32,
+        '(', 44,
+        ')', 15,
+        '*', 28,
+        '+', 26,
+        ',', 48,
+        '-', 27,
+        '.', 43,
+        '/', 40,
+        ':', 21,
+        '<', 36,
+        '=', 18,
+        '>', 38,
+        '?', 42,
+        '[', 45,
+        ']', 46,
+        '^', 33,
+        '{', 16,
+        '|', 31,
+        '}', 17,
+      );
+      if (lookahead == '\t' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(3);
+      if (set_contains(sym_identifier_character_set_1, 734, lookahead)) ADVANCE(13);
       END_STATE();
     case 4:
-      if (lookahead == '*') ADVANCE(6);
-      if (lookahead == '/') ADVANCE(44);
+      if (lookahead == '(') ADVANCE(14);
       END_STATE();
     case 5:
-      if (lookahead == '*') ADVANCE(5);
-      if (lookahead == '/') ADVANCE(45);
-      if (lookahead != 0) ADVANCE(6);
+      if (lookahead == '*') ADVANCE(8);
+      if (lookahead == '/') ADVANCE(50);
       END_STATE();
     case 6:
-      if (lookahead == '*') ADVANCE(5);
-      if (lookahead != 0) ADVANCE(6);
+      if (lookahead == ':') ADVANCE(47);
       END_STATE();
     case 7:
-      if (lookahead == ':') ADVANCE(39);
+      if (lookahead == '=') ADVANCE(35);
       END_STATE();
     case 8:
-      if (lookahead == '=') ADVANCE(28);
+      if (lookahead == '*') ADVANCE(10);
+      if ((0x1 <= lookahead && lookahead <= 0x10ffff)) ADVANCE(8);
       END_STATE();
     case 9:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (lookahead == 'B' ||
+          lookahead == 'D' ||
+          lookahead == 'H' ||
+          lookahead == 'O' ||
+          lookahead == 'b' ||
+          lookahead == 'd' ||
+          lookahead == 'h' ||
+          lookahead == 'o') ADVANCE(11);
       END_STATE();
     case 10:
-      ACCEPT_TOKEN(anon_sym_POUND_LPAREN);
+      if (lookahead == '*') ADVANCE(10);
+      if (lookahead == '/') ADVANCE(51);
+      if ((0x1 <= lookahead && lookahead <= 0x10ffff)) ADVANCE(8);
       END_STATE();
     case 11:
-      ACCEPT_TOKEN(anon_sym_RPAREN);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(25);
       END_STATE();
     case 12:
-      ACCEPT_TOKEN(anon_sym_LBRACE);
+      ACCEPT_TOKEN(ts_builtin_sym_end);
       END_STATE();
     case 13:
-      ACCEPT_TOKEN(anon_sym_RBRACE);
+      ACCEPT_TOKEN(sym_identifier);
+      if (set_contains(sym_identifier_character_set_2, 751, lookahead)) ADVANCE(13);
       END_STATE();
     case 14:
-      ACCEPT_TOKEN(anon_sym_EQ);
-      if (lookahead == '=') ADVANCE(27);
+      ACCEPT_TOKEN(anon_sym_POUND_LPAREN);
       END_STATE();
     case 15:
-      ACCEPT_TOKEN(anon_sym_DOT_DOT);
+      ACCEPT_TOKEN(anon_sym_RPAREN);
       END_STATE();
     case 16:
-      ACCEPT_TOKEN(anon_sym_COLON);
+      ACCEPT_TOKEN(anon_sym_LBRACE);
       END_STATE();
     case 17:
-      ACCEPT_TOKEN(anon_sym_DASH_GT);
+      ACCEPT_TOKEN(anon_sym_RBRACE);
       END_STATE();
     case 18:
-      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '=') ADVANCE(34);
       END_STATE();
     case 19:
-      ACCEPT_TOKEN(anon_sym_PLUS);
+      ACCEPT_TOKEN(anon_sym_DOT_DOT);
       END_STATE();
     case 20:
-      ACCEPT_TOKEN(anon_sym_DASH);
-      if (lookahead == '>') ADVANCE(17);
+      ACCEPT_TOKEN(anon_sym_COLON);
+      if (lookahead == ':') ADVANCE(47);
       END_STATE();
     case 21:
-      ACCEPT_TOKEN(anon_sym_STAR);
+      ACCEPT_TOKEN(anon_sym_COLON);
       END_STATE();
     case 22:
-      ACCEPT_TOKEN(anon_sym_BANG);
+      ACCEPT_TOKEN(anon_sym_DASH_GT);
       END_STATE();
     case 23:
-      ACCEPT_TOKEN(anon_sym_BANG);
-      if (lookahead == '=') ADVANCE(28);
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
       END_STATE();
     case 24:
-      ACCEPT_TOKEN(anon_sym_PIPE);
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\'') ADVANCE(9);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(24);
       END_STATE();
     case 25:
-      ACCEPT_TOKEN(anon_sym_AMP);
+      ACCEPT_TOKEN(sym_number);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(25);
       END_STATE();
     case 26:
-      ACCEPT_TOKEN(anon_sym_CARET);
+      ACCEPT_TOKEN(anon_sym_PLUS);
       END_STATE();
     case 27:
-      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '>') ADVANCE(22);
       END_STATE();
     case 28:
-      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      ACCEPT_TOKEN(anon_sym_STAR);
       END_STATE();
     case 29:
-      ACCEPT_TOKEN(anon_sym_LT);
-      if (lookahead == '=') ADVANCE(30);
+      ACCEPT_TOKEN(anon_sym_BANG);
+      if (lookahead == '=') ADVANCE(35);
       END_STATE();
     case 30:
-      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      ACCEPT_TOKEN(anon_sym_BANG);
       END_STATE();
     case 31:
-      ACCEPT_TOKEN(anon_sym_GT);
-      if (lookahead == '=') ADVANCE(32);
+      ACCEPT_TOKEN(anon_sym_PIPE);
       END_STATE();
     case 32:
-      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      ACCEPT_TOKEN(anon_sym_AMP);
       END_STATE();
     case 33:
-      ACCEPT_TOKEN(anon_sym_SLASH);
-      if (lookahead == '*') ADVANCE(6);
-      if (lookahead == '/') ADVANCE(44);
+      ACCEPT_TOKEN(anon_sym_CARET);
       END_STATE();
     case 34:
-      ACCEPT_TOKEN(anon_sym_PERCENT);
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
       END_STATE();
     case 35:
-      ACCEPT_TOKEN(anon_sym_DOT);
-      if (lookahead == '.') ADVANCE(15);
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
       END_STATE();
     case 36:
-      ACCEPT_TOKEN(anon_sym_LPAREN);
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '=') ADVANCE(37);
       END_STATE();
     case 37:
-      ACCEPT_TOKEN(anon_sym_LBRACK);
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
       END_STATE();
     case 38:
-      ACCEPT_TOKEN(anon_sym_RBRACK);
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(39);
       END_STATE();
     case 39:
-      ACCEPT_TOKEN(anon_sym_COLON_COLON);
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
       END_STATE();
     case 40:
-      ACCEPT_TOKEN(sym_identifier);
-      if (set_contains(sym_identifier_character_set_2, 751, lookahead)) ADVANCE(40);
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      if (lookahead == '*') ADVANCE(8);
+      if (lookahead == '/') ADVANCE(50);
       END_STATE();
     case 41:
-      ACCEPT_TOKEN(sym_number);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          lookahead == '_') ADVANCE(41);
+      ACCEPT_TOKEN(anon_sym_PERCENT);
       END_STATE();
     case 42:
-      ACCEPT_TOKEN(anon_sym_COMMA);
+      ACCEPT_TOKEN(anon_sym_QMARK);
       END_STATE();
     case 43:
-      ACCEPT_TOKEN(anon_sym_LF);
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(19);
       END_STATE();
     case 44:
-      ACCEPT_TOKEN(sym_single_line_comment);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(44);
+      ACCEPT_TOKEN(anon_sym_LPAREN);
       END_STATE();
     case 45:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(anon_sym_COLON_COLON);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(anon_sym_LF);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(sym_single_line_comment);
+      if ((0x1 <= lookahead && lookahead <= '\t') ||
+          ('\v' <= lookahead && lookahead <= 0x10ffff)) ADVANCE(50);
+      END_STATE();
+    case 51:
       ACCEPT_TOKEN(sym_multi_line_comment);
       END_STATE();
     default:
@@ -1747,252 +1948,274 @@ static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
       if (lookahead == 'e') ADVANCE(20);
       END_STATE();
     case 7:
-      if (lookahead == 'f') ADVANCE(21);
-      if (lookahead == 'n') ADVANCE(22);
+      if (lookahead == 'f') ADVANCE(84);
+      if (lookahead == 'n') ADVANCE(87);
       END_STATE();
     case 8:
-      if (lookahead == 'o') ADVANCE(23);
+      if (lookahead == 'o') ADVANCE(21);
       END_STATE();
     case 9:
-      if (lookahead == 'u') ADVANCE(24);
+      if (lookahead == 'u') ADVANCE(22);
+      if (lookahead == 'v') ADVANCE(23);
       END_STATE();
     case 10:
-      if (lookahead == 'e') ADVANCE(25);
+      if (lookahead == 'e') ADVANCE(24);
       END_STATE();
     case 11:
-      if (lookahead == 't') ADVANCE(26);
+      if (lookahead == 't') ADVANCE(25);
       END_STATE();
     case 12:
-      if (lookahead == 'y') ADVANCE(27);
+      if (lookahead == 'y') ADVANCE(26);
       END_STATE();
     case 13:
-      if (lookahead == 'h') ADVANCE(28);
+      if (lookahead == 'h') ADVANCE(27);
       END_STATE();
     case 14:
-      if (lookahead == 'b') ADVANCE(29);
+      if (lookahead == 'b') ADVANCE(28);
       END_STATE();
     case 15:
-      if (lookahead == 'n') ADVANCE(30);
+      if (lookahead == 'n') ADVANCE(29);
       END_STATE();
     case 16:
-      if (lookahead == 'm') ADVANCE(31);
+      if (lookahead == 'm') ADVANCE(30);
       END_STATE();
     case 17:
-      if (lookahead == 's') ADVANCE(32);
+      if (lookahead == 's') ADVANCE(31);
       END_STATE();
     case 18:
-      if (lookahead == 't') ADVANCE(33);
+      if (lookahead == 't') ADVANCE(32);
       END_STATE();
     case 19:
-      if (lookahead == 'r') ADVANCE(34);
+      if (lookahead == 'r') ADVANCE(86);
       END_STATE();
     case 20:
-      if (lookahead == 'n') ADVANCE(35);
+      if (lookahead == 'n') ADVANCE(93);
       END_STATE();
     case 21:
-      ACCEPT_TOKEN(anon_sym_if);
+      if (lookahead == 'd') ADVANCE(36);
       END_STATE();
     case 22:
-      ACCEPT_TOKEN(anon_sym_in);
-      if (lookahead == 'i') ADVANCE(36);
-      if (lookahead == 'p') ADVANCE(37);
-      if (lookahead == 't') ADVANCE(38);
+      if (lookahead == 't') ADVANCE(37);
       END_STATE();
     case 23:
-      if (lookahead == 'd') ADVANCE(39);
+      if (lookahead == 'e') ADVANCE(38);
       END_STATE();
     case 24:
-      if (lookahead == 't') ADVANCE(40);
+      if (lookahead == 'g') ADVANCE(81);
       END_STATE();
     case 25:
-      if (lookahead == 'g') ADVANCE(41);
+      if (lookahead == 'a') ADVANCE(39);
+      if (lookahead == 'r') ADVANCE(40);
       END_STATE();
     case 26:
-      if (lookahead == 'a') ADVANCE(42);
-      if (lookahead == 'r') ADVANCE(43);
+      if (lookahead == 'p') ADVANCE(41);
       END_STATE();
     case 27:
-      if (lookahead == 'p') ADVANCE(44);
+      if (lookahead == 'e') ADVANCE(42);
       END_STATE();
     case 28:
-      if (lookahead == 'e') ADVANCE(45);
+      if (lookahead == 'u') ADVANCE(43);
       END_STATE();
     case 29:
-      if (lookahead == 'u') ADVANCE(46);
+      if (lookahead == 's') ADVANCE(44);
       END_STATE();
     case 30:
-      if (lookahead == 's') ADVANCE(47);
+      if (lookahead == 'a') ADVANCE(45);
       END_STATE();
     case 31:
-      if (lookahead == 'a') ADVANCE(48);
+      if (lookahead == 'e') ADVANCE(85);
       END_STATE();
     case 32:
-      if (lookahead == 'e') ADVANCE(49);
+      if (lookahead == 'e') ADVANCE(46);
       END_STATE();
     case 33:
-      if (lookahead == 'e') ADVANCE(50);
+      if (lookahead == 't') ADVANCE(47);
       END_STATE();
     case 34:
-      ACCEPT_TOKEN(anon_sym_for);
+      if (lookahead == 'u') ADVANCE(48);
       END_STATE();
     case 35:
-      ACCEPT_TOKEN(anon_sym_gen);
+      if (lookahead == 'e') ADVANCE(49);
       END_STATE();
     case 36:
-      if (lookahead == 't') ADVANCE(51);
+      if (lookahead == 'u') ADVANCE(50);
       END_STATE();
     case 37:
-      if (lookahead == 'u') ADVANCE(52);
+      if (lookahead == 'p') ADVANCE(51);
       END_STATE();
     case 38:
-      if (lookahead == 'e') ADVANCE(53);
+      if (lookahead == 'r') ADVANCE(52);
       END_STATE();
     case 39:
-      if (lookahead == 'u') ADVANCE(54);
+      if (lookahead == 't') ADVANCE(53);
       END_STATE();
     case 40:
-      if (lookahead == 'p') ADVANCE(55);
+      if (lookahead == 'u') ADVANCE(54);
       END_STATE();
     case 41:
-      ACCEPT_TOKEN(anon_sym_reg);
+      if (lookahead == 'e') ADVANCE(94);
       END_STATE();
     case 42:
-      if (lookahead == 't') ADVANCE(56);
+      if (lookahead == 'n') ADVANCE(83);
       END_STATE();
     case 43:
-      if (lookahead == 'u') ADVANCE(57);
+      if (lookahead == 'i') ADVANCE(55);
       END_STATE();
     case 44:
-      if (lookahead == 'e') ADVANCE(58);
+      if (lookahead == 't') ADVANCE(80);
       END_STATE();
     case 45:
-      if (lookahead == 'n') ADVANCE(59);
+      if (lookahead == 'i') ADVANCE(56);
       END_STATE();
     case 46:
-      if (lookahead == 'i') ADVANCE(60);
+      if (lookahead == 'r') ADVANCE(57);
       END_STATE();
     case 47:
-      if (lookahead == 't') ADVANCE(61);
+      if (lookahead == 'i') ADVANCE(58);
       END_STATE();
     case 48:
-      if (lookahead == 'i') ADVANCE(62);
+      if (lookahead == 't') ADVANCE(90);
       END_STATE();
     case 49:
-      ACCEPT_TOKEN(anon_sym_else);
+      if (lookahead == 'r') ADVANCE(59);
       END_STATE();
     case 50:
-      if (lookahead == 'r') ADVANCE(63);
+      if (lookahead == 'l') ADVANCE(60);
       END_STATE();
     case 51:
-      if (lookahead == 'i') ADVANCE(64);
+      if (lookahead == 'u') ADVANCE(61);
       END_STATE();
     case 52:
-      if (lookahead == 't') ADVANCE(65);
+      if (lookahead == 'r') ADVANCE(62);
       END_STATE();
     case 53:
-      if (lookahead == 'r') ADVANCE(66);
+      if (lookahead == 'e') ADVANCE(92);
       END_STATE();
     case 54:
-      if (lookahead == 'l') ADVANCE(67);
+      if (lookahead == 'c') ADVANCE(63);
       END_STATE();
     case 55:
-      if (lookahead == 'u') ADVANCE(68);
+      if (lookahead == 'l') ADVANCE(64);
       END_STATE();
     case 56:
-      if (lookahead == 'e') ADVANCE(69);
+      if (lookahead == 'n') ADVANCE(88);
       END_STATE();
     case 57:
-      if (lookahead == 'c') ADVANCE(70);
+      if (lookahead == 'n') ADVANCE(76);
       END_STATE();
     case 58:
-      ACCEPT_TOKEN(anon_sym_type);
+      if (lookahead == 'a') ADVANCE(65);
       END_STATE();
     case 59:
-      ACCEPT_TOKEN(anon_sym_when);
+      if (lookahead == 'f') ADVANCE(66);
       END_STATE();
     case 60:
-      if (lookahead == 'l') ADVANCE(71);
+      if (lookahead == 'e') ADVANCE(78);
       END_STATE();
     case 61:
-      ACCEPT_TOKEN(anon_sym_const);
+      if (lookahead == 't') ADVANCE(91);
       END_STATE();
     case 62:
-      if (lookahead == 'n') ADVANCE(72);
+      if (lookahead == 'i') ADVANCE(67);
       END_STATE();
     case 63:
-      if (lookahead == 'n') ADVANCE(73);
+      if (lookahead == 't') ADVANCE(79);
       END_STATE();
     case 64:
-      if (lookahead == 'a') ADVANCE(74);
+      if (lookahead == 't') ADVANCE(68);
       END_STATE();
     case 65:
-      ACCEPT_TOKEN(anon_sym_input);
+      if (lookahead == 'l') ADVANCE(82);
       END_STATE();
     case 66:
-      if (lookahead == 'f') ADVANCE(75);
+      if (lookahead == 'a') ADVANCE(69);
       END_STATE();
     case 67:
-      if (lookahead == 'e') ADVANCE(76);
+      if (lookahead == 'd') ADVANCE(70);
       END_STATE();
     case 68:
-      if (lookahead == 't') ADVANCE(77);
+      if (lookahead == 'i') ADVANCE(71);
       END_STATE();
     case 69:
-      ACCEPT_TOKEN(anon_sym_state);
+      if (lookahead == 'c') ADVANCE(72);
       END_STATE();
     case 70:
-      if (lookahead == 't') ADVANCE(78);
+      if (lookahead == 'e') ADVANCE(77);
       END_STATE();
     case 71:
-      if (lookahead == 't') ADVANCE(79);
+      if (lookahead == 'n') ADVANCE(73);
       END_STATE();
     case 72:
-      ACCEPT_TOKEN(anon_sym_domain);
+      if (lookahead == 'e') ADVANCE(89);
       END_STATE();
     case 73:
-      ACCEPT_TOKEN(anon_sym_extern);
+      if (lookahead == '_') ADVANCE(74);
       END_STATE();
     case 74:
-      if (lookahead == 'l') ADVANCE(80);
+      if (lookahead == '_') ADVANCE(75);
       END_STATE();
     case 75:
-      if (lookahead == 'a') ADVANCE(81);
+      ACCEPT_TOKEN(anon_sym___builtin__);
       END_STATE();
     case 76:
-      ACCEPT_TOKEN(anon_sym_module);
+      ACCEPT_TOKEN(anon_sym_extern);
       END_STATE();
     case 77:
-      ACCEPT_TOKEN(anon_sym_output);
+      ACCEPT_TOKEN(anon_sym_override);
       END_STATE();
     case 78:
-      ACCEPT_TOKEN(anon_sym_struct);
+      ACCEPT_TOKEN(anon_sym_module);
       END_STATE();
     case 79:
-      if (lookahead == 'i') ADVANCE(82);
+      ACCEPT_TOKEN(anon_sym_struct);
       END_STATE();
     case 80:
-      ACCEPT_TOKEN(anon_sym_initial);
+      ACCEPT_TOKEN(anon_sym_const);
       END_STATE();
     case 81:
-      if (lookahead == 'c') ADVANCE(83);
+      ACCEPT_TOKEN(anon_sym_reg);
       END_STATE();
     case 82:
-      if (lookahead == 'n') ADVANCE(84);
+      ACCEPT_TOKEN(anon_sym_initial);
       END_STATE();
     case 83:
-      if (lookahead == 'e') ADVANCE(85);
+      ACCEPT_TOKEN(anon_sym_when);
       END_STATE();
     case 84:
-      if (lookahead == '_') ADVANCE(86);
+      ACCEPT_TOKEN(anon_sym_if);
       END_STATE();
     case 85:
-      ACCEPT_TOKEN(anon_sym_interface);
+      ACCEPT_TOKEN(anon_sym_else);
       END_STATE();
     case 86:
-      if (lookahead == '_') ADVANCE(87);
+      ACCEPT_TOKEN(anon_sym_for);
       END_STATE();
     case 87:
-      ACCEPT_TOKEN(anon_sym___builtin__);
+      ACCEPT_TOKEN(anon_sym_in);
+      if (lookahead == 'i') ADVANCE(33);
+      if (lookahead == 'p') ADVANCE(34);
+      if (lookahead == 't') ADVANCE(35);
+      END_STATE();
+    case 88:
+      ACCEPT_TOKEN(anon_sym_domain);
+      END_STATE();
+    case 89:
+      ACCEPT_TOKEN(anon_sym_interface);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(anon_sym_input);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(anon_sym_output);
+      END_STATE();
+    case 92:
+      ACCEPT_TOKEN(anon_sym_state);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(anon_sym_gen);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(anon_sym_type);
       END_STATE();
     default:
       return false;
@@ -2015,68 +2238,68 @@ static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [12] = {.lex_state = 2},
   [13] = {.lex_state = 2},
   [14] = {.lex_state = 2},
-  [15] = {.lex_state = 2},
-  [16] = {.lex_state = 2},
+  [15] = {.lex_state = 3},
+  [16] = {.lex_state = 3},
   [17] = {.lex_state = 2},
-  [18] = {.lex_state = 2},
-  [19] = {.lex_state = 2},
-  [20] = {.lex_state = 2},
-  [21] = {.lex_state = 2},
-  [22] = {.lex_state = 2},
-  [23] = {.lex_state = 2},
-  [24] = {.lex_state = 2},
-  [25] = {.lex_state = 1},
-  [26] = {.lex_state = 2},
-  [27] = {.lex_state = 2},
-  [28] = {.lex_state = 2},
-  [29] = {.lex_state = 2},
-  [30] = {.lex_state = 2},
-  [31] = {.lex_state = 2},
-  [32] = {.lex_state = 2},
-  [33] = {.lex_state = 2},
-  [34] = {.lex_state = 2},
-  [35] = {.lex_state = 2},
-  [36] = {.lex_state = 2},
-  [37] = {.lex_state = 2},
-  [38] = {.lex_state = 2},
-  [39] = {.lex_state = 2},
-  [40] = {.lex_state = 2},
-  [41] = {.lex_state = 2},
-  [42] = {.lex_state = 2},
-  [43] = {.lex_state = 2},
-  [44] = {.lex_state = 2},
-  [45] = {.lex_state = 2},
-  [46] = {.lex_state = 1},
-  [47] = {.lex_state = 2},
-  [48] = {.lex_state = 2},
-  [49] = {.lex_state = 2},
-  [50] = {.lex_state = 2},
-  [51] = {.lex_state = 2},
-  [52] = {.lex_state = 2},
-  [53] = {.lex_state = 2},
-  [54] = {.lex_state = 1},
-  [55] = {.lex_state = 1},
+  [18] = {.lex_state = 3},
+  [19] = {.lex_state = 3},
+  [20] = {.lex_state = 3},
+  [21] = {.lex_state = 3},
+  [22] = {.lex_state = 3},
+  [23] = {.lex_state = 3},
+  [24] = {.lex_state = 3},
+  [25] = {.lex_state = 3},
+  [26] = {.lex_state = 1},
+  [27] = {.lex_state = 3},
+  [28] = {.lex_state = 3},
+  [29] = {.lex_state = 3},
+  [30] = {.lex_state = 3},
+  [31] = {.lex_state = 3},
+  [32] = {.lex_state = 3},
+  [33] = {.lex_state = 3},
+  [34] = {.lex_state = 3},
+  [35] = {.lex_state = 3},
+  [36] = {.lex_state = 3},
+  [37] = {.lex_state = 3},
+  [38] = {.lex_state = 3},
+  [39] = {.lex_state = 3},
+  [40] = {.lex_state = 3},
+  [41] = {.lex_state = 3},
+  [42] = {.lex_state = 3},
+  [43] = {.lex_state = 3},
+  [44] = {.lex_state = 3},
+  [45] = {.lex_state = 3},
+  [46] = {.lex_state = 3},
+  [47] = {.lex_state = 3},
+  [48] = {.lex_state = 3},
+  [49] = {.lex_state = 3},
+  [50] = {.lex_state = 3},
+  [51] = {.lex_state = 3},
+  [52] = {.lex_state = 3},
+  [53] = {.lex_state = 3},
+  [54] = {.lex_state = 3},
+  [55] = {.lex_state = 3},
   [56] = {.lex_state = 2},
   [57] = {.lex_state = 2},
-  [58] = {.lex_state = 2},
-  [59] = {.lex_state = 1},
-  [60] = {.lex_state = 1},
-  [61] = {.lex_state = 1},
-  [62] = {.lex_state = 1},
+  [58] = {.lex_state = 1},
+  [59] = {.lex_state = 2},
+  [60] = {.lex_state = 2},
+  [61] = {.lex_state = 2},
+  [62] = {.lex_state = 2},
   [63] = {.lex_state = 1},
-  [64] = {.lex_state = 1},
+  [64] = {.lex_state = 2},
   [65] = {.lex_state = 1},
-  [66] = {.lex_state = 1},
-  [67] = {.lex_state = 1},
+  [66] = {.lex_state = 2},
+  [67] = {.lex_state = 2},
   [68] = {.lex_state = 1},
   [69] = {.lex_state = 1},
-  [70] = {.lex_state = 1},
+  [70] = {.lex_state = 2},
   [71] = {.lex_state = 1},
   [72] = {.lex_state = 1},
   [73] = {.lex_state = 2},
-  [74] = {.lex_state = 2},
+  [74] = {.lex_state = 1},
   [75] = {.lex_state = 1},
-  [76] = {.lex_state = 2},
+  [76] = {.lex_state = 1},
   [77] = {.lex_state = 1},
   [78] = {.lex_state = 1},
   [79] = {.lex_state = 1},
@@ -2085,68 +2308,68 @@ static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [82] = {.lex_state = 1},
   [83] = {.lex_state = 1},
   [84] = {.lex_state = 1},
-  [85] = {.lex_state = 1},
+  [85] = {.lex_state = 2},
   [86] = {.lex_state = 1},
   [87] = {.lex_state = 1},
-  [88] = {.lex_state = 1},
-  [89] = {.lex_state = 1},
+  [88] = {.lex_state = 2},
+  [89] = {.lex_state = 3},
   [90] = {.lex_state = 1},
   [91] = {.lex_state = 1},
-  [92] = {.lex_state = 0},
-  [93] = {.lex_state = 0},
-  [94] = {.lex_state = 0},
-  [95] = {.lex_state = 0},
-  [96] = {.lex_state = 0},
-  [97] = {.lex_state = 0},
-  [98] = {.lex_state = 0},
-  [99] = {.lex_state = 0},
-  [100] = {.lex_state = 0},
-  [101] = {.lex_state = 0},
-  [102] = {.lex_state = 0},
-  [103] = {.lex_state = 1},
+  [92] = {.lex_state = 1},
+  [93] = {.lex_state = 1},
+  [94] = {.lex_state = 2},
+  [95] = {.lex_state = 1},
+  [96] = {.lex_state = 1},
+  [97] = {.lex_state = 1},
+  [98] = {.lex_state = 1},
+  [99] = {.lex_state = 1},
+  [100] = {.lex_state = 1},
+  [101] = {.lex_state = 1},
+  [102] = {.lex_state = 1},
+  [103] = {.lex_state = 3},
   [104] = {.lex_state = 1},
-  [105] = {.lex_state = 0},
-  [106] = {.lex_state = 0},
-  [107] = {.lex_state = 0},
-  [108] = {.lex_state = 0},
-  [109] = {.lex_state = 0},
-  [110] = {.lex_state = 1},
-  [111] = {.lex_state = 0},
-  [112] = {.lex_state = 0},
-  [113] = {.lex_state = 0},
-  [114] = {.lex_state = 1},
-  [115] = {.lex_state = 0},
-  [116] = {.lex_state = 0},
-  [117] = {.lex_state = 0},
-  [118] = {.lex_state = 0},
-  [119] = {.lex_state = 0},
-  [120] = {.lex_state = 0},
-  [121] = {.lex_state = 0},
-  [122] = {.lex_state = 0},
-  [123] = {.lex_state = 1},
-  [124] = {.lex_state = 1},
-  [125] = {.lex_state = 0},
-  [126] = {.lex_state = 0},
-  [127] = {.lex_state = 0},
-  [128] = {.lex_state = 0},
-  [129] = {.lex_state = 0},
-  [130] = {.lex_state = 0},
-  [131] = {.lex_state = 0},
-  [132] = {.lex_state = 0},
-  [133] = {.lex_state = 0},
-  [134] = {.lex_state = 0},
-  [135] = {.lex_state = 0},
-  [136] = {.lex_state = 0},
-  [137] = {.lex_state = 0},
-  [138] = {.lex_state = 0},
-  [139] = {.lex_state = 0},
-  [140] = {.lex_state = 0},
-  [141] = {.lex_state = 0},
-  [142] = {.lex_state = 0},
-  [143] = {.lex_state = 0},
-  [144] = {.lex_state = 0},
-  [145] = {.lex_state = 0},
-  [146] = {.lex_state = 0},
+  [105] = {.lex_state = 1},
+  [106] = {.lex_state = 1},
+  [107] = {.lex_state = 2},
+  [108] = {.lex_state = 2},
+  [109] = {.lex_state = 3},
+  [110] = {.lex_state = 2},
+  [111] = {.lex_state = 2},
+  [112] = {.lex_state = 2},
+  [113] = {.lex_state = 2},
+  [114] = {.lex_state = 2},
+  [115] = {.lex_state = 2},
+  [116] = {.lex_state = 2},
+  [117] = {.lex_state = 2},
+  [118] = {.lex_state = 2},
+  [119] = {.lex_state = 2},
+  [120] = {.lex_state = 2},
+  [121] = {.lex_state = 2},
+  [122] = {.lex_state = 2},
+  [123] = {.lex_state = 3},
+  [124] = {.lex_state = 2},
+  [125] = {.lex_state = 2},
+  [126] = {.lex_state = 2},
+  [127] = {.lex_state = 2},
+  [128] = {.lex_state = 1},
+  [129] = {.lex_state = 1},
+  [130] = {.lex_state = 2},
+  [131] = {.lex_state = 2},
+  [132] = {.lex_state = 2},
+  [133] = {.lex_state = 2},
+  [134] = {.lex_state = 2},
+  [135] = {.lex_state = 2},
+  [136] = {.lex_state = 2},
+  [137] = {.lex_state = 2},
+  [138] = {.lex_state = 2},
+  [139] = {.lex_state = 2},
+  [140] = {.lex_state = 2},
+  [141] = {.lex_state = 2},
+  [142] = {.lex_state = 2},
+  [143] = {.lex_state = 1},
+  [144] = {.lex_state = 1},
+  [145] = {.lex_state = 1},
+  [146] = {.lex_state = 1},
   [147] = {.lex_state = 0},
   [148] = {.lex_state = 0},
   [149] = {.lex_state = 0},
@@ -2223,13 +2446,100 @@ static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [220] = {.lex_state = 0},
   [221] = {.lex_state = 0},
   [222] = {.lex_state = 0},
-  [223] = {.lex_state = 0},
+  [223] = {.lex_state = 3},
   [224] = {.lex_state = 0},
   [225] = {.lex_state = 0},
   [226] = {.lex_state = 0},
   [227] = {.lex_state = 0},
   [228] = {.lex_state = 0},
-  [229] = {.lex_state = 0},
+  [229] = {.lex_state = 3},
+  [230] = {.lex_state = 0},
+  [231] = {.lex_state = 0},
+  [232] = {.lex_state = 0},
+  [233] = {.lex_state = 0},
+  [234] = {.lex_state = 0},
+  [235] = {.lex_state = 0},
+  [236] = {.lex_state = 0},
+  [237] = {.lex_state = 0},
+  [238] = {.lex_state = 0},
+  [239] = {.lex_state = 0},
+  [240] = {.lex_state = 0},
+  [241] = {.lex_state = 0},
+  [242] = {.lex_state = 0},
+  [243] = {.lex_state = 0},
+  [244] = {.lex_state = 0},
+  [245] = {.lex_state = 0},
+  [246] = {.lex_state = 0},
+  [247] = {.lex_state = 0},
+  [248] = {.lex_state = 0},
+  [249] = {.lex_state = 0},
+  [250] = {.lex_state = 0},
+  [251] = {.lex_state = 0},
+  [252] = {.lex_state = 0},
+  [253] = {.lex_state = 0},
+  [254] = {.lex_state = 0},
+  [255] = {.lex_state = 0},
+  [256] = {.lex_state = 0},
+  [257] = {.lex_state = 0},
+  [258] = {.lex_state = 0},
+  [259] = {.lex_state = 0},
+  [260] = {.lex_state = 0},
+  [261] = {.lex_state = 0},
+  [262] = {.lex_state = 0},
+  [263] = {.lex_state = 0},
+  [264] = {.lex_state = 0},
+  [265] = {.lex_state = 0},
+  [266] = {.lex_state = 0},
+  [267] = {.lex_state = 0},
+  [268] = {.lex_state = 0},
+  [269] = {.lex_state = 0},
+  [270] = {.lex_state = 0},
+  [271] = {.lex_state = 0},
+  [272] = {.lex_state = 0},
+  [273] = {.lex_state = 0},
+  [274] = {.lex_state = 0},
+  [275] = {.lex_state = 0},
+  [276] = {.lex_state = 0},
+  [277] = {.lex_state = 0},
+  [278] = {.lex_state = 0},
+  [279] = {.lex_state = 0},
+  [280] = {.lex_state = 0},
+  [281] = {.lex_state = 0},
+  [282] = {.lex_state = 0},
+  [283] = {.lex_state = 0},
+  [284] = {.lex_state = 0},
+  [285] = {.lex_state = 0},
+  [286] = {.lex_state = 0},
+  [287] = {.lex_state = 0},
+  [288] = {.lex_state = 0},
+  [289] = {.lex_state = 0},
+  [290] = {.lex_state = 0},
+  [291] = {.lex_state = 0},
+  [292] = {.lex_state = 0},
+  [293] = {.lex_state = 0},
+  [294] = {.lex_state = 0},
+  [295] = {.lex_state = 0},
+  [296] = {.lex_state = 0},
+  [297] = {.lex_state = 0},
+  [298] = {.lex_state = 0},
+  [299] = {.lex_state = 0},
+  [300] = {.lex_state = 0},
+  [301] = {.lex_state = 0},
+  [302] = {.lex_state = 0},
+  [303] = {.lex_state = 0},
+  [304] = {.lex_state = 0},
+  [305] = {.lex_state = 0},
+  [306] = {.lex_state = 0},
+  [307] = {.lex_state = 0},
+  [308] = {.lex_state = 0},
+  [309] = {.lex_state = 0},
+  [310] = {.lex_state = 0},
+  [311] = {.lex_state = 0},
+  [312] = {.lex_state = 0},
+  [313] = {.lex_state = 0},
+  [314] = {.lex_state = 0},
+  [315] = {.lex_state = 0},
+  [316] = {.lex_state = 0},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
@@ -2238,6 +2548,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_identifier] = ACTIONS(1),
     [anon_sym___builtin__] = ACTIONS(1),
     [anon_sym_extern] = ACTIONS(1),
+    [anon_sym_override] = ACTIONS(1),
     [anon_sym_module] = ACTIONS(1),
     [anon_sym_struct] = ACTIONS(1),
     [anon_sym_const] = ACTIONS(1),
@@ -2263,6 +2574,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_state] = ACTIONS(1),
     [anon_sym_gen] = ACTIONS(1),
     [anon_sym_SQUOTE] = ACTIONS(1),
+    [sym_number] = ACTIONS(1),
     [anon_sym_PLUS] = ACTIONS(1),
     [anon_sym_DASH] = ACTIONS(1),
     [anon_sym_STAR] = ACTIONS(1),
@@ -2278,25 +2590,27 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_GT_EQ] = ACTIONS(1),
     [anon_sym_SLASH] = ACTIONS(1),
     [anon_sym_PERCENT] = ACTIONS(1),
+    [anon_sym_QMARK] = ACTIONS(1),
     [anon_sym_DOT] = ACTIONS(1),
     [anon_sym_LPAREN] = ACTIONS(1),
     [anon_sym_LBRACK] = ACTIONS(1),
     [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_COLON_COLON] = ACTIONS(1),
     [anon_sym_type] = ACTIONS(1),
-    [sym_number] = ACTIONS(1),
     [anon_sym_COMMA] = ACTIONS(1),
     [anon_sym_LF] = ACTIONS(1),
     [sym_single_line_comment] = ACTIONS(3),
     [sym_multi_line_comment] = ACTIONS(3),
   },
   [1] = {
-    [sym_source_file] = STATE(225),
-    [sym_global_object] = STATE(143),
-    [sym_const_and_type] = STATE(223),
-    [aux_sym__linebreak] = STATE(94),
+    [sym_source_file] = STATE(298),
+    [sym_global_object] = STATE(212),
+    [sym_const_and_type] = STATE(299),
+    [aux_sym__linebreak] = STATE(154),
     [ts_builtin_sym_end] = ACTIONS(5),
     [anon_sym___builtin__] = ACTIONS(7),
     [anon_sym_extern] = ACTIONS(7),
+    [anon_sym_override] = ACTIONS(7),
     [anon_sym_module] = ACTIONS(9),
     [anon_sym_struct] = ACTIONS(9),
     [anon_sym_const] = ACTIONS(11),
@@ -2324,29 +2638,29 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_domain,
     ACTIONS(31), 1,
       anon_sym_interface,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      sym_number,
     ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
     ACTIONS(43), 1,
-      sym_number,
+      anon_sym_COLON_COLON,
     ACTIONS(45), 1,
       anon_sym_LF,
-    STATE(3), 1,
+    STATE(4), 1,
       aux_sym__linebreak,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(46), 1,
+    STATE(26), 1,
       sym_write_modifiers,
-    STATE(51), 1,
+    STATE(73), 1,
       sym_template_global,
-    STATE(80), 1,
+    STATE(143), 1,
       aux_sym_write_modifiers_repeat1,
-    STATE(117), 1,
+    STATE(177), 1,
       sym_assign_to,
-    STATE(127), 1,
+    STATE(203), 1,
       sym_assign_left_side,
-    STATE(150), 1,
+    STATE(222), 1,
       sym_declaration,
     ACTIONS(3), 2,
       sym_single_line_comment,
@@ -2360,17 +2674,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 2,
+    STATE(245), 2,
       sym__type,
       sym_array_type,
-    STATE(144), 6,
+    STATE(220), 6,
       sym_block,
       sym_decl_assign_statement,
       sym_if_statement,
       sym_for_statement,
       sym_domain_statement,
       sym_interface_statement,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -2378,19 +2692,24 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(50), 7,
+    STATE(62), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
-  [107] = 28,
+      sym_concatenation,
+
+  [109] = 28,
     ACTIONS(15), 1,
       sym_identifier,
     ACTIONS(17), 1,
       anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_RBRACE,
     ACTIONS(21), 1,
       anon_sym_reg,
     ACTIONS(23), 1,
@@ -2401,31 +2720,29 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_domain,
     ACTIONS(31), 1,
       anon_sym_interface,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
     ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
     ACTIONS(43), 1,
-      sym_number,
+      anon_sym_COLON_COLON,
     ACTIONS(47), 1,
-      anon_sym_RBRACE,
+      sym_number,
     ACTIONS(49), 1,
       anon_sym_LF,
-    STATE(17), 1,
-      sym_namespace_list,
-    STATE(25), 1,
+    STATE(5), 1,
       aux_sym__linebreak,
-    STATE(46), 1,
+    STATE(15), 1,
+      sym_namespace_list,
+    STATE(26), 1,
       sym_write_modifiers,
-    STATE(51), 1,
+    STATE(73), 1,
       sym_template_global,
-    STATE(80), 1,
+    STATE(143), 1,
       aux_sym_write_modifiers_repeat1,
-    STATE(117), 1,
+    STATE(177), 1,
       sym_assign_to,
-    STATE(129), 1,
+    STATE(203), 1,
       sym_assign_left_side,
-    STATE(150), 1,
+    STATE(222), 1,
       sym_declaration,
     ACTIONS(3), 2,
       sym_single_line_comment,
@@ -2439,17 +2756,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 2,
+    STATE(245), 2,
       sym__type,
       sym_array_type,
-    STATE(160), 6,
+    STATE(220), 6,
       sym_block,
       sym_decl_assign_statement,
       sym_if_statement,
       sym_for_statement,
       sym_domain_statement,
       sym_interface_statement,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -2457,15 +2774,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(50), 7,
+    STATE(56), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
-  [214] = 28,
+      sym_concatenation,
+
+  [218] = 28,
     ACTIONS(15), 1,
       sym_identifier,
     ACTIONS(17), 1,
@@ -2480,32 +2800,32 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_domain,
     ACTIONS(31), 1,
       anon_sym_interface,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      sym_number,
     ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
     ACTIONS(43), 1,
-      sym_number,
-    ACTIONS(49), 1,
-      anon_sym_LF,
+      anon_sym_COLON_COLON,
     ACTIONS(51), 1,
       anon_sym_RBRACE,
-    STATE(17), 1,
+    ACTIONS(53), 1,
+      anon_sym_LF,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(25), 1,
-      aux_sym__linebreak,
-    STATE(46), 1,
+    STATE(26), 1,
       sym_write_modifiers,
-    STATE(51), 1,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    STATE(73), 1,
       sym_template_global,
-    STATE(80), 1,
+    STATE(143), 1,
       aux_sym_write_modifiers_repeat1,
-    STATE(117), 1,
+    STATE(177), 1,
       sym_assign_to,
-    STATE(150), 1,
-      sym_declaration,
-    STATE(191), 1,
+    STATE(205), 1,
       sym_assign_left_side,
+    STATE(222), 1,
+      sym_declaration,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
@@ -2518,17 +2838,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 2,
+    STATE(245), 2,
       sym__type,
       sym_array_type,
-    STATE(209), 6,
+    STATE(224), 6,
       sym_block,
       sym_decl_assign_statement,
       sym_if_statement,
       sym_for_statement,
       sym_domain_statement,
       sym_interface_statement,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -2536,15 +2856,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(50), 7,
+    STATE(62), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
-  [321] = 28,
+      sym_concatenation,
+
+  [327] = 28,
     ACTIONS(15), 1,
       sym_identifier,
     ACTIONS(17), 1,
@@ -2559,32 +2882,32 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_domain,
     ACTIONS(31), 1,
       anon_sym_interface,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
     ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
     ACTIONS(43), 1,
-      sym_number,
-    ACTIONS(49), 1,
-      anon_sym_LF,
-    ACTIONS(53), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(51), 1,
       anon_sym_RBRACE,
-    STATE(17), 1,
+    ACTIONS(53), 1,
+      anon_sym_LF,
+    ACTIONS(55), 1,
+      sym_number,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(25), 1,
-      aux_sym__linebreak,
-    STATE(46), 1,
+    STATE(26), 1,
       sym_write_modifiers,
-    STATE(51), 1,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    STATE(73), 1,
       sym_template_global,
-    STATE(80), 1,
+    STATE(143), 1,
       aux_sym_write_modifiers_repeat1,
-    STATE(117), 1,
+    STATE(177), 1,
       sym_assign_to,
-    STATE(150), 1,
-      sym_declaration,
-    STATE(191), 1,
+    STATE(205), 1,
       sym_assign_left_side,
+    STATE(222), 1,
+      sym_declaration,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
@@ -2597,17 +2920,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 2,
+    STATE(245), 2,
       sym__type,
       sym_array_type,
-    STATE(209), 6,
+    STATE(224), 6,
       sym_block,
       sym_decl_assign_statement,
       sym_if_statement,
       sym_for_statement,
       sym_domain_statement,
       sym_interface_statement,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -2615,15 +2938,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(50), 7,
+    STATE(57), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
-  [428] = 28,
+      sym_concatenation,
+
+  [436] = 28,
     ACTIONS(15), 1,
       sym_identifier,
     ACTIONS(17), 1,
@@ -2638,31 +2964,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_domain,
     ACTIONS(31), 1,
       anon_sym_interface,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      sym_number,
     ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
     ACTIONS(43), 1,
-      sym_number,
-    ACTIONS(49), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(53), 1,
       anon_sym_LF,
-    ACTIONS(55), 1,
+    ACTIONS(57), 1,
       anon_sym_RBRACE,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(25), 1,
-      aux_sym__linebreak,
-    STATE(46), 1,
+    STATE(26), 1,
       sym_write_modifiers,
-    STATE(51), 1,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    STATE(73), 1,
       sym_template_global,
-    STATE(80), 1,
+    STATE(143), 1,
       aux_sym_write_modifiers_repeat1,
-    STATE(117), 1,
+    STATE(177), 1,
       sym_assign_to,
-    STATE(150), 1,
+    STATE(222), 1,
       sym_declaration,
-    STATE(191), 1,
+    STATE(255), 1,
       sym_assign_left_side,
     ACTIONS(3), 2,
       sym_single_line_comment,
@@ -2676,17 +3002,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 2,
+    STATE(245), 2,
       sym__type,
       sym_array_type,
-    STATE(209), 6,
+    STATE(287), 6,
       sym_block,
       sym_decl_assign_statement,
       sym_if_statement,
       sym_for_statement,
       sym_domain_statement,
       sym_interface_statement,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -2694,15 +3020,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(50), 7,
+    STATE(62), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
-  [535] = 28,
+      sym_concatenation,
+
+  [545] = 28,
     ACTIONS(15), 1,
       sym_identifier,
     ACTIONS(17), 1,
@@ -2717,31 +3046,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_domain,
     ACTIONS(31), 1,
       anon_sym_interface,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      sym_number,
     ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
     ACTIONS(43), 1,
-      sym_number,
-    ACTIONS(49), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(53), 1,
       anon_sym_LF,
-    ACTIONS(57), 1,
+    ACTIONS(59), 1,
       anon_sym_RBRACE,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(25), 1,
-      aux_sym__linebreak,
-    STATE(46), 1,
+    STATE(26), 1,
       sym_write_modifiers,
-    STATE(51), 1,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    STATE(73), 1,
       sym_template_global,
-    STATE(80), 1,
+    STATE(143), 1,
       aux_sym_write_modifiers_repeat1,
-    STATE(117), 1,
+    STATE(177), 1,
       sym_assign_to,
-    STATE(150), 1,
+    STATE(222), 1,
       sym_declaration,
-    STATE(191), 1,
+    STATE(255), 1,
       sym_assign_left_side,
     ACTIONS(3), 2,
       sym_single_line_comment,
@@ -2755,17 +3084,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 2,
+    STATE(245), 2,
       sym__type,
       sym_array_type,
-    STATE(209), 6,
+    STATE(287), 6,
       sym_block,
       sym_decl_assign_statement,
       sym_if_statement,
       sym_for_statement,
       sym_domain_statement,
       sym_interface_statement,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -2773,15 +3102,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(50), 7,
+    STATE(62), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
-  [642] = 28,
+      sym_concatenation,
+
+  [654] = 28,
     ACTIONS(15), 1,
       sym_identifier,
     ACTIONS(17), 1,
@@ -2796,31 +3128,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_domain,
     ACTIONS(31), 1,
       anon_sym_interface,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      sym_number,
     ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
     ACTIONS(43), 1,
-      sym_number,
-    ACTIONS(49), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(53), 1,
       anon_sym_LF,
-    ACTIONS(59), 1,
+    ACTIONS(61), 1,
       anon_sym_RBRACE,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(25), 1,
-      aux_sym__linebreak,
-    STATE(46), 1,
+    STATE(26), 1,
       sym_write_modifiers,
-    STATE(51), 1,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    STATE(73), 1,
       sym_template_global,
-    STATE(80), 1,
+    STATE(143), 1,
       aux_sym_write_modifiers_repeat1,
-    STATE(117), 1,
+    STATE(177), 1,
       sym_assign_to,
-    STATE(150), 1,
+    STATE(222), 1,
       sym_declaration,
-    STATE(191), 1,
+    STATE(255), 1,
       sym_assign_left_side,
     ACTIONS(3), 2,
       sym_single_line_comment,
@@ -2834,17 +3166,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 2,
+    STATE(245), 2,
       sym__type,
       sym_array_type,
-    STATE(209), 6,
+    STATE(287), 6,
       sym_block,
       sym_decl_assign_statement,
       sym_if_statement,
       sym_for_statement,
       sym_domain_statement,
       sym_interface_statement,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -2852,15 +3184,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(50), 7,
+    STATE(62), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
-  [749] = 28,
+      sym_concatenation,
+
+  [763] = 28,
     ACTIONS(15), 1,
       sym_identifier,
     ACTIONS(17), 1,
@@ -2875,31 +3210,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_domain,
     ACTIONS(31), 1,
       anon_sym_interface,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      sym_number,
     ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
     ACTIONS(43), 1,
-      sym_number,
-    ACTIONS(49), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(53), 1,
       anon_sym_LF,
-    ACTIONS(61), 1,
+    ACTIONS(63), 1,
       anon_sym_RBRACE,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(25), 1,
-      aux_sym__linebreak,
-    STATE(46), 1,
+    STATE(26), 1,
       sym_write_modifiers,
-    STATE(51), 1,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    STATE(73), 1,
       sym_template_global,
-    STATE(80), 1,
+    STATE(143), 1,
       aux_sym_write_modifiers_repeat1,
-    STATE(117), 1,
+    STATE(177), 1,
       sym_assign_to,
-    STATE(150), 1,
+    STATE(222), 1,
       sym_declaration,
-    STATE(191), 1,
+    STATE(255), 1,
       sym_assign_left_side,
     ACTIONS(3), 2,
       sym_single_line_comment,
@@ -2913,17 +3248,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 2,
+    STATE(245), 2,
       sym__type,
       sym_array_type,
-    STATE(209), 6,
+    STATE(287), 6,
       sym_block,
       sym_decl_assign_statement,
       sym_if_statement,
       sym_for_statement,
       sym_domain_statement,
       sym_interface_statement,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -2931,15 +3266,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(50), 7,
+    STATE(62), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
-  [856] = 27,
+      sym_concatenation,
+
+  [872] = 27,
     ACTIONS(15), 1,
       sym_identifier,
     ACTIONS(17), 1,
@@ -2954,29 +3292,29 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_domain,
     ACTIONS(31), 1,
       anon_sym_interface,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      sym_number,
     ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
     ACTIONS(43), 1,
-      sym_number,
-    ACTIONS(49), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(53), 1,
       anon_sym_LF,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(25), 1,
-      aux_sym__linebreak,
-    STATE(46), 1,
+    STATE(26), 1,
       sym_write_modifiers,
-    STATE(51), 1,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    STATE(73), 1,
       sym_template_global,
-    STATE(80), 1,
+    STATE(143), 1,
       aux_sym_write_modifiers_repeat1,
-    STATE(117), 1,
+    STATE(177), 1,
       sym_assign_to,
-    STATE(150), 1,
+    STATE(222), 1,
       sym_declaration,
-    STATE(191), 1,
+    STATE(255), 1,
       sym_assign_left_side,
     ACTIONS(3), 2,
       sym_single_line_comment,
@@ -2990,17 +3328,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 2,
+    STATE(245), 2,
       sym__type,
       sym_array_type,
-    STATE(209), 6,
+    STATE(287), 6,
       sym_block,
       sym_decl_assign_statement,
       sym_if_statement,
       sym_for_statement,
       sym_domain_statement,
       sym_interface_statement,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -3008,39 +3346,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(50), 7,
+    STATE(62), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
-  [960] = 18,
+      sym_concatenation,
+
+  [978] = 19,
     ACTIONS(15), 1,
       sym_identifier,
     ACTIONS(21), 1,
       anon_sym_reg,
     ACTIONS(23), 1,
       anon_sym_initial,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      sym_number,
     ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
     ACTIONS(43), 1,
-      sym_number,
-    STATE(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(46), 1,
+    STATE(26), 1,
       sym_write_modifiers,
-    STATE(51), 1,
+    STATE(73), 1,
       sym_template_global,
-    STATE(80), 1,
+    STATE(143), 1,
       aux_sym_write_modifiers_repeat1,
-    STATE(142), 1,
-      sym_assign_to,
-    STATE(150), 1,
+    STATE(222), 1,
       sym_declaration,
+    STATE(235), 1,
+      sym_assign_to,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
@@ -3050,10 +3393,10 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 2,
+    STATE(245), 2,
       sym__type,
       sym_array_type,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -3061,32 +3404,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(50), 7,
+    STATE(62), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
-  [1031] = 5,
-    ACTIONS(67), 1,
+      sym_concatenation,
+
+  [1054] = 5,
+    ACTIONS(71), 1,
       anon_sym_COLON_COLON,
     STATE(13), 1,
       aux_sym_namespace_list_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(63), 8,
+    ACTIONS(69), 7,
       anon_sym_EQ,
-      anon_sym_in,
+      anon_sym_COLON,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(67), 23,
       sym_identifier,
-    ACTIONS(65), 21,
       anon_sym_POUND_LPAREN,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
@@ -3103,29 +3449,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1075] = 5,
-    ACTIONS(67), 1,
+
+  [1099] = 5,
+    ACTIONS(71), 1,
       anon_sym_COLON_COLON,
     STATE(14), 1,
       aux_sym_namespace_list_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(69), 8,
+    ACTIONS(75), 7,
       anon_sym_EQ,
-      anon_sym_in,
+      anon_sym_COLON,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(73), 23,
       sym_identifier,
-    ACTIONS(71), 21,
       anon_sym_POUND_LPAREN,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
@@ -3142,29 +3490,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1119] = 5,
-    ACTIONS(77), 1,
+
+  [1144] = 5,
+    ACTIONS(81), 1,
       anon_sym_COLON_COLON,
     STATE(14), 1,
       aux_sym_namespace_list_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(73), 8,
+    ACTIONS(79), 7,
       anon_sym_EQ,
-      anon_sym_in,
+      anon_sym_COLON,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(77), 23,
       sym_identifier,
-    ACTIONS(75), 21,
       anon_sym_POUND_LPAREN,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
@@ -3181,33 +3531,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1163] = 5,
-    ACTIONS(82), 1,
+
+  [1189] = 5,
+    ACTIONS(86), 1,
       anon_sym_POUND_LPAREN,
-    STATE(29), 1,
+    STATE(27), 1,
       sym_template_args,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(80), 8,
+    ACTIONS(88), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(84), 23,
       sym_identifier,
-    ACTIONS(84), 20,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3219,30 +3571,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1206] = 3,
+
+  [1233] = 5,
+    ACTIONS(86), 1,
+      anon_sym_POUND_LPAREN,
+    STATE(28), 1,
+      sym_template_args,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(86), 8,
+    ACTIONS(92), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(90), 23,
       sym_identifier,
-    ACTIONS(88), 22,
-      anon_sym_POUND_LPAREN,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3254,30 +3611,28 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
-      anon_sym_COLON_COLON,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1245] = 5,
-    ACTIONS(82), 1,
-      anon_sym_POUND_LPAREN,
-    STATE(28), 1,
-      sym_template_args,
+
+  [1277] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(90), 8,
+    ACTIONS(96), 7,
       anon_sym_EQ,
-      anon_sym_in,
+      anon_sym_COLON,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(94), 24,
       sym_identifier,
-    ACTIONS(92), 20,
+      anon_sym_POUND_LPAREN,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -3293,37 +3648,41 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
+      anon_sym_COLON_COLON,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1288] = 8,
-    ACTIONS(98), 1,
+
+  [1317] = 8,
+    ACTIONS(102), 1,
       anon_sym_DOT,
-    ACTIONS(100), 1,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
+    ACTIONS(106), 1,
       anon_sym_LBRACK,
-    STATE(39), 1,
+    STATE(31), 1,
       sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(96), 5,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(100), 5,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(94), 19,
+    ACTIONS(98), 20,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3335,88 +3694,146 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1336] = 12,
-    ACTIONS(98), 1,
+
+  [1367] = 15,
+    ACTIONS(102), 1,
       anon_sym_DOT,
-    ACTIONS(100), 1,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
+    ACTIONS(106), 1,
       anon_sym_LBRACK,
-    ACTIONS(108), 1,
+    ACTIONS(112), 1,
       anon_sym_PLUS,
-    ACTIONS(110), 1,
-      anon_sym_DASH,
     ACTIONS(114), 1,
+      anon_sym_DASH,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
       anon_sym_SLASH,
-    STATE(39), 1,
+    STATE(31), 1,
       sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(112), 2,
+    ACTIONS(116), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
-    ACTIONS(106), 3,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(110), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(104), 16,
+    ACTIONS(108), 14,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_QMARK,
+      anon_sym_RBRACK,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [1431] = 14,
+    ACTIONS(102), 1,
+      anon_sym_DOT,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(112), 1,
+      anon_sym_PLUS,
+    ACTIONS(114), 1,
+      anon_sym_DASH,
+    ACTIONS(118), 1,
       anon_sym_PIPE,
+    ACTIONS(120), 1,
       anon_sym_AMP,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(128), 3,
+      anon_sym_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(126), 15,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
       anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
+      anon_sym_QMARK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1392] = 13,
-    ACTIONS(98), 1,
+
+  [1493] = 13,
+    ACTIONS(102), 1,
       anon_sym_DOT,
-    ACTIONS(100), 1,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
+    ACTIONS(106), 1,
       anon_sym_LBRACK,
-    ACTIONS(108), 1,
+    ACTIONS(112), 1,
       anon_sym_PLUS,
-    ACTIONS(110), 1,
-      anon_sym_DASH,
     ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
+      anon_sym_DASH,
+    ACTIONS(120), 1,
       anon_sym_AMP,
-    STATE(39), 1,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    STATE(31), 1,
       sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(112), 2,
+    ACTIONS(116), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
-    ACTIONS(106), 3,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(132), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(104), 15,
+    ACTIONS(130), 16,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PIPE,
       anon_sym_CARET,
@@ -3424,38 +3841,46 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
+      anon_sym_QMARK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1450] = 8,
-    ACTIONS(98), 1,
+
+  [1553] = 12,
+    ACTIONS(102), 1,
       anon_sym_DOT,
-    ACTIONS(100), 1,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
+    ACTIONS(106), 1,
       anon_sym_LBRACK,
-    STATE(39), 1,
+    ACTIONS(112), 1,
+      anon_sym_PLUS,
+    ACTIONS(114), 1,
+      anon_sym_DASH,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    STATE(31), 1,
       sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(106), 5,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(136), 3,
       anon_sym_EQ,
-      anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(104), 19,
+    ACTIONS(134), 17,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
-      anon_sym_PLUS,
-      anon_sym_STAR,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
@@ -3463,40 +3888,42 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1498] = 10,
-    ACTIONS(98), 1,
+
+  [1611] = 10,
+    ACTIONS(102), 1,
       anon_sym_DOT,
-    ACTIONS(100), 1,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
+    ACTIONS(106), 1,
       anon_sym_LBRACK,
-    ACTIONS(114), 1,
+    ACTIONS(124), 1,
       anon_sym_SLASH,
-    STATE(39), 1,
+    STATE(31), 1,
       sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(112), 2,
+    ACTIONS(116), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
-    ACTIONS(106), 4,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(140), 4,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(104), 17,
+    ACTIONS(138), 18,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_PIPE,
@@ -3506,156 +3933,173 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
+      anon_sym_QMARK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1550] = 15,
-    ACTIONS(98), 1,
+
+  [1665] = 8,
+    ACTIONS(102), 1,
       anon_sym_DOT,
-    ACTIONS(100), 1,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
+    ACTIONS(106), 1,
       anon_sym_LBRACK,
-    ACTIONS(108), 1,
-      anon_sym_PLUS,
-    ACTIONS(110), 1,
-      anon_sym_DASH,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
-    ACTIONS(118), 1,
-      anon_sym_PIPE,
-    ACTIONS(120), 1,
-      anon_sym_CARET,
-    STATE(39), 1,
+    STATE(31), 1,
       sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(112), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(106), 3,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(144), 5,
       anon_sym_EQ,
+      anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(104), 13,
+      anon_sym_SLASH,
+    ACTIONS(142), 20,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1612] = 14,
-    ACTIONS(98), 1,
+
+  [1715] = 18,
+    ACTIONS(102), 1,
       anon_sym_DOT,
-    ACTIONS(100), 1,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
+    ACTIONS(106), 1,
       anon_sym_LBRACK,
-    ACTIONS(108), 1,
+    ACTIONS(112), 1,
       anon_sym_PLUS,
-    ACTIONS(110), 1,
-      anon_sym_DASH,
     ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
+      anon_sym_DASH,
     ACTIONS(118), 1,
       anon_sym_PIPE,
-    STATE(39), 1,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(148), 1,
+      anon_sym_EQ,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    STATE(31), 1,
       sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(112), 2,
+    ACTIONS(116), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
-    ACTIONS(106), 3,
-      anon_sym_EQ,
+    ACTIONS(152), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(104), 14,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(146), 9,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
-      anon_sym_CARET,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1672] = 5,
-    ACTIONS(126), 1,
-      anon_sym_LF,
-    STATE(25), 1,
-      aux_sym__linebreak,
+
+  [1785] = 14,
+    ACTIONS(15), 1,
+      sym_identifier,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(156), 1,
+      sym_number,
+    STATE(15), 1,
+      sym_namespace_list,
+    STATE(73), 1,
+      sym_template_global,
+    STATE(228), 1,
+      sym_declaration,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(122), 13,
-      anon_sym_reg,
-      anon_sym_initial,
-      anon_sym_when,
-      anon_sym_if,
-      anon_sym_for,
-      anon_sym_domain,
-      anon_sym_interface,
+    ACTIONS(33), 2,
       anon_sym_input,
       anon_sym_output,
+    ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-      anon_sym_DASH,
-      sym_identifier,
-    ACTIONS(124), 13,
-      anon_sym_RPAREN,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_DASH_GT,
+    STATE(245), 2,
+      sym__type,
+      sym_array_type,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
+      anon_sym_DASH,
       anon_sym_STAR,
       anon_sym_BANG,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-      anon_sym_LPAREN,
-      anon_sym_COLON_COLON,
-      sym_number,
-  [1713] = 3,
+    STATE(64), 9,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+
+  [1846] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(129), 8,
+    ACTIONS(160), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(158), 23,
       sym_identifier,
-    ACTIONS(131), 20,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3667,29 +4111,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1750] = 3,
+
+  [1884] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(133), 8,
+    ACTIONS(164), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(162), 23,
       sym_identifier,
-    ACTIONS(135), 20,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3701,29 +4147,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1787] = 3,
+
+  [1922] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(137), 8,
+    ACTIONS(168), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(166), 23,
       sym_identifier,
-    ACTIONS(139), 20,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3735,29 +4183,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1824] = 3,
+
+  [1960] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(141), 8,
+    ACTIONS(172), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-      sym_identifier,
-    ACTIONS(143), 20,
+    ACTIONS(170), 23,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
+      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3769,29 +4219,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1861] = 3,
+
+  [1998] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(145), 8,
+    ACTIONS(176), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-      sym_identifier,
-    ACTIONS(147), 20,
+    ACTIONS(174), 23,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
+      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3803,29 +4255,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1898] = 3,
+
+  [2036] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(149), 8,
+    ACTIONS(180), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(178), 23,
       sym_identifier,
-    ACTIONS(151), 20,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3837,29 +4291,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1935] = 3,
+
+  [2074] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(153), 8,
+    ACTIONS(184), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(182), 23,
       sym_identifier,
-    ACTIONS(155), 20,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3871,29 +4327,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1972] = 3,
+
+  [2112] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(157), 8,
+    ACTIONS(188), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(186), 23,
       sym_identifier,
-    ACTIONS(159), 20,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3905,29 +4363,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2009] = 3,
+
+  [2150] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(161), 8,
+    ACTIONS(192), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-      sym_identifier,
-    ACTIONS(163), 20,
+    ACTIONS(190), 23,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
+      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3939,29 +4399,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2046] = 3,
+
+  [2188] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(165), 8,
+    ACTIONS(196), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-      sym_identifier,
-    ACTIONS(167), 20,
+    ACTIONS(194), 23,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
+      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3973,29 +4435,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2083] = 3,
+
+  [2226] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(169), 8,
+    ACTIONS(200), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-      sym_identifier,
-    ACTIONS(171), 20,
+    ACTIONS(198), 23,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
+      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -4007,29 +4471,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2120] = 3,
+
+  [2264] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(173), 8,
+    ACTIONS(204), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-      sym_identifier,
-    ACTIONS(175), 20,
+    ACTIONS(202), 23,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
+      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -4041,29 +4507,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2157] = 3,
+
+  [2302] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(177), 8,
+    ACTIONS(208), 6,
       anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
+    ACTIONS(206), 23,
       sym_identifier,
-    ACTIONS(179), 20,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -4075,28 +4543,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2194] = 3,
+
+  [2340] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(183), 6,
+    ACTIONS(212), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(181), 21,
+    ACTIONS(210), 23,
+      sym_identifier,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -4108,28 +4579,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2230] = 3,
+
+  [2378] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(187), 6,
+    ACTIONS(216), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(185), 21,
+    ACTIONS(214), 23,
+      sym_identifier,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -4141,28 +4615,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2266] = 3,
+
+  [2416] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(191), 6,
+    ACTIONS(220), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(189), 21,
+    ACTIONS(218), 23,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -4174,28 +4651,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2302] = 3,
+
+  [2454] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(195), 6,
+    ACTIONS(224), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(193), 21,
+    ACTIONS(222), 23,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -4207,28 +4687,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2338] = 3,
+
+  [2492] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(199), 6,
+    ACTIONS(228), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(197), 21,
+    ACTIONS(226), 23,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -4240,28 +4723,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2374] = 3,
+
+  [2530] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(203), 6,
+    ACTIONS(232), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(201), 21,
+    ACTIONS(230), 23,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -4273,28 +4759,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2410] = 3,
+
+  [2568] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(207), 6,
+    ACTIONS(236), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(205), 21,
+    ACTIONS(234), 23,
+      sym_identifier,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_in,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -4306,245 +4795,177 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2446] = 13,
-    ACTIONS(15), 1,
-      sym_identifier,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
-    ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(209), 1,
-      sym_number,
-    STATE(17), 1,
-      sym_namespace_list,
-    STATE(51), 1,
-      sym_template_global,
-    STATE(151), 1,
-      sym_declaration,
+
+  [2606] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(33), 2,
-      anon_sym_input,
-      anon_sym_output,
-    ACTIONS(35), 2,
-      anon_sym_state,
-      anon_sym_gen,
-    STATE(177), 2,
-      sym__type,
-      sym_array_type,
-    ACTIONS(37), 7,
-      anon_sym_PLUS,
+    ACTIONS(240), 6,
+      anon_sym_EQ,
       anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+    ACTIONS(238), 23,
+      sym_identifier,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
       anon_sym_STAR,
-      anon_sym_BANG,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(49), 7,
-      sym__expression,
-      sym_unary_op,
-      sym_binary_op,
-      sym_array_op,
-      sym_func_call,
-      sym_field_access,
-      sym_parenthesis_expression,
-  [2502] = 17,
-    ACTIONS(100), 1,
-      anon_sym_LPAREN,
-    ACTIONS(102), 1,
-      anon_sym_LBRACK,
-    ACTIONS(108), 1,
-      anon_sym_PLUS,
-    ACTIONS(110), 1,
-      anon_sym_DASH,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
-    ACTIONS(118), 1,
-      anon_sym_PIPE,
-    ACTIONS(120), 1,
-      anon_sym_CARET,
-    ACTIONS(213), 1,
-      anon_sym_EQ,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    STATE(39), 1,
-      sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
-    ACTIONS(3), 2,
-      sym_single_line_comment,
-      sym_multi_line_comment,
-    ACTIONS(112), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(217), 2,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(215), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(211), 6,
-      anon_sym_RPAREN,
-      anon_sym_RBRACE,
-      anon_sym_in,
-      anon_sym_DASH_GT,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [2565] = 18,
-    ACTIONS(100), 1,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
       anon_sym_LBRACK,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
-    ACTIONS(118), 1,
-      anon_sym_PIPE,
-    ACTIONS(120), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    ACTIONS(221), 1,
-      anon_sym_RPAREN,
-    ACTIONS(223), 1,
+      anon_sym_RBRACK,
       anon_sym_COMMA,
-    STATE(39), 1,
-      sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
-    STATE(75), 1,
-      sym__comma,
-    STATE(166), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
+      anon_sym_LF,
+
+  [2644] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(108), 2,
-      anon_sym_PLUS,
+    ACTIONS(244), 6,
+      anon_sym_EQ,
       anon_sym_DASH,
-    ACTIONS(112), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(217), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+    ACTIONS(242), 23,
+      sym_identifier,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [2627] = 16,
-    ACTIONS(100), 1,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
       anon_sym_LBRACK,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
-    ACTIONS(118), 1,
-      anon_sym_PIPE,
-    ACTIONS(120), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    ACTIONS(227), 1,
-      anon_sym_EQ,
-    STATE(39), 1,
-      sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
+      anon_sym_RBRACK,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [2682] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(108), 2,
-      anon_sym_PLUS,
+    ACTIONS(248), 6,
+      anon_sym_EQ,
       anon_sym_DASH,
-    ACTIONS(112), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(217), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(225), 3,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+    ACTIONS(246), 23,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_COMMA,
-      anon_sym_LF,
-    ACTIONS(215), 4,
+      anon_sym_in,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [2685] = 16,
-    ACTIONS(100), 1,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
       anon_sym_LBRACK,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
-    ACTIONS(118), 1,
-      anon_sym_PIPE,
-    ACTIONS(120), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    ACTIONS(231), 1,
-      anon_sym_EQ,
-    STATE(39), 1,
-      sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
+      anon_sym_RBRACK,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [2720] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(108), 2,
-      anon_sym_PLUS,
+    ACTIONS(252), 6,
+      anon_sym_EQ,
       anon_sym_DASH,
-    ACTIONS(112), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(217), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(229), 3,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+    ACTIONS(250), 23,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_COMMA,
-      anon_sym_LF,
-    ACTIONS(215), 4,
+      anon_sym_in,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [2743] = 5,
-    ACTIONS(233), 1,
-      sym_identifier,
-    ACTIONS(239), 1,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_LPAREN,
       anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [2758] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(237), 4,
+    ACTIONS(256), 6,
       anon_sym_EQ,
+      anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(235), 16,
+      anon_sym_DOT,
+    ACTIONS(254), 23,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
+      anon_sym_in,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
       anon_sym_PLUS,
-      anon_sym_DASH,
       anon_sym_STAR,
       anon_sym_PIPE,
       anon_sym_AMP,
@@ -4554,320 +4975,518 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
-      anon_sym_DOT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2778] = 15,
-    ACTIONS(100), 1,
-      anon_sym_LPAREN,
-    ACTIONS(102), 1,
-      anon_sym_LBRACK,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
-    ACTIONS(118), 1,
-      anon_sym_PIPE,
-    ACTIONS(120), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    STATE(39), 1,
-      sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
+
+  [2796] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(108), 2,
-      anon_sym_PLUS,
+    ACTIONS(260), 6,
+      anon_sym_EQ,
       anon_sym_DASH,
-    ACTIONS(112), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(217), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(242), 3,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+    ACTIONS(258), 23,
       anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_LF,
-    ACTIONS(215), 4,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_in,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [2833] = 16,
-    ACTIONS(17), 1,
-      anon_sym_LBRACE,
-    ACTIONS(100), 1,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
       anon_sym_LBRACK,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
-    ACTIONS(118), 1,
-      anon_sym_PIPE,
-    ACTIONS(120), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    STATE(39), 1,
-      sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
-    STATE(211), 1,
-      sym_block,
+      anon_sym_RBRACK,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [2834] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(108), 2,
-      anon_sym_PLUS,
+    ACTIONS(264), 6,
+      anon_sym_EQ,
       anon_sym_DASH,
-    ACTIONS(112), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(217), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+    ACTIONS(262), 23,
+      sym_identifier,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [2889] = 9,
-    ACTIONS(15), 1,
-      sym_identifier,
-    ACTIONS(39), 1,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
-    ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(244), 1,
-      anon_sym_type,
-    ACTIONS(246), 1,
-      sym_number,
-    STATE(17), 1,
-      sym_namespace_list,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [2872] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
-      anon_sym_PLUS,
+    ACTIONS(268), 6,
+      anon_sym_EQ,
       anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+    ACTIONS(266), 23,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_in,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
       anon_sym_STAR,
-      anon_sym_BANG,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(52), 8,
-      sym__expression,
-      sym_unary_op,
-      sym_binary_op,
-      sym_array_op,
-      sym_func_call,
-      sym_field_access,
-      sym_parenthesis_expression,
-      sym_template_global,
-  [2931] = 9,
-    ACTIONS(39), 1,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
-    ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
-      sym_identifier,
-    ACTIONS(250), 1,
-      anon_sym_RPAREN,
-    ACTIONS(252), 1,
-      sym_number,
-    STATE(17), 1,
-      sym_namespace_list,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [2910] = 3,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
-      anon_sym_PLUS,
+    ACTIONS(272), 6,
+      anon_sym_EQ,
       anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+    ACTIONS(270), 23,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_in,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
       anon_sym_STAR,
-      anon_sym_BANG,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(48), 8,
-      sym__expression,
-      sym_unary_op,
-      sym_binary_op,
-      sym_array_op,
-      sym_func_call,
-      sym_field_access,
-      sym_parenthesis_expression,
-      sym_template_global,
-  [2973] = 16,
-    ACTIONS(17), 1,
-      anon_sym_LBRACE,
-    ACTIONS(100), 1,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
       anon_sym_LBRACK,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
+      anon_sym_RBRACK,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [2948] = 20,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
     ACTIONS(118), 1,
       anon_sym_PIPE,
     ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(276), 1,
+      anon_sym_EQ,
+    ACTIONS(278), 1,
       anon_sym_DOT,
-    STATE(39), 1,
+    STATE(31), 1,
       sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
-    STATE(175), 1,
-      sym_block,
+    STATE(87), 1,
+      sym__comma,
+    STATE(186), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(254), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(108), 2,
+    ACTIONS(112), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(112), 2,
+    ACTIONS(116), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
-    ACTIONS(217), 2,
+    ACTIONS(152), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(274), 3,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_LF,
+    ACTIONS(150), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [3029] = 15,
-    ACTIONS(100), 1,
+
+  [3019] = 20,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
+    ACTIONS(106), 1,
       anon_sym_LBRACK,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
     ACTIONS(118), 1,
       anon_sym_PIPE,
     ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(276), 1,
+      anon_sym_EQ,
+    ACTIONS(278), 1,
       anon_sym_DOT,
-    STATE(39), 1,
+    STATE(31), 1,
       sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
+    STATE(87), 1,
+      sym__comma,
+    STATE(190), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(261), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(108), 2,
+    ACTIONS(112), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(112), 2,
+    ACTIONS(116), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
-    ACTIONS(217), 2,
+    ACTIONS(152), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(254), 2,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(274), 3,
       anon_sym_RBRACE,
+      anon_sym_COMMA,
       anon_sym_LF,
-    ACTIONS(215), 4,
+    ACTIONS(150), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [3083] = 15,
-    ACTIONS(100), 1,
+
+  [3090] = 5,
+    ACTIONS(284), 1,
+      anon_sym_LF,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(280), 13,
+      sym_identifier,
+      anon_sym_reg,
+      anon_sym_initial,
+      anon_sym_when,
+      anon_sym_if,
+      anon_sym_for,
+      anon_sym_domain,
+      anon_sym_interface,
+      anon_sym_input,
+      anon_sym_output,
+      anon_sym_state,
+      anon_sym_gen,
+      anon_sym_DASH,
+    ACTIONS(282), 13,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DASH_GT,
+      sym_number,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
+      anon_sym_COLON_COLON,
+
+  [3131] = 21,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
       anon_sym_LBRACK,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
     ACTIONS(118), 1,
       anon_sym_PIPE,
     ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
       anon_sym_DOT,
-    STATE(39), 1,
+    ACTIONS(287), 1,
+      anon_sym_RBRACE,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    ACTIONS(291), 1,
+      anon_sym_LF,
+    STATE(31), 1,
       sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
+    STATE(87), 1,
+      sym__comma,
+    STATE(186), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(254), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(108), 2,
+    ACTIONS(112), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(112), 2,
+    ACTIONS(116), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
-    ACTIONS(217), 2,
+    ACTIONS(152), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(256), 2,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-    ACTIONS(215), 4,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [3137] = 8,
-    ACTIONS(39), 1,
+
+  [3203] = 21,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
-    ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
-      sym_identifier,
-    ACTIONS(258), 1,
-      sym_number,
-    STATE(17), 1,
-      sym_namespace_list,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    ACTIONS(293), 1,
+      anon_sym_RBRACE,
+    ACTIONS(295), 1,
+      anon_sym_LF,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    STATE(87), 1,
+      sym__comma,
+    STATE(190), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(261), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(112), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
+    ACTIONS(116), 2,
       anon_sym_STAR,
-      anon_sym_BANG,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [3275] = 18,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(112), 1,
+      anon_sym_PLUS,
+    ACTIONS(114), 1,
+      anon_sym_DASH,
+    ACTIONS(118), 1,
       anon_sym_PIPE,
+    ACTIONS(120), 1,
       anon_sym_AMP,
+    ACTIONS(122), 1,
       anon_sym_CARET,
-    STATE(57), 8,
-      sym__expression,
-      sym_unary_op,
-      sym_binary_op,
-      sym_array_op,
-      sym_func_call,
-      sym_field_access,
-      sym_parenthesis_expression,
-      sym_template_global,
-  [3176] = 8,
-    ACTIONS(39), 1,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(299), 1,
+      anon_sym_EQ,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(297), 5,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [3341] = 17,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(276), 1,
+      anon_sym_EQ,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(274), 3,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_LF,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [3403] = 11,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(260), 1,
+    ACTIONS(303), 1,
       sym_number,
-    STATE(17), 1,
+    ACTIONS(305), 1,
+      anon_sym_LF,
+    STATE(15), 1,
       sym_namespace_list,
+    STATE(65), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -4875,30 +5494,85 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(21), 8,
+    STATE(59), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3215] = 8,
-    ACTIONS(39), 1,
+
+  [3453] = 17,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(309), 1,
+      anon_sym_EQ,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(307), 3,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_LF,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [3515] = 11,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(53), 1,
+      anon_sym_LF,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(262), 1,
+    ACTIONS(311), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
+    STATE(58), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -4906,30 +5580,130 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(19), 8,
+    STATE(60), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3254] = 8,
-    ACTIONS(39), 1,
+
+  [3565] = 19,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    ACTIONS(313), 1,
+      anon_sym_RPAREN,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    STATE(87), 1,
+      sym__comma,
+    STATE(239), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [3631] = 16,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(315), 4,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [3691] = 10,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(264), 1,
+    ACTIONS(317), 1,
+      anon_sym_RPAREN,
+    ACTIONS(319), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -4937,30 +5711,37 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(24), 8,
+    STATE(66), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3293] = 8,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+
+  [3738] = 10,
+    ACTIONS(15), 1,
+      sym_identifier,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
-      sym_identifier,
-    ACTIONS(266), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(321), 1,
       sym_number,
-    STATE(17), 1,
+    ACTIONS(323), 1,
+      anon_sym_type,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -4968,30 +5749,79 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(23), 8,
+    STATE(70), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3332] = 8,
-    ACTIONS(39), 1,
+
+  [3785] = 16,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(325), 3,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_LF,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [3844] = 9,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(268), 1,
+    ACTIONS(327), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -4999,30 +5829,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(20), 8,
+    STATE(107), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3371] = 8,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+
+  [3888] = 9,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(270), 1,
+    ACTIONS(329), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5030,30 +5865,67 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(22), 8,
+    STATE(85), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3410] = 8,
-    ACTIONS(39), 1,
+
+  [3932] = 5,
+    ACTIONS(331), 1,
+      sym_identifier,
+    ACTIONS(337), 1,
+      anon_sym_LBRACK,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(335), 4,
+      anon_sym_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(333), 17,
+      anon_sym_RBRACE,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
       anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [3968] = 9,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(272), 1,
+    ACTIONS(340), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5061,30 +5933,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(56), 8,
+    STATE(18), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3449] = 8,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+
+  [4012] = 9,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(274), 1,
+    ACTIONS(342), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5092,30 +5969,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(47), 8,
+    STATE(108), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3488] = 8,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+
+  [4056] = 9,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(276), 1,
+    ACTIONS(344), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5123,30 +6005,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(74), 8,
+    STATE(88), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3527] = 8,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+
+  [4100] = 9,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(278), 1,
+    ACTIONS(346), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5154,30 +6041,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(18), 8,
+    STATE(19), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3566] = 8,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+
+  [4144] = 9,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(280), 1,
+    ACTIONS(348), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5185,30 +6077,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(76), 8,
+    STATE(20), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3605] = 8,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+
+  [4188] = 9,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(282), 1,
+    ACTIONS(350), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5216,30 +6113,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(73), 8,
+    STATE(21), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3644] = 8,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+
+  [4232] = 9,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(284), 1,
+    ACTIONS(352), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5247,106 +6149,143 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(53), 8,
+    STATE(22), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3683] = 15,
-    ACTIONS(100), 1,
+
+  [4276] = 9,
+    ACTIONS(41), 1,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
-      anon_sym_LBRACK,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
-    ACTIONS(118), 1,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
+      sym_identifier,
+    ACTIONS(354), 1,
+      sym_number,
+    STATE(15), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(39), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
       anon_sym_PIPE,
-    ACTIONS(120), 1,
+      anon_sym_AMP,
       anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    ACTIONS(286), 1,
-      anon_sym_RBRACK,
-    STATE(39), 1,
-      sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
+    STATE(23), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [4320] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
+      sym_identifier,
+    ACTIONS(356), 1,
+      sym_number,
+    STATE(15), 1,
+      sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(108), 2,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(112), 2,
       anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(217), 2,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(215), 4,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-  [3736] = 15,
-    ACTIONS(98), 1,
-      anon_sym_DOT,
-    ACTIONS(100), 1,
-      anon_sym_LPAREN,
-    ACTIONS(102), 1,
-      anon_sym_LBRACK,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
-    ACTIONS(118), 1,
+      anon_sym_BANG,
       anon_sym_PIPE,
-    ACTIONS(120), 1,
+      anon_sym_AMP,
       anon_sym_CARET,
-    ACTIONS(288), 1,
-      anon_sym_DOT_DOT,
-    STATE(39), 1,
-      sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
+    STATE(24), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [4364] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
+      sym_identifier,
+    ACTIONS(358), 1,
+      sym_number,
+    STATE(15), 1,
+      sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(108), 2,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(112), 2,
       anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(217), 2,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(215), 4,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-  [3789] = 8,
-    ACTIONS(39), 1,
-      anon_sym_LPAREN,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(109), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [4408] = 9,
     ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(290), 1,
+    ACTIONS(360), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5354,70 +6293,79 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(58), 8,
+    STATE(89), 10,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
+      sym_conditional,
       sym_array_op,
       sym_func_call,
       sym_field_access,
       sym_parenthesis_expression,
+      sym_concatenation,
       sym_template_global,
-  [3828] = 15,
-    ACTIONS(100), 1,
+
+  [4452] = 17,
+    ACTIONS(104), 1,
       anon_sym_LPAREN,
-    ACTIONS(102), 1,
+    ACTIONS(106), 1,
       anon_sym_LBRACK,
-    ACTIONS(114), 1,
-      anon_sym_SLASH,
-    ACTIONS(116), 1,
-      anon_sym_AMP,
     ACTIONS(118), 1,
       anon_sym_PIPE,
     ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
       anon_sym_DOT,
-    ACTIONS(292), 1,
-      anon_sym_RPAREN,
-    STATE(39), 1,
+    ACTIONS(362), 1,
+      anon_sym_LBRACE,
+    STATE(31), 1,
       sym_parenthesis_expression_list,
-    STATE(40), 1,
-      sym_array_bracket_expression,
+    STATE(256), 1,
+      sym_block,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(108), 2,
+    ACTIONS(112), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(112), 2,
+    ACTIONS(116), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
-    ACTIONS(217), 2,
+    ACTIONS(152), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [3881] = 5,
-    ACTIONS(49), 1,
-      anon_sym_LF,
-    STATE(25), 1,
-      aux_sym__linebreak,
+
+  [4512] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
+      sym_identifier,
+    ACTIONS(364), 1,
+      sym_number,
+    STATE(15), 1,
+      sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(294), 7,
-      anon_sym_reg,
-      anon_sym_initial,
-      anon_sym_input,
-      anon_sym_output,
-      anon_sym_state,
-      anon_sym_gen,
-      sym_identifier,
-    ACTIONS(296), 10,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5425,26 +6373,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
+    STATE(61), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [4556] = 9,
+    ACTIONS(41), 1,
       anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
+      sym_identifier,
+    ACTIONS(366), 1,
       sym_number,
-  [3913] = 5,
-    ACTIONS(302), 1,
-      anon_sym_LF,
-    STATE(77), 1,
-      aux_sym__linebreak,
+    STATE(15), 1,
+      sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(298), 7,
-      anon_sym_reg,
-      anon_sym_initial,
-      anon_sym_input,
-      anon_sym_output,
-      anon_sym_state,
-      anon_sym_gen,
-      sym_identifier,
-    ACTIONS(300), 10,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5452,24 +6409,122 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-      anon_sym_LPAREN,
-      anon_sym_COLON_COLON,
-      sym_number,
-  [3945] = 5,
-    ACTIONS(306), 1,
-      anon_sym_reg,
-    STATE(79), 1,
-      aux_sym_write_modifiers_repeat1,
-    ACTIONS(3), 2,
-      sym_single_line_comment,
-      sym_multi_line_comment,
-    ACTIONS(304), 5,
-      anon_sym_input,
-      anon_sym_output,
-      anon_sym_state,
-      anon_sym_gen,
+    STATE(67), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [4600] = 16,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(368), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [4658] = 17,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(370), 1,
+      anon_sym_COLON,
+    ACTIONS(372), 1,
+      anon_sym_RBRACK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [4718] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(309), 10,
+    ACTIONS(374), 1,
+      sym_number,
+    STATE(15), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5477,24 +6532,71 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
+    STATE(110), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [4762] = 9,
+    ACTIONS(41), 1,
       anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
       sym_number,
-  [3975] = 5,
-    ACTIONS(21), 1,
-      anon_sym_reg,
-    STATE(79), 1,
-      aux_sym_write_modifiers_repeat1,
+    STATE(15), 1,
+      sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(311), 5,
-      anon_sym_input,
-      anon_sym_output,
-      anon_sym_state,
-      anon_sym_gen,
+    ACTIONS(39), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(25), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [4806] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
       sym_identifier,
-    ACTIONS(313), 10,
+    ACTIONS(378), 1,
+      sym_number,
+    STATE(15), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5502,21 +6604,151 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
+    STATE(111), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [4850] = 9,
+    ACTIONS(41), 1,
       anon_sym_LPAREN,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
+      sym_identifier,
+    ACTIONS(380), 1,
       sym_number,
-  [4005] = 3,
+    STATE(15), 1,
+      sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(315), 6,
-      anon_sym_reg,
-      anon_sym_input,
-      anon_sym_output,
-      anon_sym_state,
-      anon_sym_gen,
+    ACTIONS(39), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(94), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [4894] = 17,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(362), 1,
+      anon_sym_LBRACE,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    STATE(296), 1,
+      sym_block,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [4954] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym_identifier,
+    ACTIONS(384), 1,
+      sym_number,
+    ACTIONS(388), 1,
+      anon_sym_COLON_COLON,
+    STATE(125), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(386), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(120), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [4998] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
       sym_identifier,
-    ACTIONS(317), 10,
+    ACTIONS(388), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(390), 1,
+      sym_number,
+    STATE(125), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(386), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -5524,60 +6756,2037 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
+    STATE(115), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [5042] = 9,
+    ACTIONS(41), 1,
       anon_sym_LPAREN,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym_identifier,
+    ACTIONS(388), 1,
       anon_sym_COLON_COLON,
+    ACTIONS(392), 1,
       sym_number,
-  [4030] = 13,
+    STATE(125), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(386), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(116), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [5086] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym_identifier,
+    ACTIONS(388), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(394), 1,
+      sym_number,
+    STATE(125), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(386), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(117), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [5130] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym_identifier,
+    ACTIONS(388), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(396), 1,
+      sym_number,
+    STATE(125), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(386), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(118), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [5174] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym_identifier,
+    ACTIONS(388), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(398), 1,
+      sym_number,
+    STATE(125), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(386), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(119), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [5218] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym_identifier,
+    ACTIONS(388), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(400), 1,
+      sym_number,
+    STATE(125), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(386), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(121), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [5262] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym_identifier,
+    ACTIONS(388), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(402), 1,
+      sym_number,
+    STATE(125), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(386), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(122), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [5306] = 17,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(370), 1,
+      anon_sym_COLON,
+    ACTIONS(404), 1,
+      anon_sym_RBRACK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [5366] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
+      sym_identifier,
+    ACTIONS(406), 1,
+      sym_number,
+    STATE(15), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(39), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(103), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [5410] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym_identifier,
+    ACTIONS(388), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(408), 1,
+      sym_number,
+    STATE(125), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(386), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(124), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [5454] = 9,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(65), 1,
+      anon_sym_LBRACE,
+    ACTIONS(301), 1,
+      sym_identifier,
+    ACTIONS(410), 1,
+      sym_number,
+    STATE(15), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(39), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(123), 10,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_conditional,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_concatenation,
+      sym_template_global,
+
+  [5498] = 16,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(372), 1,
+      anon_sym_RBRACK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [5555] = 16,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(412), 1,
+      anon_sym_RPAREN,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [5612] = 16,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(414), 1,
+      anon_sym_COLON,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [5669] = 16,
+    ACTIONS(102), 1,
+      anon_sym_DOT,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(416), 1,
+      anon_sym_DOT_DOT,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [5726] = 16,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(418), 1,
+      anon_sym_RBRACK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [5783] = 5,
+    ACTIONS(420), 1,
+      anon_sym_COLON_COLON,
+    STATE(113), 1,
+      aux_sym_namespace_list_repeat1,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(69), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(67), 17,
+      anon_sym_POUND_LPAREN,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [5818] = 5,
+    ACTIONS(420), 1,
+      anon_sym_COLON_COLON,
+    STATE(114), 1,
+      aux_sym_namespace_list_repeat1,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(75), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(73), 17,
+      anon_sym_POUND_LPAREN,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [5853] = 5,
+    ACTIONS(422), 1,
+      anon_sym_COLON_COLON,
+    STATE(114), 1,
+      aux_sym_namespace_list_repeat1,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(79), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(77), 17,
+      anon_sym_POUND_LPAREN,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [5888] = 14,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(429), 1,
+      anon_sym_PIPE,
+    ACTIONS(431), 1,
+      anon_sym_AMP,
+    ACTIONS(433), 1,
+      anon_sym_CARET,
+    ACTIONS(435), 1,
+      anon_sym_SLASH,
+    ACTIONS(437), 1,
+      anon_sym_LBRACK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(110), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(425), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(427), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(108), 6,
+      anon_sym_in,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_QMARK,
+
+  [5941] = 13,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(429), 1,
+      anon_sym_PIPE,
+    ACTIONS(431), 1,
+      anon_sym_AMP,
+    ACTIONS(435), 1,
+      anon_sym_SLASH,
+    ACTIONS(437), 1,
+      anon_sym_LBRACK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(128), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(425), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(427), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(126), 7,
+      anon_sym_in,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_QMARK,
+
+  [5992] = 12,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(431), 1,
+      anon_sym_AMP,
+    ACTIONS(435), 1,
+      anon_sym_SLASH,
+    ACTIONS(437), 1,
+      anon_sym_LBRACK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(132), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(425), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(427), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(130), 8,
+      anon_sym_in,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_QMARK,
+
+  [6041] = 11,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(435), 1,
+      anon_sym_SLASH,
+    ACTIONS(437), 1,
+      anon_sym_LBRACK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(136), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(425), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(427), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(134), 9,
+      anon_sym_in,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_QMARK,
+
+  [6088] = 10,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(435), 1,
+      anon_sym_SLASH,
+    ACTIONS(437), 1,
+      anon_sym_LBRACK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(140), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(427), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(138), 11,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_QMARK,
+
+  [6133] = 8,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(437), 1,
+      anon_sym_LBRACK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(100), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(98), 13,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+
+  [6174] = 8,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(437), 1,
+      anon_sym_LBRACK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(144), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(142), 13,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+
+  [6215] = 16,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(297), 1,
+      anon_sym_in,
+    ACTIONS(429), 1,
+      anon_sym_PIPE,
+    ACTIONS(431), 1,
+      anon_sym_AMP,
+    ACTIONS(433), 1,
+      anon_sym_CARET,
+    ACTIONS(435), 1,
+      anon_sym_SLASH,
+    ACTIONS(437), 1,
+      anon_sym_LBRACK,
+    ACTIONS(443), 1,
+      anon_sym_QMARK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(425), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(427), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(441), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(439), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [6272] = 16,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(106), 1,
+      anon_sym_LBRACK,
+    ACTIONS(118), 1,
+      anon_sym_PIPE,
+    ACTIONS(120), 1,
+      anon_sym_AMP,
+    ACTIONS(122), 1,
+      anon_sym_CARET,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(154), 1,
+      anon_sym_QMARK,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(445), 1,
+      anon_sym_COLON,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(112), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(116), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(152), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(150), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [6329] = 16,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(146), 1,
+      anon_sym_in,
+    ACTIONS(278), 1,
+      anon_sym_DOT,
+    ACTIONS(429), 1,
+      anon_sym_PIPE,
+    ACTIONS(431), 1,
+      anon_sym_AMP,
+    ACTIONS(433), 1,
+      anon_sym_CARET,
+    ACTIONS(435), 1,
+      anon_sym_SLASH,
+    ACTIONS(437), 1,
+      anon_sym_LBRACK,
+    ACTIONS(443), 1,
+      anon_sym_QMARK,
+    STATE(31), 1,
+      sym_parenthesis_expression_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(425), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(427), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(441), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    STATE(30), 2,
+      sym_array_bracket_expression,
+      sym_array_slice_expression,
+    ACTIONS(439), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+
+  [6386] = 5,
+    ACTIONS(447), 1,
+      anon_sym_POUND_LPAREN,
+    STATE(130), 1,
+      sym_template_args,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(88), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(84), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6420] = 5,
+    ACTIONS(447), 1,
+      anon_sym_POUND_LPAREN,
+    STATE(131), 1,
+      sym_template_args,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(92), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(90), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6454] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(96), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(94), 18,
+      anon_sym_POUND_LPAREN,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COLON_COLON,
+
+  [6484] = 5,
+    ACTIONS(453), 1,
+      anon_sym_LF,
+    STATE(129), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(449), 7,
+      sym_identifier,
+      anon_sym_reg,
+      anon_sym_initial,
+      anon_sym_input,
+      anon_sym_output,
+      anon_sym_state,
+      anon_sym_gen,
+    ACTIONS(451), 11,
+      anon_sym_LBRACE,
+      sym_number,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_LPAREN,
+      anon_sym_COLON_COLON,
+
+  [6517] = 5,
+    ACTIONS(53), 1,
+      anon_sym_LF,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(455), 7,
+      sym_identifier,
+      anon_sym_reg,
+      anon_sym_initial,
+      anon_sym_input,
+      anon_sym_output,
+      anon_sym_state,
+      anon_sym_gen,
+    ACTIONS(457), 11,
+      anon_sym_LBRACE,
+      sym_number,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_LPAREN,
+      anon_sym_COLON_COLON,
+
+  [6550] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(160), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(158), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6578] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(164), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(162), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6606] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(168), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(166), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6634] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(180), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(178), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6662] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(184), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(182), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6690] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(188), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(186), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6718] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(208), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(206), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6746] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(212), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(210), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6774] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(216), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(214), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6802] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(236), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(234), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6830] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(240), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(238), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6858] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(244), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(242), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6886] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(264), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(262), 16,
+      anon_sym_in,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+
+  [6914] = 5,
+    ACTIONS(21), 1,
+      anon_sym_reg,
+    STATE(144), 1,
+      aux_sym_write_modifiers_repeat1,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(459), 5,
+      sym_identifier,
+      anon_sym_input,
+      anon_sym_output,
+      anon_sym_state,
+      anon_sym_gen,
+    ACTIONS(461), 11,
+      anon_sym_LBRACE,
+      sym_number,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_LPAREN,
+      anon_sym_COLON_COLON,
+
+  [6945] = 5,
+    ACTIONS(467), 1,
+      anon_sym_reg,
+    STATE(144), 1,
+      aux_sym_write_modifiers_repeat1,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(463), 5,
+      sym_identifier,
+      anon_sym_input,
+      anon_sym_output,
+      anon_sym_state,
+      anon_sym_gen,
+    ACTIONS(465), 11,
+      anon_sym_LBRACE,
+      sym_number,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_LPAREN,
+      anon_sym_COLON_COLON,
+
+  [6976] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(470), 6,
+      sym_identifier,
+      anon_sym_reg,
+      anon_sym_input,
+      anon_sym_output,
+      anon_sym_state,
+      anon_sym_gen,
+    ACTIONS(472), 11,
+      anon_sym_LBRACE,
+      sym_number,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_LPAREN,
+      anon_sym_COLON_COLON,
+
+  [7002] = 3,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(474), 5,
+      sym_identifier,
+      anon_sym_input,
+      anon_sym_output,
+      anon_sym_state,
+      anon_sym_gen,
+    ACTIONS(476), 11,
+      anon_sym_LBRACE,
+      sym_number,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_LPAREN,
+      anon_sym_COLON_COLON,
+
+  [7027] = 13,
+    ACTIONS(15), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(478), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(480), 1,
+      anon_sym_LF,
+    STATE(15), 1,
+      sym_namespace_list,
+    STATE(148), 1,
+      aux_sym__linebreak,
+    STATE(192), 1,
+      sym_declaration,
+    STATE(240), 1,
+      sym_declaration_list,
+    STATE(289), 1,
+      sym__interface_ports_output,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(33), 2,
+      anon_sym_input,
+      anon_sym_output,
+    ACTIONS(35), 2,
+      anon_sym_state,
+      anon_sym_gen,
+    STATE(245), 3,
+      sym__type,
+      sym_array_type,
+      sym_template_global,
+
+  [7072] = 13,
+    ACTIONS(15), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(53), 1,
+      anon_sym_LF,
+    ACTIONS(478), 1,
+      anon_sym_DASH_GT,
+    STATE(15), 1,
+      sym_namespace_list,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    STATE(192), 1,
+      sym_declaration,
+    STATE(242), 1,
+      sym_declaration_list,
+    STATE(291), 1,
+      sym__interface_ports_output,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(33), 2,
+      anon_sym_input,
+      anon_sym_output,
+    ACTIONS(35), 2,
+      anon_sym_state,
+      anon_sym_gen,
+    STATE(245), 3,
+      sym__type,
+      sym_array_type,
+      sym_template_global,
+
+  [7117] = 11,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(482), 1,
+      sym_identifier,
+    ACTIONS(484), 1,
+      anon_sym_RPAREN,
+    ACTIONS(486), 1,
+      anon_sym_LF,
+    STATE(15), 1,
+      sym_namespace_list,
+    STATE(150), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(33), 2,
+      anon_sym_input,
+      anon_sym_output,
+    ACTIONS(35), 2,
+      anon_sym_state,
+      anon_sym_gen,
+    STATE(175), 2,
+      sym_template_declaration_type,
+      sym_declaration,
+    STATE(245), 3,
+      sym__type,
+      sym_array_type,
+      sym_template_global,
+
+  [7157] = 11,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(53), 1,
+      anon_sym_LF,
+    ACTIONS(482), 1,
+      sym_identifier,
+    ACTIONS(488), 1,
+      anon_sym_RPAREN,
+    STATE(15), 1,
+      sym_namespace_list,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(33), 2,
+      anon_sym_input,
+      anon_sym_output,
+    ACTIONS(35), 2,
+      anon_sym_state,
+      anon_sym_gen,
+    STATE(179), 2,
+      sym_template_declaration_type,
+      sym_declaration,
+    STATE(245), 3,
+      sym__type,
+      sym_array_type,
+      sym_template_global,
+
+  [7197] = 11,
+    ACTIONS(15), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(490), 1,
+      anon_sym_LF,
+    STATE(15), 1,
+      sym_namespace_list,
+    STATE(152), 1,
+      aux_sym__linebreak,
+    STATE(192), 1,
+      sym_declaration,
+    STATE(293), 1,
+      sym_declaration_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(33), 2,
+      anon_sym_input,
+      anon_sym_output,
+    ACTIONS(35), 2,
+      anon_sym_state,
+      anon_sym_gen,
+    STATE(245), 3,
+      sym__type,
+      sym_array_type,
+      sym_template_global,
+
+  [7236] = 11,
+    ACTIONS(15), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(53), 1,
+      anon_sym_LF,
+    STATE(15), 1,
+      sym_namespace_list,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    STATE(192), 1,
+      sym_declaration,
+    STATE(295), 1,
+      sym_declaration_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(33), 2,
+      anon_sym_input,
+      anon_sym_output,
+    ACTIONS(35), 2,
+      anon_sym_state,
+      anon_sym_gen,
+    STATE(245), 3,
+      sym__type,
+      sym_array_type,
+      sym_template_global,
+
+  [7275] = 8,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(482), 1,
+      sym_identifier,
+    STATE(15), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(33), 2,
+      anon_sym_input,
+      anon_sym_output,
+    ACTIONS(35), 2,
+      anon_sym_state,
+      anon_sym_gen,
+    STATE(252), 2,
+      sym_template_declaration_type,
+      sym_declaration,
+    STATE(245), 3,
+      sym__type,
+      sym_array_type,
+      sym_template_global,
+
+  [7306] = 9,
+    ACTIONS(11), 1,
+      anon_sym_const,
+    ACTIONS(492), 1,
+      ts_builtin_sym_end,
+    ACTIONS(494), 1,
+      anon_sym_LF,
+    STATE(155), 1,
+      aux_sym__linebreak,
+    STATE(214), 1,
+      sym_global_object,
+    STATE(299), 1,
+      sym_const_and_type,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(9), 2,
+      anon_sym_module,
+      anon_sym_struct,
+    ACTIONS(7), 3,
+      anon_sym___builtin__,
+      anon_sym_extern,
+      anon_sym_override,
+
+  [7338] = 4,
+    ACTIONS(496), 1,
+      anon_sym_LF,
+    STATE(155), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(282), 9,
+      ts_builtin_sym_end,
+      anon_sym___builtin__,
+      anon_sym_extern,
+      anon_sym_override,
+      anon_sym_module,
+      anon_sym_struct,
+      anon_sym_const,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+
+  [7360] = 9,
+    ACTIONS(11), 1,
+      anon_sym_const,
+    ACTIONS(494), 1,
+      anon_sym_LF,
+    ACTIONS(499), 1,
+      ts_builtin_sym_end,
+    STATE(155), 1,
+      aux_sym__linebreak,
+    STATE(278), 1,
+      sym_global_object,
+    STATE(299), 1,
+      sym_const_and_type,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(9), 2,
+      anon_sym_module,
+      anon_sym_struct,
+    ACTIONS(7), 3,
+      anon_sym___builtin__,
+      anon_sym_extern,
+      anon_sym_override,
+
+  [7392] = 9,
+    ACTIONS(11), 1,
+      anon_sym_const,
+    ACTIONS(494), 1,
+      anon_sym_LF,
+    ACTIONS(501), 1,
+      ts_builtin_sym_end,
+    STATE(155), 1,
+      aux_sym__linebreak,
+    STATE(278), 1,
+      sym_global_object,
+    STATE(299), 1,
+      sym_const_and_type,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(9), 2,
+      anon_sym_module,
+      anon_sym_struct,
+    ACTIONS(7), 3,
+      anon_sym___builtin__,
+      anon_sym_extern,
+      anon_sym_override,
+
+  [7424] = 9,
+    ACTIONS(11), 1,
+      anon_sym_const,
+    ACTIONS(494), 1,
+      anon_sym_LF,
+    ACTIONS(503), 1,
+      ts_builtin_sym_end,
+    STATE(155), 1,
+      aux_sym__linebreak,
+    STATE(278), 1,
+      sym_global_object,
+    STATE(299), 1,
+      sym_const_and_type,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(9), 2,
+      anon_sym_module,
+      anon_sym_struct,
+    ACTIONS(7), 3,
+      anon_sym___builtin__,
+      anon_sym_extern,
+      anon_sym_override,
+
+  [7456] = 9,
+    ACTIONS(11), 1,
+      anon_sym_const,
+    ACTIONS(494), 1,
+      anon_sym_LF,
+    ACTIONS(505), 1,
+      ts_builtin_sym_end,
+    STATE(155), 1,
+      aux_sym__linebreak,
+    STATE(278), 1,
+      sym_global_object,
+    STATE(299), 1,
+      sym_const_and_type,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(9), 2,
+      anon_sym_module,
+      anon_sym_struct,
+    ACTIONS(7), 3,
+      anon_sym___builtin__,
+      anon_sym_extern,
+      anon_sym_override,
+
+  [7488] = 8,
     ACTIONS(15), 1,
       sym_identifier,
-    ACTIONS(41), 1,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(49), 1,
-      anon_sym_LF,
-    ACTIONS(319), 1,
-      anon_sym_DASH_GT,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(25), 1,
-      aux_sym__linebreak,
-    STATE(113), 1,
+    STATE(308), 1,
       sym_declaration,
-    STATE(170), 1,
-      sym_declaration_list,
-    STATE(204), 1,
-      sym__interface_ports_output,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(33), 2,
+    ACTIONS(507), 2,
       anon_sym_input,
       anon_sym_output,
-    ACTIONS(35), 2,
+    ACTIONS(509), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 3,
+    STATE(266), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
-  [4075] = 13,
+
+  [7518] = 8,
     ACTIONS(15), 1,
       sym_identifier,
-    ACTIONS(41), 1,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(319), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(321), 1,
-      anon_sym_LF,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(82), 1,
-      aux_sym__linebreak,
-    STATE(113), 1,
+    STATE(243), 1,
       sym_declaration,
-    STATE(162), 1,
-      sym_declaration_list,
-    STATE(203), 1,
-      sym__interface_ports_output,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
@@ -5587,1707 +8796,1816 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(35), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 3,
+    STATE(245), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
-  [4120] = 11,
-    ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(323), 1,
-      sym_identifier,
-    ACTIONS(325), 1,
-      anon_sym_RPAREN,
-    ACTIONS(327), 1,
+
+  [7548] = 8,
+    ACTIONS(11), 1,
+      anon_sym_const,
+    ACTIONS(494), 1,
       anon_sym_LF,
-    STATE(17), 1,
-      sym_namespace_list,
-    STATE(86), 1,
+    STATE(155), 1,
       aux_sym__linebreak,
+    STATE(278), 1,
+      sym_global_object,
+    STATE(299), 1,
+      sym_const_and_type,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(33), 2,
-      anon_sym_input,
-      anon_sym_output,
-    ACTIONS(35), 2,
-      anon_sym_state,
-      anon_sym_gen,
-    STATE(125), 2,
-      sym_template_declaration_type,
-      sym_declaration,
-    STATE(177), 3,
-      sym__type,
-      sym_array_type,
-      sym_template_global,
-  [4160] = 3,
+    ACTIONS(9), 2,
+      anon_sym_module,
+      anon_sym_struct,
+    ACTIONS(7), 3,
+      anon_sym___builtin__,
+      anon_sym_extern,
+      anon_sym_override,
+
+  [7577] = 5,
+    ACTIONS(71), 1,
+      anon_sym_COLON_COLON,
+    STATE(13), 1,
+      aux_sym_namespace_list_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(329), 5,
-      anon_sym_input,
-      anon_sym_output,
-      anon_sym_state,
-      anon_sym_gen,
+    ACTIONS(67), 3,
       sym_identifier,
-    ACTIONS(331), 10,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_STAR,
-      anon_sym_BANG,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-      anon_sym_LPAREN,
-      anon_sym_COLON_COLON,
-      sym_number,
-  [4184] = 11,
-    ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(49), 1,
+      anon_sym_POUND_LPAREN,
+      anon_sym_LBRACK,
+    ACTIONS(511), 3,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
       anon_sym_LF,
-    ACTIONS(323), 1,
+
+  [7598] = 6,
+    ACTIONS(15), 1,
       sym_identifier,
-    ACTIONS(333), 1,
-      anon_sym_RPAREN,
-    STATE(17), 1,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(25), 1,
-      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(33), 2,
-      anon_sym_input,
-      anon_sym_output,
-    ACTIONS(35), 2,
+    ACTIONS(513), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(118), 2,
-      sym_template_declaration_type,
-      sym_declaration,
-    STATE(177), 3,
+    STATE(247), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
-  [4224] = 11,
-    ACTIONS(15), 1,
-      sym_identifier,
-    ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(335), 1,
+
+  [7621] = 4,
+    ACTIONS(517), 1,
+      anon_sym_SQUOTE,
+    STATE(170), 1,
+      sym_latency_specifier,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(515), 6,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
       anon_sym_LF,
-    STATE(17), 1,
-      sym_namespace_list,
-    STATE(88), 1,
-      aux_sym__linebreak,
-    STATE(113), 1,
-      sym_declaration,
-    STATE(201), 1,
-      sym_declaration_list,
+
+  [7640] = 4,
+    ACTIONS(517), 1,
+      anon_sym_SQUOTE,
+    STATE(171), 1,
+      sym_latency_specifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(33), 2,
-      anon_sym_input,
-      anon_sym_output,
-    ACTIONS(35), 2,
-      anon_sym_state,
-      anon_sym_gen,
-    STATE(177), 3,
-      sym__type,
-      sym_array_type,
-      sym_template_global,
-  [4263] = 11,
-    ACTIONS(15), 1,
-      sym_identifier,
-    ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(49), 1,
+    ACTIONS(519), 6,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
       anon_sym_LF,
-    STATE(17), 1,
-      sym_namespace_list,
-    STATE(25), 1,
-      aux_sym__linebreak,
-    STATE(113), 1,
-      sym_declaration,
-    STATE(208), 1,
-      sym_declaration_list,
+
+  [7659] = 4,
+    ACTIONS(517), 1,
+      anon_sym_SQUOTE,
+    STATE(172), 1,
+      sym_latency_specifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(33), 2,
-      anon_sym_input,
-      anon_sym_output,
-    ACTIONS(35), 2,
-      anon_sym_state,
-      anon_sym_gen,
-    STATE(177), 3,
-      sym__type,
-      sym_array_type,
-      sym_template_global,
-  [4302] = 8,
-    ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(323), 1,
-      sym_identifier,
-    STATE(17), 1,
-      sym_namespace_list,
+    ACTIONS(521), 6,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [7678] = 4,
+    ACTIONS(517), 1,
+      anon_sym_SQUOTE,
+    STATE(173), 1,
+      sym_latency_specifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(33), 2,
-      anon_sym_input,
-      anon_sym_output,
-    ACTIONS(35), 2,
-      anon_sym_state,
-      anon_sym_gen,
-    STATE(184), 2,
-      sym_template_declaration_type,
-      sym_declaration,
-    STATE(177), 3,
-      sym__type,
-      sym_array_type,
-      sym_template_global,
-  [4333] = 8,
+    ACTIONS(523), 6,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [7697] = 6,
     ACTIONS(15), 1,
       sym_identifier,
-    ACTIONS(41), 1,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(137), 1,
-      sym_declaration,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(33), 2,
-      anon_sym_input,
-      anon_sym_output,
-    ACTIONS(35), 2,
+    ACTIONS(525), 2,
       anon_sym_state,
       anon_sym_gen,
-    STATE(177), 3,
+    STATE(268), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
-  [4363] = 8,
-    ACTIONS(15), 1,
-      sym_identifier,
-    ACTIONS(41), 1,
+
+  [7720] = 2,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(527), 7,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+      anon_sym_in,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [7734] = 2,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(529), 7,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+      anon_sym_in,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [7748] = 2,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(531), 7,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+      anon_sym_in,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [7762] = 2,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(533), 7,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+      anon_sym_in,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
+      anon_sym_LF,
+
+  [7776] = 5,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    STATE(17), 1,
+    ACTIONS(301), 1,
+      sym_identifier,
+    STATE(15), 1,
       sym_namespace_list,
-    STATE(213), 1,
-      sym_declaration,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(33), 2,
-      anon_sym_input,
-      anon_sym_output,
-    ACTIONS(35), 2,
-      anon_sym_state,
-      anon_sym_gen,
-    STATE(177), 3,
+    STATE(244), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
-  [4393] = 9,
-    ACTIONS(11), 1,
-      anon_sym_const,
-    ACTIONS(337), 1,
-      ts_builtin_sym_end,
-    ACTIONS(339), 1,
+
+  [7795] = 7,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    ACTIONS(535), 1,
+      anon_sym_RPAREN,
+    ACTIONS(537), 1,
       anon_sym_LF,
-    STATE(98), 1,
+    STATE(153), 1,
+      sym__comma,
+    STATE(180), 1,
+      aux_sym_template_declaration_arguments_repeat1,
+    STATE(246), 1,
       aux_sym__linebreak,
-    STATE(207), 1,
-      sym_global_object,
-    STATE(223), 1,
-      sym_const_and_type,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(7), 2,
-      anon_sym___builtin__,
-      anon_sym_extern,
-    ACTIONS(9), 2,
-      anon_sym_module,
-      anon_sym_struct,
-  [4424] = 9,
-    ACTIONS(11), 1,
-      anon_sym_const,
-    ACTIONS(339), 1,
-      anon_sym_LF,
-    ACTIONS(341), 1,
-      ts_builtin_sym_end,
-    STATE(98), 1,
-      aux_sym__linebreak,
-    STATE(207), 1,
-      sym_global_object,
-    STATE(223), 1,
-      sym_const_and_type,
+
+  [7818] = 5,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(301), 1,
+      sym_identifier,
+    STATE(15), 1,
+      sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(7), 2,
-      anon_sym___builtin__,
-      anon_sym_extern,
-    ACTIONS(9), 2,
-      anon_sym_module,
-      anon_sym_struct,
-  [4455] = 9,
-    ACTIONS(11), 1,
-      anon_sym_const,
-    ACTIONS(339), 1,
-      anon_sym_LF,
-    ACTIONS(343), 1,
-      ts_builtin_sym_end,
-    STATE(98), 1,
-      aux_sym__linebreak,
-    STATE(149), 1,
-      sym_global_object,
-    STATE(223), 1,
-      sym_const_and_type,
+    STATE(248), 3,
+      sym__type,
+      sym_array_type,
+      sym_template_global,
+
+  [7837] = 5,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    STATE(11), 1,
+      sym__comma,
+    STATE(182), 1,
+      aux_sym_assign_left_side_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(7), 2,
-      anon_sym___builtin__,
-      anon_sym_extern,
-    ACTIONS(9), 2,
-      anon_sym_module,
-      anon_sym_struct,
-  [4486] = 9,
-    ACTIONS(11), 1,
-      anon_sym_const,
-    ACTIONS(339), 1,
+    ACTIONS(539), 3,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
       anon_sym_LF,
-    ACTIONS(345), 1,
-      ts_builtin_sym_end,
-    STATE(98), 1,
+
+  [7856] = 7,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    ACTIONS(541), 1,
+      anon_sym_RPAREN,
+    ACTIONS(543), 1,
+      anon_sym_LF,
+    STATE(184), 1,
+      aux_sym_template_args_repeat1,
+    STATE(249), 1,
       aux_sym__linebreak,
-    STATE(207), 1,
-      sym_global_object,
-    STATE(223), 1,
-      sym_const_and_type,
+    STATE(286), 1,
+      sym__comma,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(7), 2,
-      anon_sym___builtin__,
-      anon_sym_extern,
-    ACTIONS(9), 2,
-      anon_sym_module,
-      anon_sym_struct,
-  [4517] = 9,
-    ACTIONS(11), 1,
-      anon_sym_const,
-    ACTIONS(339), 1,
+
+  [7879] = 7,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    ACTIONS(545), 1,
+      anon_sym_RPAREN,
+    ACTIONS(547), 1,
       anon_sym_LF,
-    ACTIONS(347), 1,
-      ts_builtin_sym_end,
-    STATE(98), 1,
+    STATE(153), 1,
+      sym__comma,
+    STATE(185), 1,
+      aux_sym_template_declaration_arguments_repeat1,
+    STATE(250), 1,
       aux_sym__linebreak,
-    STATE(207), 1,
-      sym_global_object,
-    STATE(223), 1,
-      sym_const_and_type,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(7), 2,
-      anon_sym___builtin__,
-      anon_sym_extern,
-    ACTIONS(9), 2,
-      anon_sym_module,
-      anon_sym_struct,
-  [4548] = 8,
-    ACTIONS(11), 1,
-      anon_sym_const,
-    ACTIONS(339), 1,
+
+  [7902] = 7,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    ACTIONS(549), 1,
+      anon_sym_RPAREN,
+    ACTIONS(551), 1,
       anon_sym_LF,
-    STATE(98), 1,
+    STATE(153), 1,
+      sym__comma,
+    STATE(206), 1,
+      aux_sym_template_declaration_arguments_repeat1,
+    STATE(251), 1,
       aux_sym__linebreak,
-    STATE(207), 1,
-      sym_global_object,
-    STATE(223), 1,
-      sym_const_and_type,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(7), 2,
-      anon_sym___builtin__,
-      anon_sym_extern,
-    ACTIONS(9), 2,
-      anon_sym_module,
-      anon_sym_struct,
-  [4576] = 4,
-    ACTIONS(349), 1,
-      anon_sym_LF,
-    STATE(98), 1,
-      aux_sym__linebreak,
+
+  [7925] = 5,
+    ACTIONS(43), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(301), 1,
+      sym_identifier,
+    STATE(15), 1,
+      sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(124), 7,
-      ts_builtin_sym_end,
-      anon_sym___builtin__,
-      anon_sym_extern,
-      anon_sym_module,
-      anon_sym_struct,
-      anon_sym_const,
-      anon_sym_RPAREN,
-  [4596] = 4,
-    ACTIONS(354), 1,
-      anon_sym_SQUOTE,
-    STATE(107), 1,
-      sym_latency_specifier,
+    STATE(253), 3,
+      sym__type,
+      sym_array_type,
+      sym_template_global,
+
+  [7944] = 5,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    STATE(11), 1,
+      sym__comma,
+    STATE(187), 1,
+      aux_sym_assign_left_side_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(352), 7,
-      anon_sym_RPAREN,
+    ACTIONS(553), 3,
       anon_sym_RBRACE,
       anon_sym_EQ,
-      anon_sym_in,
-      anon_sym_DASH_GT,
+      anon_sym_LF,
+
+  [7963] = 7,
+    ACTIONS(289), 1,
       anon_sym_COMMA,
+    ACTIONS(555), 1,
+      anon_sym_RPAREN,
+    ACTIONS(557), 1,
       anon_sym_LF,
-  [4616] = 4,
-    ACTIONS(354), 1,
-      anon_sym_SQUOTE,
-    STATE(108), 1,
-      sym_latency_specifier,
+    STATE(188), 1,
+      aux_sym_template_args_repeat1,
+    STATE(257), 1,
+      aux_sym__linebreak,
+    STATE(286), 1,
+      sym__comma,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(356), 7,
-      anon_sym_RPAREN,
-      anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_in,
-      anon_sym_DASH_GT,
+
+  [7986] = 7,
+    ACTIONS(289), 1,
       anon_sym_COMMA,
+    ACTIONS(559), 1,
+      anon_sym_RPAREN,
+    ACTIONS(561), 1,
       anon_sym_LF,
-  [4636] = 4,
-    ACTIONS(354), 1,
-      anon_sym_SQUOTE,
-    STATE(106), 1,
-      sym_latency_specifier,
+    STATE(207), 1,
+      aux_sym_template_args_repeat1,
+    STATE(258), 1,
+      aux_sym__linebreak,
+    STATE(286), 1,
+      sym__comma,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(358), 7,
+
+  [8009] = 7,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    ACTIONS(563), 1,
       anon_sym_RPAREN,
-      anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_in,
-      anon_sym_DASH_GT,
+    ACTIONS(565), 1,
+      anon_sym_LF,
+    STATE(153), 1,
+      sym__comma,
+    STATE(206), 1,
+      aux_sym_template_declaration_arguments_repeat1,
+    STATE(260), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+
+  [8032] = 7,
+    ACTIONS(289), 1,
       anon_sym_COMMA,
+    ACTIONS(567), 1,
+      anon_sym_RBRACE,
+    ACTIONS(569), 1,
       anon_sym_LF,
-  [4656] = 4,
-    ACTIONS(354), 1,
-      anon_sym_SQUOTE,
-    STATE(105), 1,
-      sym_latency_specifier,
+    STATE(87), 1,
+      sym__comma,
+    STATE(191), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(262), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(360), 7,
-      anon_sym_RPAREN,
+
+  [8055] = 5,
+    ACTIONS(573), 1,
+      anon_sym_COMMA,
+    STATE(11), 1,
+      sym__comma,
+    STATE(187), 1,
+      aux_sym_assign_left_side_repeat1,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(571), 3,
       anon_sym_RBRACE,
       anon_sym_EQ,
-      anon_sym_in,
-      anon_sym_DASH_GT,
+      anon_sym_LF,
+
+  [8074] = 7,
+    ACTIONS(289), 1,
       anon_sym_COMMA,
+    ACTIONS(576), 1,
+      anon_sym_RPAREN,
+    ACTIONS(578), 1,
       anon_sym_LF,
-  [4676] = 5,
-    ACTIONS(67), 1,
-      anon_sym_COLON_COLON,
-    STATE(13), 1,
-      aux_sym_namespace_list_repeat1,
+    STATE(207), 1,
+      aux_sym_template_args_repeat1,
+    STATE(263), 1,
+      aux_sym__linebreak,
+    STATE(286), 1,
+      sym__comma,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(65), 3,
-      anon_sym_POUND_LPAREN,
-      anon_sym_LBRACK,
-      sym_identifier,
-    ACTIONS(362), 3,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [4697] = 6,
-    ACTIONS(15), 1,
-      sym_identifier,
-    ACTIONS(41), 1,
+
+  [8097] = 5,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    STATE(17), 1,
+    ACTIONS(301), 1,
+      sym_identifier,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(364), 2,
-      anon_sym_state,
-      anon_sym_gen,
-    STATE(180), 3,
+    STATE(209), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
-  [4720] = 2,
+
+  [8116] = 7,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    ACTIONS(580), 1,
+      anon_sym_RBRACE,
+    ACTIONS(582), 1,
+      anon_sym_LF,
+    STATE(87), 1,
+      sym__comma,
+    STATE(191), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(264), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+
+  [8139] = 5,
+    ACTIONS(586), 1,
+      anon_sym_COMMA,
+    STATE(87), 1,
+      sym__comma,
+    STATE(191), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(366), 7,
+    ACTIONS(584), 3,
       anon_sym_RPAREN,
       anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_in,
-      anon_sym_DASH_GT,
-      anon_sym_COMMA,
       anon_sym_LF,
-  [4734] = 2,
+
+  [8158] = 5,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    STATE(161), 1,
+      sym__comma,
+    STATE(193), 1,
+      aux_sym_declaration_list_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(368), 7,
-      anon_sym_RPAREN,
+    ACTIONS(589), 3,
       anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH_GT,
-      anon_sym_COMMA,
       anon_sym_LF,
-  [4748] = 2,
+
+  [8177] = 5,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    STATE(161), 1,
+      sym__comma,
+    STATE(194), 1,
+      aux_sym_declaration_list_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(370), 7,
-      anon_sym_RPAREN,
+    ACTIONS(591), 3,
       anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH_GT,
-      anon_sym_COMMA,
       anon_sym_LF,
-  [4762] = 2,
+
+  [8196] = 5,
+    ACTIONS(595), 1,
+      anon_sym_COMMA,
+    STATE(161), 1,
+      sym__comma,
+    STATE(194), 1,
+      aux_sym_declaration_list_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(372), 7,
-      anon_sym_RPAREN,
+    ACTIONS(593), 3,
       anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_in,
       anon_sym_DASH_GT,
-      anon_sym_COMMA,
       anon_sym_LF,
-  [4776] = 7,
-    ACTIONS(223), 1,
+
+  [8215] = 7,
+    ACTIONS(289), 1,
       anon_sym_COMMA,
-    ACTIONS(374), 1,
+    ACTIONS(598), 1,
       anon_sym_RPAREN,
-    ACTIONS(376), 1,
+    ACTIONS(600), 1,
       anon_sym_LF,
-    STATE(132), 1,
+    STATE(198), 1,
       aux_sym_template_args_repeat1,
-    STATE(186), 1,
+    STATE(271), 1,
       aux_sym__linebreak,
-    STATE(193), 1,
+    STATE(286), 1,
       sym__comma,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [4799] = 5,
-    ACTIONS(41), 1,
+
+  [8238] = 5,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(301), 1,
       sym_identifier,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    STATE(131), 3,
+    STATE(270), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
-  [4818] = 7,
-    ACTIONS(223), 1,
+
+  [8257] = 7,
+    ACTIONS(289), 1,
       anon_sym_COMMA,
-    ACTIONS(378), 1,
+    ACTIONS(602), 1,
       anon_sym_RPAREN,
-    ACTIONS(380), 1,
+    ACTIONS(604), 1,
       anon_sym_LF,
-    STATE(89), 1,
-      sym__comma,
-    STATE(130), 1,
-      aux_sym_template_declaration_arguments_repeat1,
-    STATE(185), 1,
+    STATE(200), 1,
+      aux_sym_template_args_repeat1,
+    STATE(274), 1,
       aux_sym__linebreak,
-    ACTIONS(3), 2,
-      sym_single_line_comment,
-      sym_multi_line_comment,
-  [4841] = 5,
-    ACTIONS(384), 1,
-      anon_sym_COMMA,
-    STATE(11), 1,
+    STATE(286), 1,
       sym__comma,
-    STATE(112), 1,
-      aux_sym_assign_left_side_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(382), 3,
-      anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_LF,
-  [4860] = 5,
-    ACTIONS(223), 1,
+
+  [8280] = 7,
+    ACTIONS(289), 1,
       anon_sym_COMMA,
-    STATE(90), 1,
+    ACTIONS(606), 1,
+      anon_sym_RPAREN,
+    ACTIONS(608), 1,
+      anon_sym_LF,
+    STATE(207), 1,
+      aux_sym_template_args_repeat1,
+    STATE(275), 1,
+      aux_sym__linebreak,
+    STATE(286), 1,
       sym__comma,
-    STATE(121), 1,
-      aux_sym_declaration_list_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(387), 3,
-      anon_sym_RBRACE,
-      anon_sym_DASH_GT,
-      anon_sym_LF,
-  [4879] = 5,
-    ACTIONS(41), 1,
+
+  [8303] = 5,
+    ACTIONS(43), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+    ACTIONS(301), 1,
       sym_identifier,
-    STATE(17), 1,
+    STATE(15), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    STATE(181), 3,
+    STATE(273), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
-  [4898] = 7,
-    ACTIONS(223), 1,
+
+  [8322] = 7,
+    ACTIONS(289), 1,
       anon_sym_COMMA,
-    ACTIONS(389), 1,
+    ACTIONS(610), 1,
       anon_sym_RPAREN,
-    ACTIONS(391), 1,
+    ACTIONS(612), 1,
       anon_sym_LF,
-    STATE(109), 1,
+    STATE(207), 1,
       aux_sym_template_args_repeat1,
-    STATE(178), 1,
+    STATE(276), 1,
       aux_sym__linebreak,
-    STATE(193), 1,
+    STATE(286), 1,
       sym__comma,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [4921] = 7,
-    ACTIONS(223), 1,
+
+  [8345] = 2,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(614), 5,
+      sym_identifier,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
       anon_sym_COMMA,
-    ACTIONS(393), 1,
+      anon_sym_LF,
+
+  [8357] = 6,
+    ACTIONS(616), 1,
+      sym_identifier,
+    ACTIONS(618), 1,
       anon_sym_RPAREN,
-    ACTIONS(395), 1,
+    ACTIONS(620), 1,
       anon_sym_LF,
-    STATE(132), 1,
-      aux_sym_template_args_repeat1,
-    STATE(183), 1,
+    STATE(178), 1,
+      sym_template_arg,
+    STATE(204), 1,
       aux_sym__linebreak,
-    STATE(193), 1,
-      sym__comma,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [4944] = 5,
-    ACTIONS(223), 1,
-      anon_sym_COMMA,
-    STATE(11), 1,
-      sym__comma,
-    STATE(120), 1,
-      aux_sym_assign_left_side_repeat1,
+
+  [8377] = 6,
+    ACTIONS(622), 1,
+      anon_sym_RBRACE,
+    ACTIONS(624), 1,
+      anon_sym_EQ,
+    ACTIONS(626), 1,
+      anon_sym_LF,
+    STATE(6), 1,
+      aux_sym__linebreak,
+    STATE(226), 1,
+      aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(397), 3,
-      anon_sym_RBRACE,
-      anon_sym_EQ,
+
+  [8397] = 6,
+    ACTIONS(53), 1,
       anon_sym_LF,
-  [4963] = 7,
-    ACTIONS(223), 1,
-      anon_sym_COMMA,
-    ACTIONS(399), 1,
+    ACTIONS(616), 1,
+      sym_identifier,
+    ACTIONS(628), 1,
       anon_sym_RPAREN,
-    ACTIONS(401), 1,
-      anon_sym_LF,
-    STATE(89), 1,
-      sym__comma,
-    STATE(119), 1,
-      aux_sym_template_declaration_arguments_repeat1,
-    STATE(190), 1,
+    STATE(58), 1,
       aux_sym__linebreak,
+    STATE(183), 1,
+      sym_template_arg,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [4986] = 7,
-    ACTIONS(223), 1,
-      anon_sym_COMMA,
-    ACTIONS(403), 1,
-      anon_sym_RPAREN,
-    ACTIONS(405), 1,
+
+  [8417] = 6,
+    ACTIONS(624), 1,
+      anon_sym_EQ,
+    ACTIONS(630), 1,
+      anon_sym_RBRACE,
+    ACTIONS(632), 1,
       anon_sym_LF,
-    STATE(89), 1,
-      sym__comma,
-    STATE(130), 1,
-      aux_sym_template_declaration_arguments_repeat1,
-    STATE(176), 1,
+    STATE(7), 1,
       aux_sym__linebreak,
+    STATE(230), 1,
+      aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5009] = 5,
-    ACTIONS(223), 1,
+
+  [8437] = 5,
+    ACTIONS(636), 1,
       anon_sym_COMMA,
-    STATE(11), 1,
+    STATE(153), 1,
       sym__comma,
-    STATE(112), 1,
-      aux_sym_assign_left_side_repeat1,
+    STATE(206), 1,
+      aux_sym_template_declaration_arguments_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(407), 3,
-      anon_sym_RBRACE,
-      anon_sym_EQ,
+    ACTIONS(634), 2,
+      anon_sym_RPAREN,
       anon_sym_LF,
-  [5028] = 5,
-    ACTIONS(223), 1,
+
+  [8455] = 5,
+    ACTIONS(641), 1,
       anon_sym_COMMA,
-    STATE(90), 1,
+    STATE(207), 1,
+      aux_sym_template_args_repeat1,
+    STATE(286), 1,
       sym__comma,
-    STATE(126), 1,
-      aux_sym_declaration_list_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(409), 3,
-      anon_sym_RBRACE,
-      anon_sym_DASH_GT,
+    ACTIONS(639), 2,
+      anon_sym_RPAREN,
       anon_sym_LF,
-  [5047] = 7,
-    ACTIONS(223), 1,
+
+  [8473] = 4,
+    ACTIONS(362), 1,
+      anon_sym_LBRACE,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(644), 2,
+      anon_sym_when,
+      anon_sym_if,
+    STATE(290), 2,
+      sym_block,
+      sym_if_statement,
+
+  [8489] = 4,
+    ACTIONS(648), 1,
+      anon_sym_LBRACK,
+    STATE(201), 1,
+      sym_array_bracket_expression,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(646), 3,
+      anon_sym_RPAREN,
       anon_sym_COMMA,
-    ACTIONS(411), 1,
+      anon_sym_LF,
+
+  [8505] = 6,
+    ACTIONS(616), 1,
+      sym_identifier,
+    ACTIONS(650), 1,
       anon_sym_RPAREN,
-    ACTIONS(413), 1,
+    ACTIONS(652), 1,
       anon_sym_LF,
-    STATE(116), 1,
-      aux_sym_template_args_repeat1,
-    STATE(189), 1,
+    STATE(195), 1,
+      sym_template_arg,
+    STATE(211), 1,
       aux_sym__linebreak,
-    STATE(193), 1,
-      sym__comma,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5070] = 5,
-    ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
+
+  [8525] = 6,
+    ACTIONS(53), 1,
+      anon_sym_LF,
+    ACTIONS(616), 1,
       sym_identifier,
-    STATE(17), 1,
-      sym_namespace_list,
+    ACTIONS(654), 1,
+      anon_sym_RPAREN,
+    STATE(58), 1,
+      aux_sym__linebreak,
+    STATE(197), 1,
+      sym_template_arg,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    STATE(182), 3,
-      sym__type,
-      sym_array_type,
-      sym_template_global,
-  [5089] = 5,
-    ACTIONS(41), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(248), 1,
-      sym_identifier,
-    STATE(17), 1,
-      sym_namespace_list,
+
+  [8545] = 5,
+    ACTIONS(656), 1,
+      ts_builtin_sym_end,
+    ACTIONS(658), 1,
+      anon_sym_LF,
+    STATE(156), 1,
+      aux_sym__linebreak,
+    STATE(215), 1,
+      aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    STATE(188), 3,
-      sym__type,
-      sym_array_type,
-      sym_template_global,
-  [5108] = 7,
-    ACTIONS(223), 1,
-      anon_sym_COMMA,
-    ACTIONS(415), 1,
-      anon_sym_RPAREN,
-    ACTIONS(417), 1,
+
+  [8562] = 4,
+    ACTIONS(11), 1,
+      anon_sym_const,
+    STATE(300), 1,
+      sym_const_and_type,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+    ACTIONS(660), 2,
+      anon_sym_module,
+      anon_sym_struct,
+
+  [8577] = 5,
+    ACTIONS(662), 1,
+      ts_builtin_sym_end,
+    ACTIONS(664), 1,
       anon_sym_LF,
-    STATE(89), 1,
-      sym__comma,
-    STATE(111), 1,
-      aux_sym_template_declaration_arguments_repeat1,
-    STATE(187), 1,
+    STATE(157), 1,
       aux_sym__linebreak,
+    STATE(217), 1,
+      aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5131] = 5,
-    ACTIONS(421), 1,
-      anon_sym_COMMA,
-    STATE(90), 1,
-      sym__comma,
-    STATE(126), 1,
-      aux_sym_declaration_list_repeat1,
+
+  [8594] = 5,
+    ACTIONS(666), 1,
+      ts_builtin_sym_end,
+    ACTIONS(668), 1,
+      anon_sym_LF,
+    STATE(158), 1,
+      aux_sym__linebreak,
+    STATE(218), 1,
+      aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(419), 3,
-      anon_sym_RBRACE,
-      anon_sym_DASH_GT,
-      anon_sym_LF,
-  [5150] = 6,
-    ACTIONS(424), 1,
-      anon_sym_RBRACE,
-    ACTIONS(426), 1,
-      anon_sym_EQ,
-    ACTIONS(428), 1,
+
+  [8611] = 5,
+    ACTIONS(362), 1,
+      anon_sym_LBRACE,
+    ACTIONS(670), 1,
+      anon_sym_POUND_LPAREN,
+    STATE(279), 1,
+      sym_template_declaration_arguments,
+    STATE(280), 1,
+      sym_block,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+
+  [8628] = 5,
+    ACTIONS(672), 1,
+      ts_builtin_sym_end,
+    ACTIONS(674), 1,
       anon_sym_LF,
-    STATE(4), 1,
+    STATE(159), 1,
       aux_sym__linebreak,
-    STATE(148), 1,
-      aux_sym_block_repeat1,
+    STATE(218), 1,
+      aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5170] = 6,
-    ACTIONS(49), 1,
+
+  [8645] = 5,
+    ACTIONS(676), 1,
+      ts_builtin_sym_end,
+    ACTIONS(678), 1,
       anon_sym_LF,
-    ACTIONS(430), 1,
-      sym_identifier,
-    ACTIONS(432), 1,
-      anon_sym_RPAREN,
-    STATE(25), 1,
+    STATE(162), 1,
       aux_sym__linebreak,
-    STATE(122), 1,
-      sym_template_arg,
+    STATE(218), 1,
+      aux_sym_source_file_repeat1,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+
+  [8662] = 5,
+    ACTIONS(362), 1,
+      anon_sym_LBRACE,
+    ACTIONS(670), 1,
+      anon_sym_POUND_LPAREN,
+    STATE(281), 1,
+      sym_template_declaration_arguments,
+    STATE(282), 1,
+      sym_block,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5190] = 6,
-    ACTIONS(426), 1,
-      anon_sym_EQ,
-    ACTIONS(434), 1,
+
+  [8679] = 5,
+    ACTIONS(622), 1,
       anon_sym_RBRACE,
-    ACTIONS(436), 1,
+    ACTIONS(626), 1,
       anon_sym_LF,
-    STATE(7), 1,
+    STATE(6), 1,
       aux_sym__linebreak,
-    STATE(155), 1,
+    STATE(226), 1,
       aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5210] = 5,
-    ACTIONS(440), 1,
-      anon_sym_COMMA,
-    STATE(89), 1,
-      sym__comma,
-    STATE(130), 1,
-      aux_sym_template_declaration_arguments_repeat1,
+
+  [8696] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(438), 2,
-      anon_sym_RPAREN,
+    ACTIONS(681), 4,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_else,
       anon_sym_LF,
-  [5228] = 4,
-    ACTIONS(102), 1,
-      anon_sym_LBRACK,
-    STATE(134), 1,
-      sym_array_bracket_expression,
+
+  [8707] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(443), 3,
-      anon_sym_RPAREN,
+    ACTIONS(274), 4,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
       anon_sym_COMMA,
       anon_sym_LF,
-  [5244] = 5,
-    ACTIONS(447), 1,
-      anon_sym_COMMA,
-    STATE(132), 1,
-      aux_sym_template_args_repeat1,
-    STATE(193), 1,
-      sym__comma,
+
+  [8718] = 3,
+    ACTIONS(685), 1,
+      anon_sym_COLON,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(445), 2,
+    ACTIONS(683), 3,
       anon_sym_RPAREN,
+      anon_sym_COMMA,
       anon_sym_LF,
-  [5262] = 6,
-    ACTIONS(430), 1,
-      sym_identifier,
-    ACTIONS(450), 1,
-      anon_sym_RPAREN,
-    ACTIONS(452), 1,
+
+  [8731] = 5,
+    ACTIONS(630), 1,
+      anon_sym_RBRACE,
+    ACTIONS(632), 1,
       anon_sym_LF,
-    STATE(115), 1,
-      sym_template_arg,
-    STATE(128), 1,
+    STATE(7), 1,
       aux_sym__linebreak,
+    STATE(230), 1,
+      aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5282] = 2,
+
+  [8748] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(454), 5,
-      anon_sym_RPAREN,
-      anon_sym_LBRACK,
-      sym_identifier,
-      anon_sym_COMMA,
+    ACTIONS(687), 4,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_else,
       anon_sym_LF,
-  [5294] = 4,
-    ACTIONS(17), 1,
-      anon_sym_LBRACE,
+
+  [8759] = 5,
+    ACTIONS(689), 1,
+      anon_sym_RBRACE,
+    ACTIONS(691), 1,
+      anon_sym_LF,
+    STATE(8), 1,
+      aux_sym__linebreak,
+    STATE(233), 1,
+      aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(456), 2,
-      anon_sym_when,
-      anon_sym_if,
-    STATE(200), 2,
-      sym_block,
-      sym_if_statement,
-  [5310] = 2,
+
+  [8776] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(458), 4,
+    ACTIONS(693), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [5321] = 2,
+
+  [8787] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(460), 4,
+    ACTIONS(307), 4,
       anon_sym_RBRACE,
-      anon_sym_DASH_GT,
+      anon_sym_EQ,
       anon_sym_COMMA,
       anon_sym_LF,
-  [5332] = 2,
+
+  [8798] = 4,
+    ACTIONS(697), 1,
+      anon_sym_COLON,
+    STATE(288), 1,
+      sym_interface_ports,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(462), 4,
-      ts_builtin_sym_end,
+    ACTIONS(695), 2,
       anon_sym_RBRACE,
-      anon_sym_else,
       anon_sym_LF,
-  [5343] = 5,
-    ACTIONS(464), 1,
+
+  [8813] = 5,
+    ACTIONS(699), 1,
       anon_sym_RBRACE,
-    ACTIONS(466), 1,
+    ACTIONS(701), 1,
       anon_sym_LF,
-    STATE(10), 1,
+    STATE(9), 1,
       aux_sym__linebreak,
-    STATE(139), 1,
+    STATE(233), 1,
       aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5360] = 2,
+
+  [8830] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(462), 4,
+    ACTIONS(703), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [5371] = 4,
-    ACTIONS(471), 1,
-      anon_sym_COLON,
-    STATE(197), 1,
-      sym_interface_ports,
-    ACTIONS(3), 2,
-      sym_single_line_comment,
-      sym_multi_line_comment,
-    ACTIONS(469), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [5386] = 2,
+
+  [8841] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(473), 4,
-      anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [5397] = 5,
-    ACTIONS(475), 1,
+    ACTIONS(705), 4,
       ts_builtin_sym_end,
-    ACTIONS(477), 1,
+      anon_sym_RBRACE,
+      anon_sym_else,
       anon_sym_LF,
-    STATE(92), 1,
-      aux_sym__linebreak,
-    STATE(146), 1,
-      aux_sym_source_file_repeat1,
-    ACTIONS(3), 2,
-      sym_single_line_comment,
-      sym_multi_line_comment,
-  [5414] = 5,
-    ACTIONS(424), 1,
+
+  [8852] = 5,
+    ACTIONS(707), 1,
       anon_sym_RBRACE,
-    ACTIONS(428), 1,
+    ACTIONS(709), 1,
       anon_sym_LF,
-    STATE(4), 1,
+    STATE(10), 1,
       aux_sym__linebreak,
-    STATE(147), 1,
+    STATE(233), 1,
       aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5431] = 2,
+
+  [8869] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(479), 4,
+    ACTIONS(712), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [5442] = 5,
-    ACTIONS(481), 1,
-      ts_builtin_sym_end,
-    ACTIONS(483), 1,
-      anon_sym_LF,
-    STATE(95), 1,
-      aux_sym__linebreak,
-    STATE(153), 1,
-      aux_sym_source_file_repeat1,
+
+  [8880] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5459] = 5,
-    ACTIONS(485), 1,
+    ACTIONS(714), 4,
       anon_sym_RBRACE,
-    ACTIONS(487), 1,
+      anon_sym_EQ,
+      anon_sym_COMMA,
       anon_sym_LF,
-    STATE(5), 1,
-      aux_sym__linebreak,
-    STATE(139), 1,
-      aux_sym_block_repeat1,
+
+  [8891] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5476] = 5,
-    ACTIONS(489), 1,
+    ACTIONS(716), 4,
+      ts_builtin_sym_end,
       anon_sym_RBRACE,
-    ACTIONS(491), 1,
+      anon_sym_else,
       anon_sym_LF,
-    STATE(9), 1,
-      aux_sym__linebreak,
-    STATE(139), 1,
-      aux_sym_block_repeat1,
+
+  [8902] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5493] = 5,
-    ACTIONS(493), 1,
+    ACTIONS(718), 4,
       ts_builtin_sym_end,
-    ACTIONS(495), 1,
+      anon_sym_RBRACE,
+      anon_sym_else,
       anon_sym_LF,
-    STATE(96), 1,
-      aux_sym__linebreak,
-    STATE(156), 1,
-      aux_sym_source_file_repeat1,
+
+  [8913] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5510] = 2,
+    ACTIONS(720), 4,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_else,
+      anon_sym_LF,
+
+  [8924] = 5,
+    ACTIONS(289), 1,
+      anon_sym_COMMA,
+    ACTIONS(722), 1,
+      anon_sym_RPAREN,
+    STATE(87), 1,
+      sym__comma,
+    STATE(191), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(229), 4,
-      anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [5521] = 2,
+
+  [8941] = 4,
+    ACTIONS(478), 1,
+      anon_sym_DASH_GT,
+    STATE(292), 1,
+      sym__interface_ports_output,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(225), 4,
+    ACTIONS(724), 2,
       anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_COMMA,
       anon_sym_LF,
-  [5532] = 2,
+
+  [8956] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(497), 4,
+    ACTIONS(726), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [5543] = 5,
-    ACTIONS(499), 1,
-      ts_builtin_sym_end,
-    ACTIONS(501), 1,
-      anon_sym_LF,
-    STATE(97), 1,
-      aux_sym__linebreak,
-    STATE(153), 1,
-      aux_sym_source_file_repeat1,
+
+  [8967] = 4,
+    ACTIONS(478), 1,
+      anon_sym_DASH_GT,
+    STATE(294), 1,
+      sym__interface_ports_output,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5560] = 5,
-    ACTIONS(504), 1,
+    ACTIONS(728), 2,
       anon_sym_RBRACE,
-    ACTIONS(506), 1,
       anon_sym_LF,
-    STATE(6), 1,
-      aux_sym__linebreak,
-    STATE(139), 1,
-      aux_sym_block_repeat1,
+
+  [8982] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5577] = 5,
-    ACTIONS(508), 1,
+    ACTIONS(730), 4,
       anon_sym_RBRACE,
-    ACTIONS(510), 1,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
       anon_sym_LF,
-    STATE(8), 1,
-      aux_sym__linebreak,
-    STATE(139), 1,
-      aux_sym_block_repeat1,
+
+  [8993] = 4,
+    ACTIONS(648), 1,
+      anon_sym_LBRACK,
+    ACTIONS(732), 1,
+      sym_identifier,
+    STATE(201), 1,
+      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5594] = 5,
-    ACTIONS(512), 1,
-      ts_builtin_sym_end,
-    ACTIONS(514), 1,
-      anon_sym_LF,
-    STATE(93), 1,
-      aux_sym__linebreak,
-    STATE(153), 1,
-      aux_sym_source_file_repeat1,
+
+  [9007] = 4,
+    ACTIONS(648), 1,
+      anon_sym_LBRACK,
+    ACTIONS(734), 1,
+      sym_identifier,
+    STATE(201), 1,
+      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5611] = 5,
-    ACTIONS(17), 1,
-      anon_sym_LBRACE,
-    ACTIONS(516), 1,
-      anon_sym_POUND_LPAREN,
-    STATE(195), 1,
-      sym_template_declaration_arguments,
-    STATE(205), 1,
-      sym_block,
+
+  [9021] = 4,
+    ACTIONS(494), 1,
+      anon_sym_LF,
+    ACTIONS(736), 1,
+      anon_sym_RPAREN,
+    STATE(155), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5628] = 5,
-    ACTIONS(17), 1,
-      anon_sym_LBRACE,
-    ACTIONS(516), 1,
-      anon_sym_POUND_LPAREN,
-    STATE(199), 1,
-      sym_template_declaration_arguments,
-    STATE(206), 1,
-      sym_block,
+
+  [9035] = 4,
+    ACTIONS(648), 1,
+      anon_sym_LBRACK,
+    ACTIONS(738), 1,
+      sym_identifier,
+    STATE(201), 1,
+      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5645] = 2,
+
+  [9049] = 4,
+    ACTIONS(648), 1,
+      anon_sym_LBRACK,
+    ACTIONS(740), 1,
+      sym_identifier,
+    STATE(201), 1,
+      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(518), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
-      anon_sym_LF,
-  [5656] = 5,
-    ACTIONS(434), 1,
-      anon_sym_RBRACE,
-    ACTIONS(436), 1,
+
+  [9063] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-    STATE(7), 1,
+    ACTIONS(742), 1,
+      anon_sym_RPAREN,
+    STATE(155), 1,
       aux_sym__linebreak,
-    STATE(154), 1,
-      aux_sym_block_repeat1,
-    ACTIONS(3), 2,
-      sym_single_line_comment,
-      sym_multi_line_comment,
-  [5673] = 3,
-    ACTIONS(522), 1,
-      anon_sym_COLON,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(520), 3,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
+
+  [9077] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-  [5686] = 4,
-    ACTIONS(319), 1,
-      anon_sym_DASH_GT,
-    STATE(202), 1,
-      sym__interface_ports_output,
+    ACTIONS(744), 1,
+      anon_sym_RPAREN,
+    STATE(155), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(524), 2,
-      anon_sym_RBRACE,
+
+  [9091] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-  [5701] = 2,
+    ACTIONS(746), 1,
+      anon_sym_RPAREN,
+    STATE(155), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(526), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
-      anon_sym_LF,
-  [5712] = 2,
+
+  [9105] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(528), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
+    ACTIONS(748), 3,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
       anon_sym_LF,
-  [5723] = 2,
+
+  [9115] = 4,
+    ACTIONS(648), 1,
+      anon_sym_LBRACK,
+    ACTIONS(750), 1,
+      sym_identifier,
+    STATE(201), 1,
+      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(526), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
+
+  [9129] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-  [5734] = 5,
-    ACTIONS(223), 1,
-      anon_sym_COMMA,
-    ACTIONS(530), 1,
-      anon_sym_RPAREN,
-    STATE(75), 1,
-      sym__comma,
-    STATE(171), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
+    ACTIONS(752), 1,
+      anon_sym_RBRACE,
+    STATE(155), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5751] = 2,
+
+  [9143] = 3,
+    ACTIONS(624), 1,
+      anon_sym_EQ,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(532), 4,
-      ts_builtin_sym_end,
+    ACTIONS(754), 2,
       anon_sym_RBRACE,
-      anon_sym_else,
       anon_sym_LF,
-  [5762] = 2,
+
+  [9155] = 3,
+    ACTIONS(758), 1,
+      anon_sym_else,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(532), 4,
-      ts_builtin_sym_end,
+    ACTIONS(756), 2,
       anon_sym_RBRACE,
-      anon_sym_else,
       anon_sym_LF,
-  [5773] = 4,
-    ACTIONS(11), 1,
-      anon_sym_const,
-    STATE(224), 1,
-      sym_const_and_type,
-    ACTIONS(3), 2,
-      sym_single_line_comment,
-      sym_multi_line_comment,
-    ACTIONS(534), 2,
-      anon_sym_module,
-      anon_sym_struct,
-  [5788] = 4,
-    ACTIONS(319), 1,
-      anon_sym_DASH_GT,
-    STATE(210), 1,
-      sym__interface_ports_output,
+
+  [9167] = 4,
+    ACTIONS(494), 1,
+      anon_sym_LF,
+    ACTIONS(760), 1,
+      anon_sym_RPAREN,
+    STATE(155), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(536), 2,
-      anon_sym_RBRACE,
+
+  [9181] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-  [5803] = 5,
-    ACTIONS(538), 1,
+    ACTIONS(762), 1,
       anon_sym_RPAREN,
-    ACTIONS(540), 1,
-      anon_sym_COMMA,
-    STATE(75), 1,
-      sym__comma,
-    STATE(171), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(155), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5820] = 2,
+
+  [9195] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(543), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
+    ACTIONS(764), 3,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
       anon_sym_LF,
-  [5831] = 2,
+
+  [9205] = 4,
+    ACTIONS(494), 1,
+      anon_sym_LF,
+    ACTIONS(766), 1,
+      anon_sym_RPAREN,
+    STATE(155), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(543), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
+
+  [9219] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-  [5842] = 2,
+    ACTIONS(768), 1,
+      anon_sym_RBRACE,
+    STATE(155), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(545), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
+
+  [9233] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-  [5853] = 3,
-    ACTIONS(549), 1,
-      anon_sym_else,
+    ACTIONS(770), 1,
+      anon_sym_RBRACE,
+    STATE(155), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(547), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [5865] = 4,
-    ACTIONS(339), 1,
+
+  [9247] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-    ACTIONS(551), 1,
+    ACTIONS(772), 1,
       anon_sym_RPAREN,
-    STATE(98), 1,
+    STATE(155), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5879] = 4,
-    ACTIONS(102), 1,
-      anon_sym_LBRACK,
-    ACTIONS(553), 1,
-      sym_identifier,
-    STATE(134), 1,
-      sym_array_bracket_expression,
-    ACTIONS(3), 2,
-      sym_single_line_comment,
-      sym_multi_line_comment,
-  [5893] = 4,
-    ACTIONS(339), 1,
+
+  [9261] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-    ACTIONS(555), 1,
-      anon_sym_RPAREN,
-    STATE(98), 1,
+    ACTIONS(774), 1,
+      anon_sym_RBRACE,
+    STATE(155), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5907] = 2,
+
+  [9275] = 4,
+    ACTIONS(515), 1,
+      anon_sym_in,
+    ACTIONS(776), 1,
+      anon_sym_SQUOTE,
+    STATE(170), 1,
+      sym_latency_specifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(557), 3,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [5917] = 4,
-    ACTIONS(102), 1,
+
+  [9289] = 4,
+    ACTIONS(648), 1,
       anon_sym_LBRACK,
-    ACTIONS(559), 1,
+    ACTIONS(778), 1,
       sym_identifier,
-    STATE(134), 1,
+    STATE(201), 1,
       sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5931] = 4,
-    ACTIONS(102), 1,
-      anon_sym_LBRACK,
-    ACTIONS(561), 1,
-      sym_identifier,
-    STATE(134), 1,
-      sym_array_bracket_expression,
+
+  [9303] = 4,
+    ACTIONS(519), 1,
+      anon_sym_in,
+    ACTIONS(776), 1,
+      anon_sym_SQUOTE,
+    STATE(171), 1,
+      sym_latency_specifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5945] = 4,
-    ACTIONS(102), 1,
+
+  [9317] = 4,
+    ACTIONS(648), 1,
       anon_sym_LBRACK,
-    ACTIONS(563), 1,
+    ACTIONS(780), 1,
       sym_identifier,
-    STATE(134), 1,
+    STATE(201), 1,
       sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5959] = 4,
-    ACTIONS(339), 1,
-      anon_sym_LF,
-    ACTIONS(565), 1,
-      anon_sym_RPAREN,
-    STATE(98), 1,
-      aux_sym__linebreak,
-    ACTIONS(3), 2,
-      sym_single_line_comment,
-      sym_multi_line_comment,
-  [5973] = 2,
+
+  [9331] = 4,
+    ACTIONS(521), 1,
+      anon_sym_in,
+    ACTIONS(776), 1,
+      anon_sym_SQUOTE,
+    STATE(172), 1,
+      sym_latency_specifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(567), 3,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [5983] = 4,
-    ACTIONS(339), 1,
-      anon_sym_LF,
-    ACTIONS(569), 1,
-      anon_sym_RPAREN,
-    STATE(98), 1,
-      aux_sym__linebreak,
+
+  [9345] = 4,
+    ACTIONS(648), 1,
+      anon_sym_LBRACK,
+    ACTIONS(782), 1,
+      sym_identifier,
+    STATE(201), 1,
+      sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [5997] = 4,
-    ACTIONS(339), 1,
+
+  [9359] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-    ACTIONS(571), 1,
+    ACTIONS(784), 1,
       anon_sym_RPAREN,
-    STATE(98), 1,
+    STATE(155), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6011] = 4,
-    ACTIONS(339), 1,
-      anon_sym_LF,
-    ACTIONS(573), 1,
-      anon_sym_RPAREN,
-    STATE(98), 1,
-      aux_sym__linebreak,
+
+  [9373] = 4,
+    ACTIONS(523), 1,
+      anon_sym_in,
+    ACTIONS(776), 1,
+      anon_sym_SQUOTE,
+    STATE(173), 1,
+      sym_latency_specifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6025] = 4,
-    ACTIONS(102), 1,
+
+  [9387] = 4,
+    ACTIONS(648), 1,
       anon_sym_LBRACK,
-    ACTIONS(575), 1,
+    ACTIONS(786), 1,
       sym_identifier,
-    STATE(134), 1,
+    STATE(201), 1,
       sym_array_bracket_expression,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6039] = 4,
-    ACTIONS(339), 1,
+
+  [9401] = 4,
+    ACTIONS(494), 1,
+      anon_sym_LF,
+    ACTIONS(788), 1,
+      anon_sym_RPAREN,
+    STATE(155), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+
+  [9415] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-    ACTIONS(577), 1,
+    ACTIONS(790), 1,
       anon_sym_RPAREN,
-    STATE(98), 1,
+    STATE(155), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6053] = 4,
-    ACTIONS(339), 1,
+
+  [9429] = 4,
+    ACTIONS(494), 1,
       anon_sym_LF,
-    ACTIONS(579), 1,
+    ACTIONS(792), 1,
       anon_sym_RPAREN,
-    STATE(98), 1,
+    STATE(155), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6067] = 3,
-    ACTIONS(426), 1,
-      anon_sym_EQ,
+
+  [9443] = 3,
+    ACTIONS(301), 1,
+      sym_identifier,
+    STATE(16), 1,
+      sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(581), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [6079] = 2,
+
+  [9454] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(583), 2,
+    ACTIONS(794), 2,
       ts_builtin_sym_end,
       anon_sym_LF,
-  [6088] = 3,
-    ACTIONS(430), 1,
-      sym_identifier,
-    STATE(179), 1,
-      sym_template_arg,
+
+  [9463] = 3,
+    ACTIONS(362), 1,
+      anon_sym_LBRACE,
+    STATE(283), 1,
+      sym_block,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6099] = 2,
+
+  [9474] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(585), 2,
+    ACTIONS(796), 2,
       ts_builtin_sym_end,
       anon_sym_LF,
-  [6108] = 3,
-    ACTIONS(17), 1,
+
+  [9483] = 3,
+    ACTIONS(362), 1,
       anon_sym_LBRACE,
-    STATE(194), 1,
+    STATE(284), 1,
       sym_block,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6119] = 3,
-    ACTIONS(248), 1,
-      sym_identifier,
-    STATE(15), 1,
-      sym_namespace_list,
+
+  [9494] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6130] = 2,
+    ACTIONS(798), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+
+  [9503] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(587), 2,
-      anon_sym_RBRACE,
+    ACTIONS(800), 2,
+      ts_builtin_sym_end,
       anon_sym_LF,
-  [6139] = 2,
+
+  [9512] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(589), 2,
-      anon_sym_RBRACE,
+    ACTIONS(802), 2,
+      ts_builtin_sym_end,
       anon_sym_LF,
-  [6148] = 3,
-    ACTIONS(17), 1,
-      anon_sym_LBRACE,
-    STATE(192), 1,
-      sym_block,
+
+  [9521] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6159] = 2,
+    ACTIONS(804), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+
+  [9530] = 3,
+    ACTIONS(616), 1,
+      sym_identifier,
+    STATE(259), 1,
+      sym_template_arg,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(591), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [6168] = 2,
+
+  [9541] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(593), 2,
+    ACTIONS(754), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [6177] = 2,
+
+  [9550] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(595), 2,
+    ACTIONS(806), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [6186] = 2,
+
+  [9559] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(597), 2,
+    ACTIONS(808), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [6195] = 2,
+
+  [9568] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(599), 2,
+    ACTIONS(810), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [6204] = 2,
+
+  [9577] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(601), 2,
-      ts_builtin_sym_end,
+    ACTIONS(812), 2,
+      anon_sym_RBRACE,
       anon_sym_LF,
-  [6213] = 2,
+
+  [9586] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(603), 2,
-      ts_builtin_sym_end,
+    ACTIONS(814), 2,
+      anon_sym_RBRACE,
       anon_sym_LF,
-  [6222] = 2,
+
+  [9595] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(605), 2,
-      ts_builtin_sym_end,
+    ACTIONS(816), 2,
+      anon_sym_RBRACE,
       anon_sym_LF,
-  [6231] = 2,
+
+  [9604] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(607), 2,
+    ACTIONS(818), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [6240] = 2,
+
+  [9613] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(581), 2,
+    ACTIONS(820), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [6249] = 2,
+
+  [9622] = 2,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(609), 2,
+    ACTIONS(822), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [6258] = 2,
+
+  [9631] = 3,
+    ACTIONS(382), 1,
+      sym_identifier,
+    STATE(126), 1,
+      sym_namespace_list,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-    ACTIONS(611), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [6267] = 2,
-    ACTIONS(613), 1,
-      anon_sym_LBRACE,
+
+  [9642] = 2,
+    ACTIONS(824), 1,
+      ts_builtin_sym_end,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6275] = 2,
-    ACTIONS(615), 1,
-      anon_sym_in,
+
+  [9650] = 2,
+    ACTIONS(826), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6283] = 2,
-    ACTIONS(617), 1,
-      anon_sym_LBRACE,
+
+  [9658] = 2,
+    ACTIONS(828), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6291] = 2,
-    ACTIONS(619), 1,
+
+  [9666] = 2,
+    ACTIONS(830), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6299] = 2,
-    ACTIONS(621), 1,
+
+  [9674] = 2,
+    ACTIONS(832), 1,
       anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6307] = 2,
-    ACTIONS(623), 1,
-      anon_sym_LBRACE,
+
+  [9682] = 2,
+    ACTIONS(834), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6315] = 2,
-    ACTIONS(625), 1,
-      anon_sym_LBRACE,
+
+  [9690] = 2,
+    ACTIONS(836), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6323] = 2,
-    ACTIONS(627), 1,
+
+  [9698] = 2,
+    ACTIONS(838), 1,
       anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6331] = 2,
-    ACTIONS(629), 1,
+
+  [9706] = 2,
+    ACTIONS(840), 1,
       anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6339] = 2,
-    ACTIONS(631), 1,
+
+  [9714] = 2,
+    ACTIONS(842), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6347] = 2,
-    ACTIONS(633), 1,
+
+  [9722] = 2,
+    ACTIONS(844), 1,
+      anon_sym_in,
+    ACTIONS(3), 2,
+      sym_single_line_comment,
+      sym_multi_line_comment,
+
+  [9730] = 2,
+    ACTIONS(846), 1,
       anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6355] = 2,
-    ACTIONS(635), 1,
-      sym_identifier,
+
+  [9738] = 2,
+    ACTIONS(848), 1,
+      anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6363] = 2,
-    ACTIONS(637), 1,
-      sym_identifier,
+
+  [9746] = 2,
+    ACTIONS(850), 1,
+      anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6371] = 2,
-    ACTIONS(639), 1,
-      ts_builtin_sym_end,
+
+  [9754] = 2,
+    ACTIONS(852), 1,
+      anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6379] = 2,
-    ACTIONS(641), 1,
-      sym_identifier,
+
+  [9762] = 2,
+    ACTIONS(854), 1,
+      anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6387] = 2,
-    ACTIONS(643), 1,
+
+  [9770] = 2,
+    ACTIONS(856), 1,
       anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6395] = 2,
-    ACTIONS(645), 1,
+
+  [9778] = 2,
+    ACTIONS(858), 1,
       anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_single_line_comment,
       sym_multi_line_comment,
-  [6403] = 2,
-    ACTIONS(647), 1,
+
+  [9786] = 2,
+    ACTIONS(860), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym_single_line_comment,
@@ -7296,233 +10614,320 @@ static const uint16_t ts_small_parse_table[] = {
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 107,
-  [SMALL_STATE(4)] = 214,
-  [SMALL_STATE(5)] = 321,
-  [SMALL_STATE(6)] = 428,
-  [SMALL_STATE(7)] = 535,
-  [SMALL_STATE(8)] = 642,
-  [SMALL_STATE(9)] = 749,
-  [SMALL_STATE(10)] = 856,
-  [SMALL_STATE(11)] = 960,
-  [SMALL_STATE(12)] = 1031,
-  [SMALL_STATE(13)] = 1075,
-  [SMALL_STATE(14)] = 1119,
-  [SMALL_STATE(15)] = 1163,
-  [SMALL_STATE(16)] = 1206,
-  [SMALL_STATE(17)] = 1245,
-  [SMALL_STATE(18)] = 1288,
-  [SMALL_STATE(19)] = 1336,
-  [SMALL_STATE(20)] = 1392,
-  [SMALL_STATE(21)] = 1450,
-  [SMALL_STATE(22)] = 1498,
-  [SMALL_STATE(23)] = 1550,
-  [SMALL_STATE(24)] = 1612,
-  [SMALL_STATE(25)] = 1672,
-  [SMALL_STATE(26)] = 1713,
-  [SMALL_STATE(27)] = 1750,
-  [SMALL_STATE(28)] = 1787,
-  [SMALL_STATE(29)] = 1824,
-  [SMALL_STATE(30)] = 1861,
-  [SMALL_STATE(31)] = 1898,
-  [SMALL_STATE(32)] = 1935,
-  [SMALL_STATE(33)] = 1972,
-  [SMALL_STATE(34)] = 2009,
-  [SMALL_STATE(35)] = 2046,
-  [SMALL_STATE(36)] = 2083,
-  [SMALL_STATE(37)] = 2120,
-  [SMALL_STATE(38)] = 2157,
-  [SMALL_STATE(39)] = 2194,
-  [SMALL_STATE(40)] = 2230,
-  [SMALL_STATE(41)] = 2266,
-  [SMALL_STATE(42)] = 2302,
-  [SMALL_STATE(43)] = 2338,
-  [SMALL_STATE(44)] = 2374,
-  [SMALL_STATE(45)] = 2410,
-  [SMALL_STATE(46)] = 2446,
-  [SMALL_STATE(47)] = 2502,
-  [SMALL_STATE(48)] = 2565,
-  [SMALL_STATE(49)] = 2627,
-  [SMALL_STATE(50)] = 2685,
-  [SMALL_STATE(51)] = 2743,
-  [SMALL_STATE(52)] = 2778,
-  [SMALL_STATE(53)] = 2833,
-  [SMALL_STATE(54)] = 2889,
-  [SMALL_STATE(55)] = 2931,
-  [SMALL_STATE(56)] = 2973,
-  [SMALL_STATE(57)] = 3029,
-  [SMALL_STATE(58)] = 3083,
-  [SMALL_STATE(59)] = 3137,
-  [SMALL_STATE(60)] = 3176,
-  [SMALL_STATE(61)] = 3215,
-  [SMALL_STATE(62)] = 3254,
-  [SMALL_STATE(63)] = 3293,
-  [SMALL_STATE(64)] = 3332,
-  [SMALL_STATE(65)] = 3371,
-  [SMALL_STATE(66)] = 3410,
-  [SMALL_STATE(67)] = 3449,
-  [SMALL_STATE(68)] = 3488,
-  [SMALL_STATE(69)] = 3527,
-  [SMALL_STATE(70)] = 3566,
-  [SMALL_STATE(71)] = 3605,
-  [SMALL_STATE(72)] = 3644,
-  [SMALL_STATE(73)] = 3683,
-  [SMALL_STATE(74)] = 3736,
-  [SMALL_STATE(75)] = 3789,
-  [SMALL_STATE(76)] = 3828,
-  [SMALL_STATE(77)] = 3881,
-  [SMALL_STATE(78)] = 3913,
-  [SMALL_STATE(79)] = 3945,
-  [SMALL_STATE(80)] = 3975,
-  [SMALL_STATE(81)] = 4005,
-  [SMALL_STATE(82)] = 4030,
-  [SMALL_STATE(83)] = 4075,
-  [SMALL_STATE(84)] = 4120,
-  [SMALL_STATE(85)] = 4160,
-  [SMALL_STATE(86)] = 4184,
-  [SMALL_STATE(87)] = 4224,
-  [SMALL_STATE(88)] = 4263,
-  [SMALL_STATE(89)] = 4302,
-  [SMALL_STATE(90)] = 4333,
-  [SMALL_STATE(91)] = 4363,
-  [SMALL_STATE(92)] = 4393,
-  [SMALL_STATE(93)] = 4424,
-  [SMALL_STATE(94)] = 4455,
-  [SMALL_STATE(95)] = 4486,
-  [SMALL_STATE(96)] = 4517,
-  [SMALL_STATE(97)] = 4548,
-  [SMALL_STATE(98)] = 4576,
-  [SMALL_STATE(99)] = 4596,
-  [SMALL_STATE(100)] = 4616,
-  [SMALL_STATE(101)] = 4636,
-  [SMALL_STATE(102)] = 4656,
-  [SMALL_STATE(103)] = 4676,
-  [SMALL_STATE(104)] = 4697,
-  [SMALL_STATE(105)] = 4720,
-  [SMALL_STATE(106)] = 4734,
-  [SMALL_STATE(107)] = 4748,
-  [SMALL_STATE(108)] = 4762,
-  [SMALL_STATE(109)] = 4776,
-  [SMALL_STATE(110)] = 4799,
-  [SMALL_STATE(111)] = 4818,
-  [SMALL_STATE(112)] = 4841,
-  [SMALL_STATE(113)] = 4860,
-  [SMALL_STATE(114)] = 4879,
-  [SMALL_STATE(115)] = 4898,
-  [SMALL_STATE(116)] = 4921,
-  [SMALL_STATE(117)] = 4944,
-  [SMALL_STATE(118)] = 4963,
-  [SMALL_STATE(119)] = 4986,
-  [SMALL_STATE(120)] = 5009,
-  [SMALL_STATE(121)] = 5028,
-  [SMALL_STATE(122)] = 5047,
-  [SMALL_STATE(123)] = 5070,
-  [SMALL_STATE(124)] = 5089,
-  [SMALL_STATE(125)] = 5108,
-  [SMALL_STATE(126)] = 5131,
-  [SMALL_STATE(127)] = 5150,
-  [SMALL_STATE(128)] = 5170,
-  [SMALL_STATE(129)] = 5190,
-  [SMALL_STATE(130)] = 5210,
-  [SMALL_STATE(131)] = 5228,
-  [SMALL_STATE(132)] = 5244,
-  [SMALL_STATE(133)] = 5262,
-  [SMALL_STATE(134)] = 5282,
-  [SMALL_STATE(135)] = 5294,
-  [SMALL_STATE(136)] = 5310,
-  [SMALL_STATE(137)] = 5321,
-  [SMALL_STATE(138)] = 5332,
-  [SMALL_STATE(139)] = 5343,
-  [SMALL_STATE(140)] = 5360,
-  [SMALL_STATE(141)] = 5371,
-  [SMALL_STATE(142)] = 5386,
-  [SMALL_STATE(143)] = 5397,
-  [SMALL_STATE(144)] = 5414,
-  [SMALL_STATE(145)] = 5431,
-  [SMALL_STATE(146)] = 5442,
-  [SMALL_STATE(147)] = 5459,
-  [SMALL_STATE(148)] = 5476,
-  [SMALL_STATE(149)] = 5493,
-  [SMALL_STATE(150)] = 5510,
-  [SMALL_STATE(151)] = 5521,
-  [SMALL_STATE(152)] = 5532,
-  [SMALL_STATE(153)] = 5543,
-  [SMALL_STATE(154)] = 5560,
-  [SMALL_STATE(155)] = 5577,
-  [SMALL_STATE(156)] = 5594,
-  [SMALL_STATE(157)] = 5611,
-  [SMALL_STATE(158)] = 5628,
-  [SMALL_STATE(159)] = 5645,
-  [SMALL_STATE(160)] = 5656,
-  [SMALL_STATE(161)] = 5673,
-  [SMALL_STATE(162)] = 5686,
-  [SMALL_STATE(163)] = 5701,
-  [SMALL_STATE(164)] = 5712,
-  [SMALL_STATE(165)] = 5723,
-  [SMALL_STATE(166)] = 5734,
-  [SMALL_STATE(167)] = 5751,
-  [SMALL_STATE(168)] = 5762,
-  [SMALL_STATE(169)] = 5773,
-  [SMALL_STATE(170)] = 5788,
-  [SMALL_STATE(171)] = 5803,
-  [SMALL_STATE(172)] = 5820,
-  [SMALL_STATE(173)] = 5831,
-  [SMALL_STATE(174)] = 5842,
-  [SMALL_STATE(175)] = 5853,
-  [SMALL_STATE(176)] = 5865,
-  [SMALL_STATE(177)] = 5879,
-  [SMALL_STATE(178)] = 5893,
-  [SMALL_STATE(179)] = 5907,
-  [SMALL_STATE(180)] = 5917,
-  [SMALL_STATE(181)] = 5931,
-  [SMALL_STATE(182)] = 5945,
-  [SMALL_STATE(183)] = 5959,
-  [SMALL_STATE(184)] = 5973,
-  [SMALL_STATE(185)] = 5983,
-  [SMALL_STATE(186)] = 5997,
-  [SMALL_STATE(187)] = 6011,
-  [SMALL_STATE(188)] = 6025,
-  [SMALL_STATE(189)] = 6039,
-  [SMALL_STATE(190)] = 6053,
-  [SMALL_STATE(191)] = 6067,
-  [SMALL_STATE(192)] = 6079,
-  [SMALL_STATE(193)] = 6088,
-  [SMALL_STATE(194)] = 6099,
-  [SMALL_STATE(195)] = 6108,
-  [SMALL_STATE(196)] = 6119,
-  [SMALL_STATE(197)] = 6130,
-  [SMALL_STATE(198)] = 6139,
-  [SMALL_STATE(199)] = 6148,
-  [SMALL_STATE(200)] = 6159,
-  [SMALL_STATE(201)] = 6168,
-  [SMALL_STATE(202)] = 6177,
-  [SMALL_STATE(203)] = 6186,
-  [SMALL_STATE(204)] = 6195,
-  [SMALL_STATE(205)] = 6204,
-  [SMALL_STATE(206)] = 6213,
-  [SMALL_STATE(207)] = 6222,
-  [SMALL_STATE(208)] = 6231,
-  [SMALL_STATE(209)] = 6240,
-  [SMALL_STATE(210)] = 6249,
-  [SMALL_STATE(211)] = 6258,
-  [SMALL_STATE(212)] = 6267,
-  [SMALL_STATE(213)] = 6275,
-  [SMALL_STATE(214)] = 6283,
-  [SMALL_STATE(215)] = 6291,
-  [SMALL_STATE(216)] = 6299,
-  [SMALL_STATE(217)] = 6307,
-  [SMALL_STATE(218)] = 6315,
-  [SMALL_STATE(219)] = 6323,
-  [SMALL_STATE(220)] = 6331,
-  [SMALL_STATE(221)] = 6339,
-  [SMALL_STATE(222)] = 6347,
-  [SMALL_STATE(223)] = 6355,
-  [SMALL_STATE(224)] = 6363,
-  [SMALL_STATE(225)] = 6371,
-  [SMALL_STATE(226)] = 6379,
-  [SMALL_STATE(227)] = 6387,
-  [SMALL_STATE(228)] = 6395,
-  [SMALL_STATE(229)] = 6403,
+  [SMALL_STATE(3)] = 109,
+  [SMALL_STATE(4)] = 218,
+  [SMALL_STATE(5)] = 327,
+  [SMALL_STATE(6)] = 436,
+  [SMALL_STATE(7)] = 545,
+  [SMALL_STATE(8)] = 654,
+  [SMALL_STATE(9)] = 763,
+  [SMALL_STATE(10)] = 872,
+  [SMALL_STATE(11)] = 978,
+  [SMALL_STATE(12)] = 1054,
+  [SMALL_STATE(13)] = 1099,
+  [SMALL_STATE(14)] = 1144,
+  [SMALL_STATE(15)] = 1189,
+  [SMALL_STATE(16)] = 1233,
+  [SMALL_STATE(17)] = 1277,
+  [SMALL_STATE(18)] = 1317,
+  [SMALL_STATE(19)] = 1367,
+  [SMALL_STATE(20)] = 1431,
+  [SMALL_STATE(21)] = 1493,
+  [SMALL_STATE(22)] = 1553,
+  [SMALL_STATE(23)] = 1611,
+  [SMALL_STATE(24)] = 1665,
+  [SMALL_STATE(25)] = 1715,
+  [SMALL_STATE(26)] = 1785,
+  [SMALL_STATE(27)] = 1846,
+  [SMALL_STATE(28)] = 1884,
+  [SMALL_STATE(29)] = 1922,
+  [SMALL_STATE(30)] = 1960,
+  [SMALL_STATE(31)] = 1998,
+  [SMALL_STATE(32)] = 2036,
+  [SMALL_STATE(33)] = 2074,
+  [SMALL_STATE(34)] = 2112,
+  [SMALL_STATE(35)] = 2150,
+  [SMALL_STATE(36)] = 2188,
+  [SMALL_STATE(37)] = 2226,
+  [SMALL_STATE(38)] = 2264,
+  [SMALL_STATE(39)] = 2302,
+  [SMALL_STATE(40)] = 2340,
+  [SMALL_STATE(41)] = 2378,
+  [SMALL_STATE(42)] = 2416,
+  [SMALL_STATE(43)] = 2454,
+  [SMALL_STATE(44)] = 2492,
+  [SMALL_STATE(45)] = 2530,
+  [SMALL_STATE(46)] = 2568,
+  [SMALL_STATE(47)] = 2606,
+  [SMALL_STATE(48)] = 2644,
+  [SMALL_STATE(49)] = 2682,
+  [SMALL_STATE(50)] = 2720,
+  [SMALL_STATE(51)] = 2758,
+  [SMALL_STATE(52)] = 2796,
+  [SMALL_STATE(53)] = 2834,
+  [SMALL_STATE(54)] = 2872,
+  [SMALL_STATE(55)] = 2910,
+  [SMALL_STATE(56)] = 2948,
+  [SMALL_STATE(57)] = 3019,
+  [SMALL_STATE(58)] = 3090,
+  [SMALL_STATE(59)] = 3131,
+  [SMALL_STATE(60)] = 3203,
+  [SMALL_STATE(61)] = 3275,
+  [SMALL_STATE(62)] = 3341,
+  [SMALL_STATE(63)] = 3403,
+  [SMALL_STATE(64)] = 3453,
+  [SMALL_STATE(65)] = 3515,
+  [SMALL_STATE(66)] = 3565,
+  [SMALL_STATE(67)] = 3631,
+  [SMALL_STATE(68)] = 3691,
+  [SMALL_STATE(69)] = 3738,
+  [SMALL_STATE(70)] = 3785,
+  [SMALL_STATE(71)] = 3844,
+  [SMALL_STATE(72)] = 3888,
+  [SMALL_STATE(73)] = 3932,
+  [SMALL_STATE(74)] = 3968,
+  [SMALL_STATE(75)] = 4012,
+  [SMALL_STATE(76)] = 4056,
+  [SMALL_STATE(77)] = 4100,
+  [SMALL_STATE(78)] = 4144,
+  [SMALL_STATE(79)] = 4188,
+  [SMALL_STATE(80)] = 4232,
+  [SMALL_STATE(81)] = 4276,
+  [SMALL_STATE(82)] = 4320,
+  [SMALL_STATE(83)] = 4364,
+  [SMALL_STATE(84)] = 4408,
+  [SMALL_STATE(85)] = 4452,
+  [SMALL_STATE(86)] = 4512,
+  [SMALL_STATE(87)] = 4556,
+  [SMALL_STATE(88)] = 4600,
+  [SMALL_STATE(89)] = 4658,
+  [SMALL_STATE(90)] = 4718,
+  [SMALL_STATE(91)] = 4762,
+  [SMALL_STATE(92)] = 4806,
+  [SMALL_STATE(93)] = 4850,
+  [SMALL_STATE(94)] = 4894,
+  [SMALL_STATE(95)] = 4954,
+  [SMALL_STATE(96)] = 4998,
+  [SMALL_STATE(97)] = 5042,
+  [SMALL_STATE(98)] = 5086,
+  [SMALL_STATE(99)] = 5130,
+  [SMALL_STATE(100)] = 5174,
+  [SMALL_STATE(101)] = 5218,
+  [SMALL_STATE(102)] = 5262,
+  [SMALL_STATE(103)] = 5306,
+  [SMALL_STATE(104)] = 5366,
+  [SMALL_STATE(105)] = 5410,
+  [SMALL_STATE(106)] = 5454,
+  [SMALL_STATE(107)] = 5498,
+  [SMALL_STATE(108)] = 5555,
+  [SMALL_STATE(109)] = 5612,
+  [SMALL_STATE(110)] = 5669,
+  [SMALL_STATE(111)] = 5726,
+  [SMALL_STATE(112)] = 5783,
+  [SMALL_STATE(113)] = 5818,
+  [SMALL_STATE(114)] = 5853,
+  [SMALL_STATE(115)] = 5888,
+  [SMALL_STATE(116)] = 5941,
+  [SMALL_STATE(117)] = 5992,
+  [SMALL_STATE(118)] = 6041,
+  [SMALL_STATE(119)] = 6088,
+  [SMALL_STATE(120)] = 6133,
+  [SMALL_STATE(121)] = 6174,
+  [SMALL_STATE(122)] = 6215,
+  [SMALL_STATE(123)] = 6272,
+  [SMALL_STATE(124)] = 6329,
+  [SMALL_STATE(125)] = 6386,
+  [SMALL_STATE(126)] = 6420,
+  [SMALL_STATE(127)] = 6454,
+  [SMALL_STATE(128)] = 6484,
+  [SMALL_STATE(129)] = 6517,
+  [SMALL_STATE(130)] = 6550,
+  [SMALL_STATE(131)] = 6578,
+  [SMALL_STATE(132)] = 6606,
+  [SMALL_STATE(133)] = 6634,
+  [SMALL_STATE(134)] = 6662,
+  [SMALL_STATE(135)] = 6690,
+  [SMALL_STATE(136)] = 6718,
+  [SMALL_STATE(137)] = 6746,
+  [SMALL_STATE(138)] = 6774,
+  [SMALL_STATE(139)] = 6802,
+  [SMALL_STATE(140)] = 6830,
+  [SMALL_STATE(141)] = 6858,
+  [SMALL_STATE(142)] = 6886,
+  [SMALL_STATE(143)] = 6914,
+  [SMALL_STATE(144)] = 6945,
+  [SMALL_STATE(145)] = 6976,
+  [SMALL_STATE(146)] = 7002,
+  [SMALL_STATE(147)] = 7027,
+  [SMALL_STATE(148)] = 7072,
+  [SMALL_STATE(149)] = 7117,
+  [SMALL_STATE(150)] = 7157,
+  [SMALL_STATE(151)] = 7197,
+  [SMALL_STATE(152)] = 7236,
+  [SMALL_STATE(153)] = 7275,
+  [SMALL_STATE(154)] = 7306,
+  [SMALL_STATE(155)] = 7338,
+  [SMALL_STATE(156)] = 7360,
+  [SMALL_STATE(157)] = 7392,
+  [SMALL_STATE(158)] = 7424,
+  [SMALL_STATE(159)] = 7456,
+  [SMALL_STATE(160)] = 7488,
+  [SMALL_STATE(161)] = 7518,
+  [SMALL_STATE(162)] = 7548,
+  [SMALL_STATE(163)] = 7577,
+  [SMALL_STATE(164)] = 7598,
+  [SMALL_STATE(165)] = 7621,
+  [SMALL_STATE(166)] = 7640,
+  [SMALL_STATE(167)] = 7659,
+  [SMALL_STATE(168)] = 7678,
+  [SMALL_STATE(169)] = 7697,
+  [SMALL_STATE(170)] = 7720,
+  [SMALL_STATE(171)] = 7734,
+  [SMALL_STATE(172)] = 7748,
+  [SMALL_STATE(173)] = 7762,
+  [SMALL_STATE(174)] = 7776,
+  [SMALL_STATE(175)] = 7795,
+  [SMALL_STATE(176)] = 7818,
+  [SMALL_STATE(177)] = 7837,
+  [SMALL_STATE(178)] = 7856,
+  [SMALL_STATE(179)] = 7879,
+  [SMALL_STATE(180)] = 7902,
+  [SMALL_STATE(181)] = 7925,
+  [SMALL_STATE(182)] = 7944,
+  [SMALL_STATE(183)] = 7963,
+  [SMALL_STATE(184)] = 7986,
+  [SMALL_STATE(185)] = 8009,
+  [SMALL_STATE(186)] = 8032,
+  [SMALL_STATE(187)] = 8055,
+  [SMALL_STATE(188)] = 8074,
+  [SMALL_STATE(189)] = 8097,
+  [SMALL_STATE(190)] = 8116,
+  [SMALL_STATE(191)] = 8139,
+  [SMALL_STATE(192)] = 8158,
+  [SMALL_STATE(193)] = 8177,
+  [SMALL_STATE(194)] = 8196,
+  [SMALL_STATE(195)] = 8215,
+  [SMALL_STATE(196)] = 8238,
+  [SMALL_STATE(197)] = 8257,
+  [SMALL_STATE(198)] = 8280,
+  [SMALL_STATE(199)] = 8303,
+  [SMALL_STATE(200)] = 8322,
+  [SMALL_STATE(201)] = 8345,
+  [SMALL_STATE(202)] = 8357,
+  [SMALL_STATE(203)] = 8377,
+  [SMALL_STATE(204)] = 8397,
+  [SMALL_STATE(205)] = 8417,
+  [SMALL_STATE(206)] = 8437,
+  [SMALL_STATE(207)] = 8455,
+  [SMALL_STATE(208)] = 8473,
+  [SMALL_STATE(209)] = 8489,
+  [SMALL_STATE(210)] = 8505,
+  [SMALL_STATE(211)] = 8525,
+  [SMALL_STATE(212)] = 8545,
+  [SMALL_STATE(213)] = 8562,
+  [SMALL_STATE(214)] = 8577,
+  [SMALL_STATE(215)] = 8594,
+  [SMALL_STATE(216)] = 8611,
+  [SMALL_STATE(217)] = 8628,
+  [SMALL_STATE(218)] = 8645,
+  [SMALL_STATE(219)] = 8662,
+  [SMALL_STATE(220)] = 8679,
+  [SMALL_STATE(221)] = 8696,
+  [SMALL_STATE(222)] = 8707,
+  [SMALL_STATE(223)] = 8718,
+  [SMALL_STATE(224)] = 8731,
+  [SMALL_STATE(225)] = 8748,
+  [SMALL_STATE(226)] = 8759,
+  [SMALL_STATE(227)] = 8776,
+  [SMALL_STATE(228)] = 8787,
+  [SMALL_STATE(229)] = 8798,
+  [SMALL_STATE(230)] = 8813,
+  [SMALL_STATE(231)] = 8830,
+  [SMALL_STATE(232)] = 8841,
+  [SMALL_STATE(233)] = 8852,
+  [SMALL_STATE(234)] = 8869,
+  [SMALL_STATE(235)] = 8880,
+  [SMALL_STATE(236)] = 8891,
+  [SMALL_STATE(237)] = 8902,
+  [SMALL_STATE(238)] = 8913,
+  [SMALL_STATE(239)] = 8924,
+  [SMALL_STATE(240)] = 8941,
+  [SMALL_STATE(241)] = 8956,
+  [SMALL_STATE(242)] = 8967,
+  [SMALL_STATE(243)] = 8982,
+  [SMALL_STATE(244)] = 8993,
+  [SMALL_STATE(245)] = 9007,
+  [SMALL_STATE(246)] = 9021,
+  [SMALL_STATE(247)] = 9035,
+  [SMALL_STATE(248)] = 9049,
+  [SMALL_STATE(249)] = 9063,
+  [SMALL_STATE(250)] = 9077,
+  [SMALL_STATE(251)] = 9091,
+  [SMALL_STATE(252)] = 9105,
+  [SMALL_STATE(253)] = 9115,
+  [SMALL_STATE(254)] = 9129,
+  [SMALL_STATE(255)] = 9143,
+  [SMALL_STATE(256)] = 9155,
+  [SMALL_STATE(257)] = 9167,
+  [SMALL_STATE(258)] = 9181,
+  [SMALL_STATE(259)] = 9195,
+  [SMALL_STATE(260)] = 9205,
+  [SMALL_STATE(261)] = 9219,
+  [SMALL_STATE(262)] = 9233,
+  [SMALL_STATE(263)] = 9247,
+  [SMALL_STATE(264)] = 9261,
+  [SMALL_STATE(265)] = 9275,
+  [SMALL_STATE(266)] = 9289,
+  [SMALL_STATE(267)] = 9303,
+  [SMALL_STATE(268)] = 9317,
+  [SMALL_STATE(269)] = 9331,
+  [SMALL_STATE(270)] = 9345,
+  [SMALL_STATE(271)] = 9359,
+  [SMALL_STATE(272)] = 9373,
+  [SMALL_STATE(273)] = 9387,
+  [SMALL_STATE(274)] = 9401,
+  [SMALL_STATE(275)] = 9415,
+  [SMALL_STATE(276)] = 9429,
+  [SMALL_STATE(277)] = 9443,
+  [SMALL_STATE(278)] = 9454,
+  [SMALL_STATE(279)] = 9463,
+  [SMALL_STATE(280)] = 9474,
+  [SMALL_STATE(281)] = 9483,
+  [SMALL_STATE(282)] = 9494,
+  [SMALL_STATE(283)] = 9503,
+  [SMALL_STATE(284)] = 9512,
+  [SMALL_STATE(285)] = 9521,
+  [SMALL_STATE(286)] = 9530,
+  [SMALL_STATE(287)] = 9541,
+  [SMALL_STATE(288)] = 9550,
+  [SMALL_STATE(289)] = 9559,
+  [SMALL_STATE(290)] = 9568,
+  [SMALL_STATE(291)] = 9577,
+  [SMALL_STATE(292)] = 9586,
+  [SMALL_STATE(293)] = 9595,
+  [SMALL_STATE(294)] = 9604,
+  [SMALL_STATE(295)] = 9613,
+  [SMALL_STATE(296)] = 9622,
+  [SMALL_STATE(297)] = 9631,
+  [SMALL_STATE(298)] = 9642,
+  [SMALL_STATE(299)] = 9650,
+  [SMALL_STATE(300)] = 9658,
+  [SMALL_STATE(301)] = 9666,
+  [SMALL_STATE(302)] = 9674,
+  [SMALL_STATE(303)] = 9682,
+  [SMALL_STATE(304)] = 9690,
+  [SMALL_STATE(305)] = 9698,
+  [SMALL_STATE(306)] = 9706,
+  [SMALL_STATE(307)] = 9714,
+  [SMALL_STATE(308)] = 9722,
+  [SMALL_STATE(309)] = 9730,
+  [SMALL_STATE(310)] = 9738,
+  [SMALL_STATE(311)] = 9746,
+  [SMALL_STATE(312)] = 9754,
+  [SMALL_STATE(313)] = 9762,
+  [SMALL_STATE(314)] = 9770,
+  [SMALL_STATE(315)] = 9778,
+  [SMALL_STATE(316)] = 9786,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
@@ -7530,321 +10935,427 @@ static const TSParseActionEntry ts_parse_actions[] = {
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
   [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
   [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0, 0, 0),
-  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
   [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(12),
-  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
-  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
-  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(81),
-  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(85),
-  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(66),
-  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(91),
-  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(226),
-  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(215),
-  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(104),
-  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(123),
-  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
-  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
-  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
-  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
-  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
-  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
-  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
-  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
-  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
-  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
-  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
-  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
-  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
-  [63] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_list, 1, 0, 1),
-  [65] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_list, 1, 0, 1),
-  [67] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
-  [69] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_list, 2, 0, 4),
-  [71] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_list, 2, 0, 4),
-  [73] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 10),
-  [75] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 10),
-  [77] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 10), SHIFT_REPEAT(229),
-  [80] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 2, 0, 7),
-  [82] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
-  [84] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 2, 0, 7),
-  [86] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 5),
-  [88] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 5),
-  [90] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 1, 0, 3),
-  [92] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 1, 0, 3),
-  [94] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_op, 2, 0, 21),
-  [96] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unary_op, 2, 0, 21),
-  [98] = {.entry = {.count = 1, .reusable = false}}, SHIFT(221),
-  [100] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
-  [102] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
-  [104] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_op, 3, 0, 32),
-  [106] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_op, 3, 0, 32),
-  [108] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
-  [110] = {.entry = {.count = 1, .reusable = false}}, SHIFT(65),
-  [112] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
-  [114] = {.entry = {.count = 1, .reusable = false}}, SHIFT(60),
-  [116] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
-  [118] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
-  [120] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
-  [122] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__linebreak, 2, 0, 0),
-  [124] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__linebreak, 2, 0, 0),
-  [126] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__linebreak, 2, 0, 0), SHIFT_REPEAT(25),
-  [129] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 5),
-  [131] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 5),
-  [133] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 2, 0, 0),
-  [135] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 2, 0, 0),
-  [137] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 2, 0, 9),
-  [139] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 2, 0, 9),
-  [141] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 3, 0, 17),
-  [143] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 3, 0, 17),
-  [145] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 11),
-  [147] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 11),
-  [149] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 28),
-  [151] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 28),
-  [153] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 28),
-  [155] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 28),
-  [157] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_bracket_expression, 3, 0, 24),
-  [159] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_bracket_expression, 3, 0, 24),
-  [161] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 3, 0, 0),
-  [163] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 3, 0, 0),
-  [165] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 3, 0, 5),
-  [167] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 3, 0, 5),
-  [169] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 38),
-  [171] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 38),
-  [173] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 11),
-  [175] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 11),
-  [177] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 6, 0, 38),
-  [179] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 6, 0, 38),
-  [181] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_call, 2, 0, 23),
-  [183] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_func_call, 2, 0, 23),
-  [185] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_op, 2, 0, 8),
-  [187] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_op, 2, 0, 8),
-  [189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 2, 0, 0),
-  [191] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 2, 0, 0),
-  [193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_access, 3, 0, 33),
-  [195] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_access, 3, 0, 33),
-  [197] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 3, 0, 5),
-  [199] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 3, 0, 5),
-  [201] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 4, 0, 11),
-  [203] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 4, 0, 11),
-  [205] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression, 3, 0, 24),
-  [207] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression, 3, 0, 24),
-  [209] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
-  [211] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_latency_specifier, 2, 0, 24),
-  [213] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_latency_specifier, 2, 0, 24),
-  [215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
-  [217] = {.entry = {.count = 1, .reusable = false}}, SHIFT(63),
-  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
-  [221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
-  [223] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
-  [225] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_to, 2, 0, 22),
-  [227] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_assign_to, 2, 0, 22),
-  [229] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_to, 1, 0, 14),
-  [231] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_assign_to, 1, 0, 14),
-  [233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type, 1, 0, 0),
-  [235] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression, 1, 0, 0),
-  [237] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expression, 1, 0, 0),
-  [239] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym__type, 1, 0, 0), REDUCE(sym__expression, 1, 0, 0),
-  [242] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 3, 0, 34),
-  [244] = {.entry = {.count = 1, .reusable = false}}, SHIFT(110),
-  [246] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
-  [248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [250] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
-  [252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
-  [254] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_decl_assign_statement, 3, 0, 31),
-  [256] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 5),
-  [258] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
-  [260] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
-  [262] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
-  [264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
-  [266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
-  [268] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
-  [272] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
-  [274] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
-  [276] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
-  [278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [280] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
-  [282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
-  [284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
-  [286] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
-  [288] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
-  [290] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
-  [292] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
-  [294] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__comma, 2, 0, 0),
-  [296] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__comma, 2, 0, 0),
-  [298] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__comma, 1, 0, 0),
-  [300] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__comma, 1, 0, 0),
-  [302] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
-  [304] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 10),
-  [306] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 10), SHIFT_REPEAT(81),
-  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 10),
-  [311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_write_modifiers, 1, 0, 15),
-  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_write_modifiers, 1, 0, 15),
-  [315] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 1, 0, 1),
-  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_write_modifiers_repeat1, 1, 0, 1),
-  [319] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
-  [321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
-  [323] = {.entry = {.count = 1, .reusable = false}}, SHIFT(103),
-  [325] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
-  [327] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
-  [329] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_write_modifiers, 1, 0, 1),
-  [331] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_write_modifiers, 1, 0, 1),
-  [333] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
-  [335] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
-  [337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 1),
-  [339] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
-  [341] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 4, 0, 11),
-  [343] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
-  [345] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 4),
-  [347] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 5),
-  [349] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__linebreak, 2, 0, 0), SHIFT_REPEAT(98),
-  [352] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 3, 0, 26),
-  [354] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
-  [356] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 4, 0, 35),
-  [358] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 2, 0, 19),
-  [360] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 3, 0, 25),
-  [362] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_type, 1, 0, 13),
-  [364] = {.entry = {.count = 1, .reusable = false}}, SHIFT(114),
-  [366] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 4, 0, 36),
-  [368] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 3, 0, 27),
-  [370] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 4, 0, 37),
-  [372] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 5, 0, 42),
-  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
-  [376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
-  [378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
-  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
-  [382] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 10),
-  [384] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 10), SHIFT_REPEAT(78),
-  [387] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration_list, 1, 0, 1),
-  [389] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
-  [391] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
-  [393] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
-  [395] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
-  [397] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_left_side, 1, 0, 1),
-  [399] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
-  [401] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
-  [403] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
-  [405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
-  [407] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_left_side, 2, 0, 4),
-  [409] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration_list, 2, 0, 4),
-  [411] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
-  [413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
-  [415] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
-  [417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
-  [419] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 10),
-  [421] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 10), SHIFT_REPEAT(78),
-  [424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
-  [426] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
-  [428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [430] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
-  [432] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
-  [434] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
-  [436] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
-  [438] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 10),
-  [440] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 10), SHIFT_REPEAT(78),
-  [443] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 4, 0, 41),
-  [445] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 10),
-  [447] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 10), SHIFT_REPEAT(78),
-  [450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
-  [452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
-  [454] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_type, 2, 0, 8),
-  [456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
-  [458] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 28),
-  [460] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 5),
-  [462] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 11),
-  [464] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 10),
-  [466] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 10), SHIFT_REPEAT(10),
-  [469] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 2, 0, 20),
-  [471] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
-  [473] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 5),
-  [475] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 1),
-  [477] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
-  [479] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 5),
-  [481] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 4),
-  [483] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
-  [485] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
-  [487] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
-  [489] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
-  [491] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [493] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 5),
-  [495] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
-  [497] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 28),
-  [499] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10),
-  [501] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(97),
-  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
-  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
-  [508] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
-  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
-  [512] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 11),
-  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
-  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
-  [518] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 0),
-  [520] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 1, 0, 13),
-  [522] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
-  [524] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 2, 0, 40),
-  [526] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 11),
-  [528] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
-  [530] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
-  [532] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 38),
-  [534] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
-  [536] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 47),
-  [538] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 10),
-  [540] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 10), SHIFT_REPEAT(78),
-  [543] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 6, 0, 38),
-  [545] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 5),
-  [547] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 3, 0, 29),
-  [549] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
-  [551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
-  [553] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
-  [555] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
-  [557] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 5),
-  [559] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
-  [561] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
-  [563] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
-  [565] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
-  [567] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 5),
-  [569] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
-  [571] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
-  [573] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
-  [575] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_const_and_type, 2, 0, 2),
-  [577] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
-  [579] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
-  [581] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 5),
-  [583] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 4, 0, 16),
-  [585] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 5, 0, 18),
-  [587] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 3, 0, 30),
-  [589] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_domain_statement, 2, 0, 20),
-  [591] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 5, 0, 43),
-  [593] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__interface_ports_output, 2, 0, 44),
-  [595] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 45),
-  [597] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 2, 0, 39),
-  [599] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 46),
-  [601] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 4, 0, 12),
-  [603] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 3, 0, 6),
-  [605] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5),
-  [607] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__interface_ports_output, 3, 0, 48),
-  [609] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 4, 0, 49),
-  [611] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_statement, 7, 0, 50),
-  [613] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 5),
-  [615] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
-  [617] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 3, 0, 0),
-  [619] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
-  [621] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 5, 0, 38),
-  [623] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 5, 0, 11),
-  [625] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 6, 0, 38),
-  [627] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 2, 0, 0),
-  [629] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 5, 0, 28),
-  [631] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
-  [633] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 28),
-  [635] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
-  [637] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
-  [639] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [641] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
-  [643] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 11),
-  [645] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 3, 0, 5),
-  [647] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(145),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(146),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(72),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(160),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(303),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(304),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(164),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(176),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [65] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [67] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_list, 1, 0, 4),
+  [69] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_list, 1, 0, 4),
+  [71] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [73] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_list, 2, 0, 3),
+  [75] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_list, 2, 0, 3),
+  [77] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 52),
+  [79] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 52),
+  [81] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 52), SHIFT_REPEAT(301),
+  [84] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 1, 0, 49),
+  [86] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [88] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 1, 0, 49),
+  [90] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 2, 0, 47),
+  [92] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 2, 0, 47),
+  [94] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 2),
+  [96] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 2),
+  [98] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_op, 2, 0, 40),
+  [100] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unary_op, 2, 0, 40),
+  [102] = {.entry = {.count = 1, .reusable = false}}, SHIFT(307),
+  [104] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [106] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [108] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [110] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [112] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [114] = {.entry = {.count = 1, .reusable = false}}, SHIFT(81),
+  [116] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [118] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [120] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [122] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [124] = {.entry = {.count = 1, .reusable = false}}, SHIFT(82),
+  [126] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [128] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [130] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [132] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [134] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [136] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [138] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [140] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [142] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [144] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_op, 3, 0, 41),
+  [146] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 5, 0, 42),
+  [148] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 5, 0, 42),
+  [150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [152] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
+  [154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [158] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 2, 0, 48),
+  [160] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 2, 0, 48),
+  [162] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 3, 0, 46),
+  [164] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 3, 0, 46),
+  [166] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 2, 0, 0),
+  [168] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 2, 0, 0),
+  [170] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_op, 2, 0, 39),
+  [172] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_op, 2, 0, 39),
+  [174] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_call, 2, 0, 43),
+  [176] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_func_call, 2, 0, 43),
+  [178] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_bracket_expression, 3, 0, 38),
+  [180] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_bracket_expression, 3, 0, 38),
+  [182] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 3, 0, 0),
+  [184] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 3, 0, 0),
+  [186] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 3, 0, 2),
+  [188] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 3, 0, 2),
+  [190] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_concatenation, 3, 0, 2),
+  [192] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_concatenation, 3, 0, 2),
+  [194] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_access, 3, 0, 44),
+  [196] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_access, 3, 0, 44),
+  [198] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 2, 0, 0),
+  [200] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 2, 0, 0),
+  [202] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression, 3, 0, 38),
+  [204] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression, 3, 0, 38),
+  [206] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 11),
+  [208] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 11),
+  [210] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 1),
+  [212] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 1),
+  [214] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 2),
+  [216] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 2),
+  [218] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_concatenation, 4, 0, 11),
+  [220] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_concatenation, 4, 0, 11),
+  [222] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_concatenation, 4, 0, 1),
+  [224] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_concatenation, 4, 0, 1),
+  [226] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_concatenation, 4, 0, 2),
+  [228] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_concatenation, 4, 0, 2),
+  [230] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 3, 0, 2),
+  [232] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 3, 0, 2),
+  [234] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 10),
+  [236] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 10),
+  [238] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 11),
+  [240] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 11),
+  [242] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 1),
+  [244] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 1),
+  [246] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_concatenation, 5, 0, 10),
+  [248] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_concatenation, 5, 0, 10),
+  [250] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_concatenation, 5, 0, 11),
+  [252] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_concatenation, 5, 0, 11),
+  [254] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_concatenation, 5, 0, 1),
+  [256] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_concatenation, 5, 0, 1),
+  [258] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 4, 0, 1),
+  [260] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 4, 0, 1),
+  [262] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 6, 0, 10),
+  [264] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 6, 0, 10),
+  [266] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_concatenation, 6, 0, 10),
+  [268] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_concatenation, 6, 0, 10),
+  [270] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_slice_expression, 5, 0, 45),
+  [272] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_slice_expression, 5, 0, 45),
+  [274] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_to, 1, 0, 15),
+  [276] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_assign_to, 1, 0, 15),
+  [278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [280] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__linebreak, 2, 0, 0),
+  [282] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__linebreak, 2, 0, 0),
+  [284] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__linebreak, 2, 0, 0), SHIFT_REPEAT(58),
+  [287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [289] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
+  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_latency_specifier, 2, 0, 38),
+  [299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_latency_specifier, 2, 0, 38),
+  [301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [303] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [307] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_to, 2, 0, 14),
+  [309] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_assign_to, 2, 0, 14),
+  [311] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [313] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [315] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 2),
+  [317] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [319] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [323] = {.entry = {.count = 1, .reusable = false}}, SHIFT(189),
+  [325] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 3, 0, 51),
+  [327] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [329] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [331] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type, 1, 0, 0),
+  [333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression, 1, 0, 0),
+  [335] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expression, 1, 0, 0),
+  [337] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym__type, 1, 0, 0), REDUCE(sym__expression, 1, 0, 0),
+  [340] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [342] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [350] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [352] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [354] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [364] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [368] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_decl_assign_statement, 3, 0, 13),
+  [370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(297),
+  [390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [398] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [400] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [420] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [422] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 52), SHIFT_REPEAT(316),
+  [425] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [427] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [429] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [431] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [433] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [435] = {.entry = {.count = 1, .reusable = false}}, SHIFT(101),
+  [437] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [439] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [441] = {.entry = {.count = 1, .reusable = false}}, SHIFT(96),
+  [443] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [445] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [447] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [449] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__comma, 1, 0, 0),
+  [451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__comma, 1, 0, 0),
+  [453] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [455] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__comma, 2, 0, 0),
+  [457] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__comma, 2, 0, 0),
+  [459] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_write_modifiers, 1, 0, 16),
+  [461] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_write_modifiers, 1, 0, 16),
+  [463] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 52),
+  [465] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 52),
+  [467] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 52), SHIFT_REPEAT(145),
+  [470] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 1, 0, 4),
+  [472] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_write_modifiers_repeat1, 1, 0, 4),
+  [474] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_write_modifiers, 1, 0, 4),
+  [476] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_write_modifiers, 1, 0, 4),
+  [478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [482] = {.entry = {.count = 1, .reusable = false}}, SHIFT(163),
+  [484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [492] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
+  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [496] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__linebreak, 2, 0, 0), SHIFT_REPEAT(155),
+  [499] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 4),
+  [501] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 2),
+  [503] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 3),
+  [505] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 4, 0, 1),
+  [507] = {.entry = {.count = 1, .reusable = false}}, SHIFT(169),
+  [509] = {.entry = {.count = 1, .reusable = false}}, SHIFT(196),
+  [511] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_type, 1, 0, 12),
+  [513] = {.entry = {.count = 1, .reusable = false}}, SHIFT(181),
+  [515] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 2, 0, 37),
+  [517] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [519] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 3, 0, 33),
+  [521] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 3, 0, 35),
+  [523] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 4, 0, 31),
+  [525] = {.entry = {.count = 1, .reusable = false}}, SHIFT(199),
+  [527] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 3, 0, 36),
+  [529] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 4, 0, 32),
+  [531] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 4, 0, 34),
+  [533] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 5, 0, 30),
+  [535] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [537] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [539] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_left_side, 1, 0, 4),
+  [541] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [543] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [545] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
+  [547] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [549] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [553] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_left_side, 2, 0, 3),
+  [555] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [557] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
+  [559] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [561] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [563] = {.entry = {.count = 1, .reusable = true}}, SHIFT(312),
+  [565] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [569] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
+  [571] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 52),
+  [573] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 52), SHIFT_REPEAT(128),
+  [576] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
+  [580] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [584] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 52),
+  [586] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 52), SHIFT_REPEAT(128),
+  [589] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration_list, 1, 0, 4),
+  [591] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration_list, 2, 0, 3),
+  [593] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 52),
+  [595] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 52), SHIFT_REPEAT(128),
+  [598] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [600] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
+  [602] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [608] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [610] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [612] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
+  [614] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_type, 2, 0, 39),
+  [616] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
+  [618] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [620] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
+  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [628] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [634] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 52),
+  [636] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 52), SHIFT_REPEAT(128),
+  [639] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 52),
+  [641] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 52), SHIFT_REPEAT(128),
+  [644] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [646] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 4, 0, 50),
+  [648] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [652] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [654] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [656] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 4),
+  [658] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [660] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
+  [662] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 2),
+  [664] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [666] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 3),
+  [668] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [670] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [672] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 1),
+  [674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [676] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 52),
+  [678] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 52), SHIFT_REPEAT(162),
+  [681] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
+  [683] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 1, 0, 12),
+  [685] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [687] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 0),
+  [689] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [691] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [693] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 2),
+  [695] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 2, 0, 20),
+  [697] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [699] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [701] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [703] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 11),
+  [705] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 1),
+  [707] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 52),
+  [709] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 52), SHIFT_REPEAT(10),
+  [712] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 2),
+  [714] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 2),
+  [716] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 10),
+  [718] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 11),
+  [720] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 1),
+  [722] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [724] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 2, 0, 26),
+  [726] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 6, 0, 10),
+  [728] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 23),
+  [730] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 2),
+  [732] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_const_and_type, 2, 0, 9),
+  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [738] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [740] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
+  [742] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [744] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [746] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
+  [748] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 2),
+  [750] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [752] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [754] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 2),
+  [756] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 3, 0, 18),
+  [758] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [762] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [764] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 2),
+  [766] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [768] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [770] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [772] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [774] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [776] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [778] = {.entry = {.count = 1, .reusable = true}}, SHIFT(265),
+  [780] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
+  [782] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [784] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [786] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
+  [788] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [790] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [792] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [794] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 2),
+  [796] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 3, 0, 8),
+  [798] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 4, 0, 6),
+  [800] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 4, 0, 7),
+  [802] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 5, 0, 5),
+  [804] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_domain_statement, 2, 0, 20),
+  [806] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 3, 0, 21),
+  [808] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 2, 0, 27),
+  [810] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 5, 0, 17),
+  [812] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 24),
+  [814] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 25),
+  [816] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__interface_ports_output, 2, 0, 29),
+  [818] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 4, 0, 22),
+  [820] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__interface_ports_output, 3, 0, 28),
+  [822] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_statement, 7, 0, 19),
+  [824] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [826] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
+  [828] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [830] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [832] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 2, 0, 0),
+  [834] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [836] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [838] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 3, 0, 0),
+  [840] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 3, 0, 2),
+  [842] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [844] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [846] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 11),
+  [848] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 1),
+  [850] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 2),
+  [852] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 5, 0, 10),
+  [854] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 5, 0, 11),
+  [856] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 5, 0, 1),
+  [858] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 6, 0, 10),
+  [860] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
 };
 
 #ifdef __cplusplus